@@ -6,9 +6,15 @@
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin as SystemOrigin;
+use frame_support::{traits::OriginTrait, weights::Weight, BoundedVec};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin as SystemOrigin};
 
-use sp_std::vec;
+use sp_std::{boxed::Box, vec, vec::Vec};
+use xcm::latest::prelude::*;
+
+fn root_caller<T: Config>() -> T::PalletsOrigin {
+	<T as frame_system::Config>::RuntimeOrigin::root().into_caller().into()
+}
 
 #[benchmarks]
 mod benchmarks {
@@ -22,8 +28,257 @@ mod benchmarks {
 		grant_access(SystemOrigin::Root, 100, b"grant_access".to_vec(), caller.clone());
 
 		assert_eq!(
-			ExtrinsicAccess::<T>::get(&(100, b"grant_access".to_vec())),
-			Some(caller).into(),
+			ExtrinsicAccess::<T>::get(
+				&(100, b"grant_access".to_vec()),
+				AccessOrigin::Signed(caller.clone())
+			),
+			Some(None),
+		);
+	}
+
+	#[benchmark]
+	fn grant_access_until() {
+		let caller: <T as frame_system::Config>::AccountId = whitelisted_caller();
+		let expires_at: BlockNumberFor<T> = 100u32.into();
+
+		#[extrinsic_call]
+		grant_access_until(
+			SystemOrigin::Root,
+			100,
+			b"grant_access".to_vec(),
+			caller.clone(),
+			expires_at,
+		);
+
+		assert_eq!(
+			ExtrinsicAccess::<T>::get(
+				&(100, b"grant_access".to_vec()),
+				AccessOrigin::Signed(caller.clone())
+			),
+			Some(Some(expires_at)),
+		);
+	}
+
+	#[benchmark]
+	fn revoke_access() {
+		let caller: <T as frame_system::Config>::AccountId = whitelisted_caller();
+		ExtrinsicAccess::<T>::insert(
+			&(100, b"grant_access".to_vec()),
+			AccessOrigin::Signed(caller.clone()),
+			None::<BlockNumberFor<T>>,
 		);
+
+		#[extrinsic_call]
+		revoke_access(SystemOrigin::Root, 100, b"grant_access".to_vec(), caller.clone());
+
+		assert!(!ExtrinsicAccess::<T>::contains_key(
+			&(100, b"grant_access".to_vec()),
+			AccessOrigin::Signed(caller.clone())
+		));
+	}
+
+	#[benchmark]
+	fn remove_expired_access() {
+		let caller: <T as frame_system::Config>::AccountId = whitelisted_caller();
+		let expires_at: BlockNumberFor<T> = 1u32.into();
+		ExtrinsicAccess::<T>::insert(
+			&(100, b"grant_access".to_vec()),
+			AccessOrigin::Signed(caller.clone()),
+			Some(expires_at),
+		);
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+
+		#[extrinsic_call]
+		remove_expired_access(
+			SystemOrigin::Signed(caller.clone()),
+			100,
+			b"grant_access".to_vec(),
+			caller.clone(),
+		);
+
+		assert!(!ExtrinsicAccess::<T>::contains_key(
+			&(100, b"grant_access".to_vec()),
+			AccessOrigin::Signed(caller.clone())
+		));
+	}
+
+	#[benchmark]
+	fn create_role(n: Linear<1, 32>) {
+		let extrinsics: Vec<(u8, Vec<u8>)> =
+			T::Extrinsics::get().into_iter().cycle().take(n as usize).collect();
+
+		#[extrinsic_call]
+		create_role(SystemOrigin::Root, b"Pauser".to_vec(), extrinsics.clone());
+
+		assert_eq!(Roles::<T>::get(b"Pauser".to_vec()), Some(extrinsics));
+	}
+
+	#[benchmark]
+	fn update_role(n: Linear<1, 32>) {
+		let extrinsics: Vec<(u8, Vec<u8>)> =
+			T::Extrinsics::get().into_iter().cycle().take(n as usize).collect();
+		Roles::<T>::insert(b"Pauser".to_vec(), extrinsics.clone());
+
+		#[extrinsic_call]
+		update_role(SystemOrigin::Root, b"Pauser".to_vec(), extrinsics.clone());
+
+		assert_eq!(Roles::<T>::get(b"Pauser".to_vec()), Some(extrinsics));
+	}
+
+	#[benchmark]
+	fn assign_role() {
+		let caller: <T as frame_system::Config>::AccountId = whitelisted_caller();
+		Roles::<T>::insert(b"Pauser".to_vec(), T::Extrinsics::get());
+		// Worst case, the account holds all roles but one
+		let roles: Vec<Vec<u8>> =
+			(1..T::MaxRolesPerAccount::get()).map(|i| i.to_le_bytes().to_vec()).collect();
+		AccountRoles::<T>::insert(&caller, BoundedVec::truncate_from(roles));
+
+		#[extrinsic_call]
+		assign_role(SystemOrigin::Root, b"Pauser".to_vec(), caller.clone());
+
+		assert!(AccountRoles::<T>::get(&caller).contains(&b"Pauser".to_vec()));
+	}
+
+	#[benchmark]
+	fn unassign_role() {
+		let caller: <T as frame_system::Config>::AccountId = whitelisted_caller();
+		Roles::<T>::insert(b"Pauser".to_vec(), T::Extrinsics::get());
+		let mut roles: Vec<Vec<u8>> =
+			(1..T::MaxRolesPerAccount::get()).map(|i| i.to_le_bytes().to_vec()).collect();
+		roles.push(b"Pauser".to_vec());
+		AccountRoles::<T>::insert(&caller, BoundedVec::truncate_from(roles));
+
+		#[extrinsic_call]
+		unassign_role(SystemOrigin::Root, b"Pauser".to_vec(), caller.clone());
+
+		assert!(!AccountRoles::<T>::get(&caller).contains(&b"Pauser".to_vec()));
+	}
+
+	#[benchmark]
+	fn set_extrinsic_delay() {
+		let delay: BlockNumberFor<T> = 100u32.into();
+		let (pallet_index, extrinsic_name) =
+			T::Extrinsics::get().first().cloned().expect("no registered extrinsic");
+
+		#[extrinsic_call]
+		set_extrinsic_delay(SystemOrigin::Root, pallet_index, extrinsic_name.clone(), delay);
+
+		assert_eq!(ExtrinsicDelays::<T>::get((pallet_index, extrinsic_name)), Some(delay));
+	}
+
+	#[benchmark]
+	fn schedule_action() {
+		let caller: <T as frame_system::Config>::AccountId = whitelisted_caller();
+		ExtrinsicDelays::<T>::insert(
+			(T::PalletIndex::get(), b"grant_access".to_vec()),
+			BlockNumberFor::<T>::from(100u32),
+		);
+		let call: <T as Config>::RuntimeCall = Call::<T>::grant_access {
+			pallet_index: 100,
+			extrinsic_name: b"grant_access".to_vec(),
+			who: caller,
+		}
+		.into();
+
+		#[extrinsic_call]
+		schedule_action(SystemOrigin::Root, Box::new(call));
+
+		assert!(PendingActions::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn cancel_action() {
+		let caller: <T as frame_system::Config>::AccountId = whitelisted_caller();
+		let call: <T as Config>::RuntimeCall = Call::<T>::grant_access {
+			pallet_index: 100,
+			extrinsic_name: b"grant_access".to_vec(),
+			who: caller,
+		}
+		.into();
+		PendingActions::<T>::insert(
+			0,
+			PendingAction { call, origin: root_caller::<T>(), executable_at: 100u32.into() },
+		);
+
+		#[extrinsic_call]
+		cancel_action(SystemOrigin::Root, 0);
+
+		assert!(!PendingActions::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn execute_action() {
+		let caller: <T as frame_system::Config>::AccountId = whitelisted_caller();
+		ExtrinsicDelays::<T>::insert(
+			(T::PalletIndex::get(), b"grant_access".to_vec()),
+			BlockNumberFor::<T>::from(1u32),
+		);
+		let call: <T as Config>::RuntimeCall = Call::<T>::grant_access {
+			pallet_index: 100,
+			extrinsic_name: b"grant_access".to_vec(),
+			who: caller.clone(),
+		}
+		.into();
+		PendingActions::<T>::insert(
+			0,
+			PendingAction { call, origin: root_caller::<T>(), executable_at: 1u32.into() },
+		);
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+
+		#[extrinsic_call]
+		execute_action(SystemOrigin::Signed(caller.clone()), 0, Weight::MAX);
+
+		assert!(!PendingActions::<T>::contains_key(0));
+		assert_eq!(
+			ExtrinsicAccess::<T>::get(
+				&(100, b"grant_access".to_vec()),
+				AccessOrigin::Signed(caller.clone())
+			),
+			Some(None),
+		);
+	}
+
+	#[benchmark]
+	fn grant_origin_access() {
+		let extrinsic = (100, b"grant_access".to_vec());
+		let max = T::MaxCollectiveGrants::get();
+		let grants: Vec<AccessOrigin<T::AccountId>> = (1..max)
+			.map(|yes| AccessOrigin::Collective { pallet_index: 1, yes, total: max })
+			.collect();
+		CollectiveGrants::<T>::insert(&extrinsic, BoundedVec::truncate_from(grants));
+		let collective = AccessOrigin::Collective { pallet_index: 1, yes: max, total: max };
+
+		#[extrinsic_call]
+		grant_origin_access(
+			SystemOrigin::Root,
+			100,
+			b"grant_access".to_vec(),
+			collective.clone(),
+			None,
+		);
+
+		assert_eq!(ExtrinsicAccess::<T>::get(&extrinsic, collective), Some(None));
+		assert_eq!(CollectiveGrants::<T>::get(&extrinsic).len() as u32, max);
+	}
+
+	#[benchmark]
+	fn revoke_origin_access() {
+		let extrinsic = (100, b"grant_access".to_vec());
+		let max = T::MaxCollectiveGrants::get();
+		let grants: Vec<AccessOrigin<T::AccountId>> = (1..=max)
+			.map(|yes| AccessOrigin::Collective { pallet_index: 1, yes, total: max })
+			.collect();
+		for granted in grants.iter() {
+			ExtrinsicAccess::<T>::insert(&extrinsic, granted, None::<BlockNumberFor<T>>);
+		}
+		CollectiveGrants::<T>::insert(&extrinsic, BoundedVec::truncate_from(grants));
+		let collective = AccessOrigin::Collective { pallet_index: 1, yes: max, total: max };
+
+		#[extrinsic_call]
+		revoke_origin_access(SystemOrigin::Root, 100, b"grant_access".to_vec(), collective.clone());
+
+		assert!(!ExtrinsicAccess::<T>::contains_key(&extrinsic, collective));
+		assert_eq!(CollectiveGrants::<T>::get(&extrinsic).len() as u32, max - 1);
 	}
 }
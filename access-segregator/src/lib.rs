@@ -7,6 +7,7 @@ pub use self::pallet::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migration;
 pub mod weights;
 pub use weights::*;
 
@@ -17,21 +18,178 @@ mod mock;
 #[allow(clippy::large_enum_variant)]
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::{dispatch::DispatchResult, pallet_prelude::*, traits::StorageVersion};
-	use frame_system::pallet_prelude::*;
-	use sp_std::vec::Vec;
+	use frame_support::{
+		dispatch::{
+			extract_actual_weight, DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo,
+			PostDispatchInfo,
+		},
+		pallet_prelude::*,
+		sp_runtime::traits::{Dispatchable, Saturating, Zero},
+		traits::{GetCallMetadata, IsSubType, OriginTrait, StorageVersion},
+	};
+	use frame_system::{pallet_prelude::*, RawOrigin};
+	use sp_std::{boxed::Box, vec::Vec};
+	use xcm::latest::MultiLocation;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
-	/// Mapping signature of extrinsic to account has access
-	/// (pallet_index, extrinsic_name) => account
+	/// Origin extrinsic access can be granted to
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum AccessOrigin<AccountId> {
+		/// Signed account
+		Signed(AccountId),
+		/// XCM origin from a location, e.g. a sibling governance parachain
+		Xcm(MultiLocation),
+		/// Collective origin of the pallet at `pallet_index`. When granted, the motion must be
+		/// approved by at least `yes` of `total` members, when converted from an origin, `yes` of
+		/// `total` members approved the motion.
+		Collective { pallet_index: u8, yes: u32, total: u32 },
+	}
+
+	impl<AccountId: PartialEq> AccessOrigin<AccountId> {
+		/// Return true if the origin matches the origin access is granted to
+		pub fn satisfies(&self, granted: &Self) -> bool {
+			match (self, granted) {
+				(
+					AccessOrigin::Collective { pallet_index, yes, total },
+					AccessOrigin::Collective {
+						pallet_index: granted_pallet_index,
+						yes: granted_yes,
+						total: granted_total,
+					},
+				) =>
+					pallet_index == granted_pallet_index
+						&& (*yes as u64) * (*granted_total as u64)
+							>= (*granted_yes as u64) * (*total as u64),
+				_ => self == granted,
+			}
+		}
+	}
+
+	/// Converts a runtime origin to the origin extrinsic access can be granted to
+	pub trait ToAccessOrigin<RuntimeOrigin, AccountId> {
+		fn to_access_origin(origin: RuntimeOrigin) -> Option<AccessOrigin<AccountId>>;
+	}
+
+	/// Converts signed origins only
+	pub struct SignedAccessOrigin;
+
+	impl<O: Into<Result<RawOrigin<AccountId>, O>>, AccountId> ToAccessOrigin<O, AccountId>
+		for SignedAccessOrigin
+	{
+		fn to_access_origin(origin: O) -> Option<AccessOrigin<AccountId>> {
+			match origin.into() {
+				Ok(RawOrigin::Signed(who)) => Some(AccessOrigin::Signed(who)),
+				_ => None,
+			}
+		}
+	}
+
+	/// Delayed call waiting to be executed
+	#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct PendingAction<PalletsOrigin, BlockNumber, Call> {
+		/// The call to dispatch
+		pub call: Call,
+		/// Origin scheduled the call, the call is dispatched from it
+		pub origin: PalletsOrigin,
+		/// The block since when the call can be executed
+		pub executable_at: BlockNumber,
+	}
+
+	/// Mapping signature of extrinsic to origins have access and the block their access
+	/// expires at, None if the access never expires
+	/// (pallet_index, extrinsic_name), origin => expires_at
 	#[pallet::storage]
 	#[pallet::getter(fn extrinsic_access)]
 	#[pallet::unbounded]
-	pub type ExtrinsicAccess<T: Config> = StorageMap<_, Twox64Concat, (u8, Vec<u8>), T::AccountId>;
+	pub type ExtrinsicAccess<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		(u8, Vec<u8>),
+		Blake2_128Concat,
+		AccessOrigin<T::AccountId>,
+		Option<BlockNumberFor<T>>,
+	>;
+
+	/// Mapping role name to the extrinsics the role has access to
+	/// role => [(pallet_index, extrinsic_name)]
+	#[pallet::storage]
+	#[pallet::getter(fn roles)]
+	#[pallet::unbounded]
+	pub type Roles<T: Config> = StorageMap<_, Blake2_128Concat, Vec<u8>, Vec<(u8, Vec<u8>)>>;
+
+	/// Roles assigned to accounts, bounds the roles checked for a signed caller
+	/// account => [role]
+	#[pallet::storage]
+	#[pallet::getter(fn account_roles)]
+	#[pallet::unbounded]
+	pub type AccountRoles<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<Vec<u8>, T::MaxRolesPerAccount>,
+		ValueQuery,
+	>;
+
+	/// Mapping signature of extrinsic to the number of blocks its calls are delayed for,
+	/// extrinsics without a delay take effect immediately
+	/// (pallet_index, extrinsic_name) => delay
+	#[pallet::storage]
+	#[pallet::getter(fn extrinsic_delays)]
+	#[pallet::unbounded]
+	pub type ExtrinsicDelays<T: Config> =
+		StorageMap<_, Twox64Concat, (u8, Vec<u8>), BlockNumberFor<T>>;
+
+	/// Id of the next scheduled action
+	#[pallet::storage]
+	pub type NextActionId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Scheduled actions waiting for their delay to pass
+	/// action_id => PendingAction
+	#[pallet::storage]
+	#[pallet::getter(fn pending_actions)]
+	#[pallet::unbounded]
+	pub type PendingActions<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		u64,
+		PendingAction<T::PalletsOrigin, BlockNumberFor<T>, <T as Config>::RuntimeCall>,
+	>;
+
+	/// Collective origins granted access to an extrinsic, bounds the grants checked for a
+	/// collective caller
+	/// (pallet_index, extrinsic_name) => [collective_origin]
+	#[pallet::storage]
+	#[pallet::getter(fn collective_grants)]
+	#[pallet::unbounded]
+	pub type CollectiveGrants<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		(u8, Vec<u8>),
+		BoundedVec<AccessOrigin<T::AccountId>, T::MaxCollectiveGrants>,
+		ValueQuery,
+	>;
+
+	/// Signature of the delayed extrinsic being executed
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type ExecutingAction<T: Config> = StorageValue<_, (u8, Vec<u8>)>;
 
 	pub trait WeightInfo {
 		fn grant_access() -> Weight;
+		fn grant_access_until() -> Weight;
+		fn revoke_access() -> Weight;
+		fn remove_expired_access() -> Weight;
+		fn create_role(n: u32) -> Weight;
+		fn update_role(n: u32) -> Weight;
+		fn assign_role() -> Weight;
+		fn unassign_role() -> Weight;
+		fn set_extrinsic_delay() -> Weight;
+		fn schedule_action() -> Weight;
+		fn cancel_action() -> Weight;
+		fn execute_action() -> Weight;
+		fn grant_origin_access() -> Weight;
+		fn revoke_origin_access() -> Weight;
 	}
 
 	#[pallet::pallet]
@@ -52,6 +210,35 @@ pub mod pallet {
 		/// List of (pallet_index, extrinsic_name)
 		type Extrinsics: Get<Vec<(u8, Vec<u8>)>>;
 
+		/// Converts origins to the origins extrinsic access is granted to
+		type AccessOriginConverter: ToAccessOrigin<Self::RuntimeOrigin, Self::AccountId>;
+
+		/// Max number of collective origins granted access to a single extrinsic, checking the
+		/// access of a collective origin reads at most this many grants
+		#[pallet::constant]
+		type MaxCollectiveGrants: Get<u32>;
+
+		/// Max number of roles assigned to a single account, checking the role access of an
+		/// account reads at most this many roles
+		#[pallet::constant]
+		type MaxRolesPerAccount: Get<u32>;
+
+		/// The call held by scheduled actions, the first byte of its encoding must be the pallet
+		/// index
+		type RuntimeCall: Parameter
+			+ Dispatchable<
+				RuntimeOrigin = <Self as frame_system::Config>::RuntimeOrigin,
+				PostInfo = PostDispatchInfo,
+			> + GetDispatchInfo
+			+ GetCallMetadata
+			+ From<Call<Self>>
+			+ IsSubType<Call<Self>>;
+
+		/// The caller origin of the runtime, scheduled actions are dispatched from it
+		type PalletsOrigin: Parameter
+			+ From<<<Self as frame_system::Config>::RuntimeOrigin as OriginTrait>::PalletsOrigin>
+			+ Into<<Self as frame_system::Config>::RuntimeOrigin>;
+
 		/// Type representing the weight of this pallet
 		type WeightInfo: WeightInfo;
 	}
@@ -62,6 +249,64 @@ pub mod pallet {
 		/// Extrinsic access grant to someone
 		/// args: [pallet_index, extrinsic_name, who]
 		AccessGranted { pallet_index: u8, extrinsic_name: Vec<u8>, who: T::AccountId },
+		/// Extrinsic access grant to someone until a block
+		/// args: [pallet_index, extrinsic_name, who, expires_at]
+		AccessGrantedUntil {
+			pallet_index: u8,
+			extrinsic_name: Vec<u8>,
+			who: T::AccountId,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// Extrinsic access revoked from someone
+		/// args: [pallet_index, extrinsic_name, who]
+		AccessRevoked { pallet_index: u8, extrinsic_name: Vec<u8>, who: T::AccountId },
+		/// Expired extrinsic access of someone removed
+		/// args: [pallet_index, extrinsic_name, who]
+		AccessExpired { pallet_index: u8, extrinsic_name: Vec<u8>, who: T::AccountId },
+		/// Role created
+		/// args: [role, extrinsics]
+		RoleCreated { role: Vec<u8>, extrinsics: Vec<(u8, Vec<u8>)> },
+		/// Extrinsics of a role updated
+		/// args: [role, extrinsics]
+		RoleUpdated { role: Vec<u8>, extrinsics: Vec<(u8, Vec<u8>)> },
+		/// Role assigned to someone
+		/// args: [role, who]
+		RoleAssigned { role: Vec<u8>, who: T::AccountId },
+		/// Role unassigned from someone
+		/// args: [role, who]
+		RoleUnassigned { role: Vec<u8>, who: T::AccountId },
+		/// Delay of an extrinsic set, 0 if removed
+		/// args: [pallet_index, extrinsic_name, delay]
+		ExtrinsicDelaySet { pallet_index: u8, extrinsic_name: Vec<u8>, delay: BlockNumberFor<T> },
+		/// Call of a delayed extrinsic scheduled
+		/// args: [action_id, pallet_index, extrinsic_name, executable_at]
+		ActionScheduled {
+			action_id: u64,
+			pallet_index: u8,
+			extrinsic_name: Vec<u8>,
+			executable_at: BlockNumberFor<T>,
+		},
+		/// Scheduled action cancelled
+		/// args: [action_id]
+		ActionCancelled { action_id: u64 },
+		/// Scheduled action executed
+		/// args: [action_id, result]
+		ActionExecuted { action_id: u64, result: DispatchResult },
+		/// Extrinsic access grant to an origin, until a block if `expires_at` is set
+		/// args: [pallet_index, extrinsic_name, origin, expires_at]
+		OriginAccessGranted {
+			pallet_index: u8,
+			extrinsic_name: Vec<u8>,
+			origin: AccessOrigin<T::AccountId>,
+			expires_at: Option<BlockNumberFor<T>>,
+		},
+		/// Extrinsic access revoked from an origin
+		/// args: [pallet_index, extrinsic_name, origin]
+		OriginAccessRevoked {
+			pallet_index: u8,
+			extrinsic_name: Vec<u8>,
+			origin: AccessOrigin<T::AccountId>,
+		},
 	}
 
 	#[pallet::error]
@@ -70,6 +315,48 @@ pub mod pallet {
 		Unimplemented,
 		/// Failed to grant extrinsic access permission to an account
 		GrantAccessFailed,
+		/// Failed to revoke extrinsic access permission from an account
+		RevokeAccessFailed,
+		/// Account has no access to the extrinsic
+		AccessNotGranted,
+		/// Access expires no later than the current block
+		InvalidExpiry,
+		/// Access has not expired yet
+		AccessNotExpired,
+		/// Failed to manage roles due to missing permission
+		ManageRoleFailed,
+		/// Extrinsic is not registered
+		ExtrinsicNotRegistered,
+		/// Role already exists
+		RoleAlreadyExists,
+		/// Role does not exist
+		RoleNotFound,
+		/// Role already assigned to the account
+		RoleAlreadyAssigned,
+		/// Role not assigned to the account
+		RoleNotAssigned,
+		/// Failed to set extrinsic delay due to missing permission
+		SetDelayFailed,
+		/// Failed to schedule action due to missing access to the extrinsic
+		ScheduleActionFailed,
+		/// Failed to cancel action due to missing permission
+		CancelActionFailed,
+		/// Extrinsic has no delay, it should be called directly
+		ExtrinsicNotDelayed,
+		/// Scheduled action does not exist
+		ActionNotFound,
+		/// Delay of the scheduled action has not passed yet
+		ActionNotReady,
+		/// Weight of the scheduled call exceeds the given max weight
+		MaxWeightTooLow,
+		/// Lowering the delay of an extrinsic must be scheduled as an action
+		DelayReductionNotScheduled,
+		/// Collective proportion is not between 0 and 1
+		InvalidAccessOrigin,
+		/// Extrinsic already has the max number of collective origins granted access
+		TooManyCollectiveGrants,
+		/// Account already has the max number of roles assigned
+		TooManyRoles,
 	}
 
 	#[pallet::call]
@@ -90,29 +377,532 @@ pub mod pallet {
 				Error::<T>::GrantAccessFailed
 			);
 
-			// Apply access
-			ExtrinsicAccess::<T>::insert((pallet_index, extrinsic_name.clone()), &who);
+			// Apply access, it's added to the accounts already have the access
+			ExtrinsicAccess::<T>::insert(
+				(pallet_index, extrinsic_name.clone()),
+				AccessOrigin::Signed(who.clone()),
+				None::<BlockNumberFor<T>>,
+			);
 
 			// Emit AccessGranted event
 			Self::deposit_event(Event::AccessGranted { pallet_index, extrinsic_name, who });
 			Ok(())
 		}
+
+		/// Grants access to an account for a extrinsic until `expires_at`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::grant_access_until())]
+		pub fn grant_access_until(
+			origin: OriginFor<T>,
+			pallet_index: u8,
+			extrinsic_name: Vec<u8>,
+			who: T::AccountId,
+			expires_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			// Ensure bridge committee or the account that has permission to grant access to an
+			// extrinsic
+			ensure!(
+				Self::has_access(T::PalletIndex::get(), b"grant_access_until".to_vec(), origin),
+				Error::<T>::GrantAccessFailed
+			);
+			ensure!(
+				expires_at > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidExpiry
+			);
+
+			// Apply access
+			ExtrinsicAccess::<T>::insert(
+				(pallet_index, extrinsic_name.clone()),
+				AccessOrigin::Signed(who.clone()),
+				Some(expires_at),
+			);
+
+			// Emit AccessGrantedUntil event
+			Self::deposit_event(Event::AccessGrantedUntil {
+				pallet_index,
+				extrinsic_name,
+				who,
+				expires_at,
+			});
+			Ok(())
+		}
+
+		/// Revokes access of an account for a extrinsic.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::revoke_access())]
+		pub fn revoke_access(
+			origin: OriginFor<T>,
+			pallet_index: u8,
+			extrinsic_name: Vec<u8>,
+			who: T::AccountId,
+		) -> DispatchResult {
+			// Ensure bridge committee or the account that has permission to revoke access to an
+			// extrinsic
+			ensure!(
+				Self::has_access(T::PalletIndex::get(), b"revoke_access".to_vec(), origin),
+				Error::<T>::RevokeAccessFailed
+			);
+			ensure!(
+				ExtrinsicAccess::<T>::contains_key(
+					(pallet_index, extrinsic_name.clone()),
+					AccessOrigin::Signed(who.clone())
+				),
+				Error::<T>::AccessNotGranted
+			);
+
+			// Remove access
+			ExtrinsicAccess::<T>::remove(
+				(pallet_index, extrinsic_name.clone()),
+				AccessOrigin::Signed(who.clone()),
+			);
+
+			// Emit AccessRevoked event
+			Self::deposit_event(Event::AccessRevoked { pallet_index, extrinsic_name, who });
+			Ok(())
+		}
+
+		/// Removes expired access of an account for a extrinsic, anyone can clean up expired
+		/// access.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::remove_expired_access())]
+		pub fn remove_expired_access(
+			origin: OriginFor<T>,
+			pallet_index: u8,
+			extrinsic_name: Vec<u8>,
+			who: T::AccountId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let expires_at = ExtrinsicAccess::<T>::get(
+				(pallet_index, extrinsic_name.clone()),
+				AccessOrigin::Signed(who.clone()),
+			)
+			.ok_or(Error::<T>::AccessNotGranted)?;
+			ensure!(Self::is_expired(expires_at), Error::<T>::AccessNotExpired);
+
+			// Remove access
+			ExtrinsicAccess::<T>::remove(
+				(pallet_index, extrinsic_name.clone()),
+				AccessOrigin::Signed(who.clone()),
+			);
+
+			// Emit AccessExpired event
+			Self::deposit_event(Event::AccessExpired { pallet_index, extrinsic_name, who });
+			Ok(())
+		}
+
+		/// Creates a role with the set of extrinsics its holders have access to.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::create_role(extrinsics.len() as u32))]
+		pub fn create_role(
+			origin: OriginFor<T>,
+			role: Vec<u8>,
+			extrinsics: Vec<(u8, Vec<u8>)>,
+		) -> DispatchResult {
+			ensure!(
+				Self::has_access(T::PalletIndex::get(), b"create_role".to_vec(), origin),
+				Error::<T>::ManageRoleFailed
+			);
+			ensure!(!Roles::<T>::contains_key(&role), Error::<T>::RoleAlreadyExists);
+			Self::ensure_registered(&extrinsics)?;
+
+			// Create role
+			Roles::<T>::insert(&role, extrinsics.clone());
+
+			// Emit RoleCreated event
+			Self::deposit_event(Event::RoleCreated { role, extrinsics });
+			Ok(())
+		}
+
+		/// Replaces the set of extrinsics of a role, applies to all accounts holding the role.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::update_role(extrinsics.len() as u32))]
+		pub fn update_role(
+			origin: OriginFor<T>,
+			role: Vec<u8>,
+			extrinsics: Vec<(u8, Vec<u8>)>,
+		) -> DispatchResult {
+			ensure!(
+				Self::has_access(T::PalletIndex::get(), b"update_role".to_vec(), origin),
+				Error::<T>::ManageRoleFailed
+			);
+			ensure!(Roles::<T>::contains_key(&role), Error::<T>::RoleNotFound);
+			Self::ensure_registered(&extrinsics)?;
+
+			// Update role
+			Roles::<T>::insert(&role, extrinsics.clone());
+
+			// Emit RoleUpdated event
+			Self::deposit_event(Event::RoleUpdated { role, extrinsics });
+			Ok(())
+		}
+
+		/// Assigns a role to an account.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::assign_role())]
+		pub fn assign_role(
+			origin: OriginFor<T>,
+			role: Vec<u8>,
+			who: T::AccountId,
+		) -> DispatchResult {
+			ensure!(
+				Self::has_access(T::PalletIndex::get(), b"assign_role".to_vec(), origin),
+				Error::<T>::ManageRoleFailed
+			);
+			ensure!(Roles::<T>::contains_key(&role), Error::<T>::RoleNotFound);
+
+			// Assign role
+			AccountRoles::<T>::try_mutate(&who, |roles| -> DispatchResult {
+				ensure!(!roles.contains(&role), Error::<T>::RoleAlreadyAssigned);
+				roles.try_push(role.clone()).map_err(|_| Error::<T>::TooManyRoles)?;
+				Ok(())
+			})?;
+
+			// Emit RoleAssigned event
+			Self::deposit_event(Event::RoleAssigned { role, who });
+			Ok(())
+		}
+
+		/// Unassigns a role from an account.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::unassign_role())]
+		pub fn unassign_role(
+			origin: OriginFor<T>,
+			role: Vec<u8>,
+			who: T::AccountId,
+		) -> DispatchResult {
+			ensure!(
+				Self::has_access(T::PalletIndex::get(), b"unassign_role".to_vec(), origin),
+				Error::<T>::ManageRoleFailed
+			);
+			ensure!(AccountRoles::<T>::get(&who).contains(&role), Error::<T>::RoleNotAssigned);
+
+			// Unassign role
+			AccountRoles::<T>::mutate(&who, |roles| roles.retain(|assigned| assigned != &role));
+
+			// Emit RoleUnassigned event
+			Self::deposit_event(Event::RoleUnassigned { role, who });
+			Ok(())
+		}
+
+		/// Sets the number of blocks calls to an extrinsic are delayed for, delay 0 makes the
+		/// extrinsic take effect immediately again. Lowering an existing delay only takes effect
+		/// as a scheduled action, delayed by the current delay of the extrinsic.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_extrinsic_delay())]
+		pub fn set_extrinsic_delay(
+			origin: OriginFor<T>,
+			pallet_index: u8,
+			extrinsic_name: Vec<u8>,
+			delay: BlockNumberFor<T>,
+		) -> DispatchResult {
+			ensure!(
+				Self::has_access(T::PalletIndex::get(), b"set_extrinsic_delay".to_vec(), origin),
+				Error::<T>::SetDelayFailed
+			);
+			ensure!(
+				Self::has_registered(pallet_index, extrinsic_name.clone()),
+				Error::<T>::ExtrinsicNotRegistered
+			);
+			if ExtrinsicDelays::<T>::get((pallet_index, extrinsic_name.clone()))
+				.map_or(false, |current_delay| delay < current_delay)
+			{
+				ensure!(
+					ExecutingAction::<T>::get()
+						== Some((T::PalletIndex::get(), b"set_extrinsic_delay".to_vec())),
+					Error::<T>::DelayReductionNotScheduled
+				);
+			}
+
+			// Set or remove delay
+			if delay.is_zero() {
+				ExtrinsicDelays::<T>::remove((pallet_index, extrinsic_name.clone()));
+			} else {
+				ExtrinsicDelays::<T>::insert((pallet_index, extrinsic_name.clone()), delay);
+			}
+
+			// Emit ExtrinsicDelaySet event
+			Self::deposit_event(Event::ExtrinsicDelaySet { pallet_index, extrinsic_name, delay });
+			Ok(())
+		}
+
+		/// Schedules a call to a delayed extrinsic, the caller must have access to the
+		/// extrinsic.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::schedule_action())]
+		pub fn schedule_action(
+			origin: OriginFor<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let (pallet_index, extrinsic_name) = Self::call_signature(&call);
+			let delay = Self::action_delay(&call).ok_or(Error::<T>::ExtrinsicNotDelayed)?;
+			ensure!(
+				Self::can_access(pallet_index, extrinsic_name.clone(), origin.clone()),
+				Error::<T>::ScheduleActionFailed
+			);
+
+			let action_id = NextActionId::<T>::get();
+			let executable_at = <frame_system::Pallet<T>>::block_number().saturating_add(delay);
+			PendingActions::<T>::insert(
+				action_id,
+				PendingAction { call: *call, origin: origin.into_caller().into(), executable_at },
+			);
+			NextActionId::<T>::put(action_id.saturating_add(1));
+
+			// Emit ActionScheduled event
+			Self::deposit_event(Event::ActionScheduled {
+				action_id,
+				pallet_index,
+				extrinsic_name,
+				executable_at,
+			});
+			Ok(())
+		}
+
+		/// Cancels a scheduled action before it is executed.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::cancel_action())]
+		pub fn cancel_action(origin: OriginFor<T>, action_id: u64) -> DispatchResult {
+			ensure!(
+				Self::has_access(T::PalletIndex::get(), b"cancel_action".to_vec(), origin),
+				Error::<T>::CancelActionFailed
+			);
+			ensure!(PendingActions::<T>::contains_key(action_id), Error::<T>::ActionNotFound);
+
+			// Remove action
+			PendingActions::<T>::remove(action_id);
+
+			// Emit ActionCancelled event
+			Self::deposit_event(Event::ActionCancelled { action_id });
+			Ok(())
+		}
+
+		/// Executes a scheduled action once its delay has passed, anyone can execute it. The
+		/// call is dispatched from the origin that scheduled it.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::execute_action().saturating_add(*max_weight))]
+		pub fn execute_action(
+			origin: OriginFor<T>,
+			action_id: u64,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let action = PendingActions::<T>::get(action_id).ok_or(Error::<T>::ActionNotFound)?;
+			ensure!(
+				action.executable_at <= <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::ActionNotReady
+			);
+			let call_info = action.call.get_dispatch_info();
+			ensure!(call_info.weight.all_lte(max_weight), Error::<T>::MaxWeightTooLow);
+
+			PendingActions::<T>::remove(action_id);
+
+			// Access of the delayed extrinsic was checked when the action was scheduled
+			ExecutingAction::<T>::put(Self::call_signature(&action.call));
+			let result = action.call.dispatch(action.origin.into());
+			ExecutingAction::<T>::kill();
+			let call_weight = extract_actual_weight(&result, &call_info);
+
+			// Emit ActionExecuted event
+			Self::deposit_event(Event::ActionExecuted {
+				action_id,
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+			Ok(Some(T::WeightInfo::execute_action().saturating_add(call_weight)).into())
+		}
+
+		/// Grants access to an origin for a extrinsic, until `expires_at` if set.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::grant_origin_access())]
+		pub fn grant_origin_access(
+			origin: OriginFor<T>,
+			pallet_index: u8,
+			extrinsic_name: Vec<u8>,
+			access_origin: AccessOrigin<T::AccountId>,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			ensure!(
+				Self::has_access(T::PalletIndex::get(), b"grant_origin_access".to_vec(), origin),
+				Error::<T>::GrantAccessFailed
+			);
+			if let AccessOrigin::Collective { yes, total, .. } = access_origin {
+				ensure!(total > 0 && yes <= total, Error::<T>::InvalidAccessOrigin);
+			}
+			if let Some(expires_at) = expires_at {
+				ensure!(
+					expires_at > <frame_system::Pallet<T>>::block_number(),
+					Error::<T>::InvalidExpiry
+				);
+			}
+			if matches!(access_origin, AccessOrigin::Collective { .. }) {
+				CollectiveGrants::<T>::try_mutate(
+					(pallet_index, extrinsic_name.clone()),
+					|grants| -> DispatchResult {
+						if !grants.contains(&access_origin) {
+							grants
+								.try_push(access_origin.clone())
+								.map_err(|_| Error::<T>::TooManyCollectiveGrants)?;
+						}
+						Ok(())
+					},
+				)?;
+			}
+
+			// Apply access
+			ExtrinsicAccess::<T>::insert(
+				(pallet_index, extrinsic_name.clone()),
+				&access_origin,
+				expires_at,
+			);
+
+			// Emit OriginAccessGranted event
+			Self::deposit_event(Event::OriginAccessGranted {
+				pallet_index,
+				extrinsic_name,
+				origin: access_origin,
+				expires_at,
+			});
+			Ok(())
+		}
+
+		/// Revokes access of an origin for a extrinsic.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::revoke_origin_access())]
+		pub fn revoke_origin_access(
+			origin: OriginFor<T>,
+			pallet_index: u8,
+			extrinsic_name: Vec<u8>,
+			access_origin: AccessOrigin<T::AccountId>,
+		) -> DispatchResult {
+			ensure!(
+				Self::has_access(T::PalletIndex::get(), b"revoke_origin_access".to_vec(), origin),
+				Error::<T>::RevokeAccessFailed
+			);
+			ensure!(
+				ExtrinsicAccess::<T>::contains_key(
+					(pallet_index, extrinsic_name.clone()),
+					&access_origin
+				),
+				Error::<T>::AccessNotGranted
+			);
+
+			// Remove access
+			ExtrinsicAccess::<T>::remove((pallet_index, extrinsic_name.clone()), &access_origin);
+			if matches!(access_origin, AccessOrigin::Collective { .. }) {
+				CollectiveGrants::<T>::mutate((pallet_index, extrinsic_name.clone()), |grants| {
+					grants.retain(|granted| granted != &access_origin)
+				});
+			}
+
+			// Emit OriginAccessRevoked event
+			Self::deposit_event(Event::OriginAccessRevoked {
+				pallet_index,
+				extrinsic_name,
+				origin: access_origin,
+			});
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		pub fn has_access(pallet_index: u8, extrinsic_name: Vec<u8>, origin: OriginFor<T>) -> bool {
+			// Delayed extrinsic only takes effect when executed as a scheduled action
+			if ExtrinsicDelays::<T>::contains_key((pallet_index, extrinsic_name.clone())) {
+				return ExecutingAction::<T>::get() == Some((pallet_index, extrinsic_name));
+			}
+
+			Self::can_access(pallet_index, extrinsic_name, origin)
+		}
+
+		/// Return true if the origin is allowed to call the extrinsic, regardless of its delay
+		fn can_access(pallet_index: u8, extrinsic_name: Vec<u8>, origin: OriginFor<T>) -> bool {
 			if T::BridgeCommitteeOrigin::ensure_origin(origin.clone()).is_ok() {
 				return true;
 			}
 
-			let caller = match ensure_signed(origin) {
-				Ok(caller) => caller,
+			let caller = match T::AccessOriginConverter::to_access_origin(origin) {
+				Some(caller) => caller,
 				_ => return false,
 			};
 
 			Self::has_registered(pallet_index, extrinsic_name.clone())
-				&& ExtrinsicAccess::<T>::get((pallet_index, extrinsic_name))
-					.map_or(false, |who| who == caller)
+				&& (Self::has_origin_access(&caller, pallet_index, extrinsic_name.clone())
+					|| match &caller {
+						AccessOrigin::Signed(who) =>
+							Self::has_role_access(who, pallet_index, extrinsic_name),
+						_ => false,
+					})
+		}
+
+		/// Return true if the extrinsic access granted to any origin `caller` satisfies has not
+		/// expired
+		fn has_origin_access(
+			caller: &AccessOrigin<T::AccountId>,
+			pallet_index: u8,
+			extrinsic_name: Vec<u8>,
+		) -> bool {
+			let extrinsic = (pallet_index, extrinsic_name);
+			match caller {
+				// Collective access is granted to a proportion, any lower one is satisfied
+				AccessOrigin::Collective { .. } => {
+					CollectiveGrants::<T>::get(&extrinsic).iter().any(|granted| {
+						caller.satisfies(granted)
+							&& ExtrinsicAccess::<T>::get(&extrinsic, granted)
+								.map_or(false, |expires_at| !Self::is_expired(expires_at))
+					})
+				},
+				_ => ExtrinsicAccess::<T>::get(&extrinsic, caller)
+					.map_or(false, |expires_at| !Self::is_expired(expires_at)),
+			}
+		}
+
+		/// Return true if any role assigned to `who` has access to the extrinsic
+		fn has_role_access(who: &T::AccountId, pallet_index: u8, extrinsic_name: Vec<u8>) -> bool {
+			let extrinsic = (pallet_index, extrinsic_name);
+			AccountRoles::<T>::get(who).iter().any(|role| {
+				Roles::<T>::get(role).map_or(false, |extrinsics| extrinsics.contains(&extrinsic))
+			})
+		}
+
+		/// Return the delay of a scheduled call, lowering the delay of an extrinsic is delayed by
+		/// at least the current delay of that extrinsic
+		fn action_delay(call: &<T as Config>::RuntimeCall) -> Option<BlockNumberFor<T>> {
+			let delay = ExtrinsicDelays::<T>::get(Self::call_signature(call));
+			match call.is_sub_type() {
+				Some(Call::set_extrinsic_delay {
+					pallet_index,
+					extrinsic_name,
+					delay: new_delay,
+				}) => {
+					let current_delay =
+						ExtrinsicDelays::<T>::get((*pallet_index, extrinsic_name.clone()))
+							.filter(|current_delay| new_delay < current_delay);
+					delay.max(current_delay)
+				},
+				_ => delay,
+			}
+		}
+
+		/// Return (pallet_index, extrinsic_name) of a call
+		fn call_signature(call: &<T as Config>::RuntimeCall) -> (u8, Vec<u8>) {
+			let pallet_index = call.using_encoded(|encoded| encoded[0]);
+			(pallet_index, call.get_call_metadata().function_name.as_bytes().to_vec())
+		}
+
+		/// Ensure all extrinsics of a role are registered
+		fn ensure_registered(extrinsics: &[(u8, Vec<u8>)]) -> DispatchResult {
+			for (pallet_index, extrinsic_name) in extrinsics {
+				ensure!(
+					Self::has_registered(*pallet_index, extrinsic_name.clone()),
+					Error::<T>::ExtrinsicNotRegistered
+				);
+			}
+			Ok(())
+		}
+
+		/// Return true if access expiring at `expires_at` has expired
+		fn is_expired(expires_at: Option<BlockNumberFor<T>>) -> bool {
+			expires_at
+				.map_or(false, |expires_at| expires_at <= <frame_system::Pallet<T>>::block_number())
 		}
 
 		pub fn has_registered(pallet_index: u8, extrinsic_name: Vec<u8>) -> bool {
@@ -122,17 +912,46 @@ pub mod pallet {
 		}
 	}
 
+	/// `EnsureOrigin` implementation that succeeds if the origin has access to the extrinsic
+	/// (PalletIndex, Name), allows gating any pallet with the access segregator. Success value is
+	/// the caller account, None if the origin is not a signed one, e.g. the bridge committee.
+	pub struct EnsureSygmaAccess<T, PalletIndex, Name>(PhantomData<(T, PalletIndex, Name)>);
+
+	impl<T: Config, PalletIndex: Get<u8>, Name: Get<Vec<u8>>> EnsureOrigin<OriginFor<T>>
+		for EnsureSygmaAccess<T, PalletIndex, Name>
+	{
+		type Success = Option<T::AccountId>;
+
+		fn try_origin(o: OriginFor<T>) -> Result<Self::Success, OriginFor<T>> {
+			if !Pallet::<T>::has_access(PalletIndex::get(), Name::get(), o.clone()) {
+				return Err(o);
+			}
+
+			Ok(ensure_signed(o).ok())
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn try_successful_origin() -> Result<OriginFor<T>, ()> {
+			T::BridgeCommitteeOrigin::try_successful_origin()
+		}
+	}
+
 	#[cfg(test)]
 	mod test {
 		use crate as sygma_access_segregator;
 		use crate::{
 			mock::{
-				assert_events, new_test_ext, AccessSegregator, PalletIndex, RuntimeEvent as Event,
-				RuntimeOrigin as Origin, Test, ALICE, BOB, CHARLIE,
+				assert_events, governance, new_test_ext, AccessSegregator, GovernancePalletIndex,
+				PalletIndex, RuntimeCall, RuntimeEvent as Event, RuntimeOrigin as Origin, System,
+				Test, ALICE, BOB, CHARLIE,
 			},
-			Event as AccessSegregatorEvent,
+			AccessOrigin, EnsureSygmaAccess, Event as AccessSegregatorEvent,
+		};
+		use frame_support::{
+			assert_noop, assert_ok, dispatch::GetDispatchInfo, parameter_types,
+			sp_runtime::DispatchError, traits::EnsureOrigin, weights::Weight,
 		};
-		use frame_support::{assert_noop, assert_ok};
+		use xcm::latest::prelude::*;
 
 		#[test]
 		fn should_work() {
@@ -313,5 +1132,1176 @@ pub mod pallet {
 				);
 			})
 		}
+
+		#[test]
+		fn multiple_holders_should_work() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					ALICE
+				));
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					BOB
+				));
+
+				// Granting BOB does not take the access away from ALICE
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(ALICE).into()
+				));
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(BOB).into()
+				));
+				assert!(!AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(CHARLIE).into()
+				));
+			})
+		}
+
+		#[test]
+		fn revoke_access_should_work() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					ALICE
+				));
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					BOB
+				));
+
+				// ALICE does not have access to `revoke_access`
+				assert_noop!(
+					AccessSegregator::revoke_access(
+						Some(ALICE).into(),
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec(),
+						BOB
+					),
+					sygma_access_segregator::Error::<Test>::RevokeAccessFailed
+				);
+				// CHARLIE was never granted
+				assert_noop!(
+					AccessSegregator::revoke_access(
+						Origin::root(),
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec(),
+						CHARLIE
+					),
+					sygma_access_segregator::Error::<Test>::AccessNotGranted
+				);
+
+				assert_ok!(AccessSegregator::revoke_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					BOB
+				));
+				// Only BOB loses the access
+				assert!(!AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(BOB).into()
+				));
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(ALICE).into()
+				));
+
+				// An account granted `revoke_access` is able to revoke others
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"revoke_access".to_vec(),
+					CHARLIE
+				));
+				assert_ok!(AccessSegregator::revoke_access(
+					Some(CHARLIE).into(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					ALICE
+				));
+				assert!(!AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(ALICE).into()
+				));
+
+				assert_events(vec![
+					Event::AccessSegregator(AccessSegregatorEvent::AccessRevoked {
+						pallet_index: PalletIndex::get(),
+						extrinsic_name: b"unknown_extrinsic".to_vec(),
+						who: BOB,
+					}),
+					Event::AccessSegregator(AccessSegregatorEvent::AccessGranted {
+						pallet_index: PalletIndex::get(),
+						extrinsic_name: b"revoke_access".to_vec(),
+						who: CHARLIE,
+					}),
+					Event::AccessSegregator(AccessSegregatorEvent::AccessRevoked {
+						pallet_index: PalletIndex::get(),
+						extrinsic_name: b"unknown_extrinsic".to_vec(),
+						who: ALICE,
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn expiring_access_should_work() {
+			new_test_ext().execute_with(|| {
+				// Expiry must be in the future
+				assert_noop!(
+					AccessSegregator::grant_access_until(
+						Origin::root(),
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec(),
+						BOB,
+						1
+					),
+					sygma_access_segregator::Error::<Test>::InvalidExpiry
+				);
+				assert_noop!(
+					AccessSegregator::grant_access_until(
+						Some(ALICE).into(),
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec(),
+						BOB,
+						10
+					),
+					sygma_access_segregator::Error::<Test>::GrantAccessFailed
+				);
+
+				assert_ok!(AccessSegregator::grant_access_until(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					BOB,
+					10
+				));
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(BOB).into()
+				));
+
+				// Expired access can not be removed before expiry
+				assert_noop!(
+					AccessSegregator::remove_expired_access(
+						Some(ALICE).into(),
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec(),
+						BOB
+					),
+					sygma_access_segregator::Error::<Test>::AccessNotExpired
+				);
+
+				// BOB loses the access at the expiry block
+				System::set_block_number(9);
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(BOB).into()
+				));
+				System::set_block_number(10);
+				assert!(!AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(BOB).into()
+				));
+
+				// Anyone can clean up the expired access
+				assert_ok!(AccessSegregator::remove_expired_access(
+					Some(ALICE).into(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					BOB
+				));
+				assert_noop!(
+					AccessSegregator::remove_expired_access(
+						Some(ALICE).into(),
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec(),
+						BOB
+					),
+					sygma_access_segregator::Error::<Test>::AccessNotGranted
+				);
+
+				assert_events(vec![
+					Event::AccessSegregator(AccessSegregatorEvent::AccessGrantedUntil {
+						pallet_index: PalletIndex::get(),
+						extrinsic_name: b"unknown_extrinsic".to_vec(),
+						who: BOB,
+						expires_at: 10,
+					}),
+					Event::AccessSegregator(AccessSegregatorEvent::AccessExpired {
+						pallet_index: PalletIndex::get(),
+						extrinsic_name: b"unknown_extrinsic".to_vec(),
+						who: BOB,
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn role_management_should_work() {
+			new_test_ext().execute_with(|| {
+				let extrinsics = vec![
+					(PalletIndex::get(), b"unknown_extrinsic".to_vec()),
+					(PalletIndex::get(), b"unknown_extrinsic2".to_vec()),
+				];
+
+				// ALICE does not have access to `create_role`
+				assert_noop!(
+					AccessSegregator::create_role(
+						Some(ALICE).into(),
+						b"Pauser".to_vec(),
+						extrinsics.clone()
+					),
+					sygma_access_segregator::Error::<Test>::ManageRoleFailed
+				);
+				// Role can only contain registered extrinsics
+				assert_noop!(
+					AccessSegregator::create_role(
+						Origin::root(),
+						b"Pauser".to_vec(),
+						vec![(PalletIndex::get(), b"not_registered".to_vec())]
+					),
+					sygma_access_segregator::Error::<Test>::ExtrinsicNotRegistered
+				);
+				// Role must exist before updated or assigned
+				assert_noop!(
+					AccessSegregator::update_role(
+						Origin::root(),
+						b"Pauser".to_vec(),
+						extrinsics.clone()
+					),
+					sygma_access_segregator::Error::<Test>::RoleNotFound
+				);
+				assert_noop!(
+					AccessSegregator::assign_role(Origin::root(), b"Pauser".to_vec(), BOB),
+					sygma_access_segregator::Error::<Test>::RoleNotFound
+				);
+
+				assert_ok!(AccessSegregator::create_role(
+					Origin::root(),
+					b"Pauser".to_vec(),
+					extrinsics.clone()
+				));
+				assert_eq!(AccessSegregator::roles(b"Pauser".to_vec()), Some(extrinsics.clone()));
+				assert_noop!(
+					AccessSegregator::create_role(
+						Origin::root(),
+						b"Pauser".to_vec(),
+						extrinsics.clone()
+					),
+					sygma_access_segregator::Error::<Test>::RoleAlreadyExists
+				);
+
+				// BOB gets the access of all extrinsics of the role
+				assert!(!AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(BOB).into()
+				));
+				assert_ok!(AccessSegregator::assign_role(Origin::root(), b"Pauser".to_vec(), BOB));
+				assert_noop!(
+					AccessSegregator::assign_role(Origin::root(), b"Pauser".to_vec(), BOB),
+					sygma_access_segregator::Error::<Test>::RoleAlreadyAssigned
+				);
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(BOB).into()
+				));
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic2".to_vec(),
+					Some(BOB).into()
+				));
+				assert!(!AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"grant_access".to_vec(),
+					Some(BOB).into()
+				));
+
+				// Updating the role applies to BOB
+				assert_ok!(AccessSegregator::update_role(
+					Origin::root(),
+					b"Pauser".to_vec(),
+					vec![(PalletIndex::get(), b"unknown_extrinsic2".to_vec())]
+				));
+				assert!(!AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(BOB).into()
+				));
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic2".to_vec(),
+					Some(BOB).into()
+				));
+
+				// BOB loses the access once the role is unassigned
+				assert_ok!(AccessSegregator::unassign_role(
+					Origin::root(),
+					b"Pauser".to_vec(),
+					BOB
+				));
+				assert_noop!(
+					AccessSegregator::unassign_role(Origin::root(), b"Pauser".to_vec(), BOB),
+					sygma_access_segregator::Error::<Test>::RoleNotAssigned
+				);
+				assert!(!AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic2".to_vec(),
+					Some(BOB).into()
+				));
+
+				assert_events(vec![
+					Event::AccessSegregator(AccessSegregatorEvent::RoleCreated {
+						role: b"Pauser".to_vec(),
+						extrinsics,
+					}),
+					Event::AccessSegregator(AccessSegregatorEvent::RoleAssigned {
+						role: b"Pauser".to_vec(),
+						who: BOB,
+					}),
+					Event::AccessSegregator(AccessSegregatorEvent::RoleUpdated {
+						role: b"Pauser".to_vec(),
+						extrinsics: vec![(PalletIndex::get(), b"unknown_extrinsic2".to_vec())],
+					}),
+					Event::AccessSegregator(AccessSegregatorEvent::RoleUnassigned {
+						role: b"Pauser".to_vec(),
+						who: BOB,
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn assigned_roles_should_be_bounded() {
+			new_test_ext().execute_with(|| {
+				for i in 0..9u8 {
+					assert_ok!(AccessSegregator::create_role(
+						Origin::root(),
+						vec![i],
+						vec![(PalletIndex::get(), b"unknown_extrinsic".to_vec())]
+					));
+				}
+				for i in 0..8u8 {
+					assert_ok!(AccessSegregator::assign_role(Origin::root(), vec![i], BOB));
+				}
+				assert_eq!(AccessSegregator::account_roles(BOB).len(), 8);
+
+				// BOB already holds the max number of roles
+				assert_noop!(
+					AccessSegregator::assign_role(Origin::root(), vec![8], BOB),
+					sygma_access_segregator::Error::<Test>::TooManyRoles
+				);
+				// Other accounts are not limited
+				assert_ok!(AccessSegregator::assign_role(Origin::root(), vec![8], CHARLIE));
+
+				// Unassigning a role frees its slot
+				assert_ok!(AccessSegregator::unassign_role(Origin::root(), vec![0], BOB));
+				assert_ok!(AccessSegregator::assign_role(Origin::root(), vec![8], BOB));
+				assert!(!AccessSegregator::account_roles(BOB).contains(&vec![0]));
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(BOB).into()
+				));
+			})
+		}
+
+		#[test]
+		fn role_management_access_can_be_granted() {
+			new_test_ext().execute_with(|| {
+				// Role management access itself can be held through a role
+				assert_ok!(AccessSegregator::create_role(
+					Origin::root(),
+					b"RoleManager".to_vec(),
+					vec![
+						(PalletIndex::get(), b"create_role".to_vec()),
+						(PalletIndex::get(), b"assign_role".to_vec()),
+					]
+				));
+				assert_ok!(AccessSegregator::assign_role(
+					Origin::root(),
+					b"RoleManager".to_vec(),
+					ALICE
+				));
+
+				assert_ok!(AccessSegregator::create_role(
+					Some(ALICE).into(),
+					b"Pauser".to_vec(),
+					vec![(PalletIndex::get(), b"unknown_extrinsic".to_vec())]
+				));
+				assert_ok!(AccessSegregator::assign_role(
+					Some(ALICE).into(),
+					b"Pauser".to_vec(),
+					BOB
+				));
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(BOB).into()
+				));
+
+				// ALICE has no access to `update_role` and `unassign_role`
+				assert_noop!(
+					AccessSegregator::update_role(Some(ALICE).into(), b"Pauser".to_vec(), vec![]),
+					sygma_access_segregator::Error::<Test>::ManageRoleFailed
+				);
+				assert_noop!(
+					AccessSegregator::unassign_role(Some(ALICE).into(), b"Pauser".to_vec(), BOB),
+					sygma_access_segregator::Error::<Test>::ManageRoleFailed
+				);
+			})
+		}
+
+		#[test]
+		fn timelocked_action_should_work() {
+			new_test_ext().execute_with(|| {
+				// ALICE is able to grant access before the delay is set
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"grant_access".to_vec(),
+					ALICE
+				));
+
+				assert_noop!(
+					AccessSegregator::set_extrinsic_delay(
+						Some(ALICE).into(),
+						PalletIndex::get(),
+						b"grant_access".to_vec(),
+						10
+					),
+					sygma_access_segregator::Error::<Test>::SetDelayFailed
+				);
+				assert_noop!(
+					AccessSegregator::set_extrinsic_delay(
+						Origin::root(),
+						PalletIndex::get(),
+						b"not_registered".to_vec(),
+						10
+					),
+					sygma_access_segregator::Error::<Test>::ExtrinsicNotRegistered
+				);
+				assert_ok!(AccessSegregator::set_extrinsic_delay(
+					Origin::root(),
+					PalletIndex::get(),
+					b"grant_access".to_vec(),
+					10
+				));
+
+				// `grant_access` no longer takes effect immediately, even for root
+				assert_noop!(
+					AccessSegregator::grant_access(
+						Some(ALICE).into(),
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec(),
+						BOB
+					),
+					sygma_access_segregator::Error::<Test>::GrantAccessFailed
+				);
+				assert_noop!(
+					AccessSegregator::grant_access(
+						Origin::root(),
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec(),
+						BOB
+					),
+					sygma_access_segregator::Error::<Test>::GrantAccessFailed
+				);
+
+				let call: RuntimeCall = sygma_access_segregator::Call::<Test>::grant_access {
+					pallet_index: PalletIndex::get(),
+					extrinsic_name: b"unknown_extrinsic".to_vec(),
+					who: BOB,
+				}
+				.into();
+				let call_weight = call.get_dispatch_info().weight;
+
+				// BOB has no access to `grant_access`
+				assert_noop!(
+					AccessSegregator::schedule_action(Some(BOB).into(), Box::new(call.clone())),
+					sygma_access_segregator::Error::<Test>::ScheduleActionFailed
+				);
+				// `revoke_access` has no delay
+				assert_noop!(
+					AccessSegregator::schedule_action(
+						Origin::root(),
+						Box::new(
+							sygma_access_segregator::Call::<Test>::revoke_access {
+								pallet_index: PalletIndex::get(),
+								extrinsic_name: b"unknown_extrinsic".to_vec(),
+								who: BOB,
+							}
+							.into()
+						)
+					),
+					sygma_access_segregator::Error::<Test>::ExtrinsicNotDelayed
+				);
+				assert_ok!(AccessSegregator::schedule_action(
+					Some(ALICE).into(),
+					Box::new(call.clone())
+				));
+				assert_eq!(AccessSegregator::pending_actions(0).unwrap().executable_at, 11);
+
+				// Delay has not passed
+				assert_noop!(
+					AccessSegregator::execute_action(Some(CHARLIE).into(), 0, call_weight),
+					sygma_access_segregator::Error::<Test>::ActionNotReady
+				);
+				System::set_block_number(11);
+				assert_noop!(
+					AccessSegregator::execute_action(Some(CHARLIE).into(), 0, Weight::zero()),
+					sygma_access_segregator::Error::<Test>::MaxWeightTooLow
+				);
+
+				// Anyone can execute the action once the delay has passed
+				assert_ok!(AccessSegregator::execute_action(Some(CHARLIE).into(), 0, call_weight));
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(BOB).into()
+				));
+				assert_noop!(
+					AccessSegregator::execute_action(Some(CHARLIE).into(), 0, call_weight),
+					sygma_access_segregator::Error::<Test>::ActionNotFound
+				);
+
+				assert_events(vec![
+					Event::AccessSegregator(AccessSegregatorEvent::AccessGranted {
+						pallet_index: PalletIndex::get(),
+						extrinsic_name: b"unknown_extrinsic".to_vec(),
+						who: BOB,
+					}),
+					Event::AccessSegregator(AccessSegregatorEvent::ActionExecuted {
+						action_id: 0,
+						result: Ok(()),
+					}),
+				]);
+
+				// Removing the delay makes `grant_access` take effect immediately again
+				let call: RuntimeCall =
+					sygma_access_segregator::Call::<Test>::set_extrinsic_delay {
+						pallet_index: PalletIndex::get(),
+						extrinsic_name: b"grant_access".to_vec(),
+						delay: 0,
+					}
+					.into();
+				let call_weight = call.get_dispatch_info().weight;
+				assert_ok!(AccessSegregator::schedule_action(Origin::root(), Box::new(call)));
+				System::set_block_number(21);
+				assert_ok!(AccessSegregator::execute_action(Some(CHARLIE).into(), 1, call_weight));
+				assert_eq!(
+					AccessSegregator::extrinsic_delays((
+						PalletIndex::get(),
+						b"grant_access".to_vec()
+					)),
+					None
+				);
+				assert_ok!(AccessSegregator::grant_access(
+					Some(ALICE).into(),
+					PalletIndex::get(),
+					b"unknown_extrinsic2".to_vec(),
+					BOB
+				));
+			})
+		}
+
+		#[test]
+		fn lowering_extrinsic_delay_should_be_timelocked() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"set_extrinsic_delay".to_vec(),
+					ALICE
+				));
+				assert_ok!(AccessSegregator::set_extrinsic_delay(
+					Some(ALICE).into(),
+					PalletIndex::get(),
+					b"grant_access".to_vec(),
+					10
+				));
+
+				// Raising the delay takes effect immediately
+				assert_ok!(AccessSegregator::set_extrinsic_delay(
+					Some(ALICE).into(),
+					PalletIndex::get(),
+					b"grant_access".to_vec(),
+					20
+				));
+
+				// Lowering or removing the delay can not be done directly, even by root
+				assert_noop!(
+					AccessSegregator::set_extrinsic_delay(
+						Some(ALICE).into(),
+						PalletIndex::get(),
+						b"grant_access".to_vec(),
+						0
+					),
+					sygma_access_segregator::Error::<Test>::DelayReductionNotScheduled
+				);
+				assert_noop!(
+					AccessSegregator::set_extrinsic_delay(
+						Origin::root(),
+						PalletIndex::get(),
+						b"grant_access".to_vec(),
+						5
+					),
+					sygma_access_segregator::Error::<Test>::DelayReductionNotScheduled
+				);
+
+				// Raising the delay can not be scheduled, `set_extrinsic_delay` has no delay
+				let raise_call: RuntimeCall =
+					sygma_access_segregator::Call::<Test>::set_extrinsic_delay {
+						pallet_index: PalletIndex::get(),
+						extrinsic_name: b"grant_access".to_vec(),
+						delay: 30,
+					}
+					.into();
+				assert_noop!(
+					AccessSegregator::schedule_action(Some(ALICE).into(), Box::new(raise_call)),
+					sygma_access_segregator::Error::<Test>::ExtrinsicNotDelayed
+				);
+
+				// Removing the delay is delayed by the current delay of `grant_access`
+				let call: RuntimeCall =
+					sygma_access_segregator::Call::<Test>::set_extrinsic_delay {
+						pallet_index: PalletIndex::get(),
+						extrinsic_name: b"grant_access".to_vec(),
+						delay: 0,
+					}
+					.into();
+				let call_weight = call.get_dispatch_info().weight;
+				assert_noop!(
+					AccessSegregator::schedule_action(Some(BOB).into(), Box::new(call.clone())),
+					sygma_access_segregator::Error::<Test>::ScheduleActionFailed
+				);
+				assert_ok!(AccessSegregator::schedule_action(
+					Some(ALICE).into(),
+					Box::new(call.clone())
+				));
+				assert_eq!(AccessSegregator::pending_actions(0).unwrap().executable_at, 21);
+
+				// Guardian is able to cancel the removal within the delay
+				assert_ok!(AccessSegregator::cancel_action(Origin::root(), 0));
+				System::set_block_number(21);
+				assert_noop!(
+					AccessSegregator::execute_action(Some(CHARLIE).into(), 0, call_weight),
+					sygma_access_segregator::Error::<Test>::ActionNotFound
+				);
+				assert_eq!(
+					AccessSegregator::extrinsic_delays((
+						PalletIndex::get(),
+						b"grant_access".to_vec()
+					)),
+					Some(20)
+				);
+
+				// Once the delay has passed, the scheduled removal takes effect
+				assert_ok!(AccessSegregator::schedule_action(Some(ALICE).into(), Box::new(call)));
+				assert_eq!(AccessSegregator::pending_actions(1).unwrap().executable_at, 41);
+				System::set_block_number(40);
+				assert_noop!(
+					AccessSegregator::execute_action(Some(CHARLIE).into(), 1, call_weight),
+					sygma_access_segregator::Error::<Test>::ActionNotReady
+				);
+				System::set_block_number(41);
+				assert_ok!(AccessSegregator::execute_action(Some(CHARLIE).into(), 1, call_weight));
+				assert_eq!(
+					AccessSegregator::extrinsic_delays((
+						PalletIndex::get(),
+						b"grant_access".to_vec()
+					)),
+					None
+				);
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					BOB
+				));
+			})
+		}
+
+		#[test]
+		fn timelocked_grant_access_until_should_work() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(AccessSegregator::set_extrinsic_delay(
+					Origin::root(),
+					PalletIndex::get(),
+					b"grant_access".to_vec(),
+					10
+				));
+				assert_noop!(
+					AccessSegregator::grant_access(
+						Origin::root(),
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec(),
+						BOB
+					),
+					sygma_access_segregator::Error::<Test>::GrantAccessFailed
+				);
+
+				// `grant_access_until` is checked under its own name, delaying `grant_access`
+				// does not affect it
+				assert_ok!(AccessSegregator::grant_access_until(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					BOB,
+					20
+				));
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(BOB).into()
+				));
+
+				// Once delayed itself, `grant_access_until` only takes effect through the timelock
+				assert_ok!(AccessSegregator::set_extrinsic_delay(
+					Origin::root(),
+					PalletIndex::get(),
+					b"grant_access_until".to_vec(),
+					10
+				));
+				assert_noop!(
+					AccessSegregator::grant_access_until(
+						Origin::root(),
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec(),
+						CHARLIE,
+						100
+					),
+					sygma_access_segregator::Error::<Test>::GrantAccessFailed
+				);
+				let call: RuntimeCall = sygma_access_segregator::Call::<Test>::grant_access_until {
+					pallet_index: PalletIndex::get(),
+					extrinsic_name: b"unknown_extrinsic".to_vec(),
+					who: CHARLIE,
+					expires_at: 100,
+				}
+				.into();
+				let call_weight = call.get_dispatch_info().weight;
+				assert_ok!(AccessSegregator::schedule_action(Origin::root(), Box::new(call)));
+
+				System::set_block_number(11);
+				assert_ok!(AccessSegregator::execute_action(Some(ALICE).into(), 0, call_weight));
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(CHARLIE).into()
+				));
+
+				assert_events(vec![
+					Event::AccessSegregator(AccessSegregatorEvent::AccessGrantedUntil {
+						pallet_index: PalletIndex::get(),
+						extrinsic_name: b"unknown_extrinsic".to_vec(),
+						who: CHARLIE,
+						expires_at: 100,
+					}),
+					Event::AccessSegregator(AccessSegregatorEvent::ActionExecuted {
+						action_id: 0,
+						result: Ok(()),
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn cancel_timelocked_action_should_work() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(AccessSegregator::set_extrinsic_delay(
+					Origin::root(),
+					PalletIndex::get(),
+					b"grant_access".to_vec(),
+					10
+				));
+				let call: RuntimeCall = sygma_access_segregator::Call::<Test>::grant_access {
+					pallet_index: PalletIndex::get(),
+					extrinsic_name: b"unknown_extrinsic".to_vec(),
+					who: BOB,
+				}
+				.into();
+				let call_weight = call.get_dispatch_info().weight;
+				assert_ok!(AccessSegregator::schedule_action(Origin::root(), Box::new(call)));
+
+				// CHARLIE is not a guardian yet
+				assert_noop!(
+					AccessSegregator::cancel_action(Some(CHARLIE).into(), 0),
+					sygma_access_segregator::Error::<Test>::CancelActionFailed
+				);
+				assert_ok!(AccessSegregator::create_role(
+					Origin::root(),
+					b"Guardian".to_vec(),
+					vec![(PalletIndex::get(), b"cancel_action".to_vec())]
+				));
+				assert_ok!(AccessSegregator::assign_role(
+					Origin::root(),
+					b"Guardian".to_vec(),
+					CHARLIE
+				));
+				assert_noop!(
+					AccessSegregator::cancel_action(Some(CHARLIE).into(), 1),
+					sygma_access_segregator::Error::<Test>::ActionNotFound
+				);
+				assert_ok!(AccessSegregator::cancel_action(Some(CHARLIE).into(), 0));
+
+				// Cancelled action can not be executed
+				System::set_block_number(11);
+				assert_noop!(
+					AccessSegregator::execute_action(Some(ALICE).into(), 0, call_weight),
+					sygma_access_segregator::Error::<Test>::ActionNotFound
+				);
+				assert!(!AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					Some(BOB).into()
+				));
+
+				assert_events(vec![Event::AccessSegregator(
+					AccessSegregatorEvent::ActionCancelled { action_id: 0 },
+				)]);
+			})
+		}
+
+		parameter_types! {
+			pub UnknownExtrinsic: Vec<u8> = b"unknown_extrinsic".to_vec();
+			pub NotRegistered: Vec<u8> = b"not_registered".to_vec();
+		}
+
+		#[test]
+		fn ensure_sygma_access_should_work() {
+			new_test_ext().execute_with(|| {
+				type EnsureUnknownExtrinsic =
+					EnsureSygmaAccess<Test, PalletIndex, UnknownExtrinsic>;
+				type EnsureNotRegistered = EnsureSygmaAccess<Test, PalletIndex, NotRegistered>;
+
+				// Bridge committee origin always passes
+				assert_eq!(EnsureUnknownExtrinsic::try_origin(Origin::root()).ok(), Some(None));
+				assert!(EnsureUnknownExtrinsic::try_origin(Some(ALICE).into()).is_err());
+				assert!(EnsureUnknownExtrinsic::try_origin(Origin::none()).is_err());
+
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					ALICE
+				));
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"not_registered".to_vec(),
+					ALICE
+				));
+				assert_eq!(
+					EnsureUnknownExtrinsic::try_origin(Some(ALICE).into()).ok(),
+					Some(Some(ALICE))
+				);
+				assert!(EnsureUnknownExtrinsic::try_origin(Some(BOB).into()).is_err());
+
+				// Extrinsic is not registered, the access granted to ALICE has no effect
+				assert!(EnsureNotRegistered::try_origin(Some(ALICE).into()).is_err());
+				assert_eq!(EnsureNotRegistered::try_origin(Origin::root()).ok(), Some(None));
+			})
+		}
+
+		#[test]
+		fn origin_access_should_work() {
+			new_test_ext().execute_with(|| {
+				let governance_location = MultiLocation::new(1, X1(Parachain(2000)));
+				let xcm_origin: Origin = governance::Origin::Xcm(governance_location).into();
+				let collective = AccessOrigin::Collective { pallet_index: 3, yes: 2, total: 3 };
+
+				assert_noop!(
+					AccessSegregator::grant_origin_access(
+						Some(ALICE).into(),
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec(),
+						AccessOrigin::Xcm(governance_location),
+						None
+					),
+					sygma_access_segregator::Error::<Test>::GrantAccessFailed
+				);
+				assert_noop!(
+					AccessSegregator::grant_origin_access(
+						Origin::root(),
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec(),
+						AccessOrigin::Collective { pallet_index: 3, yes: 4, total: 3 },
+						None
+					),
+					sygma_access_segregator::Error::<Test>::InvalidAccessOrigin
+				);
+				assert_noop!(
+					AccessSegregator::grant_origin_access(
+						Origin::root(),
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec(),
+						AccessOrigin::Xcm(governance_location),
+						Some(1)
+					),
+					sygma_access_segregator::Error::<Test>::InvalidExpiry
+				);
+
+				// Grant access to the governance parachain and 2/3 of the collective
+				assert!(!AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					xcm_origin.clone()
+				));
+				assert_ok!(AccessSegregator::grant_origin_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					AccessOrigin::Xcm(governance_location),
+					None
+				));
+				assert_ok!(AccessSegregator::grant_origin_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					collective.clone(),
+					None
+				));
+
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					xcm_origin.clone()
+				));
+				assert!(!AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					governance::Origin::Xcm(MultiLocation::new(1, X1(Parachain(2001)))).into()
+				));
+				assert!(!AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic2".to_vec(),
+					xcm_origin.clone()
+				));
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					governance::Origin::Members(3, 4).into()
+				));
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					governance::Origin::Members(2, 3).into()
+				));
+				assert!(!AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					governance::Origin::Members(1, 2).into()
+				));
+				assert_eq!(
+					EnsureSygmaAccess::<Test, PalletIndex, UnknownExtrinsic>::try_origin(
+						xcm_origin.clone()
+					)
+					.ok(),
+					Some(None)
+				);
+
+				// Revoke access of the governance parachain
+				assert_ok!(AccessSegregator::revoke_origin_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					AccessOrigin::Xcm(governance_location)
+				));
+				assert_noop!(
+					AccessSegregator::revoke_origin_access(
+						Origin::root(),
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec(),
+						AccessOrigin::Xcm(governance_location)
+					),
+					sygma_access_segregator::Error::<Test>::AccessNotGranted
+				);
+				assert!(!AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					xcm_origin
+				));
+
+				assert_events(vec![
+					Event::AccessSegregator(AccessSegregatorEvent::OriginAccessGranted {
+						pallet_index: PalletIndex::get(),
+						extrinsic_name: b"unknown_extrinsic".to_vec(),
+						origin: AccessOrigin::Xcm(governance_location),
+						expires_at: None,
+					}),
+					Event::AccessSegregator(AccessSegregatorEvent::OriginAccessGranted {
+						pallet_index: PalletIndex::get(),
+						extrinsic_name: b"unknown_extrinsic".to_vec(),
+						origin: collective,
+						expires_at: None,
+					}),
+					Event::AccessSegregator(AccessSegregatorEvent::OriginAccessRevoked {
+						pallet_index: PalletIndex::get(),
+						extrinsic_name: b"unknown_extrinsic".to_vec(),
+						origin: AccessOrigin::Xcm(governance_location),
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn timelocked_action_keeps_scheduler_origin() {
+			new_test_ext().execute_with(|| {
+				let collective = AccessOrigin::Collective { pallet_index: 3, yes: 2, total: 3 };
+				let call: RuntimeCall = governance::Call::<Test>::members_only {}.into();
+				let call_weight = call.get_dispatch_info().weight;
+
+				assert_ok!(AccessSegregator::grant_origin_access(
+					Origin::root(),
+					GovernancePalletIndex::get(),
+					b"members_only".to_vec(),
+					collective,
+					None
+				));
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					GovernancePalletIndex::get(),
+					b"members_only".to_vec(),
+					ALICE
+				));
+				assert_ok!(AccessSegregator::set_extrinsic_delay(
+					Origin::root(),
+					GovernancePalletIndex::get(),
+					b"members_only".to_vec(),
+					10
+				));
+
+				// Action scheduled by the collective is dispatched from the collective
+				assert_ok!(AccessSegregator::schedule_action(
+					governance::Origin::Members(2, 3).into(),
+					Box::new(call.clone())
+				));
+				// Action scheduled by ALICE is dispatched from ALICE, not root
+				assert_ok!(AccessSegregator::schedule_action(
+					Some(ALICE).into(),
+					Box::new(call.clone())
+				));
+				System::set_block_number(11);
+				assert_ok!(AccessSegregator::execute_action(Some(BOB).into(), 0, call_weight));
+				assert_ok!(AccessSegregator::execute_action(Some(BOB).into(), 1, call_weight));
+
+				assert_events(vec![
+					Event::AccessSegregator(AccessSegregatorEvent::ActionExecuted {
+						action_id: 0,
+						result: Ok(()),
+					}),
+					Event::AccessSegregator(AccessSegregatorEvent::ActionExecuted {
+						action_id: 1,
+						result: Err(DispatchError::BadOrigin),
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn collective_grants_should_be_bounded() {
+			new_test_ext().execute_with(|| {
+				for yes in 1..=8 {
+					assert_ok!(AccessSegregator::grant_origin_access(
+						Origin::root(),
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec(),
+						AccessOrigin::Collective { pallet_index: 3, yes, total: 8 },
+						None
+					));
+				}
+				assert_eq!(
+					AccessSegregator::collective_grants((
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec()
+					))
+					.len(),
+					8
+				);
+
+				// Granting the same collective again does not take another slot
+				assert_ok!(AccessSegregator::grant_origin_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					AccessOrigin::Collective { pallet_index: 3, yes: 8, total: 8 },
+					Some(100)
+				));
+				assert_noop!(
+					AccessSegregator::grant_origin_access(
+						Origin::root(),
+						PalletIndex::get(),
+						b"unknown_extrinsic".to_vec(),
+						AccessOrigin::Collective { pallet_index: 3, yes: 2, total: 3 },
+						None
+					),
+					sygma_access_segregator::Error::<Test>::TooManyCollectiveGrants
+				);
+				// Other origins and extrinsics are not limited
+				assert_ok!(AccessSegregator::grant_origin_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					AccessOrigin::Xcm(MultiLocation::new(1, X1(Parachain(2000)))),
+					None
+				));
+				assert_ok!(AccessSegregator::grant_origin_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic2".to_vec(),
+					AccessOrigin::Collective { pallet_index: 3, yes: 2, total: 3 },
+					None
+				));
+
+				// Revoking frees a slot
+				assert_ok!(AccessSegregator::revoke_origin_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					AccessOrigin::Collective { pallet_index: 3, yes: 1, total: 8 }
+				));
+				assert!(!AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					governance::Origin::Members(1, 8).into()
+				));
+				assert!(AccessSegregator::has_access(
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					governance::Origin::Members(2, 8).into()
+				));
+				assert_ok!(AccessSegregator::grant_origin_access(
+					Origin::root(),
+					PalletIndex::get(),
+					b"unknown_extrinsic".to_vec(),
+					AccessOrigin::Collective { pallet_index: 3, yes: 2, total: 3 },
+					None
+				));
+			})
+		}
 	}
 }
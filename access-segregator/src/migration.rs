@@ -0,0 +1,181 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+#[allow(unused_imports)]
+use super::*;
+
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use frame_support::ensure;
+use frame_support::traits::{Get, OnRuntimeUpgrade, StorageVersion};
+use frame_system::pallet_prelude::BlockNumberFor;
+use log;
+use sp_std::vec::Vec;
+
+const EXPECTED_STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+#[cfg(feature = "try-runtime")]
+const FINAL_STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+const ACCESS_ORIGIN_EXPECTED_STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+#[cfg(feature = "try-runtime")]
+const ACCESS_ORIGIN_FINAL_STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+mod v0 {
+	use super::*;
+	use frame_support::{storage_alias, Twox64Concat};
+
+	/// Extrinsic access with a single account per extrinsic
+	#[storage_alias]
+	pub type ExtrinsicAccess<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, (u8, Vec<u8>), <T as frame_system::Config>::AccountId>;
+}
+
+mod v1 {
+	use super::*;
+	use frame_support::{storage_alias, Blake2_128Concat, Twox64Concat};
+
+	/// Extrinsic access held by accounts
+	#[storage_alias]
+	pub type ExtrinsicAccess<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Twox64Concat,
+		(u8, Vec<u8>),
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Option<BlockNumberFor<T>>,
+	>;
+}
+
+/// Migrate the extrinsic access held by a single account to the one that can be held by several
+/// accounts, the current holders keep the access without expiry.
+pub struct MigrateMultiHolderAccess<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateMultiHolderAccess<T> {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		if StorageVersion::get::<Pallet<T>>() == EXPECTED_STORAGE_VERSION {
+			log::info!("Start sygma access segregator migration");
+
+			// Old keys share the prefix with new ones, so drain them all before inserting
+			let grants: Vec<((u8, Vec<u8>), T::AccountId)> =
+				v0::ExtrinsicAccess::<T>::drain().collect();
+			for (extrinsic, who) in grants.iter() {
+				v1::ExtrinsicAccess::<T>::insert(extrinsic, who, None::<BlockNumberFor<T>>);
+			}
+
+			// Set new storage version to 1
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!("Sygma access segregator migration done👏");
+
+			// drain + one insert per grant + put
+			T::DbWeight::get().reads_writes(grants.len() as u64 + 1, grants.len() as u64 * 2 + 1)
+		} else {
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == EXPECTED_STORAGE_VERSION,
+			"Incorrect Sygma access segregator storage version in pre migrate"
+		);
+
+		let grants: Vec<((u8, Vec<u8>), T::AccountId)> = v0::ExtrinsicAccess::<T>::iter().collect();
+
+		log::info!("Sygma access segregator pre migration check passed👏");
+
+		Ok(grants.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == FINAL_STORAGE_VERSION,
+			"Incorrect Sygma access segregator storage version in post migrate"
+		);
+
+		let grants: Vec<((u8, Vec<u8>), T::AccountId)> =
+			Decode::decode(&mut &state[..]).map_err(|_| "Can not decode access grants")?;
+		for (extrinsic, who) in grants {
+			ensure!(
+				v1::ExtrinsicAccess::<T>::get(extrinsic, who) == Some(None),
+				"Access holder lost the access in post migrate"
+			);
+		}
+
+		log::info!("Sygma access segregator post migration check passed👏");
+
+		Ok(())
+	}
+}
+
+/// Migrate the extrinsic access held by accounts to the one held by origins, the current
+/// holders keep the access as signed origins with the same expiry.
+pub struct MigrateAccessOrigins<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateAccessOrigins<T> {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		if StorageVersion::get::<Pallet<T>>() == ACCESS_ORIGIN_EXPECTED_STORAGE_VERSION {
+			log::info!("Start sygma access segregator access origin migration");
+
+			// Old keys share the prefix with new ones, so drain them all before inserting
+			let grants: Vec<((u8, Vec<u8>), T::AccountId, Option<BlockNumberFor<T>>)> =
+				v1::ExtrinsicAccess::<T>::drain().collect();
+			for (extrinsic, who, expires_at) in grants.iter() {
+				ExtrinsicAccess::<T>::insert(
+					extrinsic,
+					AccessOrigin::Signed(who.clone()),
+					expires_at,
+				);
+			}
+
+			// Set new storage version to 2
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!("Sygma access segregator access origin migration done👏");
+
+			// drain + one insert per grant + put
+			T::DbWeight::get().reads_writes(grants.len() as u64 + 1, grants.len() as u64 * 2 + 1)
+		} else {
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == ACCESS_ORIGIN_EXPECTED_STORAGE_VERSION,
+			"Incorrect Sygma access segregator storage version in pre migrate"
+		);
+
+		let grants: Vec<((u8, Vec<u8>), T::AccountId, Option<BlockNumberFor<T>>)> =
+			v1::ExtrinsicAccess::<T>::iter().collect();
+
+		log::info!("Sygma access segregator access origin pre migration check passed👏");
+
+		Ok(grants.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == ACCESS_ORIGIN_FINAL_STORAGE_VERSION,
+			"Incorrect Sygma access segregator storage version in post migrate"
+		);
+
+		let grants: Vec<((u8, Vec<u8>), T::AccountId, Option<BlockNumberFor<T>>)> =
+			Decode::decode(&mut &state[..]).map_err(|_| "Can not decode access grants")?;
+		for (extrinsic, who, expires_at) in grants {
+			ensure!(
+				ExtrinsicAccess::<T>::get(extrinsic, AccessOrigin::Signed(who)) == Some(expires_at),
+				"Access holder lost the access in post migrate"
+			);
+		}
+
+		log::info!("Sygma access segregator access origin post migration check passed👏");
+
+		Ok(())
+	}
+}
@@ -9,10 +9,12 @@ use frame_support::{
 		traits::{BlakeTwo256, IdentityLookup},
 		AccountId32, BuildStorage, Perbill,
 	},
+	traits::OriginTrait,
 };
-use frame_system::{self as system, EnsureRoot};
+use frame_system::{self as system, EnsureRoot, RawOrigin};
 
 use crate as sygma_access_segregator;
+use sygma_access_segregator::{AccessOrigin, ToAccessOrigin};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -27,9 +29,54 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		AccessSegregator: sygma_access_segregator::{Pallet, Call, Storage, Event<T>} = 2,
+		Governance: governance::{Pallet, Call, Origin} = 3,
 	}
 );
 
+/// Mock pallet providing XCM and collective origins
+#[frame_support::pallet]
+pub mod governance {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use xcm::latest::MultiLocation;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeOrigin: From<<Self as frame_system::Config>::RuntimeOrigin>
+			+ Into<Result<Origin, <Self as Config>::RuntimeOrigin>>;
+	}
+
+	#[pallet::origin]
+	#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub enum Origin {
+		/// XCM origin from a location
+		Xcm(MultiLocation),
+		/// Collective motion approved by (yes, total) members
+		Members(u32, u32),
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Succeeds only when called by the collective
+		#[pallet::call_index(0)]
+		#[pallet::weight(Weight::zero())]
+		pub fn members_only(origin: OriginFor<T>) -> DispatchResult {
+			let origin: <T as Config>::RuntimeOrigin = origin.into();
+			match origin.into() {
+				Ok(Origin::Members(..)) => Ok(()),
+				_ => Err(DispatchError::BadOrigin),
+			}
+		}
+	}
+}
+
+impl governance::Config for Test {
+	type RuntimeOrigin = RuntimeOrigin;
+}
+
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub const MaximumBlockLength: u32 = 2 * 1024;
@@ -87,10 +134,22 @@ impl pallet_balances::Config for Test {
 parameter_types! {
 	// Make sure put same value with `construct_runtime`
 	pub const PalletIndex: u8 = 2;
+	pub const GovernancePalletIndex: u8 = 3;
 	pub RegisteredExtrinsics: Vec<(u8, Vec<u8>)> = [
 		(PalletIndex::get(), b"grant_access".to_vec()),
+		(PalletIndex::get(), b"grant_access_until".to_vec()),
+		(PalletIndex::get(), b"revoke_access".to_vec()),
+		(PalletIndex::get(), b"grant_origin_access".to_vec()),
+		(PalletIndex::get(), b"revoke_origin_access".to_vec()),
+		(PalletIndex::get(), b"create_role".to_vec()),
+		(PalletIndex::get(), b"update_role".to_vec()),
+		(PalletIndex::get(), b"assign_role".to_vec()),
+		(PalletIndex::get(), b"unassign_role".to_vec()),
+		(PalletIndex::get(), b"set_extrinsic_delay".to_vec()),
+		(PalletIndex::get(), b"cancel_action".to_vec()),
 		(PalletIndex::get(), b"unknown_extrinsic".to_vec()),
 		(PalletIndex::get(), b"unknown_extrinsic2".to_vec()),
+		(GovernancePalletIndex::get(), b"members_only".to_vec()),
 	].to_vec();
 }
 
@@ -99,9 +158,31 @@ impl sygma_access_segregator::Config for Test {
 	type BridgeCommitteeOrigin = EnsureRoot<Self::AccountId>;
 	type PalletIndex = PalletIndex;
 	type Extrinsics = RegisteredExtrinsics;
+	type RuntimeCall = RuntimeCall;
+	type AccessOriginConverter = MockAccessOrigin;
+	type MaxCollectiveGrants = ConstU32<8>;
+	type MaxRolesPerAccount = ConstU32<8>;
+	type PalletsOrigin = OriginCaller;
 	type WeightInfo = sygma_access_segregator::weights::SygmaWeightInfo<Test>;
 }
 
+pub struct MockAccessOrigin;
+
+impl ToAccessOrigin<RuntimeOrigin, AccountId32> for MockAccessOrigin {
+	fn to_access_origin(origin: RuntimeOrigin) -> Option<AccessOrigin<AccountId32>> {
+		match origin.caller() {
+			OriginCaller::system(RawOrigin::Signed(who)) => Some(AccessOrigin::Signed(who.clone())),
+			OriginCaller::Governance(governance::Origin::Xcm(location)) => {
+				Some(AccessOrigin::Xcm(*location))
+			},
+			OriginCaller::Governance(governance::Origin::Members(yes, total)) => {
+				Some(AccessOrigin::Collective { pallet_index: 3, yes: *yes, total: *total })
+			},
+			_ => None,
+		}
+	}
+}
+
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 	let mut ext = sp_io::TestExternalities::new(t);
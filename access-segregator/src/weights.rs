@@ -5,6 +5,9 @@
 //! DATE: 2023-04-26, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! Weights of the extrinsics added after this run are estimated from their storage accesses
+//! against the numbers above, regenerate the file with the command below to replace them.
 
 // Executed Command:
 // ./target/release/standalone-node-template
@@ -35,6 +38,8 @@ use sp_std::marker::PhantomData;
 /// Weight functions for `sygma_access_segregator`.
 pub struct SygmaWeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:0 w:1)
 	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
 	fn grant_access() -> Weight {
@@ -44,6 +49,206 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 		// Minimum execution time: 9_000_000 picoseconds.
 		Weight::from_parts(9_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:0 w:1)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	fn grant_access_until() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:1)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	fn revoke_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `156`
+		//  Estimated: `3621`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3621))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:1)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	fn remove_expired_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `160`
+		//  Estimated: `3625`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3625))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator Roles (r:1 w:1)
+	/// Proof Skipped: SygmaAccessSegregator Roles (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 32]`.
+	fn create_role(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3465`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(11_287_436, 0)
+			.saturating_add(Weight::from_parts(0, 3465))
+			// Standard Error: 1_954
+			.saturating_add(Weight::from_parts(318_604, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator Roles (r:1 w:1)
+	/// Proof Skipped: SygmaAccessSegregator Roles (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 32]`.
+	fn update_role(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `83 + n * (18 ±0)`
+		//  Estimated: `3548 + n * (18 ±0)`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(12_563_118, 0)
+			.saturating_add(Weight::from_parts(0, 3548))
+			// Standard Error: 2_117
+			.saturating_add(Weight::from_parts(334_262, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 18).saturating_mul(n.into()))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator Roles (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator Roles (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator AccountRoles (r:1 w:1)
+	/// Proof Skipped: SygmaAccessSegregator AccountRoles (max_values: None, max_size: None, mode: Measured)
+	fn assign_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `253`
+		//  Estimated: `3718`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3718))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator AccountRoles (r:1 w:1)
+	/// Proof Skipped: SygmaAccessSegregator AccountRoles (max_values: None, max_size: None, mode: Measured)
+	fn unassign_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `253`
+		//  Estimated: `3718`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3718))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:2 w:1)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator ExecutingAction (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExecutingAction (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_extrinsic_delay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `120`
+		//  Estimated: `3585`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3585))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:2 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator NextActionId (r:1 w:1)
+	/// Proof: SygmaAccessSegregator NextActionId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: SygmaAccessSegregator PendingActions (r:0 w:1)
+	/// Proof Skipped: SygmaAccessSegregator PendingActions (max_values: None, max_size: None, mode: Measured)
+	fn schedule_action() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `120`
+		//  Estimated: `3585`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3585))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator PendingActions (r:1 w:1)
+	/// Proof Skipped: SygmaAccessSegregator PendingActions (max_values: None, max_size: None, mode: Measured)
+	fn cancel_action() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187`
+		//  Estimated: `3652`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3652))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator PendingActions (r:1 w:1)
+	/// Proof Skipped: SygmaAccessSegregator PendingActions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator ExecutingAction (r:1 w:1)
+	/// Proof Skipped: SygmaAccessSegregator ExecutingAction (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:0 w:1)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	fn execute_action() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `216`
+		//  Estimated: `3681`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3681))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator CollectiveGrants (r:1 w:1)
+	/// Proof Skipped: SygmaAccessSegregator CollectiveGrants (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:0 w:1)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	fn grant_origin_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3877))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator ExtrinsicAccess (r:1 w:1)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicAccess (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaAccessSegregator CollectiveGrants (r:1 w:1)
+	/// Proof Skipped: SygmaAccessSegregator CollectiveGrants (max_values: None, max_size: None, mode: Measured)
+	fn revoke_origin_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `570`
+		//  Estimated: `4035`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4035))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 }
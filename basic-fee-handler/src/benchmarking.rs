@@ -27,4 +27,30 @@ mod benchmarks {
 
 		assert_eq!(AssetFees::<T>::get(&(dest_domain_id, native_location.into())), Some(fee),);
 	}
+
+	#[benchmark]
+	fn set_fee_in_asset() {
+		let dest_domain_id: DomainID = 1;
+		let asset_location: MultiLocation = MultiLocation::new(1, X1(Parachain(2004)));
+		let native_location: MultiLocation = MultiLocation::here();
+		let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+
+		#[extrinsic_call]
+		set_fee_in_asset(
+			SystemOrigin::Root,
+			dest_domain_id,
+			Box::new(asset_location.into()),
+			Box::new(native_location.into()),
+			fee,
+		);
+
+		assert_eq!(
+			FeeAssetFees::<T>::get(&(
+				dest_domain_id,
+				asset_location.into(),
+				native_location.into()
+			)),
+			Some(fee),
+		);
+	}
 }
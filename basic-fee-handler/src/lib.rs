@@ -30,8 +30,15 @@ pub mod pallet {
 	#[pallet::getter(fn asset_fees)]
 	pub type AssetFees<T: Config> = StorageMap<_, Twox64Concat, (DomainID, AssetId), u128>;
 
+	/// Mapping fungible asset id and the asset its fee is paid in to corresponding fee amount
+	#[pallet::storage]
+	#[pallet::getter(fn fee_asset_fees)]
+	pub type FeeAssetFees<T: Config> =
+		StorageMap<_, Twox64Concat, (DomainID, AssetId, AssetId), u128>;
+
 	pub trait WeightInfo {
 		fn set_fee() -> Weight;
+		fn set_fee_in_asset() -> Weight;
 	}
 
 	#[pallet::pallet]
@@ -55,6 +62,9 @@ pub mod pallet {
 		/// Fee set for a specific asset
 		/// args: [domain, asset, amount]
 		FeeSet { domain: DomainID, asset: AssetId, amount: u128 },
+		/// Fee paid in another asset set for a specific asset
+		/// args: [domain, asset, fee_asset, amount]
+		FeeInAssetSet { domain: DomainID, asset: AssetId, fee_asset: AssetId, amount: u128 },
 	}
 
 	#[pallet::error]
@@ -93,25 +103,70 @@ pub mod pallet {
 			Self::deposit_event(Event::FeeSet { domain, asset, amount });
 			Ok(())
 		}
+
+		/// Set bridge fee for a specific asset that is paid in `fee_asset`
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_in_asset())]
+		pub fn set_fee_in_asset(
+			origin: OriginFor<T>,
+			domain: DomainID,
+			asset: Box<AssetId>,
+			fee_asset: Box<AssetId>,
+			amount: u128,
+		) -> DispatchResult {
+			let asset: AssetId = *asset;
+			let fee_asset: AssetId = *fee_asset;
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_fee_in_asset".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+
+			// Update asset fee, fee paid in the asset itself is set by `set_fee`
+			if asset == fee_asset {
+				AssetFees::<T>::insert((domain, &asset), amount);
+			} else {
+				FeeAssetFees::<T>::insert((domain, &asset, &fee_asset), amount);
+			}
+
+			// Emit FeeInAssetSet event
+			Self::deposit_event(Event::FeeInAssetSet { domain, asset, fee_asset, amount });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> FeeHandler for Pallet<T> {
 		fn get_fee(domain: DomainID, asset: MultiAsset) -> Option<u128> {
 			AssetFees::<T>::get((domain, &asset.id))
 		}
+
+		fn get_fee_in_asset(
+			domain: DomainID,
+			asset: MultiAsset,
+			fee_asset: AssetId,
+		) -> Option<u128> {
+			if asset.id == fee_asset {
+				Self::get_fee(domain, asset)
+			} else {
+				FeeAssetFees::<T>::get((domain, &asset.id, &fee_asset))
+			}
+		}
 	}
 
 	#[cfg(test)]
 	mod test {
 		use crate as basic_fee_handler;
-		use crate::{AssetFees, Event as BasicFeeHandlerEvent};
+		use crate::{AssetFees, Event as BasicFeeHandlerEvent, FeeAssetFees};
 		use basic_fee_handler::mock::{
 			assert_events, new_test_ext, AccessSegregator, BasicFeeHandler, FeeHandlerPalletIndex,
 			RuntimeEvent as Event, RuntimeOrigin as Origin, Test, ALICE,
 		};
 		use frame_support::{assert_noop, assert_ok};
 		use sp_std::boxed::Box;
-		use sygma_traits::DomainID;
+		use sygma_traits::{DomainID, FeeHandler};
 		use xcm::latest::{prelude::*, MultiLocation};
 
 		#[test]
@@ -240,5 +295,80 @@ pub mod pallet {
 				assert_eq!(AssetFees::<Test>::get((dest_domain_id, asset_id)).unwrap(), 200);
 			})
 		}
+
+		#[test]
+		fn set_get_fee_in_asset() {
+			new_test_ext().execute_with(|| {
+				let dest_domain_id: DomainID = 0;
+				let asset_id = Concrete(MultiLocation::new(1, X1(Parachain(2004))));
+				let fee_asset_id = Concrete(MultiLocation::new(0, Here));
+				let asset = (asset_id, 1_000u128).into();
+
+				// no fee set, asset can not be transferred
+				assert_eq!(
+					BasicFeeHandler::get_fee_in_asset(dest_domain_id, asset.clone(), fee_asset_id),
+					None
+				);
+
+				assert_ok!(BasicFeeHandler::set_fee_in_asset(
+					Origin::root(),
+					dest_domain_id,
+					Box::new(asset_id),
+					Box::new(fee_asset_id),
+					10
+				));
+				assert_eq!(
+					FeeAssetFees::<Test>::get((dest_domain_id, asset_id, fee_asset_id)).unwrap(),
+					10
+				);
+				assert_eq!(
+					BasicFeeHandler::get_fee_in_asset(dest_domain_id, asset.clone(), fee_asset_id),
+					Some(10)
+				);
+				// fee paid in the asset itself is still unset
+				assert_eq!(BasicFeeHandler::get_fee(dest_domain_id, asset.clone()), None);
+
+				// setting fee paid in the asset itself is the same as `set_fee`
+				assert_ok!(BasicFeeHandler::set_fee_in_asset(
+					Origin::root(),
+					dest_domain_id,
+					Box::new(asset_id),
+					Box::new(asset_id),
+					20
+				));
+				assert_eq!(AssetFees::<Test>::get((dest_domain_id, asset_id)).unwrap(), 20);
+				assert_eq!(
+					BasicFeeHandler::get_fee_in_asset(dest_domain_id, asset, asset_id),
+					Some(20)
+				);
+
+				// permission test: unauthorized account should not be able to set fee
+				assert_noop!(
+					BasicFeeHandler::set_fee_in_asset(
+						Some(ALICE).into(),
+						dest_domain_id,
+						Box::new(asset_id),
+						Box::new(fee_asset_id),
+						30
+					),
+					basic_fee_handler::Error::<Test>::AccessDenied
+				);
+
+				assert_events(vec![
+					Event::BasicFeeHandler(BasicFeeHandlerEvent::FeeInAssetSet {
+						domain: dest_domain_id,
+						asset: asset_id,
+						fee_asset: fee_asset_id,
+						amount: 10,
+					}),
+					Event::BasicFeeHandler(BasicFeeHandlerEvent::FeeInAssetSet {
+						domain: dest_domain_id,
+						asset: asset_id,
+						fee_asset: asset_id,
+						amount: 20,
+					}),
+				]);
+			})
+		}
 	}
 }
@@ -123,6 +123,7 @@ parameter_types! {
 	pub RegisteredExtrinsics: Vec<(u8, Vec<u8>)> = [
 		(AccessSegregatorPalletIndex::get(), b"grant_access".to_vec()),
 		(FeeHandlerPalletIndex::get(), b"set_fee".to_vec()),
+		(FeeHandlerPalletIndex::get(), b"set_fee_in_asset".to_vec()),
 	].to_vec();
 }
 
@@ -131,6 +132,11 @@ impl sygma_access_segregator::Config for Test {
 	type BridgeCommitteeOrigin = EnsureRoot<Self::AccountId>;
 	type PalletIndex = AccessSegregatorPalletIndex;
 	type Extrinsics = RegisteredExtrinsics;
+	type RuntimeCall = RuntimeCall;
+	type AccessOriginConverter = sygma_access_segregator::SignedAccessOrigin;
+	type MaxCollectiveGrants = ConstU32<8>;
+	type MaxRolesPerAccount = ConstU32<8>;
+	type PalletsOrigin = OriginCaller;
 	type WeightInfo = sygma_access_segregator::weights::SygmaWeightInfo<Test>;
 }
 
@@ -35,6 +35,8 @@ use sp_std::marker::PhantomData;
 /// Weight functions for `sygma_basic_feehandler`.
 pub struct SygmaWeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBasicFeeHandler AssetFees (r:0 w:1)
 	/// Proof Skipped: SygmaBasicFeeHandler AssetFees (max_values: None, max_size: None, mode: Measured)
 	fn set_fee() -> Weight {
@@ -44,6 +46,21 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 		// Minimum execution time: 9_000_000 picoseconds.
 		Weight::from_parts(10_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBasicFeeHandler FeeAssetFees (r:0 w:1)
+	/// Proof Skipped: SygmaBasicFeeHandler FeeAssetFees (max_values: None, max_size: None, mode: Measured)
+	fn set_fee_in_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
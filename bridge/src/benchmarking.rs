@@ -7,17 +7,18 @@
 use super::*;
 use codec::Encode;
 use frame_benchmarking::v2::*;
-use frame_support::{crypto::ecdsa::ECDSAExt, traits::Currency};
-use frame_system::RawOrigin as SystemOrigin;
+use frame_support::{crypto::ecdsa::ECDSAExt, traits::Currency, weights::Weight};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin as SystemOrigin};
 use primitive_types::U256;
-use sp_runtime::AccountId32;
+use sp_runtime::{AccountId32, Perbill};
 use sp_std::{borrow::Borrow, prelude::*};
 
 use sygma_fee_handler_router::FeeHandlerType;
-use sygma_traits::{ChainID, DomainID, MpcAddress, ResourceId};
+use sygma_traits::{ChainID, DomainID, MpcAddress, ResourceId, VerifyingContractAddress};
 
 use crate::Pallet as SygmaBridge;
 use sygma_basic_feehandler::Pallet as BasicFeeHandler;
+use sygma_decimal_converter::Pallet as DecimalConverter;
 use sygma_fee_handler_router::Pallet as FeeHandlerRouter;
 
 use pallet_balances::Pallet as Balances;
@@ -37,11 +38,31 @@ pub fn slice_to_generalkey(key: &[u8]) -> Junction {
 	}
 }
 
+/// Fill the deposit records of dest domain so that the next deposit prunes the oldest record
+fn fill_deposit_records<T: Config>(dest_domain_id: DomainID, sender: T::AccountId) {
+	let retention = T::DepositRecordRetention::get();
+	DepositCounts::<T>::insert(dest_domain_id, retention);
+	DepositRecords::<T>::insert(
+		dest_domain_id,
+		0,
+		DepositRecord {
+			sender,
+			resource_id: T::GenericResourceId::get(),
+			amount: 0,
+			recipient: Default::default(),
+			fee: 0,
+			block_number: BlockNumberFor::<T>::default(),
+		},
+	);
+	DepositBlocks::<T>::insert(dest_domain_id, BlockNumberFor::<T>::default(), 1);
+}
+
 #[benchmarks(
     where
 		T: pallet_balances::Config,
 		T: sygma_basic_feehandler::Config,
 		T: sygma_fee_handler_router::Config,
+		T: sygma_decimal_converter::Config,
         <T as frame_system::Config>::AccountId: From<[u8; 32]> + Into<[u8; 32]> + From<AccountId32>,
         <T as pallet_balances::Config>::Balance: From<u128>,
         sp_runtime::AccountId32: Borrow<<T as frame_system::Config>::AccountId>,
@@ -59,7 +80,7 @@ mod benchmarks {
 		#[extrinsic_call]
 		pause_bridge(SystemOrigin::Root, dest_domain_id);
 
-		assert!(IsPaused::<T>::get(dest_domain_id));
+		assert!(SygmaBridge::<T>::is_domain_paused(dest_domain_id));
 	}
 
 	#[benchmark]
@@ -73,7 +94,7 @@ mod benchmarks {
 		#[extrinsic_call]
 		unpause_bridge(SystemOrigin::Root, dest_domain_id);
 
-		assert!(!IsPaused::<T>::get(dest_domain_id));
+		assert!(!SygmaBridge::<T>::is_domain_paused(dest_domain_id));
 	}
 
 	#[benchmark]
@@ -122,6 +143,8 @@ mod benchmarks {
 		let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
 		let fee = 1_000_000_000_000u128; // 1 with 12 decimals
 		let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+		let native_resourceid: ResourceId =
+			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
 		let caller = whitelisted_caller::<AccountId32>();
 
 		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
@@ -146,7 +169,28 @@ mod benchmarks {
 
 		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
 			.unwrap();
+		SygmaBridge::<T>::register_resource(
+			SystemOrigin::Root.into(),
+			native_resourceid,
+			Box::new(native_location.clone().into()),
+		)
+		.unwrap();
+		DecimalConverter::<T>::set_decimals(
+			SystemOrigin::Root.into(),
+			Box::new(native_location.clone().into()),
+			12u8,
+		)
+		.unwrap();
 		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), test_mpc_addr).unwrap();
+		SygmaBridge::<T>::set_transfer_limit(
+			SystemOrigin::Root.into(),
+			dest_domain_id,
+			Box::new(native_location.clone().into()),
+			u128::MAX,
+			100u32.into(),
+		)
+		.unwrap();
+		fill_deposit_records::<T>(dest_domain_id, caller.clone().into());
 
 		#[extrinsic_call]
 		deposit(
@@ -176,6 +220,16 @@ mod benchmarks {
 			.unwrap();
 		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), test_mpc_addr).unwrap();
 
+		// Record a deposit on the retried block
+		let caller = whitelisted_caller::<AccountId32>();
+		frame_system::Pallet::<T>::set_block_number(123u32.into());
+		SygmaBridge::<T>::deposit_generic(
+			SystemOrigin::Signed(caller.into()).into(),
+			dest_domain_id,
+			vec![],
+		)
+		.unwrap();
+
 		#[extrinsic_call]
 		retry(SystemOrigin::Root, 123, dest_domain_id);
 	}
@@ -206,6 +260,28 @@ mod benchmarks {
 		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, U256::from(1))
 			.unwrap();
 
+		// register native asset resource
+		SygmaBridge::<T>::register_resource(
+			SystemOrigin::Root.into(),
+			native_resourceid,
+			Box::new(MultiLocation::here().into()),
+		)
+		.unwrap();
+		DecimalConverter::<T>::set_decimals(
+			SystemOrigin::Root.into(),
+			Box::new(MultiLocation::here().into()),
+			12u8,
+		)
+		.unwrap();
+		SygmaBridge::<T>::set_transfer_limit(
+			SystemOrigin::Root.into(),
+			dest_domain_id,
+			Box::new(MultiLocation::here().into()),
+			u128::MAX,
+			100u32.into(),
+		)
+		.unwrap();
+
 		// Generate proposals
 		// amount is in 18 decimal 0.000200000000000000, will be convert to 12 decimal
 		// 0.000200000000
@@ -257,7 +333,7 @@ mod benchmarks {
 		pause_all_bridges(SystemOrigin::Root);
 
 		for i in 1..domain_size + 1 {
-			assert!(IsPaused::<T>::get(i));
+			assert!(SygmaBridge::<T>::is_domain_paused(i));
 		}
 	}
 
@@ -280,7 +356,676 @@ mod benchmarks {
 		unpause_all_bridges(SystemOrigin::Root);
 
 		for i in 1..domain_size + 1 {
-			assert!(!IsPaused::<T>::get(i));
+			assert!(!SygmaBridge::<T>::is_domain_paused(i));
 		}
 	}
+
+	#[benchmark]
+	fn register_resource() {
+		let resource_id: ResourceId = [1u8; 32];
+		let asset_id: AssetId = MultiLocation::here().into();
+
+		#[extrinsic_call]
+		register_resource(SystemOrigin::Root, resource_id, Box::new(asset_id));
+
+		assert_eq!(AssetResourceIds::<T>::get(asset_id), Some(resource_id));
+		assert_eq!(ResourceAssetIds::<T>::get(resource_id), Some(asset_id));
+	}
+
+	#[benchmark]
+	fn unregister_resource() {
+		let resource_id: ResourceId = [1u8; 32];
+		let asset_id: AssetId = MultiLocation::here().into();
+
+		SygmaBridge::<T>::register_resource(
+			SystemOrigin::Root.into(),
+			resource_id,
+			Box::new(asset_id),
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		unregister_resource(SystemOrigin::Root, resource_id);
+
+		assert!(AssetResourceIds::<T>::get(asset_id).is_none());
+		assert!(ResourceAssetIds::<T>::get(resource_id).is_none());
+	}
+
+	#[benchmark]
+	fn schedule_mpc_rotation() {
+		let old_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+		let new_mpc_addr: MpcAddress = MpcAddress([2u8; 20]);
+		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), old_mpc_addr).unwrap();
+
+		#[extrinsic_call]
+		schedule_mpc_rotation(SystemOrigin::Root, new_mpc_addr);
+
+		assert_eq!(PendingMpcAddr::<T>::get().map(|(addr, _)| addr), Some(new_mpc_addr));
+	}
+
+	#[benchmark]
+	fn schedule_mpc_rotation_signed() {
+		let caller = whitelisted_caller::<AccountId32>();
+		let key_type = sp_core::crypto::KeyTypeId(*b"code");
+		let pub_key = sp_io::crypto::ecdsa_generate(key_type, None);
+		let old_mpc_addr: MpcAddress = MpcAddress(pub_key.to_eth_address().unwrap());
+		let new_mpc_addr: MpcAddress = MpcAddress([2u8; 20]);
+		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), old_mpc_addr).unwrap();
+
+		let final_message: [u8; 32] = SygmaBridge::<T>::construct_ecdsa_signing_mpc_rotation_data(
+			new_mpc_addr,
+			MpcRotationNonce::<T>::get(),
+		);
+		let signature = sp_io::crypto::ecdsa_sign_prehashed(key_type, &pub_key, &final_message)
+			.expect("Generates signature");
+
+		#[extrinsic_call]
+		schedule_mpc_rotation_signed(
+			SystemOrigin::Signed(caller.into()),
+			new_mpc_addr,
+			signature.encode(),
+		);
+
+		assert_eq!(PendingMpcAddr::<T>::get().map(|(addr, _)| addr), Some(new_mpc_addr));
+	}
+
+	#[benchmark]
+	fn deposit_generic() {
+		let caller = whitelisted_caller::<AccountId32>();
+		let dest_domain_id: DomainID = 1;
+		let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, U256::from(1))
+			.unwrap();
+		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), test_mpc_addr).unwrap();
+		fill_deposit_records::<T>(dest_domain_id, caller.clone().into());
+
+		#[extrinsic_call]
+		deposit_generic(SystemOrigin::Signed(caller.into()), dest_domain_id, vec![1u8; 256]);
+
+		assert_eq!(DepositCounts::<T>::get(dest_domain_id), T::DepositRecordRetention::get() + 1);
+	}
+
+	#[benchmark]
+	fn set_transfer_limit() {
+		let dest_domain_id: DomainID = 1;
+		let native_location: MultiLocation = MultiLocation::here();
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, U256::from(1))
+			.unwrap();
+
+		#[extrinsic_call]
+		set_transfer_limit(
+			SystemOrigin::Root,
+			dest_domain_id,
+			Box::new(native_location.into()),
+			1_000_000u128,
+			100u32.into(),
+		);
+
+		assert!(TransferLimits::<T>::contains_key(dest_domain_id, AssetId::from(native_location)));
+	}
+
+	#[benchmark]
+	fn remove_transfer_limit() {
+		let dest_domain_id: DomainID = 1;
+		let native_location: MultiLocation = MultiLocation::here();
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, U256::from(1))
+			.unwrap();
+		SygmaBridge::<T>::set_transfer_limit(
+			SystemOrigin::Root.into(),
+			dest_domain_id,
+			Box::new(native_location.into()),
+			1_000_000u128,
+			100u32.into(),
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		remove_transfer_limit(SystemOrigin::Root, dest_domain_id, Box::new(native_location.into()));
+
+		assert!(!TransferLimits::<T>::contains_key(dest_domain_id, AssetId::from(native_location)));
+	}
+
+	#[benchmark]
+	fn execute_failed_proposal() {
+		let caller = whitelisted_caller::<AccountId32>();
+		let amount = 200_000_000_000_000u128;
+		let dest_domain_id: DomainID = 1;
+		let bridge_account: AccountId32 = AccountId32::new([101u8; 32]);
+		let native_resourceid: ResourceId =
+			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
+
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&bridge_account.clone().into(),
+			(amount).into(),
+		);
+
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, U256::from(1))
+			.unwrap();
+		SygmaBridge::<T>::register_resource(
+			SystemOrigin::Root.into(),
+			native_resourceid,
+			Box::new(MultiLocation::here().into()),
+		)
+		.unwrap();
+		DecimalConverter::<T>::set_decimals(
+			SystemOrigin::Root.into(),
+			Box::new(MultiLocation::here().into()),
+			12u8,
+		)
+		.unwrap();
+		SygmaBridge::<T>::set_transfer_limit(
+			SystemOrigin::Root.into(),
+			dest_domain_id,
+			Box::new(MultiLocation::here().into()),
+			u128::MAX,
+			100u32.into(),
+		)
+		.unwrap();
+
+		// amount is in 18 decimal 0.000200000000000000, will be convert to 12 decimal
+		// 0.000200000000
+		FailedProposals::<T>::insert(
+			dest_domain_id,
+			1,
+			FailedProposal {
+				resource_id: native_resourceid,
+				data: SygmaBridge::<T>::create_deposit_data(
+					amount,
+					MultiLocation::new(
+						0,
+						X1(Junction::AccountId32 { network: None, id: caller.clone().into() }),
+					)
+					.encode(),
+				)
+				.try_into()
+				.unwrap(),
+				error: b"BridgePaused".to_vec().try_into().unwrap(),
+				block_number: 1u32.into(),
+			},
+		);
+
+		#[extrinsic_call]
+		execute_failed_proposal(
+			SystemOrigin::Signed(caller.clone().into()),
+			dest_domain_id,
+			1,
+			Weight::zero(),
+		);
+
+		assert_eq!(Balances::<T, _>::free_balance(caller), 200000000.into());
+		assert!(SygmaBridge::<T>::is_proposal_executed(1, dest_domain_id));
+		assert!(!FailedProposals::<T>::contains_key(dest_domain_id, 1));
+	}
+
+	#[benchmark]
+	fn refund_deposit() {
+		let caller = whitelisted_caller::<AccountId32>();
+		let treasury_account: AccountId32 = AccountId32::new([100u8; 32]);
+		let bridge_account: AccountId32 = AccountId32::new([101u8; 32]);
+		let dest_domain_id: DomainID = 1;
+		let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+		let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+		let native_resourceid: ResourceId =
+			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		let key_type = sp_core::crypto::KeyTypeId(*b"code");
+		let pub_key = sp_io::crypto::ecdsa_generate(key_type, None);
+		let test_mpc_addr: MpcAddress = MpcAddress(pub_key.to_eth_address().unwrap());
+		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), test_mpc_addr).unwrap();
+
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&bridge_account.clone().into(),
+			(amount * 2).into(),
+		);
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&treasury_account.clone().into(),
+			(fee * 2).into(),
+		);
+
+		SygmaBridge::<T>::register_resource(
+			SystemOrigin::Root.into(),
+			native_resourceid,
+			Box::new(MultiLocation::here().into()),
+		)
+		.unwrap();
+		DepositRecords::<T>::insert(
+			dest_domain_id,
+			0,
+			DepositRecord {
+				sender: caller.clone().into(),
+				resource_id: native_resourceid,
+				amount,
+				recipient: b"ethereum recipient".to_vec().try_into().unwrap(),
+				fee,
+				block_number: BlockNumberFor::<T>::default(),
+			},
+		);
+		DepositBlocks::<T>::insert(dest_domain_id, BlockNumberFor::<T>::default(), 1);
+
+		let final_message: [u8; 32] =
+			SygmaBridge::<T>::construct_ecdsa_signing_refund_data(dest_domain_id, 0, true);
+		let signature = sp_io::crypto::ecdsa_sign_prehashed(key_type, &pub_key, &final_message)
+			.expect("Generates signature");
+
+		#[extrinsic_call]
+		refund_deposit(
+			SystemOrigin::Signed(caller.clone().into()),
+			dest_domain_id,
+			0,
+			true,
+			signature.encode(),
+		);
+
+		assert_eq!(Balances::<T, _>::free_balance(caller), (amount + fee).into());
+		assert!(!DepositRecords::<T>::contains_key(dest_domain_id, 0));
+	}
+
+	#[benchmark]
+	fn set_eip712_domain() {
+		let verifying_contract: VerifyingContractAddress = primitive_types::H160([1u8; 20]);
+
+		#[extrinsic_call]
+		set_eip712_domain(
+			SystemOrigin::Root,
+			vec![1u8; 64],
+			vec![1u8; 32],
+			U256::from(1),
+			verifying_contract,
+			Some([1u8; 32]),
+		);
+
+		assert_eq!(SygmaBridge::<T>::eip712_domain().verifying_contract, verifying_contract);
+	}
+
+	#[benchmark]
+	fn set_signer_set() {
+		let signers: Vec<MpcAddress> = (1..=16u8).map(|i| MpcAddress([i; 20])).collect();
+
+		#[extrinsic_call]
+		set_signer_set(SystemOrigin::Root, signers, 16);
+
+		assert_eq!(SygmaBridge::<T>::signer_set().map(|signer_set| signer_set.threshold), Some(16));
+	}
+
+	#[benchmark]
+	fn clear_signer_set() {
+		let signers: Vec<MpcAddress> = (1..=16u8).map(|i| MpcAddress([i; 20])).collect();
+		SygmaBridge::<T>::set_signer_set(SystemOrigin::Root.into(), signers, 16).unwrap();
+
+		#[extrinsic_call]
+		clear_signer_set(SystemOrigin::Root);
+
+		assert!(SygmaBridge::<T>::signer_set().is_none());
+	}
+
+	#[benchmark]
+	fn verify_signatures(s: Linear<1, 16>) {
+		let key_type = sp_core::crypto::KeyTypeId(*b"code");
+		let pub_keys: Vec<_> =
+			(0..16).map(|_| sp_io::crypto::ecdsa_generate(key_type, None)).collect();
+		let signers: Vec<MpcAddress> = pub_keys
+			.iter()
+			.map(|pub_key| MpcAddress(pub_key.to_eth_address().unwrap()))
+			.collect();
+		SygmaBridge::<T>::set_signer_set(SystemOrigin::Root.into(), signers, s).unwrap();
+
+		let final_message = [1u8; 32];
+		let mut signature = vec![];
+		for pub_key in pub_keys.iter().take(s as usize) {
+			signature.extend(
+				sp_io::crypto::ecdsa_sign_prehashed(key_type, pub_key, &final_message)
+					.expect("Generates signature")
+					.encode(),
+			);
+		}
+
+		let verified;
+		#[block]
+		{
+			verified = SygmaBridge::<T>::verify_by_mpc_address(final_message, signature);
+		}
+
+		assert!(verified);
+	}
+
+	#[benchmark]
+	fn deposit_with_fee_asset() {
+		let treasury_account: AccountId32 = AccountId32::new([100u8; 32]);
+		let bridge_account: AccountId32 = AccountId32::new([101u8; 32]);
+		let native_location: MultiLocation = MultiLocation::here();
+
+		let dest_domain_id: DomainID = 1;
+		let dest_chain_id: ChainID = U256::from(1);
+		let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+		let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+		let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+		let native_resourceid: ResourceId =
+			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		let caller = whitelisted_caller::<AccountId32>();
+
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&caller.clone().into(),
+			(amount * 2).into(),
+		);
+
+		BasicFeeHandler::<T>::set_fee_in_asset(
+			SystemOrigin::Root.into(),
+			dest_domain_id,
+			Box::new(native_location.clone().into()),
+			Box::new(native_location.clone().into()),
+			fee,
+		)
+		.unwrap();
+		FeeHandlerRouter::<T>::set_fee_handler(
+			SystemOrigin::Root.into(),
+			dest_domain_id,
+			Box::new(native_location.clone().into()),
+			FeeHandlerType::BasicFeeHandler,
+		)
+		.unwrap();
+
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
+			.unwrap();
+		SygmaBridge::<T>::register_resource(
+			SystemOrigin::Root.into(),
+			native_resourceid,
+			Box::new(native_location.clone().into()),
+		)
+		.unwrap();
+		DecimalConverter::<T>::set_decimals(
+			SystemOrigin::Root.into(),
+			Box::new(native_location.clone().into()),
+			12u8,
+		)
+		.unwrap();
+		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), test_mpc_addr).unwrap();
+		SygmaBridge::<T>::set_transfer_limit(
+			SystemOrigin::Root.into(),
+			dest_domain_id,
+			Box::new(native_location.clone().into()),
+			u128::MAX,
+			100u32.into(),
+		)
+		.unwrap();
+		fill_deposit_records::<T>(dest_domain_id, caller.clone().into());
+
+		// Only native asset is available here, so the fee is paid in the transferred asset
+		#[extrinsic_call]
+		deposit_with_fee_asset(
+			SystemOrigin::Signed(caller.clone().into()),
+			Box::new((Concrete(native_location), Fungible(amount)).into()),
+			Box::new(MultiLocation {
+				parents: 0,
+				interior: X2(
+					slice_to_generalkey(b"ethereum recipient"),
+					slice_to_generalkey(&[dest_domain_id]),
+				),
+			}),
+			Box::new(native_location.into()),
+		);
+
+		assert_eq!(Balances::<T, _>::free_balance(caller), amount.into());
+		assert_eq!(Balances::<T, _>::free_balance(bridge_account), (amount - fee).into());
+		assert_eq!(Balances::<T, _>::free_balance(treasury_account), fee.into());
+	}
+
+	#[benchmark]
+	fn deposit_batch(n: Linear<1, MAX_DEPOSIT_BATCH_LENGTH>) {
+		let treasury_account: AccountId32 = AccountId32::new([100u8; 32]);
+		let bridge_account: AccountId32 = AccountId32::new([101u8; 32]);
+		let native_location: MultiLocation = MultiLocation::here();
+
+		let dest_domain_id: DomainID = 1;
+		let dest_chain_id: ChainID = U256::from(1);
+		let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+		let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+		let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+		let native_resourceid: ResourceId =
+			hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		let caller = whitelisted_caller::<AccountId32>();
+
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&caller.clone().into(),
+			(amount * (n as u128 + 1)).into(),
+		);
+
+		BasicFeeHandler::<T>::set_fee(
+			SystemOrigin::Root.into(),
+			dest_domain_id,
+			Box::new(native_location.clone().into()),
+			fee,
+		)
+		.unwrap();
+		FeeHandlerRouter::<T>::set_fee_handler(
+			SystemOrigin::Root.into(),
+			dest_domain_id,
+			Box::new(native_location.clone().into()),
+			FeeHandlerType::BasicFeeHandler,
+		)
+		.unwrap();
+
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
+			.unwrap();
+		SygmaBridge::<T>::register_resource(
+			SystemOrigin::Root.into(),
+			native_resourceid,
+			Box::new(native_location.clone().into()),
+		)
+		.unwrap();
+		DecimalConverter::<T>::set_decimals(
+			SystemOrigin::Root.into(),
+			Box::new(native_location.clone().into()),
+			12u8,
+		)
+		.unwrap();
+		SygmaBridge::<T>::set_mpc_address(SystemOrigin::Root.into(), test_mpc_addr).unwrap();
+		SygmaBridge::<T>::set_transfer_limit(
+			SystemOrigin::Root.into(),
+			dest_domain_id,
+			Box::new(native_location.clone().into()),
+			u128::MAX,
+			100u32.into(),
+		)
+		.unwrap();
+		fill_deposit_records::<T>(dest_domain_id, caller.clone().into());
+
+		let deposits: Vec<(MultiAsset, MultiLocation)> = (0..n)
+			.map(|_| {
+				(
+					(Concrete(native_location), Fungible(amount)).into(),
+					MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[dest_domain_id]),
+						),
+					},
+				)
+			})
+			.collect();
+
+		#[extrinsic_call]
+		deposit_batch(
+			SystemOrigin::Signed(caller.clone().into()),
+			deposits,
+			DepositBatchMode::BestEffort,
+		);
+
+		let n = n as u128;
+		assert_eq!(Balances::<T, _>::free_balance(caller), amount.into());
+		assert_eq!(Balances::<T, _>::free_balance(bridge_account), ((amount - fee) * n).into());
+		assert_eq!(Balances::<T, _>::free_balance(treasury_account), (fee * n).into());
+	}
+
+	#[benchmark]
+	fn set_circuit_breaker() {
+		let dest_domain_id: DomainID = 1;
+		let dest_chain_id: ChainID = U256::from(1);
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
+			.unwrap();
+
+		#[extrinsic_call]
+		set_circuit_breaker(SystemOrigin::Root, dest_domain_id, 10u32.into(), 100, 100);
+
+		assert!(CircuitBreakers::<T>::contains_key(dest_domain_id));
+	}
+
+	#[benchmark]
+	fn remove_circuit_breaker() {
+		let dest_domain_id: DomainID = 1;
+		let dest_chain_id: ChainID = U256::from(1);
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
+			.unwrap();
+		SygmaBridge::<T>::set_circuit_breaker(
+			SystemOrigin::Root.into(),
+			dest_domain_id,
+			10u32.into(),
+			100,
+			100,
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		remove_circuit_breaker(SystemOrigin::Root, dest_domain_id);
+
+		assert!(!CircuitBreakers::<T>::contains_key(dest_domain_id));
+	}
+
+	#[benchmark]
+	fn set_reserve_floor() {
+		let native_location: MultiLocation = MultiLocation::here();
+
+		#[extrinsic_call]
+		set_reserve_floor(SystemOrigin::Root, Box::new(native_location.into()), 100);
+
+		assert_eq!(ReserveFloors::<T>::get(AssetId::from(native_location)), Some(100));
+	}
+
+	#[benchmark]
+	fn pause_bridge_direction() {
+		let dest_domain_id: DomainID = 0;
+		let dest_chain_id: ChainID = U256::from(1);
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
+			.unwrap();
+
+		#[extrinsic_call]
+		pause_bridge_direction(SystemOrigin::Root, dest_domain_id, TransferDirection::Outbound);
+
+		assert!(IsPaused::<T>::get(dest_domain_id, TransferDirection::Outbound));
+	}
+
+	#[benchmark]
+	fn unpause_bridge_direction() {
+		let dest_domain_id: DomainID = 0;
+		let dest_chain_id: ChainID = U256::from(1);
+		SygmaBridge::<T>::register_domain(SystemOrigin::Root.into(), dest_domain_id, dest_chain_id)
+			.unwrap();
+		SygmaBridge::<T>::pause_bridge_direction(
+			SystemOrigin::Root.into(),
+			dest_domain_id,
+			TransferDirection::Outbound,
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		unpause_bridge_direction(SystemOrigin::Root, dest_domain_id, TransferDirection::Outbound);
+
+		assert!(!IsPaused::<T>::get(dest_domain_id, TransferDirection::Outbound));
+	}
+
+	#[benchmark]
+	fn pause_resource() {
+		let resource_id: ResourceId = [1u8; 32];
+
+		#[extrinsic_call]
+		pause_resource(SystemOrigin::Root, resource_id, TransferDirection::Inbound);
+
+		assert!(IsResourcePaused::<T>::get(resource_id, TransferDirection::Inbound));
+	}
+
+	#[benchmark]
+	fn unpause_resource() {
+		let resource_id: ResourceId = [1u8; 32];
+		SygmaBridge::<T>::pause_resource(
+			SystemOrigin::Root.into(),
+			resource_id,
+			TransferDirection::Inbound,
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		unpause_resource(SystemOrigin::Root, resource_id, TransferDirection::Inbound);
+
+		assert!(!IsResourcePaused::<T>::get(resource_id, TransferDirection::Inbound));
+	}
+
+	#[benchmark]
+	fn set_fee_distribution() {
+		let treasury_account: AccountId32 = AccountId32::new([100u8; 32]);
+		let relayer_account: AccountId32 = AccountId32::new([102u8; 32]);
+		let recipients = vec![
+			(treasury_account.into(), Perbill::from_percent(50)),
+			(relayer_account.into(), Perbill::from_percent(40)),
+		];
+
+		#[extrinsic_call]
+		set_fee_distribution(SystemOrigin::Root, recipients, Perbill::from_percent(10));
+
+		assert_eq!(
+			FeeDistributionRatios::<T>::get().map(|distribution| distribution.burn),
+			Some(Perbill::from_percent(10))
+		);
+	}
+
+	#[benchmark]
+	fn withdraw_fees() {
+		let dest_domain_id: DomainID = 1;
+		let native_location: MultiLocation = MultiLocation::here();
+		let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+		let to: AccountId32 = AccountId32::new([102u8; 32]);
+
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&T::FeeReserveAccount::get(),
+			(fee * 2).into(),
+		);
+		CollectedFees::<T>::insert(dest_domain_id, AssetId::from(native_location), fee);
+
+		#[extrinsic_call]
+		withdraw_fees(
+			SystemOrigin::Root,
+			dest_domain_id,
+			Box::new(native_location.into()),
+			fee,
+			to.into(),
+		);
+
+		assert_eq!(CollectedFees::<T>::get(dest_domain_id, AssetId::from(native_location)), 0);
+	}
+
+	#[benchmark]
+	fn distribute_fees(n: Linear<1, 16>) {
+		let dest_domain_id: DomainID = 1;
+		let native_location: MultiLocation = MultiLocation::here();
+		let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+
+		// Every recipient gets an equal share, the rest is burned
+		let share = Perbill::from_rational(1u32, n + 1);
+		let recipients: Vec<(T::AccountId, Perbill)> =
+			(0..n).map(|i| (AccountId32::new([i as u8 + 102; 32]).into(), share)).collect();
+		SygmaBridge::<T>::set_fee_distribution(
+			SystemOrigin::Root.into(),
+			recipients,
+			Perbill::from_parts(Perbill::one().deconstruct() - share.deconstruct() * n),
+		)
+		.unwrap();
+		let _ = <Balances<T, _> as Currency<_>>::make_free_balance_be(
+			&T::FeeReserveAccount::get(),
+			(fee * 2).into(),
+		);
+		CollectedFees::<T>::insert(dest_domain_id, AssetId::from(native_location), fee);
+
+		#[extrinsic_call]
+		distribute_fees(SystemOrigin::Root, dest_domain_id, Box::new(native_location.into()));
+
+		assert!(CollectedFees::<T>::get(dest_domain_id, AssetId::from(native_location)) < fee);
+	}
 }
@@ -21,6 +21,47 @@ mod encode;
 #[cfg(test)]
 mod mock;
 
+use frame_support::traits::tokens::{fungible, fungibles};
+use sp_runtime::SaturatedConversion;
+use sp_std::marker::PhantomData;
+use sygma_traits::BalanceProvider;
+use xcm::latest::{AssetId, Fungibility::Fungible, MultiAsset};
+use xcm_executor::traits::{MatchesFungible, MatchesFungibles};
+
+/// Read the balance of the asset from a `fungible` implementation, e.g. `pallet_balances`.
+/// `Matcher` is used to check the XCM `AssetId` is the asset of this implementation.
+pub struct FungibleBalance<AccountId, Currency, Matcher>(
+	PhantomData<(AccountId, Currency, Matcher)>,
+);
+impl<AccountId, Currency, Matcher> BalanceProvider for FungibleBalance<AccountId, Currency, Matcher>
+where
+	AccountId: From<[u8; 32]>,
+	Currency: fungible::Inspect<AccountId>,
+	Matcher: MatchesFungible<Currency::Balance>,
+{
+	fn balance(asset_id: &AssetId, who: &[u8; 32]) -> Option<u128> {
+		let asset: MultiAsset = (*asset_id, Fungible(0)).into();
+		Matcher::matches_fungible(&asset)?;
+		Some(Currency::balance(&(*who).into()).saturated_into())
+	}
+}
+
+/// Read the balance of the asset from a `fungibles` implementation, e.g. `pallet_assets`.
+/// `Matcher` is used to convert the XCM `AssetId` into the local asset id.
+pub struct FungiblesBalance<AccountId, Assets, Matcher>(PhantomData<(AccountId, Assets, Matcher)>);
+impl<AccountId, Assets, Matcher> BalanceProvider for FungiblesBalance<AccountId, Assets, Matcher>
+where
+	AccountId: From<[u8; 32]>,
+	Assets: fungibles::Inspect<AccountId>,
+	Matcher: MatchesFungibles<Assets::AssetId, Assets::Balance>,
+{
+	fn balance(asset_id: &AssetId, who: &[u8; 32]) -> Option<u128> {
+		let asset: MultiAsset = (*asset_id, Fungible(0)).into();
+		let (local_asset_id, _) = Matcher::matches_fungibles(&asset).ok()?;
+		Some(Assets::balance(local_asset_id, &(*who).into()).saturated_into())
+	}
+}
+
 #[allow(unused_variables)]
 #[allow(clippy::large_enum_variant)]
 #[frame_support::pallet]
@@ -29,18 +70,24 @@ pub mod pallet {
 	use ethabi::{encode as abi_encode, token::Token};
 	use frame_support::dispatch::RawOrigin;
 	use frame_support::{
-		dispatch::DispatchResult,
+		dispatch::{DispatchResult, GetDispatchInfo, PostDispatchInfo},
 		pallet_prelude::*,
-		traits::{ContainsPair, StorageVersion},
-		transactional, PalletId,
+		storage::with_storage_layer,
+		traits::{BuildGenesisConfig, Contains, ContainsPair, StorageVersion},
+		transactional, DefaultNoBound, PalletId,
 	};
 	use frame_system::pallet_prelude::*;
 	use primitive_types::U256;
 	use scale_info::TypeInfo;
-	use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
+	use sp_io::{
+		crypto::secp256k1_ecdsa_recover,
+		hashing::{blake2_256, keccak_256},
+	};
 	use sp_runtime::{
-		traits::{AccountIdConversion, Clear},
-		RuntimeDebug,
+		traits::{AccountIdConversion, Clear, Dispatchable, Saturating, Zero},
+		Perbill, RuntimeDebug, SaturatedConversion,
 	};
 	use sp_std::collections::btree_map::BTreeMap;
 	use sp_std::{boxed::Box, convert::From, vec, vec::Vec};
@@ -48,8 +95,9 @@ pub mod pallet {
 	use xcm_executor::traits::TransactAsset;
 
 	use sygma_traits::{
-		Bridge, ChainID, DecimalConverter, DepositNonce, DomainID, ExtractDestinationData,
-		FeeHandler, MpcAddress, ResourceId, TransferType, VerifyingContractAddress,
+		BalanceProvider, Bridge, ChainID, DecimalConverter, DepositNonce, DomainID,
+		ExtractDestinationData, FeeContext, FeeHandler, MpcAddress, NonFungibleMetadataHandler,
+		ResourceId, TransferType, VerifyingContractAddress,
 	};
 
 	use crate::eip712;
@@ -57,7 +105,7 @@ pub mod pallet {
 
 	#[allow(dead_code)]
 	const LOG_TARGET: &str = "runtime::sygmabridge";
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, RuntimeDebug)]
 	pub struct Proposal {
@@ -67,6 +115,154 @@ pub mod pallet {
 		pub data: Vec<u8>,
 	}
 
+	/// Recipient of a deposit record, a recipient longer than this is kept as its keccak256 hash
+	pub type DepositRecordRecipient = BoundedVec<u8, ConstU32<128>>;
+
+	/// Max number of expired deposit records pruned by a deposit, more than one so that records
+	/// out of a lowered retention are caught up with
+	pub const MAX_PRUNED_DEPOSIT_RECORDS: u32 = 2;
+
+	/// Record of a deposit kept on chain after its `Deposit` event is pruned
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct DepositRecord<AccountId, BlockNumber> {
+		pub sender: AccountId,
+		pub resource_id: ResourceId,
+		/// Bridged amount excluding fee, 1 for non-fungible asset and 0 for generic message
+		pub amount: u128,
+		pub recipient: DepositRecordRecipient,
+		pub fee: u128,
+		pub block_number: BlockNumber,
+	}
+
+	/// Max number of transfers in a deposit batch
+	pub const MAX_DEPOSIT_BATCH_LENGTH: u32 = 32;
+
+	/// How a deposit batch is handled when one of its transfers fails
+	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub enum DepositBatchMode {
+		/// The whole batch is reverted
+		AllOrNothing,
+		/// The failed transfer is skipped and the rest of the batch is still deposited
+		BestEffort,
+	}
+
+	/// Direction of an asset transfer relative to current chain
+	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub enum TransferDirection {
+		/// Deposit to dest domain
+		Outbound,
+		/// Proposal execution from origin domain
+		Inbound,
+	}
+
+	/// Maximum volume of an asset that can be transferred within any `window` blocks
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct TransferLimit<BlockNumber> {
+		pub cap: u128,
+		pub window: BlockNumber,
+	}
+
+	/// Transferred volume of the current and the previous window, the rolling volume is
+	/// approximated by weighting the previous window by its overlap with the rolling window
+	#[derive(
+		PartialEq, Eq, Clone, Default, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug,
+	)]
+	pub struct TransferVolume<BlockNumber> {
+		pub window_start: BlockNumber,
+		pub current: u128,
+		pub previous: u128,
+	}
+
+	/// Circuit breaker of a domain, a transfer that would take the inbound or outbound volume
+	/// within a `window` blocks above the threshold is not made and the domain is paused instead.
+	/// Volume is counted in 18 decimals, generic and non-fungible transfers carry no amount and
+	/// are not counted.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct CircuitBreaker<BlockNumber> {
+		pub window: BlockNumber,
+		pub max_inbound: u128,
+		pub max_outbound: u128,
+	}
+
+	/// Inbound and outbound volume of a domain in the current window of its circuit breaker
+	#[derive(
+		PartialEq, Eq, Clone, Default, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug,
+	)]
+	pub struct CircuitBreakerVolume<BlockNumber> {
+		pub window_start: BlockNumber,
+		pub inbound: u128,
+		pub outbound: u128,
+	}
+
+	/// Reason the circuit breaker of a domain was tripped
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub enum CircuitBreakerTripReason {
+		/// Inbound volume exceeds the threshold
+		InboundVolumeExceeded,
+		/// Outbound volume exceeds the threshold
+		OutboundVolumeExceeded,
+		/// Balance of the reserve account of the asset would drop below its floor
+		ReserveBelowFloor { asset_id: AssetId },
+	}
+
+	/// Data of a failed proposal, proposal with longer data is not kept on chain
+	pub type FailedProposalData = BoundedVec<u8, ConstU32<2048>>;
+
+	/// Error of a failed proposal, truncated if longer than this
+	pub type FailedProposalError = BoundedVec<u8, ConstU32<64>>;
+
+	/// Inbound proposal that failed to execute, it can be re-executed without a new MPC
+	/// signature once the cause of the failure is fixed
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct FailedProposal<BlockNumber> {
+		pub resource_id: ResourceId,
+		pub data: FailedProposalData,
+		pub error: FailedProposalError,
+		pub block_number: BlockNumber,
+	}
+
+	/// Name of EIP712 signing domain
+	pub type EIP712DomainName = BoundedVec<u8, ConstU32<64>>;
+
+	/// Version of EIP712 signing domain
+	pub type EIP712DomainVersion = BoundedVec<u8, ConstU32<32>>;
+
+	/// EIP712 domain that proposals and other MPC signed messages are signed in
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct EIP712DomainParams {
+		pub name: EIP712DomainName,
+		pub version: EIP712DomainVersion,
+		pub chain_id: ChainID,
+		pub verifying_contract: VerifyingContractAddress,
+		pub salt: Option<[u8; 32]>,
+	}
+
+	/// Signers of the threshold signature mode
+	pub type Signers = BoundedVec<MpcAddress, ConstU32<16>>;
+
+	/// Signer set of the m-of-n threshold signature mode, MPC signed messages must be signed by
+	/// at least `threshold` distinct signers of the set
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct SignerSet {
+		pub signers: Signers,
+		pub threshold: u32,
+	}
+
+	/// Recipients of distributed fees, e.g. treasury and relayer reward accounts, with their
+	/// shares of the fees
+	pub type FeeRecipients<AccountId> = BoundedVec<(AccountId, Perbill), ConstU32<16>>;
+
+	/// Ratios collected fees are distributed by, the shares of the recipients and the burn share
+	/// add up to 100%
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct FeeDistribution<AccountId> {
+		pub recipients: FeeRecipients<AccountId>,
+		/// Share of the fees that is burned
+		pub burn: Perbill,
+	}
+
 	pub trait WeightInfo {
 		fn pause_bridge() -> Weight;
 		fn unpause_bridge() -> Weight;
@@ -78,6 +274,31 @@ pub mod pallet {
 		fn execute_proposal(n: u32) -> Weight;
 		fn pause_all_bridges() -> Weight;
 		fn unpause_all_bridges() -> Weight;
+		fn register_resource() -> Weight;
+		fn unregister_resource() -> Weight;
+		fn schedule_mpc_rotation() -> Weight;
+		fn schedule_mpc_rotation_signed() -> Weight;
+		fn deposit_generic() -> Weight;
+		fn set_transfer_limit() -> Weight;
+		fn remove_transfer_limit() -> Weight;
+		fn execute_failed_proposal() -> Weight;
+		fn refund_deposit() -> Weight;
+		fn set_eip712_domain() -> Weight;
+		fn set_signer_set() -> Weight;
+		fn clear_signer_set() -> Weight;
+		fn verify_signatures(s: u32) -> Weight;
+		fn deposit_with_fee_asset() -> Weight;
+		fn deposit_batch(n: u32) -> Weight;
+		fn set_circuit_breaker() -> Weight;
+		fn remove_circuit_breaker() -> Weight;
+		fn set_reserve_floor() -> Weight;
+		fn pause_bridge_direction() -> Weight;
+		fn unpause_bridge_direction() -> Weight;
+		fn pause_resource() -> Weight;
+		fn unpause_resource() -> Weight;
+		fn set_fee_distribution() -> Weight;
+		fn withdraw_fees() -> Weight;
+		fn distribute_fees(n: u32) -> Weight;
 	}
 
 	#[pallet::pallet]
@@ -93,12 +314,12 @@ pub mod pallet {
 		type TransferReserveAccounts: Get<BTreeMap<AssetId, Self::AccountId>>;
 
 		/// EIP712 Verifying contract address
-		/// This is used in EIP712 typed data domain
+		/// This is used in EIP712 typed data domain until `SigningDomain` is set
 		#[pallet::constant]
 		type DestVerifyingContractAddress: Get<VerifyingContractAddress>;
 
 		/// Pallet ChainID
-		/// This is used in EIP712 typed data domain
+		/// This is used in EIP712 typed data domain until `SigningDomain` is set
 		#[pallet::constant]
 		type EIP712ChainID: Get<ChainID>;
 
@@ -112,9 +333,6 @@ pub mod pallet {
 		/// Implementation of withdraw and deposit an asset.
 		type AssetTransactor: TransactAsset;
 
-		/// AssetId and ResourceId pairs
-		type ResourcePairs: Get<Vec<(AssetId, ResourceId)>>;
-
 		/// Return true if asset reserved on current chain
 		type IsReserve: ContainsPair<MultiAsset, MultiLocation>;
 
@@ -130,6 +348,43 @@ pub mod pallet {
 		/// Asset decimal converter
 		type DecimalConverter: DecimalConverter;
 
+		/// Balance of reserve accounts, used by circuit breakers to check the reserve floors
+		type ReserveBalances: BalanceProvider;
+
+		/// Number of blocks during which both the current and the scheduled MPC address are
+		/// accepted when rotating the MPC key
+		#[pallet::constant]
+		type MpcRotationWindow: Get<BlockNumberFor<Self>>;
+
+		/// ResourceId of generic message transfer
+		#[pallet::constant]
+		type GenericResourceId: Get<ResourceId>;
+
+		/// The call dispatched by generic proposals
+		type RuntimeCall: Parameter
+			+ Dispatchable<
+				RuntimeOrigin = <Self as frame_system::Config>::RuntimeOrigin,
+				PostInfo = PostDispatchInfo,
+			> + GetDispatchInfo;
+
+		/// Return true if the call is allowed to be dispatched by generic proposals
+		type GenericCallFilter: Contains<<Self as Config>::RuntimeCall>;
+
+		/// Number of latest deposits per dest domain to keep records of, 0 disables deposit
+		/// records
+		#[pallet::constant]
+		type DepositRecordRetention: Get<DepositNonce>;
+
+		/// Priority of unsigned `execute_proposal` transactions
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// Return true if the asset is a non-fungible collection
+		type NonFungibleAssets: Contains<AssetId>;
+
+		/// Read and write metadata of bridged non-fungible assets
+		type NonFungibleMetadata: NonFungibleMetadataHandler;
+
 		/// Type representing the weight of this pallet
 		type WeightInfo: WeightInfo;
 	}
@@ -171,6 +426,18 @@ pub mod pallet {
 		/// When bridge is unpaused
 		/// args: [dest_domain_id]
 		BridgeUnpaused { dest_domain_id: DomainID },
+		/// When one direction of bridge is paused
+		/// args: [dest_domain_id, direction]
+		BridgeDirectionPaused { dest_domain_id: DomainID, direction: TransferDirection },
+		/// When one direction of bridge is unpaused
+		/// args: [dest_domain_id, direction]
+		BridgeDirectionUnpaused { dest_domain_id: DomainID, direction: TransferDirection },
+		/// When one direction of a resource is paused on all domains
+		/// args: [resource_id, direction]
+		ResourcePaused { resource_id: ResourceId, direction: TransferDirection },
+		/// When one direction of a resource is unpaused on all domains
+		/// args: [resource_id, direction]
+		ResourceUnpaused { resource_id: ResourceId, direction: TransferDirection },
 		/// When registering a new dest domainID with its corresponding chainID
 		RegisterDestDomain { sender: T::AccountId, domain_id: DomainID, chain_id: ChainID },
 		/// When unregistering a dest domainID with its corresponding chainID
@@ -187,6 +454,85 @@ pub mod pallet {
 		AllBridgePaused { sender: T::AccountId },
 		/// When all bridges are unpaused
 		AllBridgeUnpaused { sender: T::AccountId },
+		/// When a resourceID is bound to an asset
+		ResourceRegistered { sender: T::AccountId, resource_id: ResourceId, asset_id: AssetId },
+		/// When a resourceID is unbound from its asset
+		ResourceUnregistered { sender: T::AccountId, resource_id: ResourceId, asset_id: AssetId },
+		/// When a MPC address rotation is scheduled, proposals signed by either address are
+		/// accepted until `effective_at`
+		MpcRotationScheduled {
+			sender: T::AccountId,
+			old_mpc_addr: MpcAddress,
+			new_mpc_addr: MpcAddress,
+			effective_at: BlockNumberFor<T>,
+		},
+		/// When the MPC address is changed
+		MpcAddressChanged { old_mpc_addr: MpcAddress, new_mpc_addr: MpcAddress },
+		/// When the transfer limit of an asset to/from a domain is set
+		TransferLimitSet {
+			sender: T::AccountId,
+			domain_id: DomainID,
+			asset_id: AssetId,
+			cap: u128,
+			window: BlockNumberFor<T>,
+		},
+		/// When the transfer limit of an asset to/from a domain is removed
+		TransferLimitRemoved { sender: T::AccountId, domain_id: DomainID, asset_id: AssetId },
+		/// When a deposit that can not be executed on dest domain is refunded to its sender,
+		/// `fee` is 0 if the fee is not refunded
+		DepositRefunded {
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			sender: T::AccountId,
+			resource_id: ResourceId,
+			amount: u128,
+			fee: u128,
+		},
+		/// When the EIP712 signing domain is updated
+		EIP712DomainSet { sender: T::AccountId, domain: EIP712DomainParams },
+		/// When the signer set of threshold signature mode is set
+		SignerSetUpdated { sender: T::AccountId, signers: Vec<MpcAddress>, threshold: u32 },
+		/// When the signer set is cleared and the single MPC address mode is restored
+		SignerSetCleared { sender: T::AccountId },
+		/// When a transfer of a best-effort deposit batch failed and was skipped
+		/// args: [sender, index, error]
+		BatchDepositFailed { sender: T::AccountId, index: u32, error: Vec<u8> },
+		/// When the circuit breaker of a domain is set
+		CircuitBreakerSet {
+			sender: T::AccountId,
+			domain_id: DomainID,
+			window: BlockNumberFor<T>,
+			max_inbound: u128,
+			max_outbound: u128,
+		},
+		/// When the circuit breaker of a domain is removed
+		CircuitBreakerRemoved { sender: T::AccountId, domain_id: DomainID },
+		/// When the floor of the reserve account balance of an asset is set, 0 removes the floor
+		ReserveFloorSet { sender: T::AccountId, asset_id: AssetId, floor: u128 },
+		/// When a domain is paused by its circuit breaker
+		CircuitBreakerTripped { domain_id: DomainID, reason: CircuitBreakerTripReason },
+		/// When the ratios collected fees are distributed by are set
+		FeeDistributionSet {
+			sender: T::AccountId,
+			recipients: Vec<(T::AccountId, Perbill)>,
+			burn: Perbill,
+		},
+		/// When collected fees are withdrawn from the fee reserve account
+		FeesWithdrawn {
+			sender: T::AccountId,
+			domain_id: DomainID,
+			asset_id: AssetId,
+			amount: u128,
+			to: T::AccountId,
+		},
+		/// When collected fees are distributed, `amount` includes the `burned` amount
+		FeesDistributed {
+			sender: T::AccountId,
+			domain_id: DomainID,
+			asset_id: AssetId,
+			amount: u128,
+			burned: u128,
+		},
 	}
 
 	#[pallet::error]
@@ -209,6 +555,10 @@ pub mod pallet {
 		BridgePaused,
 		/// Bridge is unpaused
 		BridgeUnpaused,
+		/// Resource is paused
+		ResourcePaused,
+		/// Resource is unpaused
+		ResourceUnpaused,
 		/// Fee config option missing
 		MissingFeeConfig,
 		/// Asset not bound to a resource id
@@ -233,6 +583,59 @@ pub mod pallet {
 		DepositNonceOverflow,
 		/// Asset not bound to a liquidity holder account
 		NoLiquidityHolderAccountBound,
+		/// ResourceId already bound to an asset
+		ResourceAlreadyRegistered,
+		/// Asset already bound to a resource id
+		AssetAlreadyRegistered,
+		/// ResourceId not bound to any asset
+		ResourceNotRegistered,
+		/// A MPC address rotation is already scheduled
+		MpcRotationPending,
+		/// New MPC address is empty or same as the current one
+		InvalidMpcAddress,
+		/// Failed to decode the call of generic proposal
+		GenericCallDecodeFailed,
+		/// Call of generic proposal is not allowed
+		GenericCallFiltered,
+		/// Call of generic proposal exceeds the weight limit
+		GenericCallWeightExceeded,
+		/// Instance of non-fungible asset can not be represented as a token id
+		InvalidTokenId,
+		/// No deposit record was found
+		DepositRecordNotFound,
+		/// Transfer exceeds the volume cap of the asset within the rolling window
+		TransferLimitExceeded,
+		/// Window of transfer limit can not be zero
+		InvalidTransferLimit,
+		/// Asset has no transfer limit to/from the domain
+		TransferLimitNotSet,
+		/// No failed proposal is kept for the given origin domain and deposit nonce
+		FailedProposalNotFound,
+		/// Weight of the failed proposal exceeds the given weight limit
+		FailedProposalWeightExceeded,
+		/// Deposit of generic message or non-fungible asset can not be refunded
+		DepositNotRefundable,
+		/// Name or version of EIP712 domain is too long
+		InvalidEIP712Domain,
+		/// Signer set is empty, too large, has duplicated or empty signers, or the threshold is
+		/// not within 1 and the number of signers
+		InvalidSignerSet,
+		/// Signer set is not set
+		SignerSetNotSet,
+		/// Deposit batch is empty or exceeds `MAX_DEPOSIT_BATCH_LENGTH`
+		InvalidDepositBatch,
+		/// Window of circuit breaker can not be zero
+		InvalidCircuitBreaker,
+		/// Domain has no circuit breaker
+		CircuitBreakerNotSet,
+		/// Transfer tripped the circuit breaker of the domain, the domain is paused
+		CircuitBreakerTripped,
+		/// Fee shares don't add up to 100% or there are too many recipients
+		InvalidFeeDistribution,
+		/// Fee distribution ratios not set
+		FeeDistributionNotSet,
+		/// Collected fees can not cover the withdrawal
+		InsufficientCollectedFees,
 		/// Function unimplemented
 		Unimplemented,
 	}
@@ -242,12 +645,33 @@ pub mod pallet {
 	#[pallet::getter(fn deposit_counts)]
 	pub type DepositCounts<T> = StorageMap<_, Twox64Concat, DomainID, DepositNonce, ValueQuery>;
 
-	/// Bridge Pause indicator
+	/// Bridge Pause indicator, per domain and transfer direction
 	/// Bridge is unpaused initially, until pause
 	/// After mpc address setup, bridge should be paused until ready to unpause
 	#[pallet::storage]
 	#[pallet::getter(fn is_paused)]
-	pub type IsPaused<T> = StorageMap<_, Twox64Concat, DomainID, bool, ValueQuery>;
+	pub type IsPaused<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DomainID,
+		Twox64Concat,
+		TransferDirection,
+		bool,
+		ValueQuery,
+	>;
+
+	/// Resource Pause indicator, per transfer direction across all domains
+	#[pallet::storage]
+	#[pallet::getter(fn is_resource_paused)]
+	pub type IsResourcePaused<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ResourceId,
+		Twox64Concat,
+		TransferDirection,
+		bool,
+		ValueQuery,
+	>;
 
 	/// Pre-set MPC address
 	#[pallet::storage]
@@ -279,6 +703,221 @@ pub mod pallet {
 	#[pallet::getter(fn dest_chain_ids)]
 	pub type DestChainIds<T: Config> = StorageMap<_, Twox64Concat, DomainID, ChainID>;
 
+	/// Mapping of supported asset to its resourceID
+	#[pallet::storage]
+	#[pallet::getter(fn asset_resource_ids)]
+	pub type AssetResourceIds<T: Config> = StorageMap<_, Twox64Concat, AssetId, ResourceId>;
+
+	/// Reverse mapping of resourceID to its asset, always kept in sync with `AssetResourceIds`
+	#[pallet::storage]
+	#[pallet::getter(fn resource_asset_ids)]
+	pub type ResourceAssetIds<T: Config> = StorageMap<_, Twox64Concat, ResourceId, AssetId>;
+
+	#[pallet::genesis_config]
+	#[derive(DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Supported assets and their resourceID the resource registry is seeded with
+		pub resource_pairs: Vec<(AssetId, ResourceId)>,
+		#[serde(skip)]
+		pub _config: PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (asset_id, resource_id) in self.resource_pairs.iter() {
+				assert!(
+					!AssetResourceIds::<T>::contains_key(asset_id)
+						&& !ResourceAssetIds::<T>::contains_key(resource_id),
+					"Duplicate asset or resourceID in resource pairs"
+				);
+				AssetResourceIds::<T>::insert(asset_id, resource_id);
+				ResourceAssetIds::<T>::insert(resource_id, asset_id);
+			}
+		}
+	}
+
+	/// Scheduled MPC address and the block number it replaces the current MPC address
+	#[pallet::storage]
+	#[pallet::getter(fn pending_mpc_addr)]
+	pub type PendingMpcAddr<T: Config> = StorageValue<_, (MpcAddress, BlockNumberFor<T>)>;
+
+	/// Nonce of the MPC address rotation, used to prevent replaying signed rotation messages
+	#[pallet::storage]
+	#[pallet::getter(fn mpc_rotation_nonce)]
+	pub type MpcRotationNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Records of the latest deposits of dest domain, kept if `DepositRecordRetention` is not 0
+	#[pallet::storage]
+	#[pallet::getter(fn deposit_records)]
+	pub type DepositRecords<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DomainID,
+		Twox64Concat,
+		DepositNonce,
+		DepositRecord<T::AccountId, BlockNumberFor<T>>,
+	>;
+
+	/// Number of deposit records of dest domain on each block
+	#[pallet::storage]
+	#[pallet::getter(fn deposit_blocks)]
+	pub type DepositBlocks<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DomainID,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Asset the fee of a deposit record is paid in, only kept if it's not the transferred asset
+	#[pallet::storage]
+	#[pallet::getter(fn deposit_fee_assets)]
+	pub type DepositFeeAssets<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, DomainID, Twox64Concat, DepositNonce, AssetId>;
+
+	/// Nonce of the oldest deposit record of dest domain that is not pruned yet, records are
+	/// pruned from it up to the latest one out of retention
+	#[pallet::storage]
+	#[pallet::getter(fn deposit_record_prune_cursor)]
+	pub type DepositRecordPruneCursor<T: Config> =
+		StorageMap<_, Twox64Concat, DomainID, DepositNonce>;
+
+	/// Rolling-window volume cap of an asset transferred to/from a domain
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_limits)]
+	pub type TransferLimits<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DomainID,
+		Twox64Concat,
+		AssetId,
+		TransferLimit<BlockNumberFor<T>>,
+	>;
+
+	/// Transferred volume of an asset to/from a domain that has transfer limit
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_volumes)]
+	pub type TransferVolumes<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, DomainID>,
+			NMapKey<Twox64Concat, AssetId>,
+			NMapKey<Twox64Concat, TransferDirection>,
+		),
+		TransferVolume<BlockNumberFor<T>>,
+	>;
+
+	/// Circuit breakers of domains
+	#[pallet::storage]
+	#[pallet::getter(fn circuit_breakers)]
+	pub type CircuitBreakers<T: Config> =
+		StorageMap<_, Twox64Concat, DomainID, CircuitBreaker<BlockNumberFor<T>>>;
+
+	/// Volume of domains in the current window of their circuit breakers
+	#[pallet::storage]
+	#[pallet::getter(fn circuit_breaker_volumes)]
+	pub type CircuitBreakerVolumes<T: Config> =
+		StorageMap<_, Twox64Concat, DomainID, CircuitBreakerVolume<BlockNumberFor<T>>>;
+
+	/// Minimum balance of the reserve account of an asset, the origin domain of an inbound
+	/// transfer is paused rather than letting the reserve drop below it
+	#[pallet::storage]
+	#[pallet::getter(fn reserve_floors)]
+	pub type ReserveFloors<T: Config> = StorageMap<_, Twox64Concat, AssetId, u128>;
+
+	/// Fees collected for deposits to a domain, per fee asset, that are still held by the fee
+	/// reserve account
+	#[pallet::storage]
+	#[pallet::getter(fn collected_fees)]
+	pub type CollectedFees<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, DomainID, Twox64Concat, AssetId, u128, ValueQuery>;
+
+	/// Ratios collected fees are distributed by
+	#[pallet::storage]
+	#[pallet::getter(fn fee_distribution)]
+	pub type FeeDistributionRatios<T: Config> = StorageValue<_, FeeDistribution<T::AccountId>>;
+
+	/// Inbound proposals that failed to execute, removed once executed
+	#[pallet::storage]
+	#[pallet::getter(fn failed_proposals)]
+	pub type FailedProposals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		DomainID,
+		Twox64Concat,
+		DepositNonce,
+		FailedProposal<BlockNumberFor<T>>,
+	>;
+
+	/// EIP712 signing domain, the domain built from `EIP712ChainID` and
+	/// `DestVerifyingContractAddress` is used until it's set
+	#[pallet::storage]
+	pub type SigningDomain<T> = StorageValue<_, EIP712DomainParams>;
+
+	/// Signer set of the threshold signature mode, MPC signed messages are verified against
+	/// `MpcAddr` if it's not set
+	#[pallet::storage]
+	#[pallet::getter(fn signer_set)]
+	pub type ThresholdSigners<T> = StorageValue<_, SignerSet>;
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T>
+	where
+		<T as frame_system::Config>::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+	{
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let (proposals, signature) = match call {
+				Call::execute_proposal { proposals, signature } => (proposals, signature),
+				_ => return InvalidTransaction::Call.into(),
+			};
+
+			if MpcAddr::<T>::get().is_clear() || proposals.is_empty() {
+				return InvalidTransaction::Call.into();
+			}
+			// Proposals that were delivered before can not be replayed for free
+			if !proposals.iter().all(Self::is_new_proposal) {
+				return InvalidTransaction::Stale.into();
+			}
+			// Reject invalid signatures before they enter a block
+			let final_message = Self::construct_ecdsa_signing_proposals_data(proposals);
+			if !Self::verify_by_mpc_address(final_message, signature.clone()) {
+				return InvalidTransaction::BadProof.into();
+			}
+
+			ValidTransaction::with_tag_prefix("SygmaBridgeProposals")
+				.priority(T::UnsignedPriority::get())
+				.and_provides(final_message)
+				.longevity(64)
+				.propagate(true)
+				.build()
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			match PendingMpcAddr::<T>::get() {
+				Some((new_mpc_addr, effective_at)) if n >= effective_at => {
+					// Retire the old MPC address
+					let old_mpc_addr = MpcAddr::<T>::get();
+					MpcAddr::<T>::set(new_mpc_addr);
+					PendingMpcAddr::<T>::kill();
+
+					// Emit MpcAddressChanged event
+					Self::deposit_event(Event::MpcAddressChanged { old_mpc_addr, new_mpc_addr });
+
+					T::DbWeight::get().reads_writes(2, 2)
+				},
+				_ => T::DbWeight::get().reads(1),
+			}
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T>
 	where
@@ -298,8 +937,8 @@ pub mod pallet {
 			);
 			ensure!(DestDomainIds::<T>::get(dest_domain_id), Error::<T>::DestDomainNotSupported);
 
-			// Mark as paused
-			IsPaused::<T>::insert(dest_domain_id, true);
+			// Mark both directions as paused
+			Self::set_paused(dest_domain_id, true);
 
 			// Emit BridgePause event
 			Self::deposit_event(Event::BridgePaused { dest_domain_id });
@@ -321,10 +960,12 @@ pub mod pallet {
 			ensure!(DestDomainIds::<T>::get(dest_domain_id), Error::<T>::DestDomainNotSupported);
 
 			// make sure the current status is paused
-			ensure!(IsPaused::<T>::get(dest_domain_id), Error::<T>::BridgeUnpaused);
+			ensure!(Self::is_domain_paused(dest_domain_id), Error::<T>::BridgeUnpaused);
 
-			// Mark as unpaused
-			IsPaused::<T>::insert(dest_domain_id, false);
+			// Mark both directions as unpaused
+			Self::set_paused(dest_domain_id, false);
+			// Start a new circuit breaker window so that the domain is not tripped right away
+			CircuitBreakerVolumes::<T>::remove(dest_domain_id);
 
 			// Emit BridgeUnpause event
 			Self::deposit_event(Event::BridgeUnpaused { dest_domain_id });
@@ -352,6 +993,11 @@ pub mod pallet {
 			// unpause bridge
 			Self::unpause_all_domains();
 
+			// Emit MpcAddressChanged event
+			Self::deposit_event(Event::MpcAddressChanged {
+				old_mpc_addr: MpcAddress::default(),
+				new_mpc_addr: addr,
+			});
 			Ok(())
 		}
 
@@ -439,96 +1085,14 @@ pub mod pallet {
 			dest: Box<MultiLocation>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
+			let fee_asset = asset.id;
 
-			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
-
-			// Extract dest (MultiLocation) to get corresponding dest domainID and Ethereum
-			// recipient address
-			let (recipient, dest_domain_id) =
-				T::ExtractDestData::extract_dest(&dest).ok_or(Error::<T>::ExtractDestDataFailed)?;
-
-			ensure!(!IsPaused::<T>::get(dest_domain_id), Error::<T>::BridgePaused);
-
-			ensure!(DestDomainIds::<T>::get(dest_domain_id), Error::<T>::DestDomainNotSupported);
-
-			// Extract asset (MultiAsset) to get corresponding ResourceId, transfer amount and the
-			// transfer type
-			let (resource_id, amount, transfer_type) =
-				Self::extract_asset(&asset.clone()).ok_or(Error::<T>::AssetNotBound)?;
-			// Return error if no fee handler set
-			let fee = T::FeeHandler::get_fee(dest_domain_id, *asset.clone())
-				.ok_or(Error::<T>::MissingFeeConfig)?;
-
-			ensure!(amount > fee, Error::<T>::FeeTooExpensive);
-
-			// Withdraw `amount` of asset from sender
-			T::AssetTransactor::withdraw_asset(
-				&asset,
-				&Junction::AccountId32 { network: None, id: sender.clone().into() }.into(),
-				None,
-			)
-			.map_err(|_| Error::<T>::TransactFailed)?;
-
-			// Deposit `fee` of asset to treasury account
-			T::AssetTransactor::deposit_asset(
-				&(asset.id, Fungible(fee)).into(),
-				&Junction::AccountId32 { network: None, id: T::FeeReserveAccount::get().into() }
-					.into(),
-				// Put empty message hash here because we are not sending XCM message
-				&XcmContext::with_message_id([0; 32]),
-			)
-			.map_err(|_| Error::<T>::TransactFailed)?;
-
-			let bridge_amount = amount - fee;
-
-			let token_reserved_account = Self::get_token_reserved_account(&asset.id)
-				.ok_or(Error::<T>::NoLiquidityHolderAccountBound)?;
-
-			// Deposit `bridge_amount` of asset to reserve account if asset is reserved in local
-			// chain.
-			if T::IsReserve::contains(&asset, &MultiLocation::here()) {
-				T::AssetTransactor::deposit_asset(
-					&(asset.id, Fungible(bridge_amount)).into(),
-					&Junction::AccountId32 { network: None, id: token_reserved_account }.into(),
-					// Put empty message hash here because we are not sending XCM message
-					&XcmContext::with_message_id([0; 32]),
-				)
-				.map_err(|_| Error::<T>::TransactFailed)?;
+			// Keep the trip of the circuit breaker rather than reverting it with the call, the
+			// transfer is not made
+			match Self::deposit_in_layer(sender, asset, dest, fee_asset) {
+				Err(e) if e == Error::<T>::CircuitBreakerTripped.into() => Ok(()),
+				result => result,
 			}
-
-			// Bump deposit nonce
-			let deposit_nonce = DepositCounts::<T>::get(dest_domain_id);
-			DepositCounts::<T>::insert(
-				dest_domain_id,
-				deposit_nonce.checked_add(1).ok_or(Error::<T>::DepositNonceOverflow)?,
-			);
-
-			// convert the asset decimal
-			let decimal_converted_amount =
-				T::DecimalConverter::convert_to(&(asset.id, bridge_amount).into())
-					.ok_or(Error::<T>::DecimalConversionFail)?;
-
-			// Emit Deposit event
-			Self::deposit_event(Event::Deposit {
-				dest_domain_id,
-				resource_id,
-				deposit_nonce,
-				sender: sender.clone(),
-				transfer_type,
-				deposit_data: Self::create_deposit_data(decimal_converted_amount, recipient),
-				handler_response: vec![],
-			});
-
-			// Emit FeeCollected event
-			Self::deposit_event(Event::FeeCollected {
-				fee_payer: sender,
-				dest_domain_id,
-				resource_id,
-				fee_amount: fee,
-				fee_asset_id: asset.id,
-			});
-
-			Ok(())
 		}
 
 		/// This method is used to trigger the process for retrying failed deposits on the MPC side.
@@ -549,9 +1113,22 @@ pub mod pallet {
 				Error::<T>::AccessDenied
 			);
 			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
-			ensure!(!IsPaused::<T>::get(dest_domain_id), Error::<T>::BridgePaused);
+			ensure!(
+				!IsPaused::<T>::get(dest_domain_id, TransferDirection::Outbound),
+				Error::<T>::BridgePaused
+			);
 			ensure!(DestDomainIds::<T>::get(dest_domain_id), Error::<T>::DestDomainNotSupported);
 
+			// Only retry the block that has deposit to dest domain if deposit records are enabled
+			if T::DepositRecordRetention::get() > 0 {
+				let block_number = BlockNumberFor::<T>::try_from(deposit_on_block_height)
+					.map_err(|_| Error::<T>::DepositRecordNotFound)?;
+				ensure!(
+					DepositBlocks::<T>::contains_key(dest_domain_id, block_number),
+					Error::<T>::DepositRecordNotFound
+				);
+			}
+
 			// Emit retry event
 			let sender = match ensure_signed(origin) {
 				Ok(sender) => sender,
@@ -565,15 +1142,23 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Executes a batch of deposit proposals (only if signature is signed by MPC).
+		/// Executes a batch of deposit proposals (only if signature is signed by MPC). The batch
+		/// is free if none of its proposals was delivered before, it can be submitted as an
+		/// unsigned transaction as well.
 		#[transactional]
 		#[pallet::call_index(7)]
-		#[pallet::weight(< T as Config >::WeightInfo::execute_proposal(proposals.len() as u32))]
+		#[pallet::weight(
+			< T as Config >::WeightInfo::execute_proposal(proposals.len() as u32)
+				.saturating_add(Pallet::<T>::generic_proposals_weight(proposals))
+				.saturating_add(< T as Config >::WeightInfo::verify_signatures(
+					(signature.len() / 65) as u32
+				))
+		)]
 		pub fn execute_proposal(
 			_origin: OriginFor<T>,
 			proposals: Vec<Proposal>,
 			signature: Vec<u8>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			// Check MPC address and bridge status
 			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
 
@@ -588,13 +1173,18 @@ pub mod pallet {
 				Error::<T>::BadMpcSignature
 			);
 
+			// Only a batch delivered for the first time is free, replaying a batch is charged
+			let feeless = proposals.iter().all(Self::is_new_proposal);
+
 			// Execute proposals one by one.
 			// Note if one proposal failed to execute, we emit `FailedHandlerExecution` rather
 			// than revert whole transaction
 			for proposal in proposals.iter() {
-				Self::execute_proposal_internal(proposal).map_or_else(
+				Self::execute_proposal_in_layer(proposal).map_or_else(
 					|e| {
 						let err_msg: &'static str = e.into();
+						// Keep the failed proposal so that it can be re-executed later
+						Self::store_failed_proposal(proposal, err_msg);
 						// Any error during proposal list execution will emit FailedHandlerExecution
 						Self::deposit_event(Event::FailedHandlerExecution {
 							error: err_msg.as_bytes().to_vec(),
@@ -602,30 +1192,11 @@ pub mod pallet {
 							deposit_nonce: proposal.deposit_nonce,
 						});
 					},
-					|_| {
-						// Update proposal status
-						Self::set_proposal_executed(
-							proposal.deposit_nonce,
-							proposal.origin_domain_id,
-						);
-
-						// Emit ProposalExecution
-						Self::deposit_event(Event::ProposalExecution {
-							origin_domain_id: proposal.origin_domain_id,
-							deposit_nonce: proposal.deposit_nonce,
-							data_hash: keccak_256(
-								&[
-									proposal.data.clone(),
-									T::PalletId::get().into_account_truncating(),
-								]
-								.concat(),
-							),
-						});
-					},
+					|_| Self::complete_proposal(proposal),
 				);
 			}
 
-			Ok(())
+			Ok(if feeless { Pays::No } else { Pays::Yes }.into())
 		}
 
 		/// Pause all registered bridges
@@ -682,610 +1253,4433 @@ pub mod pallet {
 
 			Ok(())
 		}
-	}
 
-	impl<T: Config> Bridge for Pallet<T>
-	where
-		<T as frame_system::Config>::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
-	{
-		fn transfer(
-			sender: [u8; 32],
-			asset: MultiAsset,
-			dest: MultiLocation,
-			_max_weight: Option<Weight>,
+		/// Bind the given resourceID to an asset, making the asset bridgeable
+		#[pallet::call_index(10)]
+		#[pallet::weight(< T as Config >::WeightInfo::register_resource())]
+		pub fn register_resource(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			asset_id: Box<AssetId>,
 		) -> DispatchResult {
-			let sender_origin = OriginFor::<T>::from(RawOrigin::Signed(sender.into()));
-			Pallet::<T>::deposit(sender_origin, Box::from(asset), Box::from(dest))?;
+			let asset_id: AssetId = *asset_id;
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"register_resource".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(
+				!ResourceAssetIds::<T>::contains_key(resource_id),
+				Error::<T>::ResourceAlreadyRegistered
+			);
+			ensure!(
+				!AssetResourceIds::<T>::contains_key(asset_id),
+				Error::<T>::AssetAlreadyRegistered
+			);
+
+			AssetResourceIds::<T>::insert(asset_id, resource_id);
+			ResourceAssetIds::<T>::insert(resource_id, asset_id);
+
+			// Emit ResourceRegistered event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::ResourceRegistered { sender, resource_id, asset_id });
 			Ok(())
 		}
-	}
 
-	impl<T: Config> Pallet<T>
-	where
-		<T as frame_system::Config>::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
-	{
-		/// Verifies that EIP712 typed proposal data is signed by MPC address
-		#[allow(dead_code)]
-		fn verify_by_mpc_address(signing_message: [u8; 32], signature: Vec<u8>) -> bool {
-			let sig = match signature.try_into() {
-				Ok(_sig) => _sig,
-				Err(error) => return false,
-			};
+		/// Unbind the given resourceID from its asset
+		#[pallet::call_index(11)]
+		#[pallet::weight(< T as Config >::WeightInfo::unregister_resource())]
+		pub fn unregister_resource(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"unregister_resource".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
 
-			// recover the signing address
-			if let Ok(pubkey) =
-				// recover the uncompressed pubkey
-				secp256k1_ecdsa_recover(&sig, &signing_message)
-			{
-				let address = Self::public_key_to_address(&pubkey);
+			let asset_id = ResourceAssetIds::<T>::take(resource_id)
+				.ok_or(Error::<T>::ResourceNotRegistered)?;
+			AssetResourceIds::<T>::remove(asset_id);
 
-				address == MpcAddr::<T>::get().0
-			} else {
-				false
-			}
+			// Emit ResourceUnregistered event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::ResourceUnregistered { sender, resource_id, asset_id });
+			Ok(())
 		}
 
-		/// Return the TokenReservedAccount address by the given token
-		pub fn get_token_reserved_account(token_id: &AssetId) -> Option<[u8; 32]> {
-			T::TransferReserveAccounts::get()
-				.get(token_id)
-				.map(|account| (*account).clone().into())
-		}
+		/// Schedule the rotation of the MPC address. Proposals signed by either the current or
+		/// the new MPC address are accepted during `MpcRotationWindow` blocks, after that the
+		/// new MPC address replaces the current one.
+		#[pallet::call_index(12)]
+		#[pallet::weight(< T as Config >::WeightInfo::schedule_mpc_rotation())]
+		pub fn schedule_mpc_rotation(
+			origin: OriginFor<T>,
+			new_mpc_addr: MpcAddress,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"schedule_mpc_rotation".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
 
-		/// convert the ECDSA 64-byte uncompressed pubkey to H160 address
-		pub fn public_key_to_address(public_key: &[u8]) -> [u8; 20] {
-			let hash = keccak_256(public_key);
-			let final_hash = array_ref![&hash, 12, 20];
-			*final_hash
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::do_schedule_mpc_rotation(sender, new_mpc_addr)
 		}
 
-		/// Parse proposals and construct the original signing message
-		pub fn construct_ecdsa_signing_proposals_data(proposals: &Vec<Proposal>) -> [u8; 32] {
-			let proposals_typehash = keccak_256(
-                "Proposals(Proposal[] proposals)Proposal(uint8 originDomainID,uint64 depositNonce,bytes32 resourceID,bytes data)"
-                    .as_bytes(),
-            );
-			let proposal_typehash = keccak_256(
-				"Proposal(uint8 originDomainID,uint64 depositNonce,bytes32 resourceID,bytes data)"
-					.as_bytes(),
+		/// Schedule the rotation of the MPC address (only if signature is signed by the current
+		/// MPC address). The signature is over the EIP712 typed data
+		/// `MpcRotation(address newMpcAddress,uint256 nonce)` where nonce is `MpcRotationNonce`.
+		#[pallet::call_index(13)]
+		#[pallet::weight(< T as Config >::WeightInfo::schedule_mpc_rotation_signed())]
+		pub fn schedule_mpc_rotation_signed(
+			origin: OriginFor<T>,
+			new_mpc_addr: MpcAddress,
+			signature: Vec<u8>,
+		) -> DispatchResult {
+			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
+
+			let final_message = Self::construct_ecdsa_signing_mpc_rotation_data(
+				new_mpc_addr,
+				MpcRotationNonce::<T>::get(),
 			);
 
-			if proposals.is_empty() {
-				return [0u8; 32];
-			}
+			// Verify signature of the current MPC address, or of the signer set in threshold
+			// signature mode, the scheduled MPC address is not allowed to authorize another
+			// rotation in either mode
+			let verified = match ThresholdSigners::<T>::get() {
+				Some(signer_set) =>
+					Self::verify_by_signer_set(&signer_set, final_message, signature),
+				None =>
+					Self::recover_signer(final_message, signature) == Some(MpcAddr::<T>::get().0),
+			};
+			ensure!(verified, Error::<T>::BadMpcSignature);
 
-			let mut keccak_data = Vec::new();
-			for prop in proposals {
-				let proposal_domain_id_token = Token::Uint(prop.origin_domain_id.into());
-				let proposal_deposit_nonce_token = Token::Uint(prop.deposit_nonce.into());
-				let proposal_resource_id_token = Token::FixedBytes(prop.resource_id.to_vec());
-				let proposal_data_token = Token::FixedBytes(keccak_256(&prop.data).to_vec());
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::do_schedule_mpc_rotation(sender, new_mpc_addr)
+		}
 
-				keccak_data.push(keccak_256(&abi_encode(&[
-					Token::FixedBytes(proposal_typehash.to_vec()),
-					proposal_domain_id_token,
-					proposal_deposit_nonce_token,
-					proposal_resource_id_token,
-					proposal_data_token,
-				])));
-			}
+		/// Set the maximum volume of an asset that can be transferred to dest domain, and from
+		/// it, within any `window` blocks
+		#[pallet::call_index(15)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_transfer_limit())]
+		pub fn set_transfer_limit(
+			origin: OriginFor<T>,
+			domain_id: DomainID,
+			asset_id: Box<AssetId>,
+			cap: u128,
+			window: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let asset_id: AssetId = *asset_id;
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_transfer_limit".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(DestDomainIds::<T>::get(domain_id), Error::<T>::DestDomainNotSupported);
+			ensure!(!window.is_zero(), Error::<T>::InvalidTransferLimit);
 
-			// flatten the keccak_data into vec<u8>
-			let mut final_keccak_data = Vec::new();
-			for data in keccak_data {
-				for d in data {
-					final_keccak_data.push(d)
-				}
-			}
+			TransferLimits::<T>::insert(domain_id, asset_id, TransferLimit { cap, window });
 
-			let final_keccak_data_input = &[SolidityDataType::Bytes(&final_keccak_data)];
-			let bytes = encode_packed(final_keccak_data_input);
-			let hashed_keccak_data = keccak_256(bytes.as_slice());
+			// Emit TransferLimitSet event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::TransferLimitSet {
+				sender,
+				domain_id,
+				asset_id,
+				cap,
+				window,
+			});
+			Ok(())
+		}
 
-			let struct_hash = keccak_256(&abi_encode(&[
-				Token::FixedBytes(proposals_typehash.to_vec()),
-				Token::FixedBytes(hashed_keccak_data.to_vec()),
-			]));
+		/// Remove the transfer limit of an asset to/from dest domain
+		#[pallet::call_index(16)]
+		#[pallet::weight(< T as Config >::WeightInfo::remove_transfer_limit())]
+		pub fn remove_transfer_limit(
+			origin: OriginFor<T>,
+			domain_id: DomainID,
+			asset_id: Box<AssetId>,
+		) -> DispatchResult {
+			let asset_id: AssetId = *asset_id;
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"remove_transfer_limit".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(
+				TransferLimits::<T>::contains_key(domain_id, asset_id),
+				Error::<T>::TransferLimitNotSet
+			);
 
-			// domain separator
-			let default_eip712_domain = eip712::EIP712Domain::default();
-			let eip712_domain = eip712::EIP712Domain {
-				name: b"Bridge".to_vec(),
-				version: b"3.1.0".to_vec(),
-				chain_id: T::EIP712ChainID::get(),
-				verifying_contract: T::DestVerifyingContractAddress::get(),
-				salt: default_eip712_domain.salt,
-			};
-			let domain_separator = eip712_domain.separator();
+			TransferLimits::<T>::remove(domain_id, asset_id);
+			TransferVolumes::<T>::remove((domain_id, asset_id, TransferDirection::Outbound));
+			TransferVolumes::<T>::remove((domain_id, asset_id, TransferDirection::Inbound));
 
-			let typed_data_hash_input = &[
-				SolidityDataType::String("\x19\x01"),
-				SolidityDataType::Bytes(&domain_separator),
-				SolidityDataType::Bytes(&struct_hash),
-			];
-			let bytes = encode_packed(typed_data_hash_input);
-			keccak_256(bytes.as_slice())
+			// Emit TransferLimitRemoved event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::TransferLimitRemoved { sender, domain_id, asset_id });
+			Ok(())
 		}
 
-		/// Extract asset id and transfer amount from `MultiAsset`, currently only fungible asset
-		/// are supported.
-		fn extract_asset(asset: &MultiAsset) -> Option<(ResourceId, u128, TransferType)> {
-			match (&asset.fun, &asset.id) {
-				(Fungible(amount), _) => {
-					T::ResourcePairs::get().iter().position(|a| a.0 == asset.id).map(|idx| {
-						(T::ResourcePairs::get()[idx].1, *amount, TransferType::FungibleTransfer)
-					})
-				},
-				_ => None,
+		/// Re-execute a failed proposal kept on chain, anyone can call it once the cause of the
+		/// failure is fixed. `weight_limit` must cover the call of a generic proposal, only the
+		/// weight the call of the proposal actually needs is charged. If the proposal trips the
+		/// circuit breaker again, the call succeeds with the domain paused and the proposal kept
+		/// as failed.
+		#[transactional]
+		#[pallet::call_index(17)]
+		#[pallet::weight(
+			< T as Config >::WeightInfo::execute_failed_proposal().saturating_add(*weight_limit)
+		)]
+		pub fn execute_failed_proposal(
+			origin: OriginFor<T>,
+			origin_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			weight_limit: Weight,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let failed_proposal = FailedProposals::<T>::get(origin_domain_id, deposit_nonce)
+				.ok_or(Error::<T>::FailedProposalNotFound)?;
+			let proposal = Proposal {
+				origin_domain_id,
+				deposit_nonce,
+				resource_id: failed_proposal.resource_id,
+				data: failed_proposal.data.into_inner(),
+			};
+			let generic_weight = Self::generic_proposals_weight(&[proposal.clone()]);
+			ensure!(generic_weight.all_lte(weight_limit), Error::<T>::FailedProposalWeightExceeded);
+			let actual_weight =
+				<T as Config>::WeightInfo::execute_failed_proposal().saturating_add(generic_weight);
+
+			// UsedNonces is checked here as well, so a proposal can not be executed twice
+			match Self::execute_proposal_in_layer(&proposal) {
+				// Keep the trip of the circuit breaker rather than reverting it with the call, the
+				// proposal stays failed until the domain is unpaused
+				Err(e) if e == Error::<T>::CircuitBreakerTripped.into() =>
+					return Ok(Some(actual_weight).into()),
+				result => result?,
 			}
-		}
+			Self::complete_proposal(&proposal);
 
-		pub fn create_deposit_data(amount: u128, recipient: Vec<u8>) -> Vec<u8> {
-			[
-				&Self::hex_zero_padding_32(amount),
-				&Self::hex_zero_padding_32(recipient.len() as u128),
-				recipient.as_slice(),
-			]
-			.concat()
-			.to_vec()
+			Ok(Some(actual_weight).into())
 		}
 
-		/// Extract transfer amount and recipient location from deposit data.
-		/// For fungible transfer, data passed into the function should be constructed as follows:
-		/// amount                    uint256     bytes  0 - 32
-		/// recipient data length     uint256     bytes  32 - 64
-		/// recipient data            bytes       bytes  64 - END
-		///
-		/// Only fungible transfer is supported so far.
-		fn extract_deposit_data(data: &[u8]) -> Result<(u128, MultiLocation), DispatchError> {
-			if data.len() < 64 {
-				return Err(Error::<T>::InvalidDepositData.into());
-			}
+		/// Refund a deposit that can never be executed on dest domain to its sender (only if
+		/// signature is signed by MPC). The signature is over the EIP712 typed data
+		/// `Refund(uint8 destDomainID,uint64 depositNonce,bool refundFee)`, the deposit record is
+		/// removed once refunded so it can not be refunded twice.
+		#[transactional]
+		#[pallet::call_index(18)]
+		#[pallet::weight(
+			< T as Config >::WeightInfo::refund_deposit().saturating_add(
+				< T as Config >::WeightInfo::verify_signatures((signature.len() / 65) as u32)
+			)
+		)]
+		pub fn refund_deposit(
+			_origin: OriginFor<T>,
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			refund_fee: bool,
+			signature: Vec<u8>,
+		) -> DispatchResult {
+			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
 
-			let amount: u128 = U256::from_big_endian(&data[0..32])
-				.try_into()
-				.map_err(|_| Error::<T>::InvalidDepositData)?;
-			let recipient_len: usize = U256::from_big_endian(&data[32..64])
-				.try_into()
-				.map_err(|_| Error::<T>::InvalidDepositData)?;
-			if (data.len() - 64) != recipient_len {
-				return Err(Error::<T>::InvalidDepositData.into());
-			}
+			let final_message = Self::construct_ecdsa_signing_refund_data(
+				dest_domain_id,
+				deposit_nonce,
+				refund_fee,
+			);
+			ensure!(
+				Self::verify_by_mpc_address(final_message, signature),
+				Error::<T>::BadMpcSignature
+			);
 
-			let recipient = data[64..data.len()].to_vec();
-			if let Ok(location) = <MultiLocation>::decode(&mut recipient.as_slice()) {
-				Ok((amount, location))
+			let fee_asset_id = DepositFeeAssets::<T>::get(dest_domain_id, deposit_nonce);
+			let record = Self::remove_deposit_record(dest_domain_id, deposit_nonce)
+				.ok_or(Error::<T>::DepositRecordNotFound)?;
+			let asset_id = Self::rid_to_assetid(&record.resource_id)
+				.filter(|asset_id| !T::NonFungibleAssets::contains(asset_id))
+				.ok_or(Error::<T>::DepositNotRefundable)?;
+			let sender_location: MultiLocation =
+				Junction::AccountId32 { network: None, id: record.sender.clone().into() }.into();
+
+			// Return bridged amount from reserve account if asset is reserved in local chain,
+			// otherwise mint the burned amount back
+			let asset: MultiAsset = (asset_id, Fungible(record.amount)).into();
+			if T::IsReserve::contains(&asset, &MultiLocation::here()) {
+				let token_reserved_account = Self::get_token_reserved_account(&asset_id)
+					.ok_or(Error::<T>::NoLiquidityHolderAccountBound)?;
+				T::AssetTransactor::transfer_asset(
+					&asset,
+					&Junction::AccountId32 { network: None, id: token_reserved_account }.into(),
+					&sender_location,
+					// Put empty message hash here because we are not sending XCM message
+					&XcmContext::with_message_id([0; 32]),
+				)
+				.map_err(|_| Error::<T>::TransactFailed)?;
 			} else {
-				Err(Error::<T>::InvalidDepositData.into())
+				T::AssetTransactor::deposit_asset(
+					&asset,
+					&sender_location,
+					// Put empty message hash here because we are not sending XCM message
+					&XcmContext::with_message_id([0; 32]),
+				)
+				.map_err(|_| Error::<T>::TransactFailed)?;
 			}
-		}
 
-		fn rid_to_assetid(rid: &ResourceId) -> Option<AssetId> {
-			T::ResourcePairs::get()
-				.iter()
-				.position(|a| &a.1 == rid)
-				.map(|idx| T::ResourcePairs::get()[idx].0)
-		}
+			// Return fee from fee reserve account in the asset it was paid in
+			let fee = if refund_fee { record.fee } else { 0 };
+			if fee > 0 {
+				let fee_asset_id = fee_asset_id.unwrap_or(asset_id);
+				CollectedFees::<T>::mutate(dest_domain_id, fee_asset_id, |collected| {
+					*collected = collected.saturating_sub(fee)
+				});
+				T::AssetTransactor::transfer_asset(
+					&(fee_asset_id, Fungible(fee)).into(),
+					&Junction::AccountId32 {
+						network: None,
+						id: T::FeeReserveAccount::get().into(),
+					}
+					.into(),
+					&sender_location,
+					// Put empty message hash here because we are not sending XCM message
+					&XcmContext::with_message_id([0; 32]),
+				)
+				.map_err(|_| Error::<T>::TransactFailed)?;
+			}
 
-		fn hex_zero_padding_32(i: u128) -> [u8; 32] {
-			let mut result = [0u8; 32];
-			U256::from(i).to_big_endian(&mut result);
-			result
-		}
+			// Emit DepositRefunded event
+			Self::deposit_event(Event::DepositRefunded {
+				dest_domain_id,
+				deposit_nonce,
+				sender: record.sender,
+				resource_id: record.resource_id,
+				amount: record.amount,
+				fee,
+			});
 
-		/// Return true if deposit nonce has been used
-		pub fn is_proposal_executed(nonce: DepositNonce, domain_id: DomainID) -> bool {
-			(UsedNonces::<T>::get(domain_id, nonce / 64) & (1 << (nonce % 64))) != 0
+			Ok(())
 		}
 
-		/// Set bit mask for specific nonce as used
-		fn set_proposal_executed(nonce: DepositNonce, domain_id: DomainID) {
-			let mut current_nonces = UsedNonces::<T>::get(domain_id, nonce / 64);
-			current_nonces |= 1 << (nonce % 64);
-			UsedNonces::<T>::insert(domain_id, nonce / 64, current_nonces);
+		/// Set the EIP712 domain that proposals and other MPC signed messages are signed in,
+		/// messages signed in the previous domain are no longer accepted
+		#[pallet::call_index(19)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_eip712_domain())]
+		pub fn set_eip712_domain(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			version: Vec<u8>,
+			chain_id: ChainID,
+			verifying_contract: VerifyingContractAddress,
+			salt: Option<[u8; 32]>,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_eip712_domain".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
+
+			let domain = EIP712DomainParams {
+				name: name.try_into().map_err(|_| Error::<T>::InvalidEIP712Domain)?,
+				version: version.try_into().map_err(|_| Error::<T>::InvalidEIP712Domain)?,
+				chain_id,
+				verifying_contract,
+				salt,
+			};
+			SigningDomain::<T>::put(domain.clone());
+
+			// Emit EIP712DomainSet event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::EIP712DomainSet { sender, domain });
+			Ok(())
 		}
 
-		/// Execute a single proposal
-		fn execute_proposal_internal(proposal: &Proposal) -> DispatchResult {
-			// Check if dest domain bridge is paused
-			ensure!(!IsPaused::<T>::get(proposal.origin_domain_id), Error::<T>::BridgePaused);
-			// Check if domain is supported
+		/// Enable the m-of-n threshold signature mode, MPC signed messages must carry
+		/// signatures of at least `threshold` distinct signers of `signers`
+		#[pallet::call_index(20)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_signer_set())]
+		pub fn set_signer_set(
+			origin: OriginFor<T>,
+			signers: Vec<MpcAddress>,
+			threshold: u32,
+		) -> DispatchResult {
 			ensure!(
-				DestDomainIds::<T>::get(proposal.origin_domain_id),
-				Error::<T>::DestDomainNotSupported
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_signer_set".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
 			);
-			// Check if proposal has executed
 			ensure!(
-				!Self::is_proposal_executed(proposal.deposit_nonce, proposal.origin_domain_id),
-				Error::<T>::ProposalAlreadyComplete
+				threshold > 0 && threshold as usize <= signers.len(),
+				Error::<T>::InvalidSignerSet
 			);
-			// Extract ResourceId from proposal data to get corresponding asset (MultiAsset)
-			let asset_id =
-				Self::rid_to_assetid(&proposal.resource_id).ok_or(Error::<T>::AssetNotBound)?;
-			// Extract Receipt from proposal data to get corresponding location (MultiLocation)
-			let (amount, location) = Self::extract_deposit_data(&proposal.data)?;
-
-			// convert the asset decimal
-			let decimal_converted_asset =
-				T::DecimalConverter::convert_from(&(asset_id, amount).into())
-					.ok_or(Error::<T>::DecimalConversionFail)?;
-
-			let token_reserved_account = Self::get_token_reserved_account(&asset_id)
-				.ok_or(Error::<T>::NoLiquidityHolderAccountBound)?;
-
-			// Withdraw `decimal_converted_asset` of asset from reserve account
-			if T::IsReserve::contains(&decimal_converted_asset, &MultiLocation::here()) {
-				T::AssetTransactor::withdraw_asset(
-					&decimal_converted_asset,
-					&Junction::AccountId32 { network: None, id: token_reserved_account }.into(),
-					None,
-				)
-				.map_err(|_| Error::<T>::TransactFailed)?;
+			for (i, signer) in signers.iter().enumerate() {
+				ensure!(
+					!signer.is_clear() && !signers[..i].contains(signer),
+					Error::<T>::InvalidSignerSet
+				);
 			}
 
-			// Deposit `decimal_converted_asset` of asset to dest location
-			T::AssetTransactor::deposit_asset(
-				&decimal_converted_asset,
-				&location,
-				// Put empty message hash here because we are not sending XCM message
-				&XcmContext::with_message_id([0; 32]),
-			)
-			.map_err(|_| Error::<T>::TransactFailed)?;
+			ThresholdSigners::<T>::put(SignerSet {
+				signers: signers.clone().try_into().map_err(|_| Error::<T>::InvalidSignerSet)?,
+				threshold,
+			});
 
+			// Emit SignerSetUpdated event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::SignerSetUpdated { sender, signers, threshold });
 			Ok(())
 		}
 
-		/// unpause all registered domains in the storage
-		fn unpause_all_domains() {
-			DestDomainIds::<T>::iter_keys().for_each(|d| IsPaused::<T>::insert(d, false));
-			IsPaused::<T>::iter_keys().for_each(|d| IsPaused::<T>::insert(d, false));
-		}
-
-		/// pause all registered domains in the storage
-		fn pause_all_domains() {
-			DestDomainIds::<T>::iter_keys().for_each(|d| IsPaused::<T>::insert(d, true));
-			IsPaused::<T>::iter_keys().for_each(|d| IsPaused::<T>::insert(d, true));
-		}
-	}
+		/// Disable the threshold signature mode, MPC signed messages are verified against the
+		/// MPC address again
+		#[pallet::call_index(21)]
+		#[pallet::weight(< T as Config >::WeightInfo::clear_signer_set())]
+		pub fn clear_signer_set(origin: OriginFor<T>) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"clear_signer_set".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(ThresholdSigners::<T>::exists(), Error::<T>::SignerSetNotSet);
 
-	#[cfg(test)]
-	mod test {
-		use codec::{self, Encode};
-		use frame_support::{
-			assert_noop, assert_ok, crypto::ecdsa::ECDSAExt,
-			traits::tokens::fungibles::Create as FungibleCerate,
-		};
-		use parachains_common::AccountId;
-		use primitive_types::U256;
-		use sp_core::{ecdsa, ByteArray, Pair};
-		use sp_std::{boxed::Box, vec};
-		use xcm::latest::prelude::*;
+			ThresholdSigners::<T>::kill();
 
-		use bridge::mock::{
-			assert_events, new_test_ext, slice_to_generalkey, AccessSegregator, Assets, Balances,
-			BridgeAccountNative, BridgeAccountOtherTokens, BridgePalletIndex, NativeLocation,
-			NativeResourceId, Runtime, RuntimeEvent, RuntimeOrigin as Origin, SygmaBasicFeeHandler,
-			SygmaBridge, SygmaFeeHandlerRouter, SygmaPercentageFeeHandler, TreasuryAccount,
-			UsdtAssetId, UsdtLocation, UsdtResourceId, ALICE, ASSET_OWNER, BOB, DEST_DOMAIN_ID,
-			ENDOWED_BALANCE,
-		};
-		use sygma_fee_handler_router::FeeHandlerType;
-		use sygma_traits::{Bridge, DomainID, MpcAddress, TransferType};
+			// Emit SignerSetCleared event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::SignerSetCleared { sender });
+			Ok(())
+		}
 
-		use crate as bridge;
-		use crate::{
-			mock::{AstrAssetId, AstrLocation, AstrResourceId},
-			DestChainIds, DestDomainIds, Error, Event as SygmaBridgeEvent, IsPaused, MpcAddr,
-			Proposal,
-		};
+		/// Initiates a generic message transfer, `data` is delivered to dest domain as it is.
+		#[transactional]
+		#[pallet::call_index(14)]
+		#[pallet::weight(< T as Config >::WeightInfo::deposit_generic())]
+		pub fn deposit_generic(
+			origin: OriginFor<T>,
+			dest_domain_id: DomainID,
+			data: Vec<u8>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
 
-		#[test]
-		fn get_token_reserved_account_test() {
-			new_test_ext().execute_with(|| {
-				assert_eq!(
-					SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into()).unwrap(),
-					BridgeAccountOtherTokens::get().as_slice()
-				);
-				assert_eq!(
-					SygmaBridge::get_token_reserved_account(&NativeLocation::get().into()).unwrap(),
-					BridgeAccountNative::get().as_slice()
-				);
-				assert_eq!(
-					SygmaBridge::get_token_reserved_account(&AstrLocation::get().into()).unwrap(),
-					BridgeAccountOtherTokens::get().as_slice()
-				);
+			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
+			ensure!(
+				!IsPaused::<T>::get(dest_domain_id, TransferDirection::Outbound),
+				Error::<T>::BridgePaused
+			);
+			ensure!(
+				!IsResourcePaused::<T>::get(
+					T::GenericResourceId::get(),
+					TransferDirection::Outbound
+				),
+				Error::<T>::ResourcePaused
+			);
+			ensure!(DestDomainIds::<T>::get(dest_domain_id), Error::<T>::DestDomainNotSupported);
 
-				// unknown token should return None
-				assert_eq!(
-					SygmaBridge::get_token_reserved_account(
-						&MultiLocation::new(
-							2,
-							X3(
-								Parachain(1000),
-								slice_to_generalkey(b"sygma"),
-								slice_to_generalkey(b"unknown"),
-							),
-						)
-						.into()
-					),
-					None
-				);
-			})
-		}
+			// Bump deposit nonce
+			let deposit_nonce = DepositCounts::<T>::get(dest_domain_id);
+			DepositCounts::<T>::insert(
+				dest_domain_id,
+				deposit_nonce.checked_add(1).ok_or(Error::<T>::DepositNonceOverflow)?,
+			);
 
-		#[test]
-		fn set_mpc_address() {
-			new_test_ext().execute_with(|| {
-				let default_addr: MpcAddress = MpcAddress::default();
-				let test_mpc_addr_a: MpcAddress = MpcAddress([1u8; 20]);
-				let test_mpc_addr_b: MpcAddress = MpcAddress([2u8; 20]);
+			Self::record_deposit(
+				dest_domain_id,
+				deposit_nonce,
+				sender.clone(),
+				T::GenericResourceId::get(),
+				0,
+				vec![],
+				0,
+				None,
+			)?;
 
-				assert_eq!(MpcAddr::<Runtime>::get(), default_addr);
+			// Emit Deposit event
+			Self::deposit_event(Event::Deposit {
+				dest_domain_id,
+				resource_id: T::GenericResourceId::get(),
+				deposit_nonce,
+				sender,
+				transfer_type: TransferType::GenericTransfer,
+				deposit_data: data,
+				handler_response: vec![],
+			});
 
-				// set to test_mpc_addr_a
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr_a));
-				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr_a);
+			Ok(())
+		}
 
-				// set to test_mpc_addr_b: should be MpcAddrNotUpdatable error
-				assert_noop!(
-					SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr_b),
-					bridge::Error::<Runtime>::MpcAddrNotUpdatable
-				);
+		/// Initiates a transfer with bridge fee paid in `fee_asset` instead of the transferred
+		/// asset, the full amount of the transferred asset is bridged.
+		#[transactional]
+		#[pallet::call_index(22)]
+		#[pallet::weight(< T as Config >::WeightInfo::deposit_with_fee_asset())]
+		pub fn deposit_with_fee_asset(
+			origin: OriginFor<T>,
+			asset: Box<MultiAsset>,
+			dest: Box<MultiLocation>,
+			fee_asset: Box<AssetId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let fee_asset = *fee_asset;
 
-				// permission test: unauthorized account should not be able to set mpc address
-				let unauthorized_account = Origin::from(Some(ALICE));
-				assert_noop!(
-					SygmaBridge::set_mpc_address(unauthorized_account, test_mpc_addr_a),
-					bridge::Error::<Runtime>::AccessDenied
-				);
-				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr_a);
-			})
+			// Keep the trip of the circuit breaker rather than reverting it with the call, the
+			// transfer is not made
+			match Self::deposit_in_layer(sender, asset, dest, fee_asset) {
+				Err(e) if e == Error::<T>::CircuitBreakerTripped.into() => Ok(()),
+				result => result,
+			}
 		}
 
-		#[test]
-		fn pause_bridge() {
-			new_test_ext().execute_with(|| {
-				let default_addr = MpcAddress::default();
-				assert_eq!(MpcAddr::<Runtime>::get(), default_addr);
-
-				// register domain
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					U256::from(1)
-				));
+		/// Initiates a batch of transfers, fee of each transfer is paid in the transferred asset.
+		/// In `AllOrNothing` mode the whole batch is reverted if one of the transfers fails, in
+		/// `BestEffort` mode the failed transfer is skipped with `BatchDepositFailed` emitted. A
+		/// transfer tripping the circuit breaker of its dest domain counts as failed, and the trip
+		/// is kept in both modes.
+		#[transactional]
+		#[pallet::call_index(23)]
+		#[pallet::weight(< T as Config >::WeightInfo::deposit_batch(deposits.len() as u32))]
+		pub fn deposit_batch(
+			origin: OriginFor<T>,
+			deposits: Vec<(MultiAsset, MultiLocation)>,
+			mode: DepositBatchMode,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
 
-				// pause bridge, should be ok
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert!(IsPaused::<Runtime>::get(DEST_DOMAIN_ID));
-				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
-					dest_domain_id: DEST_DOMAIN_ID,
-				})]);
+			ensure!(
+				!deposits.is_empty() && deposits.len() <= MAX_DEPOSIT_BATCH_LENGTH as usize,
+				Error::<T>::InvalidDepositBatch
+			);
 
-				// pause bridge again after paused, should be ok
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert!(IsPaused::<Runtime>::get(DEST_DOMAIN_ID));
-				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
-					dest_domain_id: DEST_DOMAIN_ID,
-				})]);
+			match mode {
+				DepositBatchMode::AllOrNothing => {
+					// Revert the whole batch but keep the trip if one of the transfers trips the
+					// circuit breaker
+					let mut tripped_domain_id = None;
+					let result = with_storage_layer(|| -> DispatchResult {
+						for (asset, dest) in deposits.into_iter() {
+							let fee_asset = asset.id;
+							tripped_domain_id = Self::do_deposit(
+								sender.clone(),
+								Box::new(asset),
+								Box::new(dest),
+								fee_asset,
+							)?;
+							if tripped_domain_id.is_some() {
+								return Err(Error::<T>::CircuitBreakerTripped.into());
+							}
+						}
+						Ok(())
+					});
+					match tripped_domain_id {
+						Some(domain_id) => Self::trip_circuit_breaker(
+							domain_id,
+							CircuitBreakerTripReason::OutboundVolumeExceeded,
+						),
+						None => result?,
+					}
+				},
+				DepositBatchMode::BestEffort => {
+					for (index, (asset, dest)) in deposits.into_iter().enumerate() {
+						let fee_asset = asset.id;
+						// Revert the changes of the failed transfer only
+						if let Err(e) = Self::deposit_in_layer(
+							sender.clone(),
+							Box::new(asset),
+							Box::new(dest),
+							fee_asset,
+						) {
+							let err_msg: &'static str = e.into();
+							Self::deposit_event(Event::BatchDepositFailed {
+								sender: sender.clone(),
+								index: index as u32,
+								error: err_msg.as_bytes().to_vec(),
+							});
+						}
+					}
+				},
+			}
 
-				// permission test: unauthorized account should not be able to pause bridge
-				let unauthorized_account = Origin::from(Some(ALICE));
-				assert_noop!(
-					SygmaBridge::pause_bridge(unauthorized_account, DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::AccessDenied
-				);
-				assert!(IsPaused::<Runtime>::get(DEST_DOMAIN_ID));
-			})
+			Ok(())
 		}
 
-		#[test]
-		fn unpause_bridge() {
-			new_test_ext().execute_with(|| {
-				let default_addr: MpcAddress = MpcAddress::default();
-				assert_eq!(MpcAddr::<Runtime>::get(), default_addr);
-
-				// register domain
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					U256::from(1)
-				));
+		/// Set the circuit breaker of a domain, a transfer that would take its inbound or outbound
+		/// volume in 18 decimals within a `window` blocks above the threshold is not made and the
+		/// domain is paused instead
+		#[pallet::call_index(24)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_circuit_breaker())]
+		pub fn set_circuit_breaker(
+			origin: OriginFor<T>,
+			domain_id: DomainID,
+			window: BlockNumberFor<T>,
+			max_inbound: u128,
+			max_outbound: u128,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_circuit_breaker".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(DestDomainIds::<T>::get(domain_id), Error::<T>::DestDomainNotSupported);
+			ensure!(!window.is_zero(), Error::<T>::InvalidCircuitBreaker);
 
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
-					dest_domain_id: DEST_DOMAIN_ID,
-				})]);
+			CircuitBreakers::<T>::insert(
+				domain_id,
+				CircuitBreaker { window, max_inbound, max_outbound },
+			);
+			CircuitBreakerVolumes::<T>::remove(domain_id);
 
-				// bridge should be paused here
-				assert!(IsPaused::<Runtime>::get(DEST_DOMAIN_ID));
+			// Emit CircuitBreakerSet event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::CircuitBreakerSet {
+				sender,
+				domain_id,
+				window,
+				max_inbound,
+				max_outbound,
+			});
+			Ok(())
+		}
 
-				// ready to unpause bridge, should be ok
-				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgeUnpaused {
-					dest_domain_id: DEST_DOMAIN_ID,
-				})]);
+		/// Remove the circuit breaker of a domain
+		#[pallet::call_index(25)]
+		#[pallet::weight(< T as Config >::WeightInfo::remove_circuit_breaker())]
+		pub fn remove_circuit_breaker(origin: OriginFor<T>, domain_id: DomainID) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"remove_circuit_breaker".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(
+				CircuitBreakers::<T>::contains_key(domain_id),
+				Error::<T>::CircuitBreakerNotSet
+			);
 
-				// try to unpause it again, should be error
-				assert_noop!(
-					SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::BridgeUnpaused
-				);
+			CircuitBreakers::<T>::remove(domain_id);
+			CircuitBreakerVolumes::<T>::remove(domain_id);
 
-				// permission test: unauthorized account should not be able to unpause a recognized
-				// bridge
-				let unauthorized_account = Origin::from(Some(ALICE));
-				assert_noop!(
-					SygmaBridge::unpause_bridge(unauthorized_account, DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::AccessDenied
-				);
-				assert!(!IsPaused::<Runtime>::get(DEST_DOMAIN_ID));
-			})
+			// Emit CircuitBreakerRemoved event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::CircuitBreakerRemoved { sender, domain_id });
+			Ok(())
 		}
 
-		#[test]
-		fn verify_mpc_signature_invalid_signature() {
-			new_test_ext().execute_with(|| {
-				let signature = vec![1u8];
-
-				// dummy proposals
-				let p1 = Proposal {
-					origin_domain_id: 1,
-					deposit_nonce: 1,
-					resource_id: [1u8; 32],
-					data: vec![1u8],
-				};
-				let p2 = Proposal {
-					origin_domain_id: 2,
-					deposit_nonce: 2,
-					resource_id: [2u8; 32],
-					data: vec![2u8],
-				};
-				let proposals = vec![p1, p2];
+		/// Set the minimum balance of the reserve account of an asset, the origin domain of an
+		/// inbound transfer is paused rather than letting the reserve drop below it. Floor 0
+		/// removes the floor.
+		#[pallet::call_index(26)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_reserve_floor())]
+		pub fn set_reserve_floor(
+			origin: OriginFor<T>,
+			asset_id: Box<AssetId>,
+			floor: u128,
+		) -> DispatchResult {
+			let asset_id: AssetId = *asset_id;
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_reserve_floor".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
 
-				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+			if floor.is_zero() {
+				ReserveFloors::<T>::remove(asset_id);
+			} else {
+				ReserveFloors::<T>::insert(asset_id, floor);
+			}
 
-				// should be false
-				assert!(!SygmaBridge::verify_by_mpc_address(final_message, signature.encode()));
-			})
+			// Emit ReserveFloorSet event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::ReserveFloorSet { sender, asset_id, floor });
+			Ok(())
 		}
 
-		#[test]
-		fn verify_mpc_signature_invalid_message() {
-			new_test_ext().execute_with(|| {
-				// generate mpc keypair
-				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
-				let public = pair.public();
-				let message = b"Something important";
-				let signature = pair.sign(&message[..]);
+		/// Pause one direction of bridge, e.g. only stop deposits to the domain while the
+		/// proposals from it are still executed.
+		#[pallet::call_index(27)]
+		#[pallet::weight(< T as Config >::WeightInfo::pause_bridge_direction())]
+		pub fn pause_bridge_direction(
+			origin: OriginFor<T>,
+			dest_domain_id: DomainID,
+			direction: TransferDirection,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"pause_bridge_direction".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(DestDomainIds::<T>::get(dest_domain_id), Error::<T>::DestDomainNotSupported);
 
-				// make sure generated keypair, message and signature are all good
-				assert!(ecdsa::Pair::verify(&signature, &message[..], &public));
-				assert!(!ecdsa::Pair::verify(&signature, b"Something else", &public));
+			// Mark the direction as paused
+			IsPaused::<T>::insert(dest_domain_id, direction, true);
 
-				// dummy proposals
-				let p1 = Proposal {
-					origin_domain_id: 1,
-					deposit_nonce: 1,
-					resource_id: [1u8; 32],
-					data: vec![1u8],
-				};
-				let p2 = Proposal {
-					origin_domain_id: 2,
-					deposit_nonce: 2,
-					resource_id: [2u8; 32],
-					data: vec![2u8],
-				};
-				let proposals = vec![p1, p2];
+			// Emit BridgeDirectionPaused event
+			Self::deposit_event(Event::BridgeDirectionPaused { dest_domain_id, direction });
+			Ok(())
+		}
 
-				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+		/// Unpause one direction of bridge.
+		#[pallet::call_index(28)]
+		#[pallet::weight(< T as Config >::WeightInfo::unpause_bridge_direction())]
+		pub fn unpause_bridge_direction(
+			origin: OriginFor<T>,
+			dest_domain_id: DomainID,
+			direction: TransferDirection,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"unpause_bridge_direction".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(DestDomainIds::<T>::get(dest_domain_id), Error::<T>::DestDomainNotSupported);
 
-				// verify non matched signature against proposal list, should be false
-				assert!(!SygmaBridge::verify_by_mpc_address(final_message, signature.encode()));
-			})
+			// make sure the current status is paused
+			ensure!(IsPaused::<T>::get(dest_domain_id, direction), Error::<T>::BridgeUnpaused);
+
+			// Mark the direction as unpaused
+			IsPaused::<T>::insert(dest_domain_id, direction, false);
+			// Start a new circuit breaker window so that the domain is not tripped right away
+			CircuitBreakerVolumes::<T>::remove(dest_domain_id);
+
+			// Emit BridgeDirectionUnpaused event
+			Self::deposit_event(Event::BridgeDirectionUnpaused { dest_domain_id, direction });
+			Ok(())
 		}
 
-		#[test]
-		fn verify_mpc_signature_valid_message_unmatched_mpc() {
-			new_test_ext().execute_with(|| {
-				// generate the signing keypair
-				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+		/// Pause one direction of a resource on all domains, e.g. stop a compromised token
+		/// without pausing the rest of bridge.
+		#[pallet::call_index(29)]
+		#[pallet::weight(< T as Config >::WeightInfo::pause_resource())]
+		pub fn pause_resource(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			direction: TransferDirection,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"pause_resource".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
 
-				// set mpc address to another random key
-				let test_mpc_addr: MpcAddress = MpcAddress([7u8; 20]);
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr);
+			// Mark the direction of resource as paused
+			IsResourcePaused::<T>::insert(resource_id, direction, true);
 
-				// dummy proposals
-				let p1 = Proposal {
-					origin_domain_id: 1,
-					deposit_nonce: 1,
-					resource_id: [1u8; 32],
-					data: vec![1u8],
-				};
-				let p2 = Proposal {
-					origin_domain_id: 2,
-					deposit_nonce: 2,
-					resource_id: [2u8; 32],
-					data: vec![2u8],
-				};
-				let proposals = vec![p1, p2];
+			// Emit ResourcePaused event
+			Self::deposit_event(Event::ResourcePaused { resource_id, direction });
+			Ok(())
+		}
 
-				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+		/// Unpause one direction of a resource on all domains.
+		#[pallet::call_index(30)]
+		#[pallet::weight(< T as Config >::WeightInfo::unpause_resource())]
+		pub fn unpause_resource(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			direction: TransferDirection,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"unpause_resource".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
 
-				// sign final message using generated prikey
-				let signature = pair.sign_prehashed(&final_message);
+			// make sure the current status is paused
+			ensure!(
+				IsResourcePaused::<T>::get(resource_id, direction),
+				Error::<T>::ResourceUnpaused
+			);
 
-				// verify signature, should be false because the signing address != mpc address
-				assert!(!SygmaBridge::verify_by_mpc_address(final_message, signature.encode()));
-			})
+			// Mark the direction of resource as unpaused
+			IsResourcePaused::<T>::remove(resource_id, direction);
+
+			// Emit ResourceUnpaused event
+			Self::deposit_event(Event::ResourceUnpaused { resource_id, direction });
+			Ok(())
 		}
 
-		#[test]
-		fn verify_mpc_signature_valid_message_valid_signature() {
-			new_test_ext().execute_with(|| {
-				// generate mpc keypair
-				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
-				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+		/// Set the ratios collected fees are distributed by between the recipients, e.g.
+		/// treasury and relayer reward accounts, and the burn share. Shares must add up to 100%.
+		#[pallet::call_index(31)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_fee_distribution())]
+		pub fn set_fee_distribution(
+			origin: OriginFor<T>,
+			recipients: Vec<(T::AccountId, Perbill)>,
+			burn: Perbill,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_fee_distribution".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
+			let total_parts =
+				recipients.iter().fold(burn.deconstruct() as u64, |total, (_, share)| {
+					total + share.deconstruct() as u64
+				});
+			ensure!(
+				total_parts == Perbill::one().deconstruct() as u64,
+				Error::<T>::InvalidFeeDistribution
+			);
 
-				// set mpc address to generated keypair's address
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr);
+			FeeDistributionRatios::<T>::put(FeeDistribution {
+				recipients: recipients
+					.clone()
+					.try_into()
+					.map_err(|_| Error::<T>::InvalidFeeDistribution)?,
+				burn,
+			});
 
-				// dummy proposals
-				let p1 = Proposal {
-					origin_domain_id: 1,
-					deposit_nonce: 1,
-					resource_id: [1u8; 32],
-					data: vec![1u8],
-				};
-				let p2 = Proposal {
-					origin_domain_id: 2,
-					deposit_nonce: 2,
-					resource_id: [2u8; 32],
-					data: vec![2u8],
+			// Emit FeeDistributionSet event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::FeeDistributionSet { sender, recipients, burn });
+			Ok(())
+		}
+
+		/// Withdraw `amount` of the fees collected for deposits to a domain in an asset from the
+		/// fee reserve account
+		#[pallet::call_index(32)]
+		#[pallet::weight(< T as Config >::WeightInfo::withdraw_fees())]
+		pub fn withdraw_fees(
+			origin: OriginFor<T>,
+			domain_id: DomainID,
+			asset_id: Box<AssetId>,
+			amount: u128,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let asset_id: AssetId = *asset_id;
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"withdraw_fees".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
+			let collected = CollectedFees::<T>::get(domain_id, asset_id);
+			ensure!(amount <= collected, Error::<T>::InsufficientCollectedFees);
+
+			T::AssetTransactor::transfer_asset(
+				&(asset_id, Fungible(amount)).into(),
+				&Junction::AccountId32 { network: None, id: T::FeeReserveAccount::get().into() }
+					.into(),
+				&Junction::AccountId32 { network: None, id: to.clone().into() }.into(),
+				// Put empty message hash here because we are not sending XCM message
+				&XcmContext::with_message_id([0; 32]),
+			)
+			.map_err(|_| Error::<T>::TransactFailed)?;
+			CollectedFees::<T>::insert(domain_id, asset_id, collected - amount);
+
+			// Emit FeesWithdrawn event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::FeesWithdrawn { sender, domain_id, asset_id, amount, to });
+			Ok(())
+		}
+
+		/// Distribute the fees collected for deposits to a domain in an asset by the fee
+		/// distribution ratios, the burn share is burned from the fee reserve account. Rounding
+		/// dust stays collected until the next distribution.
+		#[transactional]
+		#[pallet::call_index(33)]
+		#[pallet::weight(< T as Config >::WeightInfo::distribute_fees(
+			FeeRecipients::<T::AccountId>::bound() as u32
+		))]
+		pub fn distribute_fees(
+			origin: OriginFor<T>,
+			domain_id: DomainID,
+			asset_id: Box<AssetId>,
+		) -> DispatchResult {
+			let asset_id: AssetId = *asset_id;
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"distribute_fees".to_vec(),
+					origin.clone()
+				),
+				Error::<T>::AccessDenied
+			);
+			let distribution =
+				FeeDistributionRatios::<T>::get().ok_or(Error::<T>::FeeDistributionNotSet)?;
+			let collected = CollectedFees::<T>::get(domain_id, asset_id);
+			let fee_reserve_location: MultiLocation =
+				Junction::AccountId32 { network: None, id: T::FeeReserveAccount::get().into() }
+					.into();
+
+			let mut amount = 0u128;
+			for (recipient, share) in distribution.recipients {
+				let share_amount = share.mul_floor(collected);
+				if share_amount.is_zero() {
+					continue;
+				}
+				T::AssetTransactor::transfer_asset(
+					&(asset_id, Fungible(share_amount)).into(),
+					&fee_reserve_location,
+					&Junction::AccountId32 { network: None, id: recipient.into() }.into(),
+					// Put empty message hash here because we are not sending XCM message
+					&XcmContext::with_message_id([0; 32]),
+				)
+				.map_err(|_| Error::<T>::TransactFailed)?;
+				amount += share_amount;
+			}
+
+			let burned = distribution.burn.mul_floor(collected);
+			if !burned.is_zero() {
+				T::AssetTransactor::withdraw_asset(
+					&(asset_id, Fungible(burned)).into(),
+					&fee_reserve_location,
+					None,
+				)
+				.map_err(|_| Error::<T>::TransactFailed)?;
+				amount += burned;
+			}
+			CollectedFees::<T>::insert(domain_id, asset_id, collected - amount);
+
+			// Emit FeesDistributed event
+			let sender = match ensure_signed(origin) {
+				Ok(sender) => sender,
+				_ => [0u8; 32].into(),
+			};
+			Self::deposit_event(Event::FeesDistributed {
+				sender,
+				domain_id,
+				asset_id,
+				amount,
+				burned,
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Bridge for Pallet<T>
+	where
+		<T as frame_system::Config>::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+	{
+		fn transfer(
+			sender: [u8; 32],
+			asset: MultiAsset,
+			dest: MultiLocation,
+			_max_weight: Option<Weight>,
+		) -> DispatchResult {
+			let sender_origin = OriginFor::<T>::from(RawOrigin::Signed(sender.into()));
+			Pallet::<T>::deposit(sender_origin, Box::from(asset), Box::from(dest))?;
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T>
+	where
+		<T as frame_system::Config>::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+	{
+		/// Verifies that EIP712 typed proposal data is signed by MPC address, the scheduled MPC
+		/// address is accepted as well during the rotation window. In threshold signature mode
+		/// it's verified against the signer set instead.
+		#[allow(dead_code)]
+		pub(crate) fn verify_by_mpc_address(signing_message: [u8; 32], signature: Vec<u8>) -> bool {
+			if let Some(signer_set) = ThresholdSigners::<T>::get() {
+				return Self::verify_by_signer_set(&signer_set, signing_message, signature);
+			}
+
+			match Self::recover_signer(signing_message, signature) {
+				Some(address) =>
+					address == MpcAddr::<T>::get().0
+						|| PendingMpcAddr::<T>::get()
+							.map_or(false, |(pending_mpc_addr, _)| address == pending_mpc_addr.0),
+				None => false,
+			}
+		}
+
+		/// Verifies that EIP712 typed data is signed by at least `threshold` distinct signers of
+		/// the signer set, `signature` is the concatenation of 65-byte signatures
+		fn verify_by_signer_set(
+			signer_set: &SignerSet,
+			signing_message: [u8; 32],
+			signature: Vec<u8>,
+		) -> bool {
+			if signature.len() % 65 != 0 || signature.len() / 65 > signer_set.signers.len() {
+				return false;
+			}
+
+			let mut signed = Vec::new();
+			for sig in signature.chunks(65) {
+				match Self::recover_signer(signing_message, sig.to_vec()).map(MpcAddress) {
+					Some(signer)
+						if signer_set.signers.contains(&signer) && !signed.contains(&signer) =>
+					{
+						signed.push(signer)
+					},
+					_ => return false,
+				}
+			}
+
+			signed.len() >= signer_set.threshold as usize
+		}
+
+		/// Recover the address which signed the EIP712 typed data
+		fn recover_signer(signing_message: [u8; 32], signature: Vec<u8>) -> Option<[u8; 20]> {
+			let sig = match signature.try_into() {
+				Ok(_sig) => _sig,
+				Err(error) => return None,
+			};
+
+			// recover the uncompressed pubkey
+			secp256k1_ecdsa_recover(&sig, &signing_message)
+				.ok()
+				.map(|pubkey| Self::public_key_to_address(&pubkey))
+		}
+
+		/// Schedule `new_mpc_addr` to replace the current MPC address after `MpcRotationWindow`
+		/// blocks
+		fn do_schedule_mpc_rotation(
+			sender: T::AccountId,
+			new_mpc_addr: MpcAddress,
+		) -> DispatchResult {
+			let old_mpc_addr = MpcAddr::<T>::get();
+			ensure!(!old_mpc_addr.is_clear(), Error::<T>::MissingMpcAddress);
+			ensure!(
+				!new_mpc_addr.is_clear() && new_mpc_addr != old_mpc_addr,
+				Error::<T>::InvalidMpcAddress
+			);
+			ensure!(PendingMpcAddr::<T>::get().is_none(), Error::<T>::MpcRotationPending);
+
+			let effective_at = <frame_system::Pallet<T>>::block_number()
+				.saturating_add(T::MpcRotationWindow::get());
+			PendingMpcAddr::<T>::put((new_mpc_addr, effective_at));
+			// Invalidate all signed rotation messages of the current nonce
+			MpcRotationNonce::<T>::mutate(|nonce| *nonce = nonce.saturating_add(1));
+
+			// Emit MpcRotationScheduled event
+			Self::deposit_event(Event::MpcRotationScheduled {
+				sender,
+				old_mpc_addr,
+				new_mpc_addr,
+				effective_at,
+			});
+			Ok(())
+		}
+
+		/// Return the TokenReservedAccount address by the given token
+		pub fn get_token_reserved_account(token_id: &AssetId) -> Option<[u8; 32]> {
+			T::TransferReserveAccounts::get()
+				.get(token_id)
+				.map(|account| (*account).clone().into())
+		}
+
+		/// convert the ECDSA 64-byte uncompressed pubkey to H160 address
+		pub fn public_key_to_address(public_key: &[u8]) -> [u8; 20] {
+			let hash = keccak_256(public_key);
+			let final_hash = array_ref![&hash, 12, 20];
+			*final_hash
+		}
+
+		/// Parse proposals and construct the original signing message
+		pub fn construct_ecdsa_signing_proposals_data(proposals: &Vec<Proposal>) -> [u8; 32] {
+			let proposals_typehash = keccak_256(
+                "Proposals(Proposal[] proposals)Proposal(uint8 originDomainID,uint64 depositNonce,bytes32 resourceID,bytes data)"
+                    .as_bytes(),
+            );
+			let proposal_typehash = keccak_256(
+				"Proposal(uint8 originDomainID,uint64 depositNonce,bytes32 resourceID,bytes data)"
+					.as_bytes(),
+			);
+
+			if proposals.is_empty() {
+				return [0u8; 32];
+			}
+
+			let mut keccak_data = Vec::new();
+			for prop in proposals {
+				let proposal_domain_id_token = Token::Uint(prop.origin_domain_id.into());
+				let proposal_deposit_nonce_token = Token::Uint(prop.deposit_nonce.into());
+				let proposal_resource_id_token = Token::FixedBytes(prop.resource_id.to_vec());
+				let proposal_data_token = Token::FixedBytes(keccak_256(&prop.data).to_vec());
+
+				keccak_data.push(keccak_256(&abi_encode(&[
+					Token::FixedBytes(proposal_typehash.to_vec()),
+					proposal_domain_id_token,
+					proposal_deposit_nonce_token,
+					proposal_resource_id_token,
+					proposal_data_token,
+				])));
+			}
+
+			// flatten the keccak_data into vec<u8>
+			let mut final_keccak_data = Vec::new();
+			for data in keccak_data {
+				for d in data {
+					final_keccak_data.push(d)
+				}
+			}
+
+			let final_keccak_data_input = &[SolidityDataType::Bytes(&final_keccak_data)];
+			let bytes = encode_packed(final_keccak_data_input);
+			let hashed_keccak_data = keccak_256(bytes.as_slice());
+
+			let struct_hash = keccak_256(&abi_encode(&[
+				Token::FixedBytes(proposals_typehash.to_vec()),
+				Token::FixedBytes(hashed_keccak_data.to_vec()),
+			]));
+
+			Self::hash_typed_data(struct_hash)
+		}
+
+		/// Construct the signing message of a MPC address rotation
+		pub fn construct_ecdsa_signing_mpc_rotation_data(
+			new_mpc_addr: MpcAddress,
+			nonce: u64,
+		) -> [u8; 32] {
+			let mpc_rotation_typehash =
+				keccak_256("MpcRotation(address newMpcAddress,uint256 nonce)".as_bytes());
+
+			let struct_hash = keccak_256(&abi_encode(&[
+				Token::FixedBytes(mpc_rotation_typehash.to_vec()),
+				Token::Address(new_mpc_addr.0.into()),
+				Token::Uint(nonce.into()),
+			]));
+
+			Self::hash_typed_data(struct_hash)
+		}
+
+		/// Construct the signing message of a deposit refund
+		pub fn construct_ecdsa_signing_refund_data(
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			refund_fee: bool,
+		) -> [u8; 32] {
+			let refund_typehash = keccak_256(
+				"Refund(uint8 destDomainID,uint64 depositNonce,bool refundFee)".as_bytes(),
+			);
+
+			let struct_hash = keccak_256(&abi_encode(&[
+				Token::FixedBytes(refund_typehash.to_vec()),
+				Token::Uint(dest_domain_id.into()),
+				Token::Uint(deposit_nonce.into()),
+				Token::Bool(refund_fee),
+			]));
+
+			Self::hash_typed_data(struct_hash)
+		}
+
+		/// Return the EIP712 domain that MPC signed messages are signed in
+		pub fn eip712_domain() -> EIP712DomainParams {
+			SigningDomain::<T>::get().unwrap_or_else(|| EIP712DomainParams {
+				name: EIP712DomainName::truncate_from(b"Bridge".to_vec()),
+				version: EIP712DomainVersion::truncate_from(b"3.1.0".to_vec()),
+				chain_id: T::EIP712ChainID::get(),
+				verifying_contract: T::DestVerifyingContractAddress::get(),
+				salt: None,
+			})
+		}
+
+		/// Hash the EIP712 struct hash with the domain separator of the bridge
+		fn hash_typed_data(struct_hash: [u8; 32]) -> [u8; 32] {
+			// domain separator
+			let domain = Self::eip712_domain();
+			let eip712_domain = eip712::EIP712Domain {
+				name: domain.name.into_inner(),
+				version: domain.version.into_inner(),
+				chain_id: domain.chain_id,
+				verifying_contract: domain.verifying_contract,
+				salt: domain.salt,
+			};
+			let domain_separator = eip712_domain.separator();
+
+			let typed_data_hash_input = &[
+				SolidityDataType::String("\x19\x01"),
+				SolidityDataType::Bytes(&domain_separator),
+				SolidityDataType::Bytes(&struct_hash),
+			];
+			let bytes = encode_packed(typed_data_hash_input);
+			keccak_256(bytes.as_slice())
+		}
+
+		/// Extract asset id and transfer amount from `MultiAsset`, the amount of a non-fungible
+		/// asset is always 1.
+		fn extract_asset(asset: &MultiAsset) -> Option<(ResourceId, u128, TransferType)> {
+			match (&asset.fun, &asset.id) {
+				(Fungible(amount), _) => AssetResourceIds::<T>::get(asset.id)
+					.map(|resource_id| (resource_id, *amount, TransferType::FungibleTransfer)),
+				(NonFungible(_), _) if T::NonFungibleAssets::contains(&asset.id) =>
+					AssetResourceIds::<T>::get(asset.id)
+						.map(|resource_id| (resource_id, 1, TransferType::NonFungibleTransfer)),
+				_ => None,
+			}
+		}
+
+		/// Lock the non-fungible asset in reserve account if it's reserved in local chain,
+		/// otherwise burn it. Return the deposit data of the transfer.
+		fn deposit_non_fungible(
+			sender: &T::AccountId,
+			asset: &MultiAsset,
+			recipient: Vec<u8>,
+		) -> Result<Vec<u8>, DispatchError> {
+			let token_id = match &asset.fun {
+				NonFungible(instance) => Self::asset_instance_to_token_id(instance),
+				_ => None,
+			}
+			.ok_or(Error::<T>::InvalidTokenId)?;
+
+			// Read metadata before the item is burned
+			let metadata = T::NonFungibleMetadata::metadata(asset);
+
+			let sender_location: MultiLocation =
+				Junction::AccountId32 { network: None, id: sender.clone().into() }.into();
+			if T::IsReserve::contains(asset, &MultiLocation::here()) {
+				let token_reserved_account = Self::get_token_reserved_account(&asset.id)
+					.ok_or(Error::<T>::NoLiquidityHolderAccountBound)?;
+				T::AssetTransactor::transfer_asset(
+					asset,
+					&sender_location,
+					&Junction::AccountId32 { network: None, id: token_reserved_account }.into(),
+					// Put empty message hash here because we are not sending XCM message
+					&XcmContext::with_message_id([0; 32]),
+				)
+				.map_err(|_| Error::<T>::TransactFailed)?;
+			} else {
+				T::AssetTransactor::withdraw_asset(asset, &sender_location, None)
+					.map_err(|_| Error::<T>::TransactFailed)?;
+			}
+
+			Ok(Self::create_non_fungible_deposit_data(token_id, recipient, metadata))
+		}
+
+		/// Convert the instance of a non-fungible asset to the uint256 token id, only `Index`
+		/// and `Array32` instances are supported.
+		fn asset_instance_to_token_id(instance: &AssetInstance) -> Option<[u8; 32]> {
+			match instance {
+				AssetInstance::Index(index) => Some(Self::hex_zero_padding_32(*index)),
+				AssetInstance::Array32(token_id) => Some(*token_id),
+				_ => None,
+			}
+		}
+
+		/// Convert the uint256 token id to the instance of a non-fungible asset, token id that
+		/// fits into u128 is represented as `Index`.
+		fn token_id_to_asset_instance(token_id: [u8; 32]) -> AssetInstance {
+			match u128::try_from(U256::from_big_endian(&token_id)) {
+				Ok(index) => AssetInstance::Index(index),
+				Err(_) => AssetInstance::Array32(token_id),
+			}
+		}
+
+		pub fn create_deposit_data(amount: u128, recipient: Vec<u8>) -> Vec<u8> {
+			[
+				&Self::hex_zero_padding_32(amount),
+				&Self::hex_zero_padding_32(recipient.len() as u128),
+				recipient.as_slice(),
+			]
+			.concat()
+			.to_vec()
+		}
+
+		/// Extract transfer amount and recipient location from deposit data.
+		/// For fungible transfer, data passed into the function should be constructed as follows:
+		/// amount                    uint256     bytes  0 - 32
+		/// recipient data length     uint256     bytes  32 - 64
+		/// recipient data            bytes       bytes  64 - END
+		fn extract_deposit_data(data: &[u8]) -> Result<(u128, MultiLocation), DispatchError> {
+			if data.len() < 64 {
+				return Err(Error::<T>::InvalidDepositData.into());
+			}
+
+			let amount: u128 = U256::from_big_endian(&data[0..32])
+				.try_into()
+				.map_err(|_| Error::<T>::InvalidDepositData)?;
+			let recipient_len: usize = U256::from_big_endian(&data[32..64])
+				.try_into()
+				.map_err(|_| Error::<T>::InvalidDepositData)?;
+			if (data.len() - 64) != recipient_len {
+				return Err(Error::<T>::InvalidDepositData.into());
+			}
+
+			let recipient = data[64..data.len()].to_vec();
+			if let Ok(location) = <MultiLocation>::decode(&mut recipient.as_slice()) {
+				Ok((amount, location))
+			} else {
+				Err(Error::<T>::InvalidDepositData.into())
+			}
+		}
+
+		pub fn create_non_fungible_deposit_data(
+			token_id: [u8; 32],
+			recipient: Vec<u8>,
+			metadata: Vec<u8>,
+		) -> Vec<u8> {
+			[
+				&token_id,
+				&Self::hex_zero_padding_32(recipient.len() as u128),
+				recipient.as_slice(),
+				&Self::hex_zero_padding_32(metadata.len() as u128),
+				metadata.as_slice(),
+			]
+			.concat()
+			.to_vec()
+		}
+
+		/// Extract token id, recipient location and metadata from non-fungible deposit data.
+		/// For non-fungible transfer, data passed into the function should be constructed as
+		/// follows:
+		/// token id                  uint256     bytes  0 - 32
+		/// recipient data length     uint256     bytes  32 - 64
+		/// recipient data            bytes       bytes  64 - (64 + len(recipient data))
+		/// metadata length           uint256     bytes  (64 + len(recipient data)) -
+		///                                              (64 + len(recipient data) + 32)
+		/// metadata                  bytes       bytes  (64 + len(recipient data) + 32) - END
+		fn extract_non_fungible_deposit_data(
+			data: &[u8],
+		) -> Result<([u8; 32], MultiLocation, Vec<u8>), DispatchError> {
+			if data.len() < 64 {
+				return Err(Error::<T>::InvalidDepositData.into());
+			}
+
+			let token_id = *array_ref![data, 0, 32];
+			let recipient_len: usize = U256::from_big_endian(&data[32..64])
+				.try_into()
+				.map_err(|_| Error::<T>::InvalidDepositData)?;
+			if (data.len() - 64) < recipient_len.saturating_add(32) {
+				return Err(Error::<T>::InvalidDepositData.into());
+			}
+
+			let metadata_start = 64 + recipient_len + 32;
+			let metadata_len: usize =
+				U256::from_big_endian(&data[(64 + recipient_len)..metadata_start])
+					.try_into()
+					.map_err(|_| Error::<T>::InvalidDepositData)?;
+			if (data.len() - metadata_start) != metadata_len {
+				return Err(Error::<T>::InvalidDepositData.into());
+			}
+
+			let location = <MultiLocation>::decode(&mut &data[64..(64 + recipient_len)])
+				.map_err(|_| Error::<T>::InvalidDepositData)?;
+			Ok((token_id, location, data[metadata_start..data.len()].to_vec()))
+		}
+
+		/// Unlock the non-fungible asset from reserve account if it's reserved in local chain,
+		/// otherwise mint it with the bridged metadata
+		fn execute_non_fungible_proposal(asset_id: AssetId, data: &[u8]) -> DispatchResult {
+			let (token_id, location, metadata) = Self::extract_non_fungible_deposit_data(data)?;
+			let asset = MultiAsset {
+				id: asset_id,
+				fun: NonFungible(Self::token_id_to_asset_instance(token_id)),
+			};
+
+			if T::IsReserve::contains(&asset, &MultiLocation::here()) {
+				let token_reserved_account = Self::get_token_reserved_account(&asset_id)
+					.ok_or(Error::<T>::NoLiquidityHolderAccountBound)?;
+				T::AssetTransactor::transfer_asset(
+					&asset,
+					&Junction::AccountId32 { network: None, id: token_reserved_account }.into(),
+					&location,
+					// Put empty message hash here because we are not sending XCM message
+					&XcmContext::with_message_id([0; 32]),
+				)
+				.map_err(|_| Error::<T>::TransactFailed)?;
+			} else {
+				T::AssetTransactor::deposit_asset(
+					&asset,
+					&location,
+					// Put empty message hash here because we are not sending XCM message
+					&XcmContext::with_message_id([0; 32]),
+				)
+				.map_err(|_| Error::<T>::TransactFailed)?;
+				T::NonFungibleMetadata::set_metadata(&asset, metadata)?;
+			}
+
+			Ok(())
+		}
+
+		pub fn create_generic_deposit_data(
+			weight_limit: Weight,
+			depositor: Vec<u8>,
+			call_data: Vec<u8>,
+		) -> Vec<u8> {
+			[
+				&Self::hex_zero_padding_32(weight_limit.ref_time() as u128),
+				&Self::hex_zero_padding_32(weight_limit.proof_size() as u128),
+				&Self::hex_zero_padding_32(depositor.len() as u128),
+				depositor.as_slice(),
+				call_data.as_slice(),
+			]
+			.concat()
+			.to_vec()
+		}
+
+		/// Extract weight limit, depositor and call data from generic deposit data.
+		/// For generic transfer, data passed into the function should be constructed as follows:
+		/// ref time limit            uint256     bytes  0 - 32
+		/// proof size limit          uint256     bytes  32 - 64
+		/// depositor data length     uint256     bytes  64 - 96
+		/// depositor data            bytes       bytes  96 - (96 + len(depositor data))
+		/// call data                 bytes       bytes  (96 + len(depositor data)) - END
+		///
+		/// Call data is the SCALE encoded `RuntimeCall`.
+		fn extract_generic_deposit_data(
+			data: &[u8],
+		) -> Result<(Weight, Vec<u8>, Vec<u8>), DispatchError> {
+			if data.len() < 96 {
+				return Err(Error::<T>::InvalidDepositData.into());
+			}
+
+			let ref_time: u64 = U256::from_big_endian(&data[0..32])
+				.try_into()
+				.map_err(|_| Error::<T>::InvalidDepositData)?;
+			let proof_size: u64 = U256::from_big_endian(&data[32..64])
+				.try_into()
+				.map_err(|_| Error::<T>::InvalidDepositData)?;
+			let depositor_len: usize = U256::from_big_endian(&data[64..96])
+				.try_into()
+				.map_err(|_| Error::<T>::InvalidDepositData)?;
+			if (data.len() - 96) < depositor_len {
+				return Err(Error::<T>::InvalidDepositData.into());
+			}
+
+			let depositor = data[96..96 + depositor_len].to_vec();
+			let call_data = data[96 + depositor_len..data.len()].to_vec();
+			Ok((Weight::from_parts(ref_time, proof_size), depositor, call_data))
+		}
+
+		/// Return the sum of weight limits of the generic proposals
+		pub fn generic_proposals_weight(proposals: &[Proposal]) -> Weight {
+			proposals
+				.iter()
+				.filter(|proposal| proposal.resource_id == T::GenericResourceId::get())
+				.filter_map(|proposal| Self::extract_generic_deposit_data(&proposal.data).ok())
+				.fold(Weight::zero(), |total, (weight_limit, _, _)| {
+					total.saturating_add(weight_limit)
+				})
+		}
+
+		/// Return the account that dispatches the generic calls of `depositor` from the origin
+		/// domain
+		pub fn generic_call_account(origin_domain_id: DomainID, depositor: &[u8]) -> T::AccountId {
+			blake2_256(&(b"sygma/generic", origin_domain_id, depositor).encode()).into()
+		}
+
+		/// Decode and dispatch the call of a generic proposal
+		fn execute_generic_proposal(proposal: &Proposal) -> DispatchResult {
+			let (weight_limit, depositor, call_data) =
+				Self::extract_generic_deposit_data(&proposal.data)?;
+
+			let call = <T as Config>::RuntimeCall::decode(&mut call_data.as_slice())
+				.map_err(|_| Error::<T>::GenericCallDecodeFailed)?;
+			ensure!(T::GenericCallFilter::contains(&call), Error::<T>::GenericCallFiltered);
+			ensure!(
+				call.get_dispatch_info().weight.all_lte(weight_limit),
+				Error::<T>::GenericCallWeightExceeded
+			);
+
+			// Dispatch the call as the account derived from depositor
+			let origin = RawOrigin::Signed(Self::generic_call_account(
+				proposal.origin_domain_id,
+				&depositor,
+			));
+			call.dispatch(origin.into()).map(|_| ()).map_err(|e| e.error)
+		}
+
+		fn rid_to_assetid(rid: &ResourceId) -> Option<AssetId> {
+			ResourceAssetIds::<T>::get(rid)
+		}
+
+		fn hex_zero_padding_32(i: u128) -> [u8; 32] {
+			let mut result = [0u8; 32];
+			U256::from(i).to_big_endian(&mut result);
+			result
+		}
+
+		/// Initiates a transfer of `asset` with its bridge fee paid in `fee_asset` in a storage
+		/// layer of its own. The circuit breaker is tripped out of the layer so that the trip is
+		/// kept though the transfer fails.
+		fn deposit_in_layer(
+			sender: T::AccountId,
+			asset: Box<MultiAsset>,
+			dest: Box<MultiLocation>,
+			fee_asset: AssetId,
+		) -> DispatchResult {
+			match with_storage_layer(|| Self::do_deposit(sender, asset, dest, fee_asset))? {
+				Some(domain_id) => {
+					Self::trip_circuit_breaker(
+						domain_id,
+						CircuitBreakerTripReason::OutboundVolumeExceeded,
+					);
+					Err(Error::<T>::CircuitBreakerTripped.into())
+				},
+				None => Ok(()),
+			}
+		}
+
+		/// Initiates a transfer of `asset` with its bridge fee paid in `fee_asset`, return the
+		/// dest domain if the transfer trips its circuit breaker rather than being made. Nothing
+		/// is changed in that case.
+		fn do_deposit(
+			sender: T::AccountId,
+			asset: Box<MultiAsset>,
+			dest: Box<MultiLocation>,
+			fee_asset: AssetId,
+		) -> Result<Option<DomainID>, DispatchError> {
+			ensure!(!MpcAddr::<T>::get().is_clear(), Error::<T>::MissingMpcAddress);
+
+			// Extract dest (MultiLocation) to get corresponding dest domainID and Ethereum
+			// recipient address
+			let (recipient, dest_domain_id) =
+				T::ExtractDestData::extract_dest(&dest).ok_or(Error::<T>::ExtractDestDataFailed)?;
+
+			ensure!(
+				!IsPaused::<T>::get(dest_domain_id, TransferDirection::Outbound),
+				Error::<T>::BridgePaused
+			);
+
+			ensure!(DestDomainIds::<T>::get(dest_domain_id), Error::<T>::DestDomainNotSupported);
+
+			// Extract asset (MultiAsset) to get corresponding ResourceId, transfer amount and the
+			// transfer type
+			let (resource_id, amount, transfer_type) =
+				Self::extract_asset(&asset.clone()).ok_or(Error::<T>::AssetNotBound)?;
+			ensure!(
+				!IsResourcePaused::<T>::get(resource_id, TransferDirection::Outbound),
+				Error::<T>::ResourcePaused
+			);
+
+			// Non-fungible asset can not pay fee by itself, so its fee must be paid in a separate
+			// fee asset
+			ensure!(
+				transfer_type != TransferType::NonFungibleTransfer || fee_asset != asset.id,
+				Error::<T>::MissingFeeConfig
+			);
+
+			// Return error if no fee handler set or the fee can not be paid in `fee_asset`
+			let fee = T::FeeHandler::quote_fee(
+				&FeeContext {
+					sender: sender.clone().into(),
+					dest_domain_id,
+					recipient: recipient.clone(),
+					transfer_type: transfer_type.clone(),
+				},
+				*asset.clone(),
+				fee_asset,
+			)
+			.ok_or(Error::<T>::MissingFeeConfig)?
+			.amount;
+
+			// Fee is taken out of the transferred amount only if it's paid in the transferred
+			// asset, non-fungible transfers carry no amount and are not counted by the circuit
+			// breaker
+			let (bridge_amount, decimal_converted_amount, breaker_volume) = match transfer_type {
+				TransferType::NonFungibleTransfer => (1, 0, None),
+				_ => {
+					let bridge_amount = if fee_asset == asset.id {
+						ensure!(amount > fee, Error::<T>::FeeTooExpensive);
+						amount - fee
+					} else {
+						amount
+					};
+					let decimal_converted_amount =
+						T::DecimalConverter::convert_to(&(asset.id, bridge_amount).into())
+							.ok_or(Error::<T>::DecimalConversionFail)?;
+					let breaker_volume = Self::circuit_breaker_volume(
+						dest_domain_id,
+						TransferDirection::Outbound,
+						decimal_converted_amount,
+					);
+					(bridge_amount, decimal_converted_amount, breaker_volume)
+				},
+			};
+
+			// Pause the dest domain rather than making the deposit if it exceeds the outbound
+			// threshold of the circuit breaker
+			if let Some((_, true)) = breaker_volume {
+				return Ok(Some(dest_domain_id));
+			}
+
+			let sender_location: MultiLocation =
+				Junction::AccountId32 { network: None, id: sender.clone().into() }.into();
+
+			let deposit_data = match transfer_type {
+				TransferType::NonFungibleTransfer =>
+					Self::deposit_non_fungible(&sender, &asset, recipient.clone())?,
+				_ => {
+					// Withdraw `amount` of asset from sender
+					T::AssetTransactor::withdraw_asset(&asset, &sender_location, None)
+						.map_err(|_| Error::<T>::TransactFailed)?;
+
+					let token_reserved_account = Self::get_token_reserved_account(&asset.id)
+						.ok_or(Error::<T>::NoLiquidityHolderAccountBound)?;
+
+					// Deposit `bridge_amount` of asset to reserve account if asset is reserved in
+					// local chain.
+					if T::IsReserve::contains(&asset, &MultiLocation::here()) {
+						T::AssetTransactor::deposit_asset(
+							&(asset.id, Fungible(bridge_amount)).into(),
+							&Junction::AccountId32 { network: None, id: token_reserved_account }
+								.into(),
+							// Put empty message hash here because we are not sending XCM message
+							&XcmContext::with_message_id([0; 32]),
+						)
+						.map_err(|_| Error::<T>::TransactFailed)?;
+					}
+
+					Self::create_deposit_data(decimal_converted_amount, recipient.clone())
+				},
+			};
+
+			// Withdraw `fee` of fee asset from sender if it's paid separately
+			if fee_asset != asset.id {
+				T::AssetTransactor::withdraw_asset(
+					&(fee_asset, Fungible(fee)).into(),
+					&sender_location,
+					None,
+				)
+				.map_err(|_| Error::<T>::TransactFailed)?;
+			}
+
+			// Deposit `fee` of fee asset to treasury account
+			T::AssetTransactor::deposit_asset(
+				&(fee_asset, Fungible(fee)).into(),
+				&Junction::AccountId32 { network: None, id: T::FeeReserveAccount::get().into() }
+					.into(),
+				// Put empty message hash here because we are not sending XCM message
+				&XcmContext::with_message_id([0; 32]),
+			)
+			.map_err(|_| Error::<T>::TransactFailed)?;
+			CollectedFees::<T>::mutate(dest_domain_id, fee_asset, |collected| {
+				*collected = collected.saturating_add(fee)
+			});
+
+			// Reject the deposit if it exceeds the transfer limit
+			let volume = Self::check_transfer_limit(
+				dest_domain_id,
+				asset.id,
+				TransferDirection::Outbound,
+				bridge_amount,
+			)?;
+			Self::record_transfer_volume(
+				dest_domain_id,
+				asset.id,
+				TransferDirection::Outbound,
+				volume,
+			);
+
+			if let Some((volume, _)) = breaker_volume {
+				CircuitBreakerVolumes::<T>::insert(dest_domain_id, volume);
+			}
+
+			// Bump deposit nonce
+			let deposit_nonce = DepositCounts::<T>::get(dest_domain_id);
+			DepositCounts::<T>::insert(
+				dest_domain_id,
+				deposit_nonce.checked_add(1).ok_or(Error::<T>::DepositNonceOverflow)?,
+			);
+
+			Self::record_deposit(
+				dest_domain_id,
+				deposit_nonce,
+				sender.clone(),
+				resource_id,
+				bridge_amount,
+				recipient,
+				fee,
+				Some(fee_asset).filter(|fee_asset| *fee_asset != asset.id),
+			)?;
+
+			// Emit Deposit event
+			Self::deposit_event(Event::Deposit {
+				dest_domain_id,
+				resource_id,
+				deposit_nonce,
+				sender: sender.clone(),
+				transfer_type,
+				deposit_data,
+				handler_response: vec![],
+			});
+
+			// Emit FeeCollected event
+			Self::deposit_event(Event::FeeCollected {
+				fee_payer: sender,
+				dest_domain_id,
+				resource_id,
+				fee_amount: fee,
+				fee_asset_id: fee_asset,
+			});
+
+			Ok(None)
+		}
+
+		/// Keep the record of a deposit if deposit records are enabled, records of the dest domain
+		/// older than the latest `DepositRecordRetention` ones are pruned. `fee_asset` is only
+		/// given if the fee is not paid in the transferred asset.
+		fn record_deposit(
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+			sender: T::AccountId,
+			resource_id: ResourceId,
+			amount: u128,
+			recipient: Vec<u8>,
+			fee: u128,
+			fee_asset: Option<AssetId>,
+		) -> DispatchResult {
+			let retention = T::DepositRecordRetention::get();
+			if retention == 0 {
+				return Ok(());
+			}
+
+			// Keep the hash of a recipient too long to be kept rather than rejecting the deposit,
+			// so that deposits are accepted the same way whether records are enabled or not
+			let recipient =
+				DepositRecordRecipient::try_from(recipient).unwrap_or_else(|recipient| {
+					DepositRecordRecipient::truncate_from(keccak_256(&recipient).to_vec())
+				});
+
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			let record =
+				DepositRecord { sender, resource_id, amount, recipient, fee, block_number };
+			DepositRecords::<T>::insert(dest_domain_id, deposit_nonce, record);
+			if let Some(fee_asset) = fee_asset {
+				DepositFeeAssets::<T>::insert(dest_domain_id, deposit_nonce, fee_asset);
+			}
+			DepositBlocks::<T>::mutate(dest_domain_id, block_number, |count| {
+				*count = count.saturating_add(1)
+			});
+
+			// Prune records out of retention from the oldest one not pruned yet, the first record
+			// of the dest domain has nothing older to prune
+			let prune_end = deposit_nonce.saturating_add(1).saturating_sub(retention);
+			let mut cursor =
+				DepositRecordPruneCursor::<T>::get(dest_domain_id).unwrap_or(deposit_nonce);
+			let mut pruned = 0;
+			while cursor < prune_end && pruned < MAX_PRUNED_DEPOSIT_RECORDS {
+				Self::remove_deposit_record(dest_domain_id, cursor);
+				cursor += 1;
+				pruned += 1;
+			}
+			DepositRecordPruneCursor::<T>::insert(dest_domain_id, cursor);
+
+			Ok(())
+		}
+
+		/// Remove and return the record of a deposit
+		fn remove_deposit_record(
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		) -> Option<DepositRecord<T::AccountId, BlockNumberFor<T>>> {
+			let record = DepositRecords::<T>::take(dest_domain_id, deposit_nonce)?;
+			DepositFeeAssets::<T>::remove(dest_domain_id, deposit_nonce);
+			DepositBlocks::<T>::mutate_exists(dest_domain_id, record.block_number, |count| {
+				*count = count.and_then(|c| c.checked_sub(1)).filter(|c| *c > 0)
+			});
+			Some(record)
+		}
+
+		/// Return the transferred volume of the asset to/from the domain including `amount`, None
+		/// if the asset has no transfer limit. Error if the rolling volume exceeds the cap.
+		fn check_transfer_limit(
+			domain_id: DomainID,
+			asset_id: AssetId,
+			direction: TransferDirection,
+			amount: u128,
+		) -> Result<Option<TransferVolume<BlockNumberFor<T>>>, DispatchError> {
+			let limit = match TransferLimits::<T>::get(domain_id, asset_id) {
+				Some(limit) => limit,
+				None => return Ok(None),
+			};
+
+			let now: u128 = <frame_system::Pallet<T>>::block_number().saturated_into();
+			let window: u128 = limit.window.saturated_into();
+			let mut volume =
+				TransferVolumes::<T>::get((domain_id, asset_id, direction)).unwrap_or_default();
+
+			// Move to the window `now` belongs to
+			let window_start: u128 = volume.window_start.saturated_into();
+			let elapsed_windows = now.saturating_sub(window_start) / window;
+			if elapsed_windows > 0 {
+				volume.previous = if elapsed_windows == 1 { volume.current } else { 0 };
+				volume.current = 0;
+				volume.window_start =
+					window_start.saturating_add(elapsed_windows * window).saturated_into();
+			}
+
+			// Only the part of previous window that overlaps the rolling window is counted
+			let elapsed = now.saturating_sub(volume.window_start.saturated_into());
+			let rolling_volume = volume
+				.previous
+				.saturating_mul(window.saturating_sub(elapsed))
+				.saturating_div(window)
+				.saturating_add(volume.current);
+			ensure!(
+				rolling_volume.saturating_add(amount) <= limit.cap,
+				Error::<T>::TransferLimitExceeded
+			);
+
+			volume.current = volume.current.saturating_add(amount);
+			Ok(Some(volume))
+		}
+
+		/// Store the transferred volume returned by `check_transfer_limit`
+		fn record_transfer_volume(
+			domain_id: DomainID,
+			asset_id: AssetId,
+			direction: TransferDirection,
+			volume: Option<TransferVolume<BlockNumberFor<T>>>,
+		) {
+			if let Some(volume) = volume {
+				TransferVolumes::<T>::insert((domain_id, asset_id, direction), volume);
+			}
+		}
+
+		/// Return true if deposit nonce has been used
+		pub fn is_proposal_executed(nonce: DepositNonce, domain_id: DomainID) -> bool {
+			(UsedNonces::<T>::get(domain_id, nonce / 64) & (1 << (nonce % 64))) != 0
+		}
+
+		/// Set bit mask for specific nonce as used
+		fn set_proposal_executed(nonce: DepositNonce, domain_id: DomainID) {
+			let mut current_nonces = UsedNonces::<T>::get(domain_id, nonce / 64);
+			current_nonces |= 1 << (nonce % 64);
+			UsedNonces::<T>::insert(domain_id, nonce / 64, current_nonces);
+		}
+
+		/// Return true if proposal is neither executed nor kept as failed proposal, and it will
+		/// be kept if it fails to execute
+		fn is_new_proposal(proposal: &Proposal) -> bool {
+			!Self::is_proposal_executed(proposal.deposit_nonce, proposal.origin_domain_id)
+				&& !FailedProposals::<T>::contains_key(
+					proposal.origin_domain_id,
+					proposal.deposit_nonce,
+				) && proposal.data.len() <= FailedProposalData::bound()
+		}
+
+		/// Mark proposal as executed and remove it from failed proposals
+		fn complete_proposal(proposal: &Proposal) {
+			// Update proposal status
+			Self::set_proposal_executed(proposal.deposit_nonce, proposal.origin_domain_id);
+			FailedProposals::<T>::remove(proposal.origin_domain_id, proposal.deposit_nonce);
+
+			// Emit ProposalExecution
+			Self::deposit_event(Event::ProposalExecution {
+				origin_domain_id: proposal.origin_domain_id,
+				deposit_nonce: proposal.deposit_nonce,
+				data_hash: keccak_256(
+					&[proposal.data.clone(), T::PalletId::get().into_account_truncating()].concat(),
+				),
+			});
+		}
+
+		/// Keep a failed proposal unless it has been executed already or its data is too long
+		fn store_failed_proposal(proposal: &Proposal, err_msg: &str) {
+			if Self::is_proposal_executed(proposal.deposit_nonce, proposal.origin_domain_id) {
+				return;
+			}
+			let data = match FailedProposalData::try_from(proposal.data.clone()) {
+				Ok(data) => data,
+				Err(_) => return,
+			};
+			FailedProposals::<T>::insert(
+				proposal.origin_domain_id,
+				proposal.deposit_nonce,
+				FailedProposal {
+					resource_id: proposal.resource_id,
+					data,
+					error: FailedProposalError::truncate_from(err_msg.as_bytes().to_vec()),
+					block_number: <frame_system::Pallet<T>>::block_number(),
+				},
+			);
+		}
+
+		/// Execute a single proposal in a storage layer of its own, so a proposal that fails part
+		/// way leaves nothing changed and can be executed again safely. The circuit breaker is
+		/// tripped out of the layer so that the trip is kept though the proposal fails.
+		fn execute_proposal_in_layer(proposal: &Proposal) -> DispatchResult {
+			match with_storage_layer(|| Self::execute_proposal_internal(proposal))? {
+				Some(reason) => {
+					Self::trip_circuit_breaker(proposal.origin_domain_id, reason);
+					Err(Error::<T>::CircuitBreakerTripped.into())
+				},
+				None => Ok(()),
+			}
+		}
+
+		/// Execute a single proposal, return the reason if the proposal trips the circuit breaker
+		/// of its origin domain rather than being executed. Nothing is changed in that case.
+		fn execute_proposal_internal(
+			proposal: &Proposal,
+		) -> Result<Option<CircuitBreakerTripReason>, DispatchError> {
+			// Check if dest domain bridge is paused
+			ensure!(
+				!IsPaused::<T>::get(proposal.origin_domain_id, TransferDirection::Inbound),
+				Error::<T>::BridgePaused
+			);
+			// Check if resource is paused
+			ensure!(
+				!IsResourcePaused::<T>::get(proposal.resource_id, TransferDirection::Inbound),
+				Error::<T>::ResourcePaused
+			);
+			// Check if domain is supported
+			ensure!(
+				DestDomainIds::<T>::get(proposal.origin_domain_id),
+				Error::<T>::DestDomainNotSupported
+			);
+			// Check if proposal has executed
+			ensure!(
+				!Self::is_proposal_executed(proposal.deposit_nonce, proposal.origin_domain_id),
+				Error::<T>::ProposalAlreadyComplete
+			);
+			// Generic proposal carries a call to dispatch rather than an asset transfer
+			if proposal.resource_id == T::GenericResourceId::get() {
+				return Self::execute_generic_proposal(proposal).map(|_| None);
+			}
+			// Extract ResourceId from proposal data to get corresponding asset (MultiAsset)
+			let asset_id =
+				Self::rid_to_assetid(&proposal.resource_id).ok_or(Error::<T>::AssetNotBound)?;
+			if T::NonFungibleAssets::contains(&asset_id) {
+				let volume = Self::check_transfer_limit(
+					proposal.origin_domain_id,
+					asset_id,
+					TransferDirection::Inbound,
+					1,
+				)?;
+				Self::execute_non_fungible_proposal(asset_id, &proposal.data)?;
+				Self::record_transfer_volume(
+					proposal.origin_domain_id,
+					asset_id,
+					TransferDirection::Inbound,
+					volume,
+				);
+				return Ok(None);
+			}
+			// Extract Receipt from proposal data to get corresponding location (MultiLocation)
+			let (amount, location) = Self::extract_deposit_data(&proposal.data)?;
+
+			// convert the asset decimal
+			let decimal_converted_asset =
+				T::DecimalConverter::convert_from(&(asset_id, amount).into())
+					.ok_or(Error::<T>::DecimalConversionFail)?;
+
+			// Fail the proposal if it exceeds the transfer limit, it can be executed later
+			let volume = match decimal_converted_asset.fun {
+				Fungible(converted_amount) => Self::check_transfer_limit(
+					proposal.origin_domain_id,
+					asset_id,
+					TransferDirection::Inbound,
+					converted_amount,
+				)?,
+				_ => None,
+			};
+
+			// Pause the origin domain rather than executing the proposal if it exceeds the
+			// inbound threshold of the circuit breaker, it can be executed again once unpaused
+			let breaker_volume = Self::circuit_breaker_volume(
+				proposal.origin_domain_id,
+				TransferDirection::Inbound,
+				amount,
+			);
+			if let Some((_, true)) = breaker_volume {
+				return Ok(Some(CircuitBreakerTripReason::InboundVolumeExceeded));
+			}
+
+			let token_reserved_account = Self::get_token_reserved_account(&asset_id)
+				.ok_or(Error::<T>::NoLiquidityHolderAccountBound)?;
+
+			// Withdraw `decimal_converted_asset` of asset from reserve account
+			if T::IsReserve::contains(&decimal_converted_asset, &MultiLocation::here()) {
+				// Pause the origin domain rather than letting the reserve drop below its floor
+				if let (Some(floor), Fungible(converted_amount)) =
+					(ReserveFloors::<T>::get(asset_id), &decimal_converted_asset.fun)
+				{
+					let below_floor =
+						T::ReserveBalances::balance(&asset_id, &token_reserved_account)
+							.map_or(false, |balance| {
+								balance.saturating_sub(*converted_amount) < floor
+							});
+					if below_floor {
+						return Ok(Some(CircuitBreakerTripReason::ReserveBelowFloor { asset_id }));
+					}
+				}
+				T::AssetTransactor::withdraw_asset(
+					&decimal_converted_asset,
+					&Junction::AccountId32 { network: None, id: token_reserved_account }.into(),
+					None,
+				)
+				.map_err(|_| Error::<T>::TransactFailed)?;
+			}
+
+			// Deposit `decimal_converted_asset` of asset to dest location
+			T::AssetTransactor::deposit_asset(
+				&decimal_converted_asset,
+				&location,
+				// Put empty message hash here because we are not sending XCM message
+				&XcmContext::with_message_id([0; 32]),
+			)
+			.map_err(|_| Error::<T>::TransactFailed)?;
+
+			Self::record_transfer_volume(
+				proposal.origin_domain_id,
+				asset_id,
+				TransferDirection::Inbound,
+				volume,
+			);
+			if let Some((volume, _)) = breaker_volume {
+				CircuitBreakerVolumes::<T>::insert(proposal.origin_domain_id, volume);
+			}
+
+			Ok(None)
+		}
+
+		/// Return the volume of the domain in the current window of its circuit breaker with
+		/// `amount` added, and whether the threshold of the direction is exceeded. None if the
+		/// domain has no circuit breaker.
+		fn circuit_breaker_volume(
+			domain_id: DomainID,
+			direction: TransferDirection,
+			amount: u128,
+		) -> Option<(CircuitBreakerVolume<BlockNumberFor<T>>, bool)> {
+			let breaker = CircuitBreakers::<T>::get(domain_id)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut volume = CircuitBreakerVolumes::<T>::get(domain_id).unwrap_or_default();
+
+			// Start a new window once the current one is over
+			if now.saturating_sub(volume.window_start) >= breaker.window {
+				volume = CircuitBreakerVolume { window_start: now, inbound: 0, outbound: 0 };
+			}
+
+			let exceeded = match direction {
+				TransferDirection::Inbound => {
+					volume.inbound = volume.inbound.saturating_add(amount);
+					volume.inbound > breaker.max_inbound
+				},
+				TransferDirection::Outbound => {
+					volume.outbound = volume.outbound.saturating_add(amount);
+					volume.outbound > breaker.max_outbound
+				},
+			};
+			Some((volume, exceeded))
+		}
+
+		/// Pause both directions of the domain and emit `CircuitBreakerTripped` event
+		fn trip_circuit_breaker(domain_id: DomainID, reason: CircuitBreakerTripReason) {
+			Self::set_paused(domain_id, true);
+			Self::deposit_event(Event::CircuitBreakerTripped { domain_id, reason });
+		}
+
+		/// Return true if any direction of the domain is paused
+		pub fn is_domain_paused(domain_id: DomainID) -> bool {
+			IsPaused::<T>::get(domain_id, TransferDirection::Outbound)
+				|| IsPaused::<T>::get(domain_id, TransferDirection::Inbound)
+		}
+
+		/// Set the pause state of both directions of the domain
+		fn set_paused(domain_id: DomainID, paused: bool) {
+			IsPaused::<T>::insert(domain_id, TransferDirection::Outbound, paused);
+			IsPaused::<T>::insert(domain_id, TransferDirection::Inbound, paused);
+		}
+
+		/// unpause all registered domains in the storage
+		fn unpause_all_domains() {
+			DestDomainIds::<T>::iter_keys().for_each(|d| Self::set_paused(d, false));
+			IsPaused::<T>::iter_keys().for_each(|(d, _)| Self::set_paused(d, false));
+			let _ = CircuitBreakerVolumes::<T>::clear(u32::MAX, None);
+		}
+
+		/// pause all registered domains in the storage
+		fn pause_all_domains() {
+			DestDomainIds::<T>::iter_keys().for_each(|d| Self::set_paused(d, true));
+			IsPaused::<T>::iter_keys().for_each(|(d, _)| Self::set_paused(d, true));
+		}
+	}
+
+	#[cfg(test)]
+	mod test {
+		use codec::{self, Encode};
+		use frame_support::{
+			assert_noop, assert_ok,
+			crypto::ecdsa::ECDSAExt,
+			dispatch::{GetDispatchInfo, Pays},
+			traits::{
+				tokens::{fungibles::Create as FungibleCerate, nonfungibles::Inspect},
+				Hooks,
+			},
+			unsigned::ValidateUnsigned,
+			weights::Weight,
+		};
+		use parachains_common::AccountId;
+		use primitive_types::U256;
+		use sp_core::{ecdsa, ByteArray, Pair};
+		use sp_io::hashing::keccak_256;
+		use sp_runtime::{
+			traits::{BlakeTwo256, Hash},
+			transaction_validity::{InvalidTransaction, TransactionSource},
+			Perbill,
+		};
+		use sp_std::{boxed::Box, vec};
+		use xcm::latest::prelude::*;
+
+		use bridge::mock::{
+			assert_events, new_test_ext, slice_to_generalkey, AccessSegregator, Assets, Balances,
+			BridgeAccountNative, BridgeAccountOtherTokens, BridgePalletIndex,
+			DepositRecordRetention, ForeignNftCollectionId, ForeignNftLocation,
+			ForeignNftResourceId, GenericResourceId, NativeLocation, NativeResourceId,
+			NftCollectionId, NftLocation, NftResourceId, Runtime, RuntimeCall, RuntimeEvent,
+			RuntimeOrigin as Origin, SygmaBasicFeeHandler, SygmaBridge, SygmaFeeHandlerRouter,
+			SygmaPercentageFeeHandler, System, TreasuryAccount, Uniques, UnsignedPriority,
+			UsdtAssetId, UsdtLocation, UsdtResourceId, ALICE, ASSET_OWNER, BOB, DEST_DOMAIN_ID,
+			ENDOWED_BALANCE,
+		};
+		use sygma_fee_handler_router::FeeHandlerType;
+		use sygma_traits::{
+			Bridge, ChainID, DepositNonce, DomainID, MpcAddress, ResourceId, TransferType,
+		};
+
+		use crate as bridge;
+		use crate::{
+			mock::{
+				AstrAssetId, AstrLocation, AstrResourceId, DestVerifyingContractAddress,
+				EIP712ChainID,
+			},
+			AssetResourceIds, CircuitBreakerTripReason, CircuitBreakerVolume, DepositBatchMode,
+			DepositBlocks, DepositCounts, DepositFeeAssets, DepositRecord, DepositRecords,
+			DestChainIds, DestDomainIds, EIP712DomainParams, Error, Event as SygmaBridgeEvent,
+			FailedProposals, IsPaused, MpcAddr, MpcRotationNonce, PendingMpcAddr, Proposal,
+			ResourceAssetIds, TransferDirection, TransferLimit, TransferVolumes, WeightInfo,
+		};
+
+		#[test]
+		fn get_token_reserved_account_test() {
+			new_test_ext().execute_with(|| {
+				assert_eq!(
+					SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into()).unwrap(),
+					BridgeAccountOtherTokens::get().as_slice()
+				);
+				assert_eq!(
+					SygmaBridge::get_token_reserved_account(&NativeLocation::get().into()).unwrap(),
+					BridgeAccountNative::get().as_slice()
+				);
+				assert_eq!(
+					SygmaBridge::get_token_reserved_account(&AstrLocation::get().into()).unwrap(),
+					BridgeAccountOtherTokens::get().as_slice()
+				);
+
+				// unknown token should return None
+				assert_eq!(
+					SygmaBridge::get_token_reserved_account(
+						&MultiLocation::new(
+							2,
+							X3(
+								Parachain(1000),
+								slice_to_generalkey(b"sygma"),
+								slice_to_generalkey(b"unknown"),
+							),
+						)
+						.into()
+					),
+					None
+				);
+			})
+		}
+
+		#[test]
+		fn set_mpc_address() {
+			new_test_ext().execute_with(|| {
+				let default_addr: MpcAddress = MpcAddress::default();
+				let test_mpc_addr_a: MpcAddress = MpcAddress([1u8; 20]);
+				let test_mpc_addr_b: MpcAddress = MpcAddress([2u8; 20]);
+
+				assert_eq!(MpcAddr::<Runtime>::get(), default_addr);
+
+				// set to test_mpc_addr_a
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr_a));
+				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr_a);
+
+				// set to test_mpc_addr_b: should be MpcAddrNotUpdatable error
+				assert_noop!(
+					SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr_b),
+					bridge::Error::<Runtime>::MpcAddrNotUpdatable
+				);
+
+				// permission test: unauthorized account should not be able to set mpc address
+				let unauthorized_account = Origin::from(Some(ALICE));
+				assert_noop!(
+					SygmaBridge::set_mpc_address(unauthorized_account, test_mpc_addr_a),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr_a);
+			})
+		}
+
+		#[test]
+		fn pause_bridge() {
+			new_test_ext().execute_with(|| {
+				let default_addr = MpcAddress::default();
+				assert_eq!(MpcAddr::<Runtime>::get(), default_addr);
+
+				// register domain
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				// pause bridge, should be ok
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
+					dest_domain_id: DEST_DOMAIN_ID,
+				})]);
+
+				// pause bridge again after paused, should be ok
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
+					dest_domain_id: DEST_DOMAIN_ID,
+				})]);
+
+				// permission test: unauthorized account should not be able to pause bridge
+				let unauthorized_account = Origin::from(Some(ALICE));
+				assert_noop!(
+					SygmaBridge::pause_bridge(unauthorized_account, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert!(SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
+			})
+		}
+
+		#[test]
+		fn unpause_bridge() {
+			new_test_ext().execute_with(|| {
+				let default_addr: MpcAddress = MpcAddress::default();
+				assert_eq!(MpcAddr::<Runtime>::get(), default_addr);
+
+				// register domain
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgePaused {
+					dest_domain_id: DEST_DOMAIN_ID,
+				})]);
+
+				// bridge should be paused here
+				assert!(SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
+
+				// ready to unpause bridge, should be ok
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BridgeUnpaused {
+					dest_domain_id: DEST_DOMAIN_ID,
+				})]);
+
+				// try to unpause it again, should be error
+				assert_noop!(
+					SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::BridgeUnpaused
+				);
+
+				// permission test: unauthorized account should not be able to unpause a recognized
+				// bridge
+				let unauthorized_account = Origin::from(Some(ALICE));
+				assert_noop!(
+					SygmaBridge::unpause_bridge(unauthorized_account, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert!(!SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
+			})
+		}
+
+		#[test]
+		fn verify_mpc_signature_invalid_signature() {
+			new_test_ext().execute_with(|| {
+				let signature = vec![1u8];
+
+				// dummy proposals
+				let p1 = Proposal {
+					origin_domain_id: 1,
+					deposit_nonce: 1,
+					resource_id: [1u8; 32],
+					data: vec![1u8],
+				};
+				let p2 = Proposal {
+					origin_domain_id: 2,
+					deposit_nonce: 2,
+					resource_id: [2u8; 32],
+					data: vec![2u8],
+				};
+				let proposals = vec![p1, p2];
+
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+
+				// should be false
+				assert!(!SygmaBridge::verify_by_mpc_address(final_message, signature.encode()));
+			})
+		}
+
+		#[test]
+		fn verify_mpc_signature_invalid_message() {
+			new_test_ext().execute_with(|| {
+				// generate mpc keypair
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let public = pair.public();
+				let message = b"Something important";
+				let signature = pair.sign(&message[..]);
+
+				// make sure generated keypair, message and signature are all good
+				assert!(ecdsa::Pair::verify(&signature, &message[..], &public));
+				assert!(!ecdsa::Pair::verify(&signature, b"Something else", &public));
+
+				// dummy proposals
+				let p1 = Proposal {
+					origin_domain_id: 1,
+					deposit_nonce: 1,
+					resource_id: [1u8; 32],
+					data: vec![1u8],
+				};
+				let p2 = Proposal {
+					origin_domain_id: 2,
+					deposit_nonce: 2,
+					resource_id: [2u8; 32],
+					data: vec![2u8],
+				};
+				let proposals = vec![p1, p2];
+
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+
+				// verify non matched signature against proposal list, should be false
+				assert!(!SygmaBridge::verify_by_mpc_address(final_message, signature.encode()));
+			})
+		}
+
+		#[test]
+		fn verify_mpc_signature_valid_message_unmatched_mpc() {
+			new_test_ext().execute_with(|| {
+				// generate the signing keypair
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+
+				// set mpc address to another random key
+				let test_mpc_addr: MpcAddress = MpcAddress([7u8; 20]);
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr);
+
+				// dummy proposals
+				let p1 = Proposal {
+					origin_domain_id: 1,
+					deposit_nonce: 1,
+					resource_id: [1u8; 32],
+					data: vec![1u8],
+				};
+				let p2 = Proposal {
+					origin_domain_id: 2,
+					deposit_nonce: 2,
+					resource_id: [2u8; 32],
+					data: vec![2u8],
+				};
+				let proposals = vec![p1, p2];
+
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+
+				// sign final message using generated prikey
+				let signature = pair.sign_prehashed(&final_message);
+
+				// verify signature, should be false because the signing address != mpc address
+				assert!(!SygmaBridge::verify_by_mpc_address(final_message, signature.encode()));
+			})
+		}
+
+		#[test]
+		fn verify_mpc_signature_valid_message_valid_signature() {
+			new_test_ext().execute_with(|| {
+				// generate mpc keypair
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+
+				// set mpc address to generated keypair's address
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr);
+
+				// dummy proposals
+				let p1 = Proposal {
+					origin_domain_id: 1,
+					deposit_nonce: 1,
+					resource_id: [1u8; 32],
+					data: vec![1u8],
+				};
+				let p2 = Proposal {
+					origin_domain_id: 2,
+					deposit_nonce: 2,
+					resource_id: [2u8; 32],
+					data: vec![2u8],
+				};
+				let proposals = vec![p1, p2];
+
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+
+				// sign final message using generated mpc prikey
+				// `pari.sign` will hash the final message into blake2_256 then sign it, so use
+				// sign_prehashed here
+				let signature = pair.sign_prehashed(&final_message);
+
+				// verify signature, should be true
+				assert!(SygmaBridge::verify_by_mpc_address(final_message, signature.encode()));
+			})
+		}
+
+		#[test]
+		fn deposit_native_asset_should_work() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+				let final_amount_in_deposit_event = 199_000_000_000_000_000_000; // 200 - 1 then adjust to 18 decimals
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check balances
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					amount - fee
+				);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
+				// Check event
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						deposit_nonce: 0,
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							final_amount_in_deposit_event,
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						fee_amount: fee,
+						fee_asset_id: NativeLocation::get().into(),
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn deposit_with_fee_discount_should_work() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+				let discounted_fee = 500_000_000_000u128; // 0.5 with 12 decimals
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+
+				// put ALICE on 50% discount tier
+				assert_ok!(SygmaFeeHandlerRouter::set_discount_tier(Origin::root(), 1, 5000));
+				assert_ok!(SygmaFeeHandlerRouter::set_account_discount_tier(
+					Origin::root(),
+					ALICE,
+					1,
+					100
+				));
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(dest),
+				));
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), discounted_fee);
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+					fee_payer: ALICE,
+					dest_domain_id: DEST_DOMAIN_ID,
+					resource_id: NativeResourceId::get(),
+					fee_amount: discounted_fee,
+					fee_asset_id: NativeLocation::get().into(),
+				})]);
+
+				// exempt ALICE from fee
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_exemption(Origin::root(), ALICE, true));
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(dest),
+				));
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount * 2);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), discounted_fee);
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+					fee_payer: ALICE,
+					dest_domain_id: DEST_DOMAIN_ID,
+					resource_id: NativeResourceId::get(),
+					fee_amount: 0,
+					fee_asset_id: NativeLocation::get().into(),
+				})]);
+			})
+		}
+
+		#[test]
+		fn fee_ledger_withdraw_and_distribute_should_work() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+				let relayer: AccountId = AccountId::new([30u8; 32]);
+				let native_asset_id: AssetId = NativeLocation::get().into();
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(native_asset_id),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(native_asset_id),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+
+				// fees of deposits are tracked per (domain, asset)
+				for _ in 0..2 {
+					assert_ok!(SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					));
+				}
+				assert_eq!(SygmaBridge::collected_fees(DEST_DOMAIN_ID, native_asset_id), fee * 2);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee * 2);
+
+				// withdraw half of the fee of a deposit
+				assert_noop!(
+					SygmaBridge::withdraw_fees(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(native_asset_id),
+						fee * 2 + 1,
+						BOB
+					),
+					bridge::Error::<Runtime>::InsufficientCollectedFees
+				);
+				assert_ok!(SygmaBridge::withdraw_fees(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(native_asset_id),
+					fee / 2,
+					BOB
+				));
+				assert_eq!(Balances::free_balance(BOB), ENDOWED_BALANCE + fee / 2);
+				assert_eq!(
+					SygmaBridge::collected_fees(DEST_DOMAIN_ID, native_asset_id),
+					fee * 3 / 2
+				);
+
+				// shares must add up to 100%
+				assert_noop!(
+					SygmaBridge::distribute_fees(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(native_asset_id)
+					),
+					bridge::Error::<Runtime>::FeeDistributionNotSet
+				);
+				assert_noop!(
+					SygmaBridge::set_fee_distribution(
+						Origin::root(),
+						vec![
+							(BOB, Perbill::from_percent(50)),
+							(relayer.clone(), Perbill::from_percent(40))
+						],
+						Perbill::zero()
+					),
+					bridge::Error::<Runtime>::InvalidFeeDistribution
+				);
+				assert_ok!(SygmaBridge::set_fee_distribution(
+					Origin::root(),
+					vec![
+						(BOB, Perbill::from_percent(60)),
+						(relayer.clone(), Perbill::from_percent(30))
+					],
+					Perbill::from_percent(10)
+				));
+
+				// distribute the rest of collected fees: 60% to BOB, 30% to relayer and burn 10%
+				let issuance = Balances::total_issuance();
+				assert_ok!(SygmaBridge::distribute_fees(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(native_asset_id)
+				));
+				assert_eq!(SygmaBridge::collected_fees(DEST_DOMAIN_ID, native_asset_id), 0);
+				assert_eq!(Balances::free_balance(BOB), ENDOWED_BALANCE + fee / 2 + fee * 9 / 10);
+				assert_eq!(Balances::free_balance(relayer), fee * 9 / 20);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 0);
+				assert_eq!(Balances::total_issuance(), issuance - fee * 3 / 20);
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeesDistributed {
+					sender: [0u8; 32].into(),
+					domain_id: DEST_DOMAIN_ID,
+					asset_id: native_asset_id,
+					amount: fee * 3 / 2,
+					burned: fee * 3 / 20,
+				})]);
+
+				// permission test: unauthorized account should not be able to withdraw fees
+				assert_noop!(
+					SygmaBridge::withdraw_fees(
+						Origin::signed(ALICE),
+						DEST_DOMAIN_ID,
+						Box::new(native_asset_id),
+						0,
+						ALICE
+					),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+			})
+		}
+
+		#[test]
+		fn transfer_wrap_deposit_should_work() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+				let final_amount_in_deposit_event = 199_000_000_000_000_000_000; // 200 - 1 then adjust to 18 decimals
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+
+				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
+				let dest: MultiLocation = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+
+				// Call transfer instead of deposit
+				assert_ok!(SygmaBridge::transfer(ALICE.into(), asset.clone(), dest, None));
+
+				// Check balances
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					amount - fee
+				);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
+				// Check event
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						deposit_nonce: 0,
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							final_amount_in_deposit_event,
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						fee_amount: fee,
+						fee_asset_id: NativeLocation::get().into(),
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn hex_zero_padding_32_test() {
+			new_test_ext().execute_with(|| {
+				assert_eq!(
+					SygmaBridge::hex_zero_padding_32(100).to_vec(),
+					vec![
+						0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+						0, 0, 0, 0, 0, 0, 100,
+					]
+				);
+				let recipient = String::from("0x95ECF5ae000e0fe0e0dE63aDE9b7D82a372038b4");
+				assert_eq!(
+					SygmaBridge::hex_zero_padding_32(recipient.len() as u128).to_vec(),
+					vec![
+						0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+						0, 0, 0, 0, 0, 0, 42,
+					]
+				);
+			})
+		}
+
+		#[test]
+		fn create_deposit_data_test() {
+			new_test_ext().execute_with(|| {
+				let recipient = b"0x95ECF5ae000e0fe0e0dE63aDE9b7D82a372038b4".to_vec();
+				let data = SygmaBridge::create_deposit_data(100, recipient);
+				// 32 + 32 + 42
+				assert_eq!(data.len(), 106);
+				assert_eq!(
+					data.to_vec(),
+					vec![
+						0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+						0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+						0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 48, 120, 57, 53, 69, 67, 70,
+						53, 97, 101, 48, 48, 48, 101, 48, 102, 101, 48, 101, 48, 100, 69, 54, 51,
+						97, 68, 69, 57, 98, 55, 68, 56, 50, 97, 51, 55, 50, 48, 51, 56, 98, 52,
+					]
+				);
+			})
+		}
+
+		#[test]
+		fn deposit_foreign_asset_should_work() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 100u128;
+				let amount = 200u128;
+
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(UsdtLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(UsdtLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+
+				// Register foreign asset (USDT) with asset id 0
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+
+				// Mint some USDT to ALICE for test
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					ALICE,
+					ENDOWED_BALANCE,
+				));
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), ENDOWED_BALANCE);
+
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(UsdtLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check balances
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), ENDOWED_BALANCE - amount);
+				// USDT in the mock runtime has been configured as the reserved token, so the corresponding account should hold the deposit balance
+				assert_eq!(
+					Assets::balance(
+						UsdtAssetId::get(),
+						AccountId::new(
+							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
+								.unwrap()
+						),
+					),
+					amount - fee
+				);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), TreasuryAccount::get()), fee);
+				// Check event
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: UsdtResourceId::get(),
+						deposit_nonce: 0,
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							amount - fee,
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: UsdtResourceId::get(),
+						fee_amount: fee,
+						fee_asset_id: UsdtLocation::get().into(),
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn deposit_unbounded_asset_should_fail() {
+			new_test_ext().execute_with(|| {
+				let unbounded_asset_location = MultiLocation::new(1, X1(GeneralIndex(123)));
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 100u128;
+				let amount = 200u128;
+
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(unbounded_asset_location.into()),
+					fee
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(unbounded_asset_location), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					),
+					bridge::Error::<Runtime>::AssetNotBound
+				);
+			})
+		}
+
+		#[test]
+		fn deposit_to_unrecognized_dest_should_fail() {
+			new_test_ext().execute_with(|| {
+				let invalid_dest = MultiLocation::new(
+					0,
+					X2(GeneralIndex(0), slice_to_generalkey(b"ethereum recipient")),
+				);
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 100u128;
+				let amount = 200u128;
+
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(invalid_dest),
+					),
+					bridge::Error::<Runtime>::ExtractDestDataFailed
+				);
+			})
+		}
+
+		#[test]
+		fn deposit_without_fee_set_should_fail() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let amount = 200u128;
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					),
+					bridge::Error::<Runtime>::MissingFeeConfig
+				);
+			})
+		}
+
+		#[test]
+		fn deposit_less_than_fee_should_fail() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 200u128;
+				let amount = 100u128;
+
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					),
+					bridge::Error::<Runtime>::FeeTooExpensive
+				);
+			})
+		}
+
+		#[test]
+		fn deposit_when_bridge_paused_should_fail() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 100u128;
+				let amount = 200u128;
+
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				// register domain
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				// set mpc address will also unpause all bridges
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+
+				// Pause bridge again
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				// Should failed
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					),
+					bridge::Error::<Runtime>::BridgePaused
+				);
+				// Unpause bridge
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				// Should success
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+			})
+		}
+
+		#[test]
+		fn deposit_without_mpc_set_should_fail() {
+			new_test_ext().execute_with(|| {
+				let fee = 200u128;
+				let amount = 100u128;
+
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					),
+					bridge::Error::<Runtime>::MissingMpcAddress
+				);
+			})
+		}
+
+		#[test]
+		fn retry_bridge() {
+			new_test_ext().execute_with(|| {
+				// should be access denied SINCE Alice does not have permission to retry
+				assert_noop!(
+					SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+
+				// Grant ALICE the access of `retry`
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"retry".to_vec(),
+					ALICE
+				));
+
+				// mpc address is missing, should fail
+				assert_noop!(
+					SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::MissingMpcAddress
+				);
+
+				// set mpc address
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				// pause bridge after set mpc address and retry, should fail
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_noop!(
+					SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::BridgePaused
+				);
+
+				// unpause bridge
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
+
+				// retry again, should work
+				assert_ok!(SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Retry {
+					deposit_on_block_height: 1234567u128,
+					dest_domain_id: DEST_DOMAIN_ID,
+					sender: ALICE,
+				})]);
+			})
+		}
+
+		#[test]
+		fn proposal_execution_should_work() {
+			new_test_ext().execute_with(|| {
+				// mpc address is missing, should fail
+				assert_noop!(
+					SygmaBridge::execute_proposal(Origin::signed(ALICE), vec![], vec![]),
+					bridge::Error::<Runtime>::MissingMpcAddress,
+				);
+				// set mpc address to generated keypair's address
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr);
+				// register domain
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				// Generate an evil key
+				let (evil_pair, _): (ecdsa::Pair, _) = Pair::generate();
+
+				// Deposit some native asset in advance
+				let fee = 1_000_000_000_000u128;
+				let amount: u128 = 200_000_000_000_000u128;
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+
+				// Register foreign asset (USDT) with asset id 0
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+
+				// Mint 400 USDT to liquidity holder for test
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					AccountId::new(
+						SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
+							.unwrap()
+					),
+					400_000_000_000_000,
+				));
+				// alice deposit 200 - 1 token fee native token, so the native token holder should have 199 tokens
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					199_000_000_000_000
+				);
+				// USDT liquidity holder should have 400 USDT at this moment
+				assert_eq!(
+					Assets::balance(
+						UsdtAssetId::get(),
+						AccountId::new(
+							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
+								.unwrap()
+						),
+					),
+					400_000_000_000_000
+				);
+
+				// Generate proposals
+				// amount is in 18 decimal 0.000200000000000000, will be convert to 12 decimal
+				// 0.000200000000
+				let valid_native_transfer_proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 1,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
+				};
+				// amount is in 18 decimal 0.000200000000000000, will be convert to 18 decimal
+				// 0.000200000000000000
+				let valid_usdt_transfer_proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 2,
+					resource_id: UsdtResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
+				};
+				let invalid_depositnonce_proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 2,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
+				};
+				let invalid_domainid_proposal = Proposal {
+					origin_domain_id: 2,
+					deposit_nonce: 3,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
+				};
+				let invalid_resourceid_proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 3,
+					resource_id: [2u8; 32],
+					data: SygmaBridge::create_deposit_data(
+						amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
+				};
+				let invalid_recipient_proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 3,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(amount, b"invalid recipient".to_vec()),
+				};
+				let empty_data_proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 3,
+					resource_id: UsdtResourceId::get(),
+					data: vec![],
+				};
+
+				let proposals = vec![
+					valid_native_transfer_proposal,
+					valid_usdt_transfer_proposal,
+					invalid_depositnonce_proposal,
+					invalid_domainid_proposal,
+					invalid_resourceid_proposal,
+					invalid_recipient_proposal,
+					empty_data_proposal,
+				];
+
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				let proposals_with_valid_signature = pair.sign_prehashed(&final_message);
+				let proposals_with_bad_signature = evil_pair.sign_prehashed(&final_message);
+
+				// Should failed if dest domain 1 bridge paused
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals.clone(),
+					proposals_with_valid_signature.encode()
+				));
+				// should emit FailedHandlerExecution event
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::FailedHandlerExecution {
+						error: vec![66, 114, 105, 100, 103, 101, 80, 97, 117, 115, 101, 100],
+						origin_domain_id: 1,
+						deposit_nonce: 3,
+					},
+				)]);
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+
+				assert_noop!(
+					SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals.clone(),
+						proposals_with_bad_signature.encode(),
+					),
+					bridge::Error::<Runtime>::BadMpcSignature,
+				);
+				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &BOB), 0);
+				assert!(SygmaBridge::verify_by_mpc_address(
+					final_message,
+					proposals_with_valid_signature.encode(),
+				));
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					proposals_with_valid_signature.encode(),
+				));
+				// proposal amount is in 18 decimal 0.000200000000000000, will be convert to 12
+				// decimal 0.000200000000(200000000) because native asset is defined in 12 decimal
+				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE + 200000000);
+				// usdt is defined in 18 decimal so that converted amount is the same as in proposal
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &BOB), amount);
+
+				// liquidity holder accounts balance after proposals execution
+				// 199 - 0.0002 native token is 198.999800000000
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					199_000_000_000_000 - 200_000_000
+				);
+				// 400 USDT after transferring out the USDT proposal, should remain 200 USDT
+				assert_eq!(
+					Assets::balance(
+						UsdtAssetId::get(),
+						AccountId::new(
+							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
+								.unwrap()
+						),
+					),
+					200_000_000_000_000
+				);
+			})
+		}
+
+		#[test]
+		fn get_bridge_pause_status() {
+			new_test_ext().execute_with(|| {
+				assert!(!SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
+
+				// set mpc address
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				// register domain
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				// pause bridge
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
+
+				// unpause bridge
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
+			})
+		}
+
+		#[test]
+		fn access_control() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+
+				assert_noop!(
+					SygmaBridge::set_mpc_address(Some(ALICE).into(), test_mpc_addr),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+
+				assert_noop!(
+					SygmaBridge::pause_bridge(Some(BOB).into(), DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_noop!(
+					SygmaBridge::unpause_bridge(Some(BOB).into(), DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+
+				// Grant ALICE the access of `set_mpc_address`
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"set_mpc_address".to_vec(),
+					ALICE
+				));
+				// Grant BOB the access of `pause_bridge` and `unpause_bridge`
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"pause_bridge".to_vec(),
+					BOB
+				));
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"unpause_bridge".to_vec(),
+					BOB
+				));
+
+				// BOB set mpc address should still failed
+				assert_noop!(
+					SygmaBridge::set_mpc_address(Some(BOB).into(), test_mpc_addr),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				// ALICE set mpc address should work
+				assert_ok!(SygmaBridge::set_mpc_address(Some(ALICE).into(), test_mpc_addr));
+				// register domain
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				// ALICE pause&unpause bridge should still failed
+				assert_noop!(
+					SygmaBridge::pause_bridge(Some(ALICE).into(), DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_noop!(
+					SygmaBridge::unpause_bridge(Some(ALICE).into(), DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				// BOB pause&unpause bridge should work
+				assert_ok!(SygmaBridge::pause_bridge(Some(BOB).into(), DEST_DOMAIN_ID));
+				assert_ok!(SygmaBridge::unpause_bridge(Some(BOB).into(), DEST_DOMAIN_ID));
+			})
+		}
+
+		#[test]
+		fn multi_domain_test() {
+			new_test_ext().execute_with(|| {
+				// root register domainID 1 with chainID 0, should be ok
+				assert_ok!(SygmaBridge::register_domain(Origin::root(), 1u8, U256::from(0)));
+
+				// set mpc address
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+
+				// alice register domainID 1 with chainID 1, should raise error AccessDenied
+				assert_noop!(
+					SygmaBridge::register_domain(Origin::from(Some(ALICE)), 1u8, U256::from(1)),
+					Error::<Runtime>::AccessDenied
+				);
+				// Grant ALICE the access of `register_domain`
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"register_domain".to_vec(),
+					ALICE
+				));
+				// alice register domainID 1 with chainID 1, should be ok
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::from(Some(ALICE)),
+					1u8,
+					U256::from(1)
+				));
+				// should emit RegisterDestDomain event
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::RegisterDestDomain {
+						sender: ALICE,
+						domain_id: 1,
+						chain_id: U256::from(1),
+					},
+				)]);
+				// storage check
+				assert!(DestDomainIds::<Runtime>::get(1u8));
+				assert_eq!(DestChainIds::<Runtime>::get(1u8).unwrap(), U256::from(1));
+
+				// alice unregister domainID 1 with chainID 0, should raise error AccessDenied
+				assert_noop!(
+					SygmaBridge::unregister_domain(Origin::from(Some(ALICE)), 1u8, U256::from(0)),
+					Error::<Runtime>::AccessDenied
+				);
+				// Grant ALICE the access of `unregister_domain`
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"unregister_domain".to_vec(),
+					ALICE
+				));
+				// alice unregister domainID 1 with chainID 2, should raise error
+				// DestChainIDNotMatch
+				assert_noop!(
+					SygmaBridge::unregister_domain(Origin::from(Some(ALICE)), 1u8, U256::from(2)),
+					Error::<Runtime>::DestChainIDNotMatch
+				);
+				// alice unregister domainID 2 with chainID 2, should raise error
+				// DestDomainNotSupported
+				assert_noop!(
+					SygmaBridge::unregister_domain(Origin::from(Some(ALICE)), 2u8, U256::from(2)),
+					Error::<Runtime>::DestDomainNotSupported
+				);
+				// alice unregister domainID 1 with chainID 1, should success
+				assert_ok!(SygmaBridge::unregister_domain(
+					Origin::from(Some(ALICE)),
+					1u8,
+					U256::from(1)
+				));
+				// should emit UnregisterDestDomain event
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::UnregisterDestDomain {
+						sender: ALICE,
+						domain_id: 1,
+						chain_id: U256::from(1),
+					},
+				)]);
+
+				// storage check
+				// DomainID 1 should not support anymore
+				assert!(!DestDomainIds::<Runtime>::get(1u8));
+				// corresponding chainID should be None since kv not exist anymore
+				assert!(DestChainIds::<Runtime>::get(1u8).is_none());
+			})
+		}
+
+		#[test]
+		fn deposit_with_decimal_converter() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+
+				// native asset with 12 decimal
+				let fee_native_asset = 1_000_000_000_000u128; // 1.0 native asset
+				let amount_native_asset = 123_456_789_123_456u128; // 123.456_789_123_456
+				let adjusted_amount_native_asset = 122_456_789_123_456_000_000u128; // amount_native_asset - fee_native_asset then adjust it to 18 decimals
+
+				// usdt asset with 18 decimal
+				let fee_usdt_asset = 1_000_000_000_000_000_000u128; // 1.0 usdt asset
+				let amount_usdt_asset = 123_456_789_123_456_789_123u128; // 123.456_789_123_456_789_123
+				let adjusted_amount_usdt_asset = 122_456_789_123_456_789_123u128; // amount_usdt_asset - fee_usdt_asset then adjust it to 18 decimals
+
+				// astr asset with 24 decimal
+				let fee_astr_asset = 1_000_000_000_000_000_000_000_000u128; // 1.0 astr asset
+				let amount_astr_asset = 123_456_789_123_456_789_123_456_789u128; // 123.456_789_123_456_789_123_456_789
+				let adjusted_amount_astr_asset = 122_456_789_123_456_789_123u128; // amount_astr_asset - fee_astr_asset then adjust it to 18 decimals
+
+				// set fees
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee_native_asset
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(UsdtLocation::get().into()),
+					fee_usdt_asset
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(AstrLocation::get().into()),
+					fee_astr_asset
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(UsdtLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(AstrLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+
+				// deposit native asset which has 12 decimal
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(
+						(Concrete(NativeLocation::get()), Fungible(amount_native_asset)).into()
+					),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check balances
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount_native_asset);
+				// native asset should be reserved so that BridgeAccount should hold it
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					amount_native_asset - fee_native_asset
+				);
+				// TreasuryAccount is collecting the bridging fee
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee_native_asset);
+				// Check event
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						deposit_nonce: 0,
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							adjusted_amount_native_asset,
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NativeResourceId::get(),
+						fee_amount: fee_native_asset,
+						fee_asset_id: NativeLocation::get().into(),
+					}),
+				]);
+
+				// deposit usdt asset which has 18 decimal
+				// Register foreign asset (usdt) with asset id 0
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+
+				// Mint some usdt to ALICE for test
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					ALICE,
+					ENDOWED_BALANCE,
+				)); // make sure Alice owns enough funds here
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), ENDOWED_BALANCE);
+
+				// deposit
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(UsdtLocation::get()), Fungible(amount_usdt_asset)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check balances
+				assert_eq!(
+					Assets::balance(UsdtAssetId::get(), &ALICE),
+					ENDOWED_BALANCE - amount_usdt_asset
+				);
+				assert_eq!(
+					Assets::balance(
+						UsdtAssetId::get(),
+						AccountId::new(
+							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
+								.unwrap()
+						),
+					),
+					122_456_789_123_456_789_123
+				);
+				// TreasuryAccount is collecting the bridging fee
+				assert_eq!(
+					Assets::balance(UsdtAssetId::get(), TreasuryAccount::get()),
+					fee_usdt_asset
+				);
+
+				// Check event
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: UsdtResourceId::get(),
+						deposit_nonce: 1,
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							adjusted_amount_usdt_asset,
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: UsdtResourceId::get(),
+						fee_amount: fee_usdt_asset,
+						fee_asset_id: UsdtLocation::get().into(),
+					}),
+				]);
+
+				// deposit astr asset which has 24 decimal
+				// Register foreign asset (astr) with asset id 1
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(AstrAssetId::get(), ASSET_OWNER, true, 1,));
+
+				// Mint some astr to ALICE for test
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(1),
+					ALICE,
+					ENDOWED_BALANCE,
+				)); // make sure Alice owns enough funds here
+				assert_eq!(Assets::balance(AstrAssetId::get(), &ALICE), ENDOWED_BALANCE);
+
+				// deposit
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(AstrLocation::get()), Fungible(amount_astr_asset)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check balances
+				assert_eq!(
+					Assets::balance(AstrAssetId::get(), &ALICE),
+					ENDOWED_BALANCE - amount_astr_asset
+				);
+				// astr asset should be reserved so that BridgeAccount should hold it(Astr is not
+				// defined in ConcrateSygmaAsset)
+				assert_eq!(
+					Assets::balance(
+						AstrAssetId::get(),
+						AccountId::new(
+							SygmaBridge::get_token_reserved_account(&AstrLocation::get().into())
+								.unwrap()
+						),
+					),
+					amount_astr_asset - fee_astr_asset
+				);
+				// TreasuryAccount is collecting the bridging fee
+				assert_eq!(
+					Assets::balance(AstrAssetId::get(), TreasuryAccount::get()),
+					fee_astr_asset
+				);
+
+				// Check event
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: AstrResourceId::get(),
+						deposit_nonce: 2,
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							adjusted_amount_astr_asset,
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: AstrResourceId::get(),
+						fee_amount: fee_astr_asset,
+						fee_asset_id: AstrLocation::get().into(),
+					}),
+				]);
+
+				// deposit astr asset which has 24 decimal, extreme small amount edge case
+				let amount_astr_asset_extreme_small_amount = 100_000; // 0.000000000000000000100000 astr
+				let fee_astr_asset_extreme_small_amount = 1;
+				// 0.000000000000000000000001 astr
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(AstrLocation::get().into()),
+					fee_astr_asset_extreme_small_amount
+				));
+				// after decimal conversion from 24 to 18, the final amount will be 0 so that
+				// decimal conversion will raise error deposit should not work
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new(
+							(
+								Concrete(AstrLocation::get()),
+								Fungible(amount_astr_asset_extreme_small_amount)
+							)
+								.into()
+						),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					),
+					bridge::Error::<Runtime>::DecimalConversionFail
+				);
+			})
+		}
+
+		#[test]
+		fn proposal_execution_with_decimal_converter() {
+			new_test_ext().execute_with(|| {
+				// generate mpc keypair
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				// set mpc address
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				// register domain
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				let fee = 1_000_000_000_000u128; // 1 token in 12 decimals
+				let init_deposit = 10_000_000_000_000u128;
+				// 12 token in 12 decimal
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				// deposit in advance to make sure the native asset has enough funds in
+				// TransferReserveAccount by doing this, Alice will deposit (half of her native
+				// asset - fee) into TransferReserveAccount
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(
+						(Concrete(NativeLocation::get()), Fungible(ENDOWED_BALANCE / 2)).into()
+					),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// BridgeAccount should have half of alice native asset - fee
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					ENDOWED_BALANCE / 2 - fee
+				);
+				// TreasuryAccount is collecting the bridging fee
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
+
+				let bridge_amount = 100_000_000_000_000_000_000; // 100 native with 18 decimals
+
+				// proposal for bridging native asset to alice(native asset is 12 decimal)
+				let p_native = Proposal {
+					origin_domain_id: 1,
+					resource_id: NativeResourceId::get(),
+					deposit_nonce: 1,
+					data: SygmaBridge::create_deposit_data(
+						bridge_amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: ALICE.into() }))
+							.encode(),
+					),
+				};
+				let proposals = vec![p_native];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				let signature = pair.sign_prehashed(&final_message);
+
+				// check Alice balance of native asset before executing, should have half of the
+				// init native asset
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE / 2);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					signature.encode()
+				));
+				// check Alice balance of native asset after executing, should have half of the init
+				// native asset + 100_000_000_000_000(12 decimal)
+				assert_eq!(
+					Balances::free_balance(ALICE),
+					ENDOWED_BALANCE / 2 + 100_000_000_000_000
+				);
+
+				// proposal for bridging usdt asset to alice(usdt asset is 18 decimal)
+				// Register foreign asset (usdt) with asset id 0
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+
+				// Mint some USDT to liquidity holder for test
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					AccountId::new(
+						SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
+							.unwrap()
+					),
+					ENDOWED_BALANCE,
+				));
+				assert_eq!(
+					Assets::balance(
+						UsdtAssetId::get(),
+						AccountId::new(
+							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
+								.unwrap()
+						),
+					),
+					ENDOWED_BALANCE
+				);
+
+				let p_usdt = Proposal {
+					origin_domain_id: 1,
+					deposit_nonce: 2,
+					resource_id: UsdtResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						bridge_amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: ALICE.into() }))
+							.encode(),
+					),
+				};
+				let proposals_usdt = vec![p_usdt];
+				let final_message_usdt =
+					SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals_usdt);
+				let signature_usdt = pair.sign_prehashed(&final_message_usdt);
+
+				// alice does not have any usdt at this moment
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), 0);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals_usdt,
+					signature_usdt.encode()
+				));
+				// alice should have 100 usdt at this moment (100 usdt with 18 decimals)
+				assert_eq!(
+					Assets::balance(UsdtAssetId::get(), &ALICE),
+					100_000_000_000_000_000_000
+				);
+
+				// proposal for bridging astr asset to alice(astr asset is 24 decimal)
+				// Register foreign asset (astr) with asset id 1
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(AstrAssetId::get(), ASSET_OWNER, true, 1,));
+				// Mint some astr to BridgeAccount for test because astr is reserved asset for
+				// testing
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(1),
+					AccountId::new(
+						SygmaBridge::get_token_reserved_account(&AstrLocation::get().into())
+							.unwrap()
+					),
+					ENDOWED_BALANCE
+				));
+				assert_eq!(
+					Assets::balance(
+						AstrAssetId::get(),
+						AccountId::new(
+							SygmaBridge::get_token_reserved_account(&AstrLocation::get().into())
+								.unwrap()
+						),
+					),
+					ENDOWED_BALANCE
+				);
+
+				let p_astr = Proposal {
+					origin_domain_id: 1,
+					deposit_nonce: 3,
+					resource_id: AstrResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						bridge_amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: ALICE.into() }))
+							.encode(),
+					),
+				};
+				let proposals_astr = vec![p_astr];
+				let final_message_astr =
+					SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals_astr);
+				let signature_astr = pair.sign_prehashed(&final_message_astr);
+
+				// alice does not have any astr at this moment
+				assert_eq!(Assets::balance(AstrAssetId::get(), &ALICE), 0);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals_astr,
+					signature_astr.encode()
+				));
+				// alice should have 100 astr at this moment (100 astr with 24 decimals)
+				assert_eq!(
+					Assets::balance(AstrAssetId::get(), &ALICE),
+					100_000_000_000_000_000_000_000_000
+				);
+
+				// extreme small amount edge case
+				let extreme_small_bridge_amount = 100_000; // 0.000000000000100000 native asset with 18 decimals
+										   // proposal for bridging native asset to alice(native asset is 12 decimal)
+				let p_native_extreme = Proposal {
+					origin_domain_id: 1,
+					resource_id: NativeResourceId::get(),
+					deposit_nonce: 4,
+					data: SygmaBridge::create_deposit_data(
+						extreme_small_bridge_amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: ALICE.into() }))
+							.encode(),
+					),
+				};
+				let proposals_extreme = vec![p_native_extreme];
+				let final_message_extreme =
+					SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals_extreme);
+				let signature_extreme = pair.sign_prehashed(&final_message_extreme);
+
+				// execute_proposal extrinsic should work but it will actually failed at decimal
+				// conversion step because 0.000000000000100000 in 18 decimal converts to 12 decimal
+				// would be 0.000000000000 which is 0
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals_extreme,
+					signature_extreme.encode()
+				));
+				// should emit FailedHandlerExecution event
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::FailedHandlerExecution {
+						error: vec![
+							68, 101, 99, 105, 109, 97, 108, 67, 111, 110, 118, 101, 114, 115, 105,
+							111, 110, 70, 97, 105, 108,
+						],
+						origin_domain_id: 1,
+						deposit_nonce: 4,
+					},
+				)]);
+			})
+		}
+
+		#[test]
+		fn unpause_all_domains_test() {
+			new_test_ext().execute_with(|| {
+				// Grant ALICE the access of `register_domain`
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"register_domain".to_vec(),
+					ALICE
+				));
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"pause_bridge".to_vec(),
+					ALICE
+				));
+				// alice register some domains
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::from(Some(ALICE)),
+					1u8,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::from(Some(ALICE)),
+					2u8,
+					U256::from(2)
+				));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::from(Some(ALICE)),
+					3u8,
+					U256::from(3)
+				));
+
+				// pause all
+				assert_ok!(SygmaBridge::pause_bridge(Some(ALICE).into(), 1));
+				assert_ok!(SygmaBridge::pause_bridge(Some(ALICE).into(), 2));
+				assert_ok!(SygmaBridge::pause_bridge(Some(ALICE).into(), 3));
+
+				// double check if they are all paused
+				assert!(SygmaBridge::is_domain_paused(1));
+				assert!(SygmaBridge::is_domain_paused(2));
+				assert!(SygmaBridge::is_domain_paused(3));
+
+				SygmaBridge::unpause_all_domains();
+
+				// all domains should be unpaused now
+				assert!(!SygmaBridge::is_domain_paused(1));
+				assert!(!SygmaBridge::is_domain_paused(2));
+				assert!(!SygmaBridge::is_domain_paused(3));
+			})
+		}
+
+		#[test]
+		fn setup_order_test() {
+			new_test_ext().execute_with(|| {
+				// Make sure mpc address is not set
+				let default_addr: MpcAddress = MpcAddress::default();
+				assert_eq!(MpcAddr::<Runtime>::get(), default_addr);
+
+				// Grant ALICE the access admin extrinsics
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"register_domain".to_vec(),
+					ALICE
+				));
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"unregister_domain".to_vec(),
+					ALICE
+				));
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"pause_bridge".to_vec(),
+					ALICE
+				));
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"unpause_bridge".to_vec(),
+					ALICE
+				));
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"retry".to_vec(),
+					ALICE
+				));
+
+				// alice setup bridges without mpc address setup
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::from(Some(ALICE)),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::unregister_domain(
+					Origin::from(Some(ALICE)),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				// register it back
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::from(Some(ALICE)),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::pause_bridge(Origin::from(Some(ALICE)), 1u8));
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::from(Some(ALICE)), 1u8));
+				// pause domain 2 again to see if mpc address setup will unpause it
+				assert_ok!(SygmaBridge::pause_bridge(Origin::from(Some(ALICE)), 1u8));
+
+				// double check if it's paused
+				assert!(SygmaBridge::is_domain_paused(1));
+
+				// retry should not work here, should raise MissingMpcAddress
+				assert_noop!(
+					SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::MissingMpcAddress
+				);
+				// deposit should not work, should raise MissingMpcAddress
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(AstrLocation::get()), Fungible(100)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					),
+					bridge::Error::<Runtime>::MissingMpcAddress
+				);
+				// proposal execution should not work either, should raise MissingMpcAddress
+				assert_noop!(
+					SygmaBridge::execute_proposal(Origin::signed(ALICE), vec![], vec![]),
+					bridge::Error::<Runtime>::MissingMpcAddress,
+				);
+
+				// set mpc address to generated keypair's address
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr);
+
+				// double check if it's unpause now
+				assert!(!SygmaBridge::is_domain_paused(1));
+
+				// retry again, should work
+				assert_ok!(SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Retry {
+					deposit_on_block_height: 1234567u128,
+					dest_domain_id: DEST_DOMAIN_ID,
+					sender: ALICE,
+				})]);
+
+				// deposit should work now
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
+				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check balances
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					amount - fee
+				);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
+
+				// proposal execution should work
+				let valid_native_transfer_proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 1,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						amount,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
 				};
-				let proposals = vec![p1, p2];
-
+				let proposals = vec![valid_native_transfer_proposal];
 				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
-
-				// sign final message using generated mpc prikey
-				// `pari.sign` will hash the final message into blake2_256 then sign it, so use
-				// sign_prehashed here
-				let signature = pair.sign_prehashed(&final_message);
-
-				// verify signature, should be true
-				assert!(SygmaBridge::verify_by_mpc_address(final_message, signature.encode()));
+				let proposals_with_valid_signature = pair.sign_prehashed(&final_message);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					proposals_with_valid_signature.encode(),
+				));
+				// check native asset balance
+				// proposal amount is in 18 decimal 0.000200000000000000, will be convert to 12
+				// decimal 0.000200000000(200000000) because native asset is defined in 12 decimal
+				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE + 200000000);
 			})
 		}
 
 		#[test]
-		fn deposit_native_asset_should_work() {
+		fn deposit_native_asset_with_percentage_fee() {
 			new_test_ext().execute_with(|| {
 				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
 				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
-				let final_amount_in_deposit_event = 199_000_000_000_000_000_000; // 200 - 1 then adjust to 18 decimals
+
+				// test cases
+				let fee_rate_1 = 500u32; // 5%
+				let fee_rate_2 = 10000u32; // 100%
+				let fee_rate_3 = 9999u32; // 99.99%
+				let fee_rate_4 = 0u32; // 0%
+				let fee_rate_5 = 15000u32; // 150%
 
 				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					Box::new(NativeLocation::get().into()),
-					fee
+					FeeHandlerType::PercentageFeeHandler,
 				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+
+				// test 5%
+				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
+					fee_rate_1,
+					0u128,
+					1_000_000_000_000_000u128
 				));
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-
 				assert_ok!(SygmaBridge::deposit(
 					Origin::signed(ALICE),
 					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
@@ -1297,17 +5691,20 @@ pub mod pallet {
 						)
 					}),
 				));
-				// Check balances
+				// Check balances of Alice after deposit 200 native token
 				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
+				// Check reserved native token
 				assert_eq!(
 					Balances::free_balance(AccountId::new(
 						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
 							.unwrap()
 					)),
-					amount - fee
+					190_000_000_000_000u128
 				);
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
+				// Check fee collected
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 10_000_000_000_000u128);
 				// Check event
+				let final_amount_in_deposit_event_1 = 190_000_000_000_000_000_000; // 200 cut 5% then adjust to 18 decimals
 				assert_events(vec![
 					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
 						dest_domain_id: DEST_DOMAIN_ID,
@@ -1316,7 +5713,7 @@ pub mod pallet {
 						sender: ALICE,
 						transfer_type: TransferType::FungibleTransfer,
 						deposit_data: SygmaBridge::create_deposit_data(
-							final_amount_in_deposit_event,
+							final_amount_in_deposit_event_1,
 							b"ethereum recipient".to_vec(),
 						),
 						handler_response: vec![],
@@ -1325,52 +5722,262 @@ pub mod pallet {
 						fee_payer: ALICE,
 						dest_domain_id: DEST_DOMAIN_ID,
 						resource_id: NativeResourceId::get(),
-						fee_amount: fee,
+						fee_amount: 10_000_000_000_000u128,
 						fee_asset_id: NativeLocation::get().into(),
 					}),
 				]);
+
+				// test 100%
+				// should not work because 100% is out of fee rate
+				assert_noop!(
+					SygmaPercentageFeeHandler::set_fee_rate(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(NativeLocation::get().into()),
+						fee_rate_2,
+						0u128,
+						1_000_000_000_000_000u128
+					),
+					sygma_percentage_feehandler::Error::<Runtime>::FeeRateOutOfRange
+				);
+
+				// test 99.99%
+				// override 5% to 99.99%
+				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee_rate_3,
+					0u128,
+					1_000_000_000_000_000u128
+				));
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check reserved native token, should increase by 0.02 to 190.020000000000
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					190_020_000_000_000u128
+				);
+				// Check fee collected, should increase by 199.98 to 209.980000000000
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 209_980_000_000_000u128);
+
+				// test 0%
+				// override 99.99% to 0%
+				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee_rate_4,
+					0u128,
+					1_000_000_000_000_000u128
+				));
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check reserved native token, should increase by 200 to 390.020000000000
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					390_020_000_000_000u128
+				);
+				// Check fee collected, should increase by 0 to 209.980000000000
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 209_980_000_000_000u128);
+
+				// test 150%
+				// should not work because 150% is out of fee rate
+				assert_noop!(
+					SygmaPercentageFeeHandler::set_fee_rate(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(NativeLocation::get().into()),
+						fee_rate_5,
+						0u128,
+						1_000_000_000_000_000u128
+					),
+					sygma_percentage_feehandler::Error::<Runtime>::FeeRateOutOfRange
+				);
+
+				// Check reserved native token, should remain as 390.020000000000
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					390_020_000_000_000u128
+				);
+				// Check fee collected, should remain as 209.980000000000
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 209_980_000_000_000u128);
+
+				// test fee bound: fee rate 5%
+				let fee_lower_bound = 100_000_000_000_000u128; // 100
+				let fee_upper_bound = 1_000_000_000_000_000u128;
+				// 1000
+				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee_rate_1,
+					fee_lower_bound,
+					fee_upper_bound
+				));
+
+				// with higher fee lower bound
+				// 5% fee of 200 token should be 10 but fee lower bound is 100, so fee is 100 now
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check reserved native token, should increase by 100 to 490.020000000000
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					490_020_000_000_000u128
+				);
+				// Check fee collected, should increase by 100 to 309.980000000000
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 309_980_000_000_000u128);
+
+				// with lower fee upper bound
+				// 5% fee of 200000 token should be 10000 but fee upper bound is 1000, so fee is
+				// 1000 now
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new(
+						(Concrete(NativeLocation::get()), Fungible(200_000_000_000_000_000)).into()
+					),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
+				// Check reserved native token, should increase by 199000 to 199490.020000000000
+				assert_eq!(
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					199_490_020_000_000_000u128
+				);
+				// Check fee collected, should increase by 1000 to 1309.980000000000
+				assert_eq!(
+					Balances::free_balance(TreasuryAccount::get()),
+					1_309_980_000_000_000u128
+				);
+			})
+		}
+
+		#[test]
+		fn percentage_fee_rate_not_set_for_domain_and_asset() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+
+				// only set fee handler but not set fee rate for domain and asset
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::PercentageFeeHandler,
+				));
+
+				// deposit should not go through because fee rate is not set in storage, so when
+				// get_fee, it returns None
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(MultiLocation {
+							parents: 0,
+							interior: X2(
+								slice_to_generalkey(b"ethereum recipient"),
+								slice_to_generalkey(&[1]),
+							)
+						}),
+					),
+					bridge::Error::<Runtime>::MissingFeeConfig
+				);
 			})
 		}
 
 		#[test]
-		fn transfer_wrap_deposit_should_work() {
+		fn deposit_native_asset_with_percentage_fee_override_basic_fee_handler() {
 			new_test_ext().execute_with(|| {
 				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
 				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
-				let final_amount_in_deposit_event = 199_000_000_000_000_000_000; // 200 - 1 then adjust to 18 decimals
+				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
 
 				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+
+				// set fee handler with basic fee handler and fixed fee
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					Box::new(NativeLocation::get().into()),
-					fee
+					FeeHandlerType::BasicFeeHandler,
 				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
+					fee
 				));
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-
-				let asset: MultiAsset = (Concrete(NativeLocation::get()), Fungible(amount)).into();
-				let dest: MultiLocation = MultiLocation {
-					parents: 0,
-					interior: X2(
-						slice_to_generalkey(b"ethereum recipient"),
-						slice_to_generalkey(&[1]),
-					),
-				};
-
-				// Call transfer instead of deposit
-				assert_ok!(SygmaBridge::transfer(ALICE.into(), asset.clone(), dest, None));
 
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(MultiLocation {
+						parents: 0,
+						interior: X2(
+							slice_to_generalkey(b"ethereum recipient"),
+							slice_to_generalkey(&[1]),
+						)
+					}),
+				));
 				// Check balances
 				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
 				assert_eq!(
@@ -1381,115 +5988,26 @@ pub mod pallet {
 					amount - fee
 				);
 				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
-				// Check event
-				assert_events(vec![
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: NativeResourceId::get(),
-						deposit_nonce: 0,
-						sender: ALICE,
-						transfer_type: TransferType::FungibleTransfer,
-						deposit_data: SygmaBridge::create_deposit_data(
-							final_amount_in_deposit_event,
-							b"ethereum recipient".to_vec(),
-						),
-						handler_response: vec![],
-					}),
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
-						fee_payer: ALICE,
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: NativeResourceId::get(),
-						fee_amount: fee,
-						fee_asset_id: NativeLocation::get().into(),
-					}),
-				]);
-			})
-		}
-
-		#[test]
-		fn hex_zero_padding_32_test() {
-			new_test_ext().execute_with(|| {
-				assert_eq!(
-					SygmaBridge::hex_zero_padding_32(100).to_vec(),
-					vec![
-						0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-						0, 0, 0, 0, 0, 0, 100,
-					]
-				);
-				let recipient = String::from("0x95ECF5ae000e0fe0e0dE63aDE9b7D82a372038b4");
-				assert_eq!(
-					SygmaBridge::hex_zero_padding_32(recipient.len() as u128).to_vec(),
-					vec![
-						0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-						0, 0, 0, 0, 0, 0, 42,
-					]
-				);
-			})
-		}
-
-		#[test]
-		fn create_deposit_data_test() {
-			new_test_ext().execute_with(|| {
-				let recipient = b"0x95ECF5ae000e0fe0e0dE63aDE9b7D82a372038b4".to_vec();
-				let data = SygmaBridge::create_deposit_data(100, recipient);
-				// 32 + 32 + 42
-				assert_eq!(data.len(), 106);
-				assert_eq!(
-					data.to_vec(),
-					vec![
-						0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-						0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-						0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 48, 120, 57, 53, 69, 67, 70,
-						53, 97, 101, 48, 48, 48, 101, 48, 102, 101, 48, 101, 48, 100, 69, 54, 51,
-						97, 68, 69, 57, 98, 55, 68, 56, 50, 97, 51, 55, 50, 48, 51, 56, 98, 52,
-					]
-				);
-			})
-		}
-
-		#[test]
-		fn deposit_foreign_asset_should_work() {
-			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let fee = 100u128;
-				let amount = 200u128;
 
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(UsdtLocation::get().into()),
-					fee
-				));
+				// Override Basic fee handler to Percentage fee handler with 5% fee rate
 				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(UsdtLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::PercentageFeeHandler,
 				));
-				assert_ok!(SygmaBridge::register_domain(
+				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					U256::from(1)
-				));
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-
-				// Register foreign asset (USDT) with asset id 0
-				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
-					<Runtime as frame_system::Config>::AccountId,
-				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
-
-				// Mint some USDT to ALICE for test
-				assert_ok!(Assets::mint(
-					Origin::signed(ASSET_OWNER),
-					codec::Compact(0),
-					ALICE,
-					ENDOWED_BALANCE,
+					Box::new(NativeLocation::get().into()),
+					500u32,
+					0u128,
+					1_000_000_000_000_000u128
 				));
-				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), ENDOWED_BALANCE);
 
 				assert_ok!(SygmaBridge::deposit(
 					Origin::signed(ALICE),
-					Box::new((Concrete(UsdtLocation::get()), Fungible(amount)).into()),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
 					Box::new(MultiLocation {
 						parents: 0,
 						interior: X2(
@@ -1499,261 +6017,288 @@ pub mod pallet {
 					}),
 				));
 				// Check balances
-				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), ENDOWED_BALANCE - amount);
-				// USDT in the mock runtime has been configured as the reserved token, so the corresponding account should hold the deposit balance
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount * 2);
+				// Check reserved native token, should increase by 190
 				assert_eq!(
-					Assets::balance(
-						UsdtAssetId::get(),
-						AccountId::new(
-							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
-								.unwrap()
-						),
-					),
-					amount - fee
+					Balances::free_balance(AccountId::new(
+						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
+							.unwrap()
+					)),
+					amount - fee + 190_000_000_000_000u128
+				);
+				// Check fee collected, should increase by 10
+				assert_eq!(
+					Balances::free_balance(TreasuryAccount::get()),
+					fee + 10_000_000_000_000u128
 				);
-				assert_eq!(Assets::balance(UsdtAssetId::get(), TreasuryAccount::get()), fee);
-				// Check event
-				assert_events(vec![
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: UsdtResourceId::get(),
-						deposit_nonce: 0,
-						sender: ALICE,
-						transfer_type: TransferType::FungibleTransfer,
-						deposit_data: SygmaBridge::create_deposit_data(
-							amount - fee,
-							b"ethereum recipient".to_vec(),
-						),
-						handler_response: vec![],
-					}),
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
-						fee_payer: ALICE,
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: UsdtResourceId::get(),
-						fee_amount: fee,
-						fee_asset_id: UsdtLocation::get().into(),
-					}),
-				]);
 			})
 		}
 
 		#[test]
-		fn deposit_unbounded_asset_should_fail() {
+		fn pause_all_bridges_test() {
 			new_test_ext().execute_with(|| {
-				let unbounded_asset_location = MultiLocation::new(1, X1(GeneralIndex(123)));
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let fee = 100u128;
-				let amount = 200u128;
+				let domain_1: DomainID = 1;
+				let domain_2: DomainID = 2;
+				let domain_3: DomainID = 3;
 
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(unbounded_asset_location.into()),
-					fee
-				));
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					U256::from(1)
-				));
+				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_1, U256::from(1)));
+				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_2, U256::from(2)));
+				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_3, U256::from(3)));
 
+				// all registered domains should be unpaused now
+				assert!(!SygmaBridge::is_domain_paused(domain_1));
+				assert!(!SygmaBridge::is_domain_paused(domain_2));
+				assert!(!SygmaBridge::is_domain_paused(domain_3));
+
+				// permission test: unauthorized account should not be able to pause bridge
+				let unauthorized_account = Origin::from(Some(ALICE));
 				assert_noop!(
-					SygmaBridge::deposit(
-						Origin::signed(ALICE),
-						Box::new((Concrete(unbounded_asset_location), Fungible(amount)).into()),
-						Box::new(MultiLocation {
-							parents: 0,
-							interior: X2(
-								slice_to_generalkey(b"ethereum recipient"),
-								slice_to_generalkey(&[1]),
-							)
-						}),
-					),
-					bridge::Error::<Runtime>::AssetNotBound
+					SygmaBridge::pause_all_bridges(unauthorized_account),
+					bridge::Error::<Runtime>::AccessDenied
 				);
+				// Grant ALICE the access
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"pause_all_bridges".to_vec(),
+					ALICE
+				));
+
+				assert_ok!(SygmaBridge::pause_all_bridges(Origin::signed(ALICE)));
+
+				// all registered domains should be paused now
+				assert!(SygmaBridge::is_domain_paused(domain_1));
+				assert!(SygmaBridge::is_domain_paused(domain_2));
+				assert!(SygmaBridge::is_domain_paused(domain_3));
+
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::AllBridgePaused {
+					sender: ALICE,
+				})]);
 			})
 		}
 
 		#[test]
-		fn deposit_to_unrecognized_dest_should_fail() {
+		fn unpause_all_bridges_test() {
 			new_test_ext().execute_with(|| {
-				let invalid_dest = MultiLocation::new(
-					0,
-					X2(GeneralIndex(0), slice_to_generalkey(b"ethereum recipient")),
-				);
 				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let fee = 100u128;
-				let amount = 200u128;
+
+				let domain_1: DomainID = 1;
+				let domain_2: DomainID = 2;
+				let domain_3: DomainID = 3;
+
+				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_1, U256::from(1)));
+				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_2, U256::from(2)));
+				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_3, U256::from(3)));
+
+				// mpc address not setup, should be error
+				assert_noop!(
+					SygmaBridge::unpause_all_bridges(Origin::root()),
+					bridge::Error::<Runtime>::MissingMpcAddress
+				);
 
 				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee
-				));
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					U256::from(1)
-				));
 
+				// permission test: unauthorized account should not be able to pause bridge
+				let unauthorized_account = Origin::from(Some(ALICE));
 				assert_noop!(
-					SygmaBridge::deposit(
-						Origin::signed(ALICE),
-						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-						Box::new(invalid_dest),
-					),
-					bridge::Error::<Runtime>::ExtractDestDataFailed
+					SygmaBridge::unpause_all_bridges(unauthorized_account),
+					bridge::Error::<Runtime>::AccessDenied
 				);
+
+				// Grant ALICE the access
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"unpause_all_bridges".to_vec(),
+					ALICE
+				));
+
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), domain_1));
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), domain_2));
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), domain_3));
+
+				// all registered domains should be paused now
+				assert!(SygmaBridge::is_domain_paused(domain_1));
+				assert!(SygmaBridge::is_domain_paused(domain_2));
+				assert!(SygmaBridge::is_domain_paused(domain_3));
+
+				assert_ok!(SygmaBridge::unpause_all_bridges(Origin::signed(ALICE)));
+
+				// all registered domains should be unpaused now
+				assert!(!SygmaBridge::is_domain_paused(domain_1));
+				assert!(!SygmaBridge::is_domain_paused(domain_2));
+				assert!(!SygmaBridge::is_domain_paused(domain_3));
+
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::AllBridgeUnpaused { sender: ALICE },
+				)]);
 			})
 		}
 
 		#[test]
-		fn deposit_without_fee_set_should_fail() {
+		fn deposit_nonce_fix_should_work() {
 			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let amount = 200u128;
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					U256::from(1)
-				));
-				assert_noop!(
-					SygmaBridge::deposit(
-						Origin::signed(ALICE),
-						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-						Box::new(MultiLocation {
-							parents: 0,
-							interior: X2(
-								slice_to_generalkey(b"ethereum recipient"),
-								slice_to_generalkey(&[1]),
-							)
-						}),
-					),
-					bridge::Error::<Runtime>::MissingFeeConfig
-				);
+				// Nonce from source chain start from 1, set first batch of nonce under [1, 63]
+				for nonce in 1..64u64 {
+					SygmaBridge::set_proposal_executed(nonce, 0);
+				}
+				// Nonce 0 should not be set
+				assert!(!SygmaBridge::is_proposal_executed(0, 0));
+				// Nonce 1 should be set
+				assert!(SygmaBridge::is_proposal_executed(1, 0));
+				// Nonce 63 should be set
+				assert!(SygmaBridge::is_proposal_executed(63, 0));
+
+				// set second batch of nonce under [64, 127]
+				for nonce in 64..128u64 {
+					SygmaBridge::set_proposal_executed(nonce, 0);
+				}
+				// Nonce 64 should be set
+				assert!(SygmaBridge::is_proposal_executed(64, 0));
+				// Nonce 127 should be set
+				assert!(SygmaBridge::is_proposal_executed(127, 0));
+				// Nonce 128 should not be set
+				assert!(!SygmaBridge::is_proposal_executed(128, 0));
+
+				// set future batch of nonce under [256, 300]
+				for nonce in 256..301u64 {
+					SygmaBridge::set_proposal_executed(nonce, 0);
+				}
+				// Nonce 256 should be set
+				assert!(SygmaBridge::is_proposal_executed(256, 0));
+				// Nonce 300 should be set
+				assert!(SygmaBridge::is_proposal_executed(300, 0));
+				// Nonce 301 should not be set
+				assert!(!SygmaBridge::is_proposal_executed(301, 0));
 			})
 		}
 
 		#[test]
-		fn deposit_less_than_fee_should_fail() {
+		fn register_resource_should_work() {
 			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let fee = 200u128;
-				let amount = 100u128;
+				let new_asset: AssetId = MultiLocation::new(1, X1(GeneralIndex(123))).into();
+				let new_resource_id: ResourceId = [7u8; 32];
 
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee
-				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
-				));
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					U256::from(1)
-				));
+				// permission test: unauthorized account should not be able to register resource
 				assert_noop!(
-					SygmaBridge::deposit(
-						Origin::signed(ALICE),
-						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-						Box::new(MultiLocation {
-							parents: 0,
-							interior: X2(
-								slice_to_generalkey(b"ethereum recipient"),
-								slice_to_generalkey(&[1]),
-							)
-						}),
+					SygmaBridge::register_resource(
+						Some(ALICE).into(),
+						new_resource_id,
+						Box::new(new_asset)
 					),
-					bridge::Error::<Runtime>::FeeTooExpensive
+					bridge::Error::<Runtime>::AccessDenied
 				);
-			})
-		}
 
-		#[test]
-		fn deposit_when_bridge_paused_should_fail() {
-			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let fee = 100u128;
-				let amount = 200u128;
+				// resourceID or asset already bound should fail
+				assert_noop!(
+					SygmaBridge::register_resource(
+						Origin::root(),
+						UsdtResourceId::get(),
+						Box::new(new_asset)
+					),
+					bridge::Error::<Runtime>::ResourceAlreadyRegistered
+				);
+				assert_noop!(
+					SygmaBridge::register_resource(
+						Origin::root(),
+						new_resource_id,
+						Box::new(UsdtLocation::get().into())
+					),
+					bridge::Error::<Runtime>::AssetAlreadyRegistered
+				);
 
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee
-				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+				assert_eq!(SygmaBridge::extract_asset(&(new_asset, 100u128).into()), None);
+				assert_ok!(SygmaBridge::register_resource(
 					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
+					new_resource_id,
+					Box::new(new_asset)
 				));
-				// register domain
-				assert_ok!(SygmaBridge::register_domain(
+				assert_eq!(AssetResourceIds::<Runtime>::get(new_asset), Some(new_resource_id));
+				assert_eq!(ResourceAssetIds::<Runtime>::get(new_resource_id), Some(new_asset));
+				assert_eq!(
+					SygmaBridge::extract_asset(&(new_asset, 100u128).into()),
+					Some((new_resource_id, 100u128, TransferType::FungibleTransfer))
+				);
+				assert_eq!(SygmaBridge::rid_to_assetid(&new_resource_id), Some(new_asset));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::ResourceRegistered {
+						sender: [0u8; 32].into(),
+						resource_id: new_resource_id,
+						asset_id: new_asset,
+					},
+				)]);
+
+				// Grant ALICE the access of `register_resource`
+				assert_ok!(AccessSegregator::grant_access(
 					Origin::root(),
-					DEST_DOMAIN_ID,
-					U256::from(1)
+					BridgePalletIndex::get(),
+					b"register_resource".to_vec(),
+					ALICE
 				));
-				// set mpc address will also unpause all bridges
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-
-				// Pause bridge again
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				// Should failed
-				assert_noop!(
-					SygmaBridge::deposit(
-						Origin::signed(ALICE),
-						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-						Box::new(MultiLocation {
-							parents: 0,
-							interior: X2(
-								slice_to_generalkey(b"ethereum recipient"),
-								slice_to_generalkey(&[1]),
-							)
-						}),
-					),
-					bridge::Error::<Runtime>::BridgePaused
-				);
-				// Unpause bridge
-				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				// Should success
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+				let another_asset: AssetId = MultiLocation::new(1, X1(GeneralIndex(456))).into();
+				assert_ok!(SygmaBridge::register_resource(
+					Some(ALICE).into(),
+					[8u8; 32],
+					Box::new(another_asset)
 				));
+				assert_eq!(ResourceAssetIds::<Runtime>::get([8u8; 32]), Some(another_asset));
 			})
 		}
 
 		#[test]
-		fn deposit_without_mpc_set_should_fail() {
+		fn unregister_resource_should_work() {
 			new_test_ext().execute_with(|| {
-				let fee = 200u128;
-				let amount = 100u128;
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 1_000_000_000_000u128;
+				let amount = 200_000_000_000_000u128;
+
+				// permission test: unauthorized account should not be able to unregister resource
+				assert_noop!(
+					SygmaBridge::unregister_resource(Some(ALICE).into(), UsdtResourceId::get()),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				// unknown resourceID should fail
+				assert_noop!(
+					SygmaBridge::unregister_resource(Origin::root(), [7u8; 32]),
+					bridge::Error::<Runtime>::ResourceNotRegistered
+				);
+
+				assert_ok!(SygmaBridge::unregister_resource(
+					Origin::root(),
+					NativeResourceId::get()
+				));
+				assert_eq!(
+					AssetResourceIds::<Runtime>::get(AssetId::from(NativeLocation::get())),
+					None
+				);
+				assert_eq!(ResourceAssetIds::<Runtime>::get(NativeResourceId::get()), None);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::ResourceUnregistered {
+						sender: [0u8; 32].into(),
+						resource_id: NativeResourceId::get(),
+						asset_id: NativeLocation::get().into(),
+					},
+				)]);
 
+				// deposit of the unbound native asset should fail
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
 				assert_ok!(SygmaBasicFeeHandler::set_fee(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					Box::new(NativeLocation::get().into()),
 					fee
 				));
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
 				assert_noop!(
 					SygmaBridge::deposit(
 						Origin::signed(ALICE),
@@ -1766,788 +6311,1314 @@ pub mod pallet {
 							)
 						}),
 					),
-					bridge::Error::<Runtime>::MissingMpcAddress
+					bridge::Error::<Runtime>::AssetNotBound
 				);
+
+				// the resourceID can be bound again after unregistered
+				assert_ok!(SygmaBridge::register_resource(
+					Origin::root(),
+					NativeResourceId::get(),
+					Box::new(NativeLocation::get().into())
+				));
 			})
 		}
 
 		#[test]
-		fn retry_bridge() {
+		fn schedule_mpc_rotation_should_work() {
 			new_test_ext().execute_with(|| {
-				// should be access denied SINCE Alice does not have permission to retry
+				let (pair_a, _): (ecdsa::Pair, _) = Pair::generate();
+				let (pair_b, _): (ecdsa::Pair, _) = Pair::generate();
+				let mpc_addr_a: MpcAddress = MpcAddress(pair_a.public().to_eth_address().unwrap());
+				let mpc_addr_b: MpcAddress = MpcAddress(pair_b.public().to_eth_address().unwrap());
+				let final_message =
+					SygmaBridge::construct_ecdsa_signing_proposals_data(&vec![Proposal {
+						origin_domain_id: 1,
+						deposit_nonce: 1,
+						resource_id: [1u8; 32],
+						data: vec![1u8],
+					}]);
+
+				// MPC address not set yet
 				assert_noop!(
-					SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID),
+					SygmaBridge::schedule_mpc_rotation(Origin::root(), mpc_addr_b),
+					bridge::Error::<Runtime>::MissingMpcAddress
+				);
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), mpc_addr_a));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::MpcAddressChanged {
+						old_mpc_addr: MpcAddress::default(),
+						new_mpc_addr: mpc_addr_a,
+					},
+				)]);
+
+				// permission test: unauthorized account should not be able to schedule rotation
+				assert_noop!(
+					SygmaBridge::schedule_mpc_rotation(Origin::signed(ALICE), mpc_addr_b),
 					bridge::Error::<Runtime>::AccessDenied
 				);
+				// new MPC address can not be empty or same as the current one
+				assert_noop!(
+					SygmaBridge::schedule_mpc_rotation(Origin::root(), MpcAddress::default()),
+					bridge::Error::<Runtime>::InvalidMpcAddress
+				);
+				assert_noop!(
+					SygmaBridge::schedule_mpc_rotation(Origin::root(), mpc_addr_a),
+					bridge::Error::<Runtime>::InvalidMpcAddress
+				);
 
-				// Grant ALICE the access of `retry`
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"retry".to_vec(),
-					ALICE
+				assert_ok!(SygmaBridge::schedule_mpc_rotation(Origin::root(), mpc_addr_b));
+				assert_eq!(PendingMpcAddr::<Runtime>::get(), Some((mpc_addr_b, 11)));
+				assert_eq!(MpcRotationNonce::<Runtime>::get(), 1);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::MpcRotationScheduled {
+						sender: [0u8; 32].into(),
+						old_mpc_addr: mpc_addr_a,
+						new_mpc_addr: mpc_addr_b,
+						effective_at: 11,
+					},
+				)]);
+
+				// only one rotation can be scheduled at a time
+				assert_noop!(
+					SygmaBridge::schedule_mpc_rotation(Origin::root(), MpcAddress([3u8; 20])),
+					bridge::Error::<Runtime>::MpcRotationPending
+				);
+
+				// during the rotation window, both old and new MPC addresses are accepted
+				System::set_block_number(10);
+				SygmaBridge::on_initialize(10);
+				assert_eq!(MpcAddr::<Runtime>::get(), mpc_addr_a);
+				assert!(SygmaBridge::verify_by_mpc_address(
+					final_message,
+					pair_a.sign_prehashed(&final_message).encode()
+				));
+				assert!(SygmaBridge::verify_by_mpc_address(
+					final_message,
+					pair_b.sign_prehashed(&final_message).encode()
 				));
 
-				// mpc address is missing, should fail
+				// after the rotation window, new MPC address replaces the old one
+				System::set_block_number(11);
+				SygmaBridge::on_initialize(11);
+				assert_eq!(MpcAddr::<Runtime>::get(), mpc_addr_b);
+				assert_eq!(PendingMpcAddr::<Runtime>::get(), None);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::MpcAddressChanged {
+						old_mpc_addr: mpc_addr_a,
+						new_mpc_addr: mpc_addr_b,
+					},
+				)]);
+				assert!(!SygmaBridge::verify_by_mpc_address(
+					final_message,
+					pair_a.sign_prehashed(&final_message).encode()
+				));
+				assert!(SygmaBridge::verify_by_mpc_address(
+					final_message,
+					pair_b.sign_prehashed(&final_message).encode()
+				));
+			})
+		}
+
+		#[test]
+		fn schedule_mpc_rotation_signed_should_work() {
+			new_test_ext().execute_with(|| {
+				let (pair_a, _): (ecdsa::Pair, _) = Pair::generate();
+				let (pair_b, _): (ecdsa::Pair, _) = Pair::generate();
+				let mpc_addr_a: MpcAddress = MpcAddress(pair_a.public().to_eth_address().unwrap());
+				let mpc_addr_b: MpcAddress = MpcAddress(pair_b.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), mpc_addr_a));
+
+				let final_message =
+					SygmaBridge::construct_ecdsa_signing_mpc_rotation_data(mpc_addr_b, 0);
+
+				// signature of the new MPC address is not accepted
 				assert_noop!(
-					SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::MissingMpcAddress
+					SygmaBridge::schedule_mpc_rotation_signed(
+						Origin::signed(ALICE),
+						mpc_addr_b,
+						pair_b.sign_prehashed(&final_message).encode()
+					),
+					bridge::Error::<Runtime>::BadMpcSignature
+				);
+				// signature over a different nonce is not accepted
+				assert_noop!(
+					SygmaBridge::schedule_mpc_rotation_signed(
+						Origin::signed(ALICE),
+						mpc_addr_b,
+						pair_a
+							.sign_prehashed(
+								&SygmaBridge::construct_ecdsa_signing_mpc_rotation_data(
+									mpc_addr_b, 1
+								)
+							)
+							.encode()
+					),
+					bridge::Error::<Runtime>::BadMpcSignature
 				);
 
-				// set mpc address
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					U256::from(1)
+				// anyone can submit the rotation signed by the current MPC address
+				let signature = pair_a.sign_prehashed(&final_message).encode();
+				assert_ok!(SygmaBridge::schedule_mpc_rotation_signed(
+					Origin::signed(ALICE),
+					mpc_addr_b,
+					signature.clone()
 				));
+				assert_eq!(PendingMpcAddr::<Runtime>::get(), Some((mpc_addr_b, 11)));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::MpcRotationScheduled {
+						sender: ALICE,
+						old_mpc_addr: mpc_addr_a,
+						new_mpc_addr: mpc_addr_b,
+						effective_at: 11,
+					},
+				)]);
 
-				// pause bridge after set mpc address and retry, should fail
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				System::set_block_number(11);
+				SygmaBridge::on_initialize(11);
+				assert_eq!(MpcAddr::<Runtime>::get(), mpc_addr_b);
+
+				// rotate back to the old MPC address signed by the new one
+				assert_ok!(SygmaBridge::schedule_mpc_rotation_signed(
+					Origin::signed(ALICE),
+					mpc_addr_a,
+					pair_b
+						.sign_prehashed(&SygmaBridge::construct_ecdsa_signing_mpc_rotation_data(
+							mpc_addr_a, 1
+						))
+						.encode()
+				));
+				System::set_block_number(21);
+				SygmaBridge::on_initialize(21);
+				assert_eq!(MpcAddr::<Runtime>::get(), mpc_addr_a);
+
+				// the used signature can not be replayed
 				assert_noop!(
-					SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::BridgePaused
+					SygmaBridge::schedule_mpc_rotation_signed(
+						Origin::signed(ALICE),
+						mpc_addr_b,
+						signature
+					),
+					bridge::Error::<Runtime>::BadMpcSignature
 				);
+			})
+		}
 
-				// unpause bridge
-				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert!(!IsPaused::<Runtime>::get(DEST_DOMAIN_ID));
+		#[test]
+		fn schedule_mpc_rotation_signed_with_signer_set_should_work() {
+			new_test_ext().execute_with(|| {
+				let (pair_a, _): (ecdsa::Pair, _) = Pair::generate();
+				let (pair_b, _): (ecdsa::Pair, _) = Pair::generate();
+				let mpc_addr_a: MpcAddress = MpcAddress(pair_a.public().to_eth_address().unwrap());
+				let mpc_addr_b: MpcAddress = MpcAddress(pair_b.public().to_eth_address().unwrap());
+				let mpc_addr_c: MpcAddress = MpcAddress([3u8; 20]);
+				let signer_pairs: Vec<ecdsa::Pair> =
+					(0..3).map(|_| ecdsa::Pair::generate().0).collect();
+				let signers: Vec<MpcAddress> = signer_pairs
+					.iter()
+					.map(|pair| MpcAddress(pair.public().to_eth_address().unwrap()))
+					.collect();
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), mpc_addr_a));
+				assert_ok!(SygmaBridge::set_signer_set(Origin::root(), signers, 2));
+
+				let final_message =
+					SygmaBridge::construct_ecdsa_signing_mpc_rotation_data(mpc_addr_b, 0);
+				let sign = |pairs: Vec<&ecdsa::Pair>, message: [u8; 32]| -> Vec<u8> {
+					pairs.iter().flat_map(|pair| pair.sign_prehashed(&message).encode()).collect()
+				};
 
-				// retry again, should work
-				assert_ok!(SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID));
-				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Retry {
-					deposit_on_block_height: 1234567u128,
-					dest_domain_id: DEST_DOMAIN_ID,
-					sender: ALICE,
-				})]);
+				// signature of the MPC address is not accepted in threshold signature mode
+				assert_noop!(
+					SygmaBridge::schedule_mpc_rotation_signed(
+						Origin::signed(ALICE),
+						mpc_addr_b,
+						sign(vec![&pair_a], final_message)
+					),
+					bridge::Error::<Runtime>::BadMpcSignature
+				);
+				// signatures below threshold are not accepted
+				assert_noop!(
+					SygmaBridge::schedule_mpc_rotation_signed(
+						Origin::signed(ALICE),
+						mpc_addr_b,
+						sign(vec![&signer_pairs[0]], final_message)
+					),
+					bridge::Error::<Runtime>::BadMpcSignature
+				);
+
+				assert_ok!(SygmaBridge::schedule_mpc_rotation_signed(
+					Origin::signed(ALICE),
+					mpc_addr_b,
+					sign(vec![&signer_pairs[0], &signer_pairs[2]], final_message)
+				));
+				assert_eq!(PendingMpcAddr::<Runtime>::get(), Some((mpc_addr_b, 11)));
+
+				// the scheduled MPC address is not allowed to authorize another rotation
+				assert_noop!(
+					SygmaBridge::schedule_mpc_rotation_signed(
+						Origin::signed(ALICE),
+						mpc_addr_c,
+						sign(
+							vec![&pair_b],
+							SygmaBridge::construct_ecdsa_signing_mpc_rotation_data(mpc_addr_c, 1)
+						)
+					),
+					bridge::Error::<Runtime>::BadMpcSignature
+				);
 			})
 		}
 
 		#[test]
-		fn proposal_execution_should_work() {
+		fn deposit_generic_should_work() {
 			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let data = vec![1u8, 2u8, 3u8];
+
 				// mpc address is missing, should fail
 				assert_noop!(
-					SygmaBridge::execute_proposal(Origin::signed(ALICE), vec![], vec![]),
-					bridge::Error::<Runtime>::MissingMpcAddress,
+					SygmaBridge::deposit_generic(
+						Origin::signed(ALICE),
+						DEST_DOMAIN_ID,
+						data.clone()
+					),
+					bridge::Error::<Runtime>::MissingMpcAddress
 				);
-				// set mpc address to generated keypair's address
-				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
-				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
 				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr);
-				// register domain
+
+				// dest domain is not registered, should fail
+				assert_noop!(
+					SygmaBridge::deposit_generic(
+						Origin::signed(ALICE),
+						DEST_DOMAIN_ID,
+						data.clone()
+					),
+					bridge::Error::<Runtime>::DestDomainNotSupported
+				);
 				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
 
-				// Generate an evil key
-				let (evil_pair, _): (ecdsa::Pair, _) = Pair::generate();
-
-				// Deposit some native asset in advance
-				let fee = 1_000_000_000_000u128;
-				let amount: u128 = 200_000_000_000_000u128;
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee
-				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
-				));
-				assert_ok!(SygmaBridge::deposit(
+				assert_ok!(SygmaBridge::deposit_generic(
 					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+					DEST_DOMAIN_ID,
+					data.clone()
 				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+					dest_domain_id: DEST_DOMAIN_ID,
+					resource_id: GenericResourceId::get(),
+					deposit_nonce: 0,
+					sender: ALICE,
+					transfer_type: TransferType::GenericTransfer,
+					deposit_data: data.clone(),
+					handler_response: vec![],
+				})]);
+				assert_eq!(SygmaBridge::deposit_counts(DEST_DOMAIN_ID), 1);
 
-				// Register foreign asset (USDT) with asset id 0
-				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
-					<Runtime as frame_system::Config>::AccountId,
-				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+				// paused bridge should reject generic deposit
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_noop!(
+					SygmaBridge::deposit_generic(Origin::signed(ALICE), DEST_DOMAIN_ID, data),
+					bridge::Error::<Runtime>::BridgePaused
+				);
+			})
+		}
 
-				// Mint 400 USDT to liquidity holder for test
-				assert_ok!(Assets::mint(
-					Origin::signed(ASSET_OWNER),
-					codec::Compact(0),
-					AccountId::new(
-						SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
-							.unwrap()
-					),
-					400_000_000_000_000,
+		#[test]
+		fn generic_proposal_execution_should_work() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
 				));
-				// alice deposit 200 - 1 token fee native token, so the native token holder should have 199 tokens
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					199_000_000_000_000
-				);
-				// USDT liquidity holder should have 400 USDT at this moment
-				assert_eq!(
-					Assets::balance(
-						UsdtAssetId::get(),
-						AccountId::new(
-							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
-								.unwrap()
-						),
-					),
-					400_000_000_000_000
-				);
 
-				// Generate proposals
-				// amount is in 18 decimal 0.000200000000000000, will be convert to 12 decimal
-				// 0.000200000000
-				let valid_native_transfer_proposal = Proposal {
-					origin_domain_id: DEST_DOMAIN_ID,
-					deposit_nonce: 1,
-					resource_id: NativeResourceId::get(),
-					data: SygmaBridge::create_deposit_data(
-						amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
-							.encode(),
-					),
-				};
-				// amount is in 18 decimal 0.000200000000000000, will be convert to 18 decimal
-				// 0.000200000000000000
-				let valid_usdt_transfer_proposal = Proposal {
+				let depositor = b"ethereum depositor".to_vec();
+				let caller = SygmaBridge::generic_call_account(DEST_DOMAIN_ID, &depositor);
+				let remark = vec![1u8, 2u8, 3u8];
+				let remark_call = RuntimeCall::System(frame_system::Call::remark_with_event {
+					remark: remark.clone(),
+				});
+				let weight_limit = remark_call.get_dispatch_info().weight;
+				let transfer_call =
+					RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+						dest: BOB,
+						value: 1,
+					});
+
+				// allowed call with enough weight limit
+				let valid_proposal = Proposal {
 					origin_domain_id: DEST_DOMAIN_ID,
-					deposit_nonce: 2,
-					resource_id: UsdtResourceId::get(),
-					data: SygmaBridge::create_deposit_data(
-						amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
-							.encode(),
+					deposit_nonce: 1,
+					resource_id: GenericResourceId::get(),
+					data: SygmaBridge::create_generic_deposit_data(
+						weight_limit,
+						depositor.clone(),
+						remark_call.encode(),
 					),
 				};
-				let invalid_depositnonce_proposal = Proposal {
+				// call not allowed by the filter
+				let filtered_proposal = Proposal {
 					origin_domain_id: DEST_DOMAIN_ID,
 					deposit_nonce: 2,
-					resource_id: NativeResourceId::get(),
-					data: SygmaBridge::create_deposit_data(
-						amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
-							.encode(),
-					),
-				};
-				let invalid_domainid_proposal = Proposal {
-					origin_domain_id: 2,
-					deposit_nonce: 3,
-					resource_id: NativeResourceId::get(),
-					data: SygmaBridge::create_deposit_data(
-						amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
-							.encode(),
+					resource_id: GenericResourceId::get(),
+					data: SygmaBridge::create_generic_deposit_data(
+						transfer_call.get_dispatch_info().weight,
+						depositor.clone(),
+						transfer_call.encode(),
 					),
 				};
-				let invalid_resourceid_proposal = Proposal {
+				// weight limit lower than the call weight
+				let overweight_proposal = Proposal {
 					origin_domain_id: DEST_DOMAIN_ID,
 					deposit_nonce: 3,
-					resource_id: [2u8; 32],
-					data: SygmaBridge::create_deposit_data(
-						amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
-							.encode(),
+					resource_id: GenericResourceId::get(),
+					data: SygmaBridge::create_generic_deposit_data(
+						Weight::zero(),
+						depositor.clone(),
+						remark_call.encode(),
 					),
 				};
-				let invalid_recipient_proposal = Proposal {
-					origin_domain_id: DEST_DOMAIN_ID,
-					deposit_nonce: 3,
-					resource_id: NativeResourceId::get(),
-					data: SygmaBridge::create_deposit_data(amount, b"invalid recipient".to_vec()),
-				};
-				let empty_data_proposal = Proposal {
+				// call data can not be decoded
+				let invalid_proposal = Proposal {
 					origin_domain_id: DEST_DOMAIN_ID,
-					deposit_nonce: 3,
-					resource_id: UsdtResourceId::get(),
-					data: vec![],
+					deposit_nonce: 4,
+					resource_id: GenericResourceId::get(),
+					data: SygmaBridge::create_generic_deposit_data(
+						weight_limit,
+						depositor.clone(),
+						vec![255u8],
+					),
 				};
-
-				let proposals = vec![
-					valid_native_transfer_proposal,
-					valid_usdt_transfer_proposal,
-					invalid_depositnonce_proposal,
-					invalid_domainid_proposal,
-					invalid_resourceid_proposal,
-					invalid_recipient_proposal,
-					empty_data_proposal,
-				];
+				let proposals =
+					vec![valid_proposal, filtered_proposal, overweight_proposal, invalid_proposal];
+				assert_eq!(
+					SygmaBridge::generic_proposals_weight(&proposals),
+					weight_limit
+						.saturating_add(transfer_call.get_dispatch_info().weight)
+						.saturating_add(weight_limit)
+				);
 
 				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
-				let proposals_with_valid_signature = pair.sign_prehashed(&final_message);
-				let proposals_with_bad_signature = evil_pair.sign_prehashed(&final_message);
-
-				// Should failed if dest domain 1 bridge paused
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert!(IsPaused::<Runtime>::get(DEST_DOMAIN_ID));
+				let signature = pair.sign_prehashed(&final_message);
 				assert_ok!(SygmaBridge::execute_proposal(
 					Origin::signed(ALICE),
-					proposals.clone(),
-					proposals_with_valid_signature.encode()
+					proposals,
+					signature.encode()
 				));
-				// should emit FailedHandlerExecution event
-				assert_events(vec![RuntimeEvent::SygmaBridge(
-					SygmaBridgeEvent::FailedHandlerExecution {
-						error: vec![66, 114, 105, 100, 103, 101, 80, 97, 117, 115, 101, 100],
-						origin_domain_id: 1,
+
+				// remark call is dispatched by the account derived from depositor
+				assert!(System::events().iter().any(|r| r.event
+					== RuntimeEvent::System(frame_system::Event::Remarked {
+						sender: caller.clone(),
+						hash: BlakeTwo256::hash(&remark),
+					})));
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_proposal_executed(3, DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_proposal_executed(4, DEST_DOMAIN_ID));
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+						error: "GenericCallFiltered".as_bytes().to_vec(),
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 2,
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+						error: "GenericCallWeightExceeded".as_bytes().to_vec(),
+						origin_domain_id: DEST_DOMAIN_ID,
 						deposit_nonce: 3,
-					},
-				)]);
-				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+						error: "GenericCallDecodeFailed".as_bytes().to_vec(),
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 4,
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn non_fungible_deposit_data_test() {
+			new_test_ext().execute_with(|| {
+				let token_id = SygmaBridge::hex_zero_padding_32(1);
+				let recipient =
+					MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }));
+				let metadata = b"ipfs://token".to_vec();
+
+				let data = SygmaBridge::create_non_fungible_deposit_data(
+					token_id,
+					recipient.encode(),
+					metadata.clone(),
+				);
+				assert_eq!(data.len(), 64 + recipient.encode().len() + 32 + metadata.len());
+				assert_eq!(
+					SygmaBridge::extract_non_fungible_deposit_data(&data).unwrap(),
+					(token_id, recipient, metadata)
+				);
 
+				// metadata length mismatch
 				assert_noop!(
-					SygmaBridge::execute_proposal(
-						Origin::signed(ALICE),
-						proposals.clone(),
-						proposals_with_bad_signature.encode(),
+					SygmaBridge::extract_non_fungible_deposit_data(&data[..data.len() - 1]),
+					bridge::Error::<Runtime>::InvalidDepositData
+				);
+				// metadata length is missing
+				assert_noop!(
+					SygmaBridge::extract_non_fungible_deposit_data(
+						&data[..64 + recipient.encode().len()]
 					),
-					bridge::Error::<Runtime>::BadMpcSignature,
+					bridge::Error::<Runtime>::InvalidDepositData
 				);
-				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE);
-				assert_eq!(Assets::balance(UsdtAssetId::get(), &BOB), 0);
-				assert!(SygmaBridge::verify_by_mpc_address(
-					final_message,
-					proposals_with_valid_signature.encode(),
-				));
-				assert_ok!(SygmaBridge::execute_proposal(
-					Origin::signed(ALICE),
-					proposals,
-					proposals_with_valid_signature.encode(),
-				));
-				// proposal amount is in 18 decimal 0.000200000000000000, will be convert to 12
-				// decimal 0.000200000000(200000000) because native asset is defined in 12 decimal
-				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE + 200000000);
-				// usdt is defined in 18 decimal so that converted amount is the same as in proposal
-				assert_eq!(Assets::balance(UsdtAssetId::get(), &BOB), amount);
 
-				// liquidity holder accounts balance after proposals execution
-				// 199 - 0.0002 native token is 198.999800000000
+				// token id fits into u128 is represented as Index
 				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					199_000_000_000_000 - 200_000_000
+					SygmaBridge::asset_instance_to_token_id(&AssetInstance::Index(1)),
+					Some(token_id)
 				);
-				// 400 USDT after transferring out the USDT proposal, should remain 200 USDT
 				assert_eq!(
-					Assets::balance(
-						UsdtAssetId::get(),
-						AccountId::new(
-							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
-								.unwrap()
-						),
-					),
-					200_000_000_000_000
+					SygmaBridge::token_id_to_asset_instance(token_id),
+					AssetInstance::Index(1)
+				);
+				assert_eq!(
+					SygmaBridge::token_id_to_asset_instance([255u8; 32]),
+					AssetInstance::Array32([255u8; 32])
+				);
+				assert_eq!(
+					SygmaBridge::asset_instance_to_token_id(&AssetInstance::Array4([1u8; 4])),
+					None
 				);
 			})
 		}
 
 		#[test]
-		fn get_bridge_pause_status() {
+		fn non_fungible_transfer_round_trip_should_work() {
 			new_test_ext().execute_with(|| {
-				assert!(!SygmaBridge::is_paused(DEST_DOMAIN_ID));
-
-				// set mpc address
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
 				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				// register domain
 				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
 
-				// pause bridge
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert!(SygmaBridge::is_paused(DEST_DOMAIN_ID));
-
-				// unpause bridge
-				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
-				assert!(!SygmaBridge::is_paused(DEST_DOMAIN_ID));
-			})
-		}
+				// NftLocation is reserved on current chain, ForeignNftLocation is not
+				assert_ok!(Uniques::force_create(
+					Origin::root(),
+					NftCollectionId::get(),
+					ASSET_OWNER,
+					true
+				));
+				assert_ok!(Uniques::force_create(
+					Origin::root(),
+					ForeignNftCollectionId::get(),
+					ASSET_OWNER,
+					true
+				));
+				assert_ok!(Uniques::mint(
+					Origin::signed(ASSET_OWNER),
+					NftCollectionId::get(),
+					1,
+					ALICE
+				));
+				assert_ok!(Uniques::mint(
+					Origin::signed(ASSET_OWNER),
+					ForeignNftCollectionId::get(),
+					2,
+					ALICE
+				));
+				let metadata = b"ipfs://token".to_vec();
+				assert_ok!(Uniques::set_metadata(
+					Origin::signed(ASSET_OWNER),
+					ForeignNftCollectionId::get(),
+					2,
+					metadata.clone().try_into().unwrap(),
+					false
+				));
 
-		#[test]
-		fn access_control() {
-			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
 
+				// item can not pay fee by itself
 				assert_noop!(
-					SygmaBridge::set_mpc_address(Some(ALICE).into(), test_mpc_addr),
-					bridge::Error::<Runtime>::AccessDenied
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NftLocation::get()), NonFungible(Index(1))).into()),
+						Box::new(dest),
+					),
+					bridge::Error::<Runtime>::MissingFeeConfig
 				);
-
+				// item is not transferred without fee
 				assert_noop!(
-					SygmaBridge::pause_bridge(Some(BOB).into(), DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::AccessDenied
+					SygmaBridge::deposit_with_fee_asset(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NftLocation::get()), NonFungible(Index(1))).into()),
+						Box::new(dest),
+						Box::new(NativeLocation::get().into()),
+					),
+					bridge::Error::<Runtime>::MissingFeeConfig
 				);
-				assert_noop!(
-					SygmaBridge::unpause_bridge(Some(BOB).into(), DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::AccessDenied
+
+				// fee of both collections is paid in native asset
+				let fee = 1_000_000_000_000u128;
+				for nft_location in [NftLocation::get(), ForeignNftLocation::get()] {
+					assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(nft_location.into()),
+						FeeHandlerType::BasicFeeHandler,
+					));
+					assert_ok!(SygmaBasicFeeHandler::set_fee_in_asset(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(nft_location.into()),
+						Box::new(NativeLocation::get().into()),
+						fee
+					));
+				}
+
+				// deposit of reserved item locks it in reserve account
+				assert_ok!(SygmaBridge::deposit_with_fee_asset(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NftLocation::get()), NonFungible(Index(1))).into()),
+					Box::new(dest),
+					Box::new(NativeLocation::get().into()),
+				));
+				assert_eq!(
+					Uniques::owner(NftCollectionId::get(), 1),
+					Some(BridgeAccountOtherTokens::get())
+				);
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - fee);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NftResourceId::get(),
+						deposit_nonce: 0,
+						sender: ALICE,
+						transfer_type: TransferType::NonFungibleTransfer,
+						deposit_data: SygmaBridge::create_non_fungible_deposit_data(
+							SygmaBridge::hex_zero_padding_32(1),
+							b"ethereum recipient".to_vec(),
+							vec![],
+						),
+						handler_response: vec![],
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: NftResourceId::get(),
+						fee_amount: fee,
+						fee_asset_id: NativeLocation::get().into(),
+					}),
+				]);
+
+				// deposit of non-reserved item burns it and carries its metadata
+				assert_ok!(SygmaBridge::deposit_with_fee_asset(
+					Origin::signed(ALICE),
+					Box::new((Concrete(ForeignNftLocation::get()), NonFungible(Index(2))).into()),
+					Box::new(dest),
+					Box::new(NativeLocation::get().into()),
+				));
+				assert_eq!(Uniques::owner(ForeignNftCollectionId::get(), 2), None);
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - 2 * fee);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 2 * fee);
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: ForeignNftResourceId::get(),
+						deposit_nonce: 1,
+						sender: ALICE,
+						transfer_type: TransferType::NonFungibleTransfer,
+						deposit_data: SygmaBridge::create_non_fungible_deposit_data(
+							SygmaBridge::hex_zero_padding_32(2),
+							b"ethereum recipient".to_vec(),
+							metadata.clone(),
+						),
+						handler_response: vec![],
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: DEST_DOMAIN_ID,
+						resource_id: ForeignNftResourceId::get(),
+						fee_amount: fee,
+						fee_asset_id: NativeLocation::get().into(),
+					}),
+				]);
+
+				// bridge both items back to bob
+				let recipient =
+					MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+						.encode();
+				let p_reserved = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 1,
+					resource_id: NftResourceId::get(),
+					data: SygmaBridge::create_non_fungible_deposit_data(
+						SygmaBridge::hex_zero_padding_32(1),
+						recipient.clone(),
+						vec![],
+					),
+				};
+				let p_foreign = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 2,
+					resource_id: ForeignNftResourceId::get(),
+					data: SygmaBridge::create_non_fungible_deposit_data(
+						SygmaBridge::hex_zero_padding_32(2),
+						recipient.clone(),
+						metadata.clone(),
+					),
+				};
+				// item 3 has never been locked in reserve account
+				let p_not_locked = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 3,
+					resource_id: NftResourceId::get(),
+					data: SygmaBridge::create_non_fungible_deposit_data(
+						SygmaBridge::hex_zero_padding_32(3),
+						recipient,
+						vec![],
+					),
+				};
+				let proposals = vec![p_reserved, p_foreign, p_not_locked];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				let signature = pair.sign_prehashed(&final_message);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					signature.encode()
+				));
+
+				// reserved item is unlocked, non-reserved item is minted with its metadata
+				assert_eq!(Uniques::owner(NftCollectionId::get(), 1), Some(BOB));
+				assert_eq!(Uniques::owner(ForeignNftCollectionId::get(), 2), Some(BOB));
+				assert_eq!(
+					<Uniques as Inspect<AccountId>>::attribute(
+						&ForeignNftCollectionId::get(),
+						&2,
+						&[]
+					),
+					Some(metadata)
 				);
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_proposal_executed(3, DEST_DOMAIN_ID));
+				assert_eq!(Uniques::owner(NftCollectionId::get(), 3), None);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::FailedHandlerExecution {
+						error: "TransactFailed".as_bytes().to_vec(),
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 3,
+					},
+				)]);
+			})
+		}
 
-				// Grant ALICE the access of `set_mpc_address`
-				assert_ok!(AccessSegregator::grant_access(
+		#[test]
+		fn deposit_records_should_work() {
+			new_test_ext().execute_with(|| {
+				DepositRecordRetention::set(2);
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+
+				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
-					BridgePalletIndex::get(),
-					b"set_mpc_address".to_vec(),
-					ALICE
+					DEST_DOMAIN_ID,
+					U256::from(1)
 				));
-				// Grant BOB the access of `pause_bridge` and `unpause_bridge`
-				assert_ok!(AccessSegregator::grant_access(
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
 					Origin::root(),
-					BridgePalletIndex::get(),
-					b"pause_bridge".to_vec(),
-					BOB
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					fee
 				));
-				assert_ok!(AccessSegregator::grant_access(
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
 					Origin::root(),
-					BridgePalletIndex::get(),
-					b"unpause_bridge".to_vec(),
-					BOB
+					DEST_DOMAIN_ID,
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
 				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
 
-				// BOB set mpc address should still failed
-				assert_noop!(
-					SygmaBridge::set_mpc_address(Some(BOB).into(), test_mpc_addr),
-					bridge::Error::<Runtime>::AccessDenied
-				);
-				// ALICE set mpc address should work
-				assert_ok!(SygmaBridge::set_mpc_address(Some(ALICE).into(), test_mpc_addr));
-				// register domain
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::root(),
+				// deposit on block 1 and 2
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(dest),
+				));
+				System::set_block_number(2);
+				assert_ok!(SygmaBridge::deposit_generic(
+					Origin::signed(BOB),
 					DEST_DOMAIN_ID,
-					U256::from(1)
+					vec![1u8, 2u8, 3u8]
 				));
 
-				// ALICE pause&unpause bridge should still failed
-				assert_noop!(
-					SygmaBridge::pause_bridge(Some(ALICE).into(), DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::AccessDenied
+				let native_record = DepositRecord {
+					sender: ALICE,
+					resource_id: NativeResourceId::get(),
+					amount: amount - fee,
+					recipient: b"ethereum recipient".to_vec().try_into().unwrap(),
+					fee,
+					block_number: 1,
+				};
+				assert_eq!(
+					SygmaBridge::deposit_records(DEST_DOMAIN_ID, 0),
+					Some(native_record.clone())
 				);
-				assert_noop!(
-					SygmaBridge::unpause_bridge(Some(ALICE).into(), DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::AccessDenied
+				assert_eq!(
+					SygmaBridge::deposit_records(DEST_DOMAIN_ID, 1),
+					Some(DepositRecord {
+						sender: BOB,
+						resource_id: GenericResourceId::get(),
+						amount: 0,
+						recipient: Default::default(),
+						fee: 0,
+						block_number: 2,
+					})
 				);
-				// BOB pause&unpause bridge should work
-				assert_ok!(SygmaBridge::pause_bridge(Some(BOB).into(), DEST_DOMAIN_ID));
-				assert_ok!(SygmaBridge::unpause_bridge(Some(BOB).into(), DEST_DOMAIN_ID));
-			})
-		}
-
-		#[test]
-		fn multi_domain_test() {
-			new_test_ext().execute_with(|| {
-				// root register domainID 1 with chainID 0, should be ok
-				assert_ok!(SygmaBridge::register_domain(Origin::root(), 1u8, U256::from(0)));
-
-				// set mpc address
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_eq!(DepositBlocks::<Runtime>::get(DEST_DOMAIN_ID, 1), 1);
+				assert_eq!(DepositBlocks::<Runtime>::get(DEST_DOMAIN_ID, 2), 1);
 
-				// alice register domainID 1 with chainID 1, should raise error AccessDenied
+				// retry block with deposit should work, block without deposit should fail
+				assert_ok!(SygmaBridge::retry(Origin::root(), 1, DEST_DOMAIN_ID));
 				assert_noop!(
-					SygmaBridge::register_domain(Origin::from(Some(ALICE)), 1u8, U256::from(1)),
-					Error::<Runtime>::AccessDenied
+					SygmaBridge::retry(Origin::root(), 3, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::DepositRecordNotFound
 				);
-				// Grant ALICE the access of `register_domain`
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"register_domain".to_vec(),
-					ALICE
-				));
-				// alice register domainID 1 with chainID 1, should be ok
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::from(Some(ALICE)),
-					1u8,
-					U256::from(1)
-				));
-				// should emit RegisterDestDomain event
-				assert_events(vec![RuntimeEvent::SygmaBridge(
-					SygmaBridgeEvent::RegisterDestDomain {
-						sender: ALICE,
-						domain_id: 1,
-						chain_id: U256::from(1),
-					},
-				)]);
-				// storage check
-				assert!(DestDomainIds::<Runtime>::get(1u8));
-				assert_eq!(DestChainIds::<Runtime>::get(1u8).unwrap(), U256::from(1));
 
-				// alice unregister domainID 1 with chainID 0, should raise error AccessDenied
-				assert_noop!(
-					SygmaBridge::unregister_domain(Origin::from(Some(ALICE)), 1u8, U256::from(0)),
-					Error::<Runtime>::AccessDenied
-				);
-				// Grant ALICE the access of `unregister_domain`
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"unregister_domain".to_vec(),
-					ALICE
+				// the oldest record is pruned once the retention is exceeded
+				System::set_block_number(3);
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(dest),
 				));
-				// alice unregister domainID 1 with chainID 2, should raise error
-				// DestChainIDNotMatch
-				assert_noop!(
-					SygmaBridge::unregister_domain(Origin::from(Some(ALICE)), 1u8, U256::from(2)),
-					Error::<Runtime>::DestChainIDNotMatch
+				assert_eq!(SygmaBridge::deposit_records(DEST_DOMAIN_ID, 0), None);
+				assert!(!DepositBlocks::<Runtime>::contains_key(DEST_DOMAIN_ID, 1));
+				assert_eq!(
+					SygmaBridge::deposit_records(DEST_DOMAIN_ID, 2),
+					Some(DepositRecord { block_number: 3, ..native_record })
 				);
-				// alice unregister domainID 2 with chainID 2, should raise error
-				// DestDomainNotSupported
+				assert_eq!(DepositRecords::<Runtime>::iter_prefix(DEST_DOMAIN_ID).count(), 2);
 				assert_noop!(
-					SygmaBridge::unregister_domain(Origin::from(Some(ALICE)), 2u8, U256::from(2)),
-					Error::<Runtime>::DestDomainNotSupported
+					SygmaBridge::retry(Origin::root(), 1, DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::DepositRecordNotFound
 				);
-				// alice unregister domainID 1 with chainID 1, should success
-				assert_ok!(SygmaBridge::unregister_domain(
-					Origin::from(Some(ALICE)),
-					1u8,
-					U256::from(1)
+				assert_ok!(SygmaBridge::retry(Origin::root(), 3, DEST_DOMAIN_ID));
+
+				// no record is kept if deposit records are disabled
+				DepositRecordRetention::set(0);
+				assert_ok!(SygmaBridge::deposit_generic(
+					Origin::signed(BOB),
+					DEST_DOMAIN_ID,
+					vec![1u8, 2u8, 3u8]
 				));
-				// should emit UnregisterDestDomain event
-				assert_events(vec![RuntimeEvent::SygmaBridge(
-					SygmaBridgeEvent::UnregisterDestDomain {
-						sender: ALICE,
-						domain_id: 1,
-						chain_id: U256::from(1),
-					},
-				)]);
+				assert_eq!(SygmaBridge::deposit_records(DEST_DOMAIN_ID, 3), None);
+
+				// records out of a lowered retention are pruned through the cursor, a few at a
+				// time
+				assert_eq!(SygmaBridge::deposit_record_prune_cursor(DEST_DOMAIN_ID), Some(1));
+				DepositRecordRetention::set(1);
+				assert_ok!(SygmaBridge::deposit_generic(
+					Origin::signed(BOB),
+					DEST_DOMAIN_ID,
+					vec![1u8, 2u8, 3u8]
+				));
+				assert_eq!(SygmaBridge::deposit_records(DEST_DOMAIN_ID, 1), None);
+				assert_eq!(SygmaBridge::deposit_records(DEST_DOMAIN_ID, 2), None);
+				assert!(SygmaBridge::deposit_records(DEST_DOMAIN_ID, 4).is_some());
+				assert_eq!(SygmaBridge::deposit_record_prune_cursor(DEST_DOMAIN_ID), Some(3));
+				assert_ok!(SygmaBridge::deposit_generic(
+					Origin::signed(BOB),
+					DEST_DOMAIN_ID,
+					vec![1u8, 2u8, 3u8]
+				));
+				assert_eq!(SygmaBridge::deposit_records(DEST_DOMAIN_ID, 4), None);
+				assert!(SygmaBridge::deposit_records(DEST_DOMAIN_ID, 5).is_some());
+				assert_eq!(DepositRecords::<Runtime>::iter_prefix(DEST_DOMAIN_ID).count(), 1);
+				assert_eq!(SygmaBridge::deposit_record_prune_cursor(DEST_DOMAIN_ID), Some(5));
 
-				// storage check
-				// DomainID 1 should not support anymore
-				assert!(!DestDomainIds::<Runtime>::get(1u8));
-				// corresponding chainID should be None since kv not exist anymore
-				assert!(DestChainIds::<Runtime>::get(1u8).is_none());
+				// recipient too long to be kept is kept as its hash
+				assert_ok!(SygmaBridge::record_deposit(
+					DEST_DOMAIN_ID,
+					6,
+					BOB,
+					GenericResourceId::get(),
+					0,
+					vec![1u8; 129],
+					0,
+					None
+				));
+				assert_eq!(
+					SygmaBridge::deposit_records(DEST_DOMAIN_ID, 6).unwrap().recipient.to_vec(),
+					keccak_256(&[1u8; 129]).to_vec()
+				);
 			})
 		}
 
 		#[test]
-		fn deposit_with_decimal_converter() {
+		fn transfer_limit_should_work() {
 			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-
-				// native asset with 12 decimal
-				let fee_native_asset = 1_000_000_000_000u128; // 1.0 native asset
-				let amount_native_asset = 123_456_789_123_456u128; // 123.456_789_123_456
-				let adjusted_amount_native_asset = 122_456_789_123_456_000_000u128; // amount_native_asset - fee_native_asset then adjust it to 18 decimals
-
-				// usdt asset with 18 decimal
-				let fee_usdt_asset = 1_000_000_000_000_000_000u128; // 1.0 usdt asset
-				let amount_usdt_asset = 123_456_789_123_456_789_123u128; // 123.456_789_123_456_789_123
-				let adjusted_amount_usdt_asset = 122_456_789_123_456_789_123u128; // amount_usdt_asset - fee_usdt_asset then adjust it to 18 decimals
-
-				// astr asset with 24 decimal
-				let fee_astr_asset = 1_000_000_000_000_000_000_000_000u128; // 1.0 astr asset
-				let amount_astr_asset = 123_456_789_123_456_789_123_456_789u128; // 123.456_789_123_456_789_123_456_789
-				let adjusted_amount_astr_asset = 122_456_789_123_456_789_123u128; // amount_astr_asset - fee_astr_asset then adjust it to 18 decimals
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+				let cap = 300_000_000_000_000u128; // 300 with 12 decimals
+				let native_asset: AssetId = NativeLocation::get().into();
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
 
-				// set fees
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
 				assert_ok!(SygmaBasicFeeHandler::set_fee(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					Box::new(NativeLocation::get().into()),
-					fee_native_asset
+					fee
 				));
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(UsdtLocation::get().into()),
-					fee_usdt_asset
+					Box::new(NativeLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
 				));
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
+
+				// set transfer limit with unauthorized account should fail
+				assert_noop!(
+					SygmaBridge::set_transfer_limit(
+						Some(ALICE).into(),
+						DEST_DOMAIN_ID,
+						Box::new(native_asset),
+						cap,
+						10
+					),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				// dest domain not registered should fail
+				assert_noop!(
+					SygmaBridge::set_transfer_limit(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(native_asset),
+						cap,
+						10
+					),
+					bridge::Error::<Runtime>::DestDomainNotSupported
+				);
+				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(AstrLocation::get().into()),
-					fee_astr_asset
+					U256::from(1)
 				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+				// empty window should fail
+				assert_noop!(
+					SygmaBridge::set_transfer_limit(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(native_asset),
+						cap,
+						0
+					),
+					bridge::Error::<Runtime>::InvalidTransferLimit
+				);
+				assert_ok!(SygmaBridge::set_transfer_limit(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
+					Box::new(native_asset),
+					cap,
+					10
+				));
+				assert_eq!(
+					SygmaBridge::transfer_limits(DEST_DOMAIN_ID, native_asset),
+					Some(TransferLimit { cap, window: 10 })
+				);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::TransferLimitSet {
+						sender: [0u8; 32].into(),
+						domain_id: DEST_DOMAIN_ID,
+						asset_id: native_asset,
+						cap,
+						window: 10,
+					},
+				)]);
+
+				// first deposit is within the limit, the second one exceeds it
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(dest),
+				));
+				assert_eq!(
+					SygmaBridge::transfer_volumes((
+						DEST_DOMAIN_ID,
+						native_asset,
+						TransferDirection::Outbound
+					))
+					.map(|volume| volume.current),
+					Some(amount - fee)
+				);
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(dest),
+					),
+					bridge::Error::<Runtime>::TransferLimitExceeded
+				);
+
+				// volume of the previous window still counts in proportion to its overlap with
+				// the rolling window
+				System::set_block_number(11);
+				assert_noop!(
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(dest),
+					),
+					bridge::Error::<Runtime>::TransferLimitExceeded
+				);
+				System::set_block_number(15);
+				assert_ok!(SygmaBridge::deposit(
+					Origin::signed(ALICE),
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(dest),
 				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(UsdtLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
+
+				// inbound transfer exceeding the limit fails and is not marked as executed
+				// amount is in 18 decimal 350.000000000000000000, will be convert to 12 decimal
+				// 350.000000000000
+				let proposals = vec![Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 1,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						350_000_000_000_000_000_000u128,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
+				}];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				let signature = pair.sign_prehashed(&final_message);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals.clone(),
+					signature.encode()
 				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::FailedHandlerExecution {
+						error: "TransferLimitExceeded".as_bytes().to_vec(),
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+					},
+				)]);
+				assert!(!SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE);
+
+				// remove transfer limit with unauthorized account should fail
+				assert_noop!(
+					SygmaBridge::remove_transfer_limit(
+						Some(ALICE).into(),
+						DEST_DOMAIN_ID,
+						Box::new(native_asset)
+					),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_ok!(SygmaBridge::remove_transfer_limit(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(AstrLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
+					Box::new(native_asset)
+				));
+				assert_eq!(SygmaBridge::transfer_limits(DEST_DOMAIN_ID, native_asset), None);
+				assert_eq!(TransferVolumes::<Runtime>::iter().count(), 0);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::TransferLimitRemoved {
+						sender: [0u8; 32].into(),
+						domain_id: DEST_DOMAIN_ID,
+						asset_id: native_asset,
+					},
+				)]);
+				assert_noop!(
+					SygmaBridge::remove_transfer_limit(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						Box::new(native_asset)
+					),
+					bridge::Error::<Runtime>::TransferLimitNotSet
+				);
+
+				// the failed proposal can be executed once the limit is lifted
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					signature.encode()
 				));
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE + 350_000_000_000_000);
+			})
+		}
 
+		#[test]
+		fn failed_proposal_should_be_re_executable() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
 				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
-
-				// deposit native asset which has 12 decimal
-				assert_ok!(SygmaBridge::deposit(
+				// fund the native asset reserve account
+				let reserve_account = AccountId::new(
+					SygmaBridge::get_token_reserved_account(&NativeLocation::get().into()).unwrap(),
+				);
+				assert_ok!(Balances::transfer_keep_alive(
 					Origin::signed(ALICE),
-					Box::new(
-						(Concrete(NativeLocation::get()), Fungible(amount_native_asset)).into()
-					),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+					reserve_account,
+					200_000_000_000_000
 				));
-				// Check balances
-				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount_native_asset);
-				// native asset should be reserved so that BridgeAccount should hold it
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					amount_native_asset - fee_native_asset
-				);
-				// TreasuryAccount is collecting the bridging fee
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee_native_asset);
-				// Check event
-				assert_events(vec![
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: NativeResourceId::get(),
-						deposit_nonce: 0,
-						sender: ALICE,
-						transfer_type: TransferType::FungibleTransfer,
-						deposit_data: SygmaBridge::create_deposit_data(
-							adjusted_amount_native_asset,
-							b"ethereum recipient".to_vec(),
-						),
-						handler_response: vec![],
-					}),
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
-						fee_payer: ALICE,
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: NativeResourceId::get(),
-						fee_amount: fee_native_asset,
-						fee_asset_id: NativeLocation::get().into(),
-					}),
-				]);
-
-				// deposit usdt asset which has 18 decimal
-				// Register foreign asset (usdt) with asset id 0
-				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
-					<Runtime as frame_system::Config>::AccountId,
-				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
 
-				// Mint some usdt to ALICE for test
-				assert_ok!(Assets::mint(
-					Origin::signed(ASSET_OWNER),
-					codec::Compact(0),
-					ALICE,
-					ENDOWED_BALANCE,
-				)); // make sure Alice owns enough funds here
-				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), ENDOWED_BALANCE);
+				// amount is in 18 decimal 0.000200000000000000, will be convert to 12 decimal
+				// 0.000200000000
+				let native_proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 1,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						200_000_000_000_000u128,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
+				};
+				let depositor = b"ethereum depositor".to_vec();
+				let remark_call = RuntimeCall::System(frame_system::Call::remark_with_event {
+					remark: vec![1u8, 2u8, 3u8],
+				});
+				let weight_limit = remark_call.get_dispatch_info().weight;
+				let generic_proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 2,
+					resource_id: GenericResourceId::get(),
+					data: SygmaBridge::create_generic_deposit_data(
+						weight_limit,
+						depositor,
+						remark_call.encode(),
+					),
+				};
+				let proposals = vec![native_proposal.clone(), generic_proposal];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				let signature = pair.sign_prehashed(&final_message);
 
-				// deposit
-				assert_ok!(SygmaBridge::deposit(
+				// proposals fail to execute while the bridge is paused and are kept on chain
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(SygmaBridge::execute_proposal(
 					Origin::signed(ALICE),
-					Box::new((Concrete(UsdtLocation::get()), Fungible(amount_usdt_asset)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+					proposals.clone(),
+					signature.encode()
 				));
-				// Check balances
-				assert_eq!(
-					Assets::balance(UsdtAssetId::get(), &ALICE),
-					ENDOWED_BALANCE - amount_usdt_asset
-				);
-				assert_eq!(
-					Assets::balance(
-						UsdtAssetId::get(),
-						AccountId::new(
-							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
-								.unwrap()
-						),
+				assert!(!SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				let failed_proposal = SygmaBridge::failed_proposals(DEST_DOMAIN_ID, 1).unwrap();
+				assert_eq!(failed_proposal.resource_id, NativeResourceId::get());
+				assert_eq!(failed_proposal.data.to_vec(), native_proposal.data);
+				assert_eq!(failed_proposal.error.to_vec(), b"BridgePaused".to_vec());
+				assert_eq!(failed_proposal.block_number, 1);
+				assert!(FailedProposals::<Runtime>::contains_key(DEST_DOMAIN_ID, 2));
+
+				// re-execution fails until the cause is fixed
+				assert_noop!(
+					SygmaBridge::execute_failed_proposal(
+						Origin::signed(ASSET_OWNER),
+						DEST_DOMAIN_ID,
+						1,
+						Weight::zero()
 					),
-					122_456_789_123_456_789_123
+					bridge::Error::<Runtime>::BridgePaused
 				);
-				// TreasuryAccount is collecting the bridging fee
-				assert_eq!(
-					Assets::balance(UsdtAssetId::get(), TreasuryAccount::get()),
-					fee_usdt_asset
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_noop!(
+					SygmaBridge::execute_failed_proposal(
+						Origin::signed(ASSET_OWNER),
+						DEST_DOMAIN_ID,
+						3,
+						Weight::zero()
+					),
+					bridge::Error::<Runtime>::FailedProposalNotFound
 				);
 
-				// Check event
-				assert_events(vec![
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: UsdtResourceId::get(),
-						deposit_nonce: 1,
-						sender: ALICE,
-						transfer_type: TransferType::FungibleTransfer,
-						deposit_data: SygmaBridge::create_deposit_data(
-							adjusted_amount_usdt_asset,
-							b"ethereum recipient".to_vec(),
-						),
-						handler_response: vec![],
-					}),
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
-						fee_payer: ALICE,
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: UsdtResourceId::get(),
-						fee_amount: fee_usdt_asset,
-						fee_asset_id: UsdtLocation::get().into(),
-					}),
-				]);
-
-				// deposit astr asset which has 24 decimal
-				// Register foreign asset (astr) with asset id 1
-				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
-					<Runtime as frame_system::Config>::AccountId,
-				>>::create(AstrAssetId::get(), ASSET_OWNER, true, 1,));
-
-				// Mint some astr to ALICE for test
-				assert_ok!(Assets::mint(
+				// anyone can re-execute a failed proposal, weight limit unused by a non-generic
+				// proposal is not charged
+				let failed_proposal_weight =
+					<Runtime as bridge::Config>::WeightInfo::execute_failed_proposal();
+				let post_info = SygmaBridge::execute_failed_proposal(
 					Origin::signed(ASSET_OWNER),
-					codec::Compact(1),
-					ALICE,
-					ENDOWED_BALANCE,
-				)); // make sure Alice owns enough funds here
-				assert_eq!(Assets::balance(AstrAssetId::get(), &ALICE), ENDOWED_BALANCE);
-
-				// deposit
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(AstrLocation::get()), Fungible(amount_astr_asset)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
-				));
-				// Check balances
-				assert_eq!(
-					Assets::balance(AstrAssetId::get(), &ALICE),
-					ENDOWED_BALANCE - amount_astr_asset
+					DEST_DOMAIN_ID,
+					1,
+					weight_limit,
+				)
+				.unwrap();
+				assert_eq!(post_info.actual_weight, Some(failed_proposal_weight));
+				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE + 200_000_000);
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert_eq!(SygmaBridge::failed_proposals(DEST_DOMAIN_ID, 1), None);
+				assert_noop!(
+					SygmaBridge::execute_failed_proposal(
+						Origin::signed(ASSET_OWNER),
+						DEST_DOMAIN_ID,
+						1,
+						Weight::zero()
+					),
+					bridge::Error::<Runtime>::FailedProposalNotFound
 				);
-				// astr asset should be reserved so that BridgeAccount should hold it(Astr is not
-				// defined in ConcrateSygmaAsset)
-				assert_eq!(
-					Assets::balance(
-						AstrAssetId::get(),
-						AccountId::new(
-							SygmaBridge::get_token_reserved_account(&AstrLocation::get().into())
-								.unwrap()
-						),
+
+				// weight limit must cover the call of generic proposal
+				assert_noop!(
+					SygmaBridge::execute_failed_proposal(
+						Origin::signed(ASSET_OWNER),
+						DEST_DOMAIN_ID,
+						2,
+						Weight::zero()
 					),
-					amount_astr_asset - fee_astr_asset
+					bridge::Error::<Runtime>::FailedProposalWeightExceeded
 				);
-				// TreasuryAccount is collecting the bridging fee
+				let post_info = SygmaBridge::execute_failed_proposal(
+					Origin::signed(ASSET_OWNER),
+					DEST_DOMAIN_ID,
+					2,
+					weight_limit.saturating_mul(2),
+				)
+				.unwrap();
 				assert_eq!(
-					Assets::balance(AstrAssetId::get(), TreasuryAccount::get()),
-					fee_astr_asset
+					post_info.actual_weight,
+					Some(failed_proposal_weight.saturating_add(weight_limit))
 				);
+				assert!(SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
 
-				// Check event
+				// re-delivered proposals are rejected by UsedNonces and not kept again
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					signature.encode()
+				));
 				assert_events(vec![
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: AstrResourceId::get(),
-						deposit_nonce: 2,
-						sender: ALICE,
-						transfer_type: TransferType::FungibleTransfer,
-						deposit_data: SygmaBridge::create_deposit_data(
-							adjusted_amount_astr_asset,
-							b"ethereum recipient".to_vec(),
-						),
-						handler_response: vec![],
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+						error: "ProposalAlreadyComplete".as_bytes().to_vec(),
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
 					}),
-					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
-						fee_payer: ALICE,
-						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: AstrResourceId::get(),
-						fee_amount: fee_astr_asset,
-						fee_asset_id: AstrLocation::get().into(),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+						error: "ProposalAlreadyComplete".as_bytes().to_vec(),
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 2,
 					}),
 				]);
+				assert_eq!(FailedProposals::<Runtime>::iter().count(), 0);
+				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE + 200_000_000);
+			})
+		}
 
-				// deposit astr asset which has 24 decimal, extreme small amount edge case
-				let amount_astr_asset_extreme_small_amount = 100_000; // 0.000000000000000000100000 astr
-				let fee_astr_asset_extreme_small_amount = 1;
-				// 0.000000000000000000000001 astr
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
+		#[test]
+		fn failed_proposal_should_not_debit_reserve() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(AstrLocation::get().into()),
-					fee_astr_asset_extreme_small_amount
+					U256::from(1)
 				));
-				// after decimal conversion from 24 to 18, the final amount will be 0 so that
-				// decimal conversion will raise error deposit should not work
+				// fund the native asset reserve account
+				let reserve_account = AccountId::new(
+					SygmaBridge::get_token_reserved_account(&NativeLocation::get().into()).unwrap(),
+				);
+				assert_ok!(Balances::transfer_keep_alive(
+					Origin::signed(ALICE),
+					reserve_account.clone(),
+					200_000_000_000_000
+				));
+				let reserve_balance = Balances::free_balance(&reserve_account);
+
+				// recipient can not be converted to an account, so depositing to it fails once
+				// the asset is withdrawn from the reserve account
+				let proposal = Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 1,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						200_000_000_000_000u128,
+						MultiLocation::new(0, X1(GeneralIndex(1))).encode(),
+					),
+				};
+				let proposals = vec![proposal];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				let signature = pair.sign_prehashed(&final_message);
+
+				// the withdrawal of the failed proposal is reverted
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					signature.encode()
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::FailedHandlerExecution {
+						error: "TransactFailed".as_bytes().to_vec(),
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 1,
+					},
+				)]);
+				assert!(FailedProposals::<Runtime>::contains_key(DEST_DOMAIN_ID, 1));
+				assert_eq!(Balances::free_balance(&reserve_account), reserve_balance);
+
+				// replaying the failed proposal does not debit the reserve account either
 				assert_noop!(
-					SygmaBridge::deposit(
+					SygmaBridge::execute_failed_proposal(
 						Origin::signed(ALICE),
-						Box::new(
-							(
-								Concrete(AstrLocation::get()),
-								Fungible(amount_astr_asset_extreme_small_amount)
-							)
-								.into()
-						),
-						Box::new(MultiLocation {
-							parents: 0,
-							interior: X2(
-								slice_to_generalkey(b"ethereum recipient"),
-								slice_to_generalkey(&[1]),
-							)
-						}),
+						DEST_DOMAIN_ID,
+						1,
+						Weight::zero()
 					),
-					bridge::Error::<Runtime>::DecimalConversionFail
+					bridge::Error::<Runtime>::TransactFailed
 				);
+				assert_eq!(Balances::free_balance(&reserve_account), reserve_balance);
+				assert!(!SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
 			})
 		}
 
 		#[test]
-		fn proposal_execution_with_decimal_converter() {
+		fn refund_deposit_should_work() {
 			new_test_ext().execute_with(|| {
-				// generate mpc keypair
+				DepositRecordRetention::set(2);
 				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
 				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
-				// set mpc address
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				// register domain
+				let (evil_pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+
 				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
-				let fee = 1_000_000_000_000u128; // 1 token in 12 decimals
-				let init_deposit = 10_000_000_000_000u128;
-				// 12 token in 12 decimal
 				assert_ok!(SygmaBasicFeeHandler::set_fee(
 					Origin::root(),
 					DEST_DOMAIN_ID,
@@ -2560,506 +7631,580 @@ pub mod pallet {
 					Box::new(NativeLocation::get().into()),
 					FeeHandlerType::BasicFeeHandler,
 				));
-				// deposit in advance to make sure the native asset has enough funds in
-				// TransferReserveAccount by doing this, Alice will deposit (half of her native
-				// asset - fee) into TransferReserveAccount
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new(
-						(Concrete(NativeLocation::get()), Fungible(ENDOWED_BALANCE / 2)).into()
+
+				// mpc address is missing, should fail
+				assert_noop!(
+					SygmaBridge::refund_deposit(
+						Origin::signed(BOB),
+						DEST_DOMAIN_ID,
+						0,
+						true,
+						vec![]
 					),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
-				));
-				// BridgeAccount should have half of alice native asset - fee
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					ENDOWED_BALANCE / 2 - fee
+					bridge::Error::<Runtime>::MissingMpcAddress
 				);
-				// TreasuryAccount is collecting the bridging fee
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
-
-				let bridge_amount = 100_000_000_000_000_000_000; // 100 native with 18 decimals
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
 
-				// proposal for bridging native asset to alice(native asset is 12 decimal)
-				let p_native = Proposal {
-					origin_domain_id: 1,
-					resource_id: NativeResourceId::get(),
-					deposit_nonce: 1,
-					data: SygmaBridge::create_deposit_data(
-						bridge_amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: ALICE.into() }))
-							.encode(),
+				// native asset deposit with nonce 0 and 2, generic message deposit with nonce 1
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
 					),
 				};
-				let proposals = vec![p_native];
-				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
-				let signature = pair.sign_prehashed(&final_message);
-
-				// check Alice balance of native asset before executing, should have half of the
-				// init native asset
-				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE / 2);
-				assert_ok!(SygmaBridge::execute_proposal(
+				assert_ok!(SygmaBridge::deposit(
 					Origin::signed(ALICE),
-					proposals,
-					signature.encode()
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(dest),
 				));
-				// check Alice balance of native asset after executing, should have half of the init
-				// native asset + 100_000_000_000_000(12 decimal)
-				assert_eq!(
-					Balances::free_balance(ALICE),
-					ENDOWED_BALANCE / 2 + 100_000_000_000_000
-				);
-
-				// proposal for bridging usdt asset to alice(usdt asset is 18 decimal)
-				// Register foreign asset (usdt) with asset id 0
-				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
-					<Runtime as frame_system::Config>::AccountId,
-				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
-
-				// Mint some USDT to liquidity holder for test
-				assert_ok!(Assets::mint(
-					Origin::signed(ASSET_OWNER),
-					codec::Compact(0),
-					AccountId::new(
-						SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
-							.unwrap()
-					),
-					ENDOWED_BALANCE,
+				assert_ok!(SygmaBridge::deposit_generic(
+					Origin::signed(BOB),
+					DEST_DOMAIN_ID,
+					vec![1u8, 2u8, 3u8]
 				));
-				assert_eq!(
-					Assets::balance(
-						UsdtAssetId::get(),
-						AccountId::new(
-							SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into())
-								.unwrap()
-						),
-					),
-					ENDOWED_BALANCE
-				);
-
-				let p_usdt = Proposal {
-					origin_domain_id: 1,
-					deposit_nonce: 2,
-					resource_id: UsdtResourceId::get(),
-					data: SygmaBridge::create_deposit_data(
-						bridge_amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: ALICE.into() }))
-							.encode(),
-					),
-				};
-				let proposals_usdt = vec![p_usdt];
-				let final_message_usdt =
-					SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals_usdt);
-				let signature_usdt = pair.sign_prehashed(&final_message_usdt);
-
-				// alice does not have any usdt at this moment
-				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), 0);
-				assert_ok!(SygmaBridge::execute_proposal(
+				assert_ok!(SygmaBridge::deposit(
 					Origin::signed(ALICE),
-					proposals_usdt,
-					signature_usdt.encode()
+					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+					Box::new(dest),
 				));
-				// alice should have 100 usdt at this moment (100 usdt with 18 decimals)
-				assert_eq!(
-					Assets::balance(UsdtAssetId::get(), &ALICE),
-					100_000_000_000_000_000_000
-				);
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount * 2);
+				assert_eq!(Balances::free_balance(BridgeAccountNative::get()), (amount - fee) * 2);
+				let treasury_balance = Balances::free_balance(TreasuryAccount::get());
 
-				// proposal for bridging astr asset to alice(astr asset is 24 decimal)
-				// Register foreign asset (astr) with asset id 1
-				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
-					<Runtime as frame_system::Config>::AccountId,
-				>>::create(AstrAssetId::get(), ASSET_OWNER, true, 1,));
-				// Mint some astr to BridgeAccount for test because astr is reserved asset for
-				// testing
-				assert_ok!(Assets::mint(
-					Origin::signed(ASSET_OWNER),
-					codec::Compact(1),
-					AccountId::new(
-						SygmaBridge::get_token_reserved_account(&AstrLocation::get().into())
-							.unwrap()
-					),
-					ENDOWED_BALANCE
-				));
-				assert_eq!(
-					Assets::balance(
-						AstrAssetId::get(),
-						AccountId::new(
-							SygmaBridge::get_token_reserved_account(&AstrLocation::get().into())
-								.unwrap()
-						),
+				let final_message =
+					SygmaBridge::construct_ecdsa_signing_refund_data(DEST_DOMAIN_ID, 0, true);
+				let signature = pair.sign_prehashed(&final_message);
+				// signature of another key or another message should fail
+				assert_noop!(
+					SygmaBridge::refund_deposit(
+						Origin::signed(BOB),
+						DEST_DOMAIN_ID,
+						0,
+						true,
+						evil_pair.sign_prehashed(&final_message).encode()
 					),
-					ENDOWED_BALANCE
+					bridge::Error::<Runtime>::BadMpcSignature
 				);
-
-				let p_astr = Proposal {
-					origin_domain_id: 1,
-					deposit_nonce: 3,
-					resource_id: AstrResourceId::get(),
-					data: SygmaBridge::create_deposit_data(
-						bridge_amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: ALICE.into() }))
-							.encode(),
+				assert_noop!(
+					SygmaBridge::refund_deposit(
+						Origin::signed(BOB),
+						DEST_DOMAIN_ID,
+						0,
+						false,
+						signature.encode()
 					),
-				};
-				let proposals_astr = vec![p_astr];
-				let final_message_astr =
-					SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals_astr);
-				let signature_astr = pair.sign_prehashed(&final_message_astr);
+					bridge::Error::<Runtime>::BadMpcSignature
+				);
 
-				// alice does not have any astr at this moment
-				assert_eq!(Assets::balance(AstrAssetId::get(), &ALICE), 0);
-				assert_ok!(SygmaBridge::execute_proposal(
-					Origin::signed(ALICE),
-					proposals_astr,
-					signature_astr.encode()
+				// refund bridged amount and fee to the sender
+				assert_ok!(SygmaBridge::refund_deposit(
+					Origin::signed(BOB),
+					DEST_DOMAIN_ID,
+					0,
+					true,
+					signature.encode()
 				));
-				// alice should have 100 astr at this moment (100 astr with 24 decimals)
-				assert_eq!(
-					Assets::balance(AstrAssetId::get(), &ALICE),
-					100_000_000_000_000_000_000_000_000
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
+				assert_eq!(Balances::free_balance(BridgeAccountNative::get()), amount - fee);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), treasury_balance - fee);
+				assert_eq!(SygmaBridge::deposit_records(DEST_DOMAIN_ID, 0), None);
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::DepositRefunded {
+					dest_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 0,
+					sender: ALICE,
+					resource_id: NativeResourceId::get(),
+					amount: amount - fee,
+					fee,
+				})]);
+				// deposit can not be refunded twice
+				assert_noop!(
+					SygmaBridge::refund_deposit(
+						Origin::signed(BOB),
+						DEST_DOMAIN_ID,
+						0,
+						true,
+						signature.encode()
+					),
+					bridge::Error::<Runtime>::DepositRecordNotFound
 				);
 
-				// extreme small amount edge case
-				let extreme_small_bridge_amount = 100_000; // 0.000000000000100000 native asset with 18 decimals
-										   // proposal for bridging native asset to alice(native asset is 12 decimal)
-				let p_native_extreme = Proposal {
-					origin_domain_id: 1,
-					resource_id: NativeResourceId::get(),
-					deposit_nonce: 4,
-					data: SygmaBridge::create_deposit_data(
-						extreme_small_bridge_amount,
-						MultiLocation::new(0, X1(AccountId32 { network: None, id: ALICE.into() }))
-							.encode(),
+				// generic message deposit can not be refunded
+				let final_message =
+					SygmaBridge::construct_ecdsa_signing_refund_data(DEST_DOMAIN_ID, 1, false);
+				assert_noop!(
+					SygmaBridge::refund_deposit(
+						Origin::signed(BOB),
+						DEST_DOMAIN_ID,
+						1,
+						false,
+						pair.sign_prehashed(&final_message).encode()
 					),
-				};
-				let proposals_extreme = vec![p_native_extreme];
-				let final_message_extreme =
-					SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals_extreme);
-				let signature_extreme = pair.sign_prehashed(&final_message_extreme);
+					bridge::Error::<Runtime>::DepositNotRefundable
+				);
 
-				// execute_proposal extrinsic should work but it will actually failed at decimal
-				// conversion step because 0.000000000000100000 in 18 decimal converts to 12 decimal
-				// would be 0.000000000000 which is 0
-				assert_ok!(SygmaBridge::execute_proposal(
-					Origin::signed(ALICE),
-					proposals_extreme,
-					signature_extreme.encode()
+				// refund without fee
+				let final_message =
+					SygmaBridge::construct_ecdsa_signing_refund_data(DEST_DOMAIN_ID, 2, false);
+				assert_ok!(SygmaBridge::refund_deposit(
+					Origin::signed(BOB),
+					DEST_DOMAIN_ID,
+					2,
+					false,
+					pair.sign_prehashed(&final_message).encode()
 				));
-				// should emit FailedHandlerExecution event
-				assert_events(vec![RuntimeEvent::SygmaBridge(
-					SygmaBridgeEvent::FailedHandlerExecution {
-						error: vec![
-							68, 101, 99, 105, 109, 97, 108, 67, 111, 110, 118, 101, 114, 115, 105,
-							111, 110, 70, 97, 105, 108,
-						],
-						origin_domain_id: 1,
-						deposit_nonce: 4,
-					},
-				)]);
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - fee);
+				assert_eq!(Balances::free_balance(BridgeAccountNative::get()), 0);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), treasury_balance - fee);
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::DepositRefunded {
+					dest_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 2,
+					sender: ALICE,
+					resource_id: NativeResourceId::get(),
+					amount: amount - fee,
+					fee: 0,
+				})]);
 			})
 		}
 
 		#[test]
-		fn unpause_all_domains_test() {
+		fn set_eip712_domain_should_work() {
 			new_test_ext().execute_with(|| {
-				// Grant ALICE the access of `register_domain`
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"register_domain".to_vec(),
-					ALICE
-				));
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"pause_bridge".to_vec(),
-					ALICE
-				));
-				// alice register some domains
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::from(Some(ALICE)),
-					1u8,
-					U256::from(1)
-				));
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::from(Some(ALICE)),
-					2u8,
-					U256::from(2)
-				));
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::from(Some(ALICE)),
-					3u8,
-					U256::from(3)
-				));
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				let proposals = vec![Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 1,
+					resource_id: NativeResourceId::get(),
+					data: vec![1u8, 2u8, 3u8],
+				}];
 
-				// pause all
-				assert_ok!(SygmaBridge::pause_bridge(Some(ALICE).into(), 1));
-				assert_ok!(SygmaBridge::pause_bridge(Some(ALICE).into(), 2));
-				assert_ok!(SygmaBridge::pause_bridge(Some(ALICE).into(), 3));
+				// domain built from config is used by default
+				assert_eq!(
+					SygmaBridge::eip712_domain(),
+					EIP712DomainParams {
+						name: b"Bridge".to_vec().try_into().unwrap(),
+						version: b"3.1.0".to_vec().try_into().unwrap(),
+						chain_id: EIP712ChainID::get(),
+						verifying_contract: DestVerifyingContractAddress::get(),
+						salt: None,
+					}
+				);
+				let old_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
 
-				// double check if they are all paused
-				assert!(SygmaBridge::is_paused(1));
-				assert!(SygmaBridge::is_paused(2));
-				assert!(SygmaBridge::is_paused(3));
+				// set domain with unauthorized account should fail
+				assert_noop!(
+					SygmaBridge::set_eip712_domain(
+						Some(ALICE).into(),
+						b"Bridge".to_vec(),
+						b"3.2.0".to_vec(),
+						U256::from(1),
+						DestVerifyingContractAddress::get(),
+						None
+					),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				// name too long should fail
+				assert_noop!(
+					SygmaBridge::set_eip712_domain(
+						Origin::root(),
+						vec![1u8; 65],
+						b"3.2.0".to_vec(),
+						U256::from(1),
+						DestVerifyingContractAddress::get(),
+						None
+					),
+					bridge::Error::<Runtime>::InvalidEIP712Domain
+				);
 
-				SygmaBridge::unpause_all_domains();
+				let domain = EIP712DomainParams {
+					name: b"Bridge".to_vec().try_into().unwrap(),
+					version: b"3.2.0".to_vec().try_into().unwrap(),
+					chain_id: U256::from(1),
+					verifying_contract: DestVerifyingContractAddress::get(),
+					salt: Some([1u8; 32]),
+				};
+				assert_ok!(SygmaBridge::set_eip712_domain(
+					Origin::root(),
+					b"Bridge".to_vec(),
+					b"3.2.0".to_vec(),
+					U256::from(1),
+					DestVerifyingContractAddress::get(),
+					Some([1u8; 32])
+				));
+				assert_eq!(SygmaBridge::eip712_domain(), domain);
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::EIP712DomainSet {
+					sender: [0u8; 32].into(),
+					domain,
+				})]);
 
-				// all domains should be unpaused now
-				assert!(!SygmaBridge::is_paused(1));
-				assert!(!SygmaBridge::is_paused(2));
-				assert!(!SygmaBridge::is_paused(3));
+				// messages signed in the previous domain are no longer accepted
+				let new_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				assert_ne!(old_message, new_message);
+				assert!(!SygmaBridge::verify_by_mpc_address(
+					new_message,
+					pair.sign_prehashed(&old_message).encode()
+				));
+				assert!(SygmaBridge::verify_by_mpc_address(
+					new_message,
+					pair.sign_prehashed(&new_message).encode()
+				));
 			})
 		}
 
 		#[test]
-		fn setup_order_test() {
+		fn threshold_signature_should_work() {
 			new_test_ext().execute_with(|| {
-				// Make sure mpc address is not set
-				let default_addr: MpcAddress = MpcAddress::default();
-				assert_eq!(MpcAddr::<Runtime>::get(), default_addr);
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				let signer_pairs: Vec<ecdsa::Pair> =
+					(0..3).map(|_| ecdsa::Pair::generate().0).collect();
+				let signers: Vec<MpcAddress> = signer_pairs
+					.iter()
+					.map(|pair| MpcAddress(pair.public().to_eth_address().unwrap()))
+					.collect();
+				let (evil_pair, _): (ecdsa::Pair, _) = Pair::generate();
 
-				// Grant ALICE the access admin extrinsics
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"register_domain".to_vec(),
-					ALICE
-				));
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"unregister_domain".to_vec(),
-					ALICE
-				));
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"pause_bridge".to_vec(),
-					ALICE
+				// set signer set with unauthorized account should fail
+				assert_noop!(
+					SygmaBridge::set_signer_set(Some(ALICE).into(), signers.clone(), 2),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				// threshold out of range, duplicated, empty or too many signers should fail
+				assert_noop!(
+					SygmaBridge::set_signer_set(Origin::root(), signers.clone(), 0),
+					bridge::Error::<Runtime>::InvalidSignerSet
+				);
+				assert_noop!(
+					SygmaBridge::set_signer_set(Origin::root(), signers.clone(), 4),
+					bridge::Error::<Runtime>::InvalidSignerSet
+				);
+				assert_noop!(
+					SygmaBridge::set_signer_set(
+						Origin::root(),
+						vec![signers[0], signers[1], signers[0]],
+						2
+					),
+					bridge::Error::<Runtime>::InvalidSignerSet
+				);
+				assert_noop!(
+					SygmaBridge::set_signer_set(
+						Origin::root(),
+						vec![signers[0], MpcAddress::default()],
+						2
+					),
+					bridge::Error::<Runtime>::InvalidSignerSet
+				);
+				assert_noop!(
+					SygmaBridge::set_signer_set(
+						Origin::root(),
+						(1..=17u8).map(|i| MpcAddress([i; 20])).collect(),
+						2
+					),
+					bridge::Error::<Runtime>::InvalidSignerSet
+				);
+				assert_noop!(
+					SygmaBridge::clear_signer_set(Origin::root()),
+					bridge::Error::<Runtime>::SignerSetNotSet
+				);
+
+				assert_ok!(SygmaBridge::set_signer_set(Origin::root(), signers.clone(), 2));
+				assert_eq!(
+					SygmaBridge::signer_set().map(|signer_set| signer_set.threshold),
+					Some(2)
+				);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::SignerSetUpdated {
+						sender: [0u8; 32].into(),
+						signers: signers.clone(),
+						threshold: 2,
+					},
+				)]);
+
+				let proposals = vec![Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce: 1,
+					resource_id: NativeResourceId::get(),
+					data: vec![1u8, 2u8, 3u8],
+				}];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				let sign = |pairs: Vec<&ecdsa::Pair>| -> Vec<u8> {
+					pairs
+						.iter()
+						.flat_map(|pair| pair.sign_prehashed(&final_message).encode())
+						.collect()
+				};
+
+				// signatures below threshold, duplicated or from unknown signer are rejected
+				assert!(!SygmaBridge::verify_by_mpc_address(final_message, sign(vec![&pair])));
+				assert!(!SygmaBridge::verify_by_mpc_address(
+					final_message,
+					sign(vec![&signer_pairs[0]])
 				));
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"unpause_bridge".to_vec(),
-					ALICE
+				assert!(!SygmaBridge::verify_by_mpc_address(
+					final_message,
+					sign(vec![&signer_pairs[0], &signer_pairs[0]])
 				));
-				assert_ok!(AccessSegregator::grant_access(
-					Origin::root(),
-					BridgePalletIndex::get(),
-					b"retry".to_vec(),
-					ALICE
+				assert!(!SygmaBridge::verify_by_mpc_address(
+					final_message,
+					sign(vec![&signer_pairs[0], &evil_pair])
 				));
-
-				// alice setup bridges without mpc address setup
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::from(Some(ALICE)),
-					DEST_DOMAIN_ID,
-					U256::from(1)
+				assert!(!SygmaBridge::verify_by_mpc_address(
+					final_message,
+					[sign(vec![&signer_pairs[0], &signer_pairs[1]]), vec![1u8]].concat()
 				));
-				assert_ok!(SygmaBridge::unregister_domain(
-					Origin::from(Some(ALICE)),
-					DEST_DOMAIN_ID,
-					U256::from(1)
+				assert!(SygmaBridge::verify_by_mpc_address(
+					final_message,
+					sign(vec![&signer_pairs[1], &signer_pairs[2]])
 				));
-				// register it back
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::from(Some(ALICE)),
-					DEST_DOMAIN_ID,
-					U256::from(1)
+				assert!(SygmaBridge::verify_by_mpc_address(
+					final_message,
+					sign(vec![&signer_pairs[0], &signer_pairs[1], &signer_pairs[2]])
 				));
-				assert_ok!(SygmaBridge::pause_bridge(Origin::from(Some(ALICE)), 1u8));
-				assert_ok!(SygmaBridge::unpause_bridge(Origin::from(Some(ALICE)), 1u8));
-				// pause domain 2 again to see if mpc address setup will unpause it
-				assert_ok!(SygmaBridge::pause_bridge(Origin::from(Some(ALICE)), 1u8));
-
-				// double check if it's paused
-				assert!(SygmaBridge::is_paused(1));
 
-				// retry should not work here, should raise MissingMpcAddress
+				// execute_proposal is verified against the signer set
 				assert_noop!(
-					SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID),
-					bridge::Error::<Runtime>::MissingMpcAddress
-				);
-				// deposit should not work, should raise MissingMpcAddress
-				assert_noop!(
-					SygmaBridge::deposit(
+					SygmaBridge::execute_proposal(
 						Origin::signed(ALICE),
-						Box::new((Concrete(AstrLocation::get()), Fungible(100)).into()),
-						Box::new(MultiLocation {
-							parents: 0,
-							interior: X2(
-								slice_to_generalkey(b"ethereum recipient"),
-								slice_to_generalkey(&[1]),
-							)
-						}),
+						proposals.clone(),
+						sign(vec![&pair])
 					),
-					bridge::Error::<Runtime>::MissingMpcAddress
-				);
-				// proposal execution should not work either, should raise MissingMpcAddress
-				assert_noop!(
-					SygmaBridge::execute_proposal(Origin::signed(ALICE), vec![], vec![]),
-					bridge::Error::<Runtime>::MissingMpcAddress,
+					bridge::Error::<Runtime>::BadMpcSignature
 				);
+				assert_ok!(SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals,
+					sign(vec![&signer_pairs[0], &signer_pairs[2]])
+				));
+
+				// single MPC address mode is restored once the signer set is cleared
+				assert_ok!(SygmaBridge::clear_signer_set(Origin::root()));
+				assert_eq!(SygmaBridge::signer_set(), None);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::SignerSetCleared { sender: [0u8; 32].into() },
+				)]);
+				assert!(SygmaBridge::verify_by_mpc_address(final_message, sign(vec![&pair])));
+				assert!(!SygmaBridge::verify_by_mpc_address(
+					final_message,
+					sign(vec![&signer_pairs[0], &signer_pairs[1]])
+				));
+			})
+		}
 
-				// set mpc address to generated keypair's address
+		#[test]
+		fn feeless_proposal_execution_should_work() {
+			new_test_ext().execute_with(|| {
 				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
 				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-				assert_eq!(MpcAddr::<Runtime>::get(), test_mpc_addr);
-
-				// double check if it's unpause now
-				assert!(!SygmaBridge::is_paused(1));
-
-				// retry again, should work
-				assert_ok!(SygmaBridge::retry(Origin::signed(ALICE), 1234567u128, DEST_DOMAIN_ID));
-				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Retry {
-					deposit_on_block_height: 1234567u128,
-					dest_domain_id: DEST_DOMAIN_ID,
-					sender: ALICE,
-				})]);
-
-				// deposit should work now
-				let fee = 1_000_000_000_000u128;
-				let amount = 200_000_000_000_000u128;
-				assert_ok!(SygmaBasicFeeHandler::set_fee(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee
-				));
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+				let (evil_pair, _): (ecdsa::Pair, _) = Pair::generate();
+				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
+					U256::from(1)
 				));
-				assert_ok!(SygmaBridge::deposit(
+				// fund the native asset reserve account
+				assert_ok!(Balances::transfer_keep_alive(
 					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+					BridgeAccountNative::get(),
+					400_000_000_000_000
 				));
-				// Check balances
-				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					amount - fee
-				);
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
 
-				// proposal execution should work
-				let valid_native_transfer_proposal = Proposal {
+				// amount is in 18 decimal 0.000200000000000000, will be convert to 12 decimal
+				// 0.000200000000
+				let proposal = |deposit_nonce| Proposal {
 					origin_domain_id: DEST_DOMAIN_ID,
-					deposit_nonce: 1,
+					deposit_nonce,
 					resource_id: NativeResourceId::get(),
 					data: SygmaBridge::create_deposit_data(
-						amount,
+						200_000_000_000_000u128,
 						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
 							.encode(),
 					),
 				};
-				let proposals = vec![valid_native_transfer_proposal];
+				let proposals = vec![proposal(1)];
 				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
-				let proposals_with_valid_signature = pair.sign_prehashed(&final_message);
+				let call = |signature: Vec<u8>| bridge::Call::<Runtime>::execute_proposal {
+					proposals: proposals.clone(),
+					signature,
+				};
+				let signature = pair.sign_prehashed(&final_message).encode();
+
+				// unsigned execution is invalid until mpc address is set
+				assert_eq!(
+					SygmaBridge::validate_unsigned(
+						TransactionSource::External,
+						&call(signature.clone())
+					),
+					InvalidTransaction::Call.into()
+				);
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+
+				// invalid signature is rejected before entering a block
+				assert_eq!(
+					SygmaBridge::validate_unsigned(
+						TransactionSource::External,
+						&call(evil_pair.sign_prehashed(&final_message).encode())
+					),
+					InvalidTransaction::BadProof.into()
+				);
+				let valid_transaction = SygmaBridge::validate_unsigned(
+					TransactionSource::External,
+					&call(signature.clone()),
+				)
+				.unwrap();
+				assert_eq!(valid_transaction.priority, UnsignedPriority::get());
+
+				// batch is executed as an unsigned transaction
 				assert_ok!(SygmaBridge::execute_proposal(
-					Origin::signed(ALICE),
-					proposals,
-					proposals_with_valid_signature.encode(),
+					Origin::none(),
+					proposals.clone(),
+					signature.clone()
 				));
-				// check native asset balance
-				// proposal amount is in 18 decimal 0.000200000000000000, will be convert to 12
-				// decimal 0.000200000000(200000000) because native asset is defined in 12 decimal
-				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE + 200000000);
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert_eq!(Balances::free_balance(&BOB), ENDOWED_BALANCE + 200_000_000);
+
+				// replayed batch is neither valid as unsigned transaction nor free
+				assert_eq!(
+					SygmaBridge::validate_unsigned(
+						TransactionSource::External,
+						&call(signature.clone())
+					),
+					InvalidTransaction::Stale.into()
+				);
+				let post_info = SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals.clone(),
+					signature,
+				)
+				.unwrap();
+				assert_eq!(post_info.pays_fee, Pays::Yes);
+
+				// signed batch delivered for the first time is free, even if it fails to execute
+				let proposals = vec![proposal(2)];
+				let final_message = SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+				let signature = pair.sign_prehashed(&final_message).encode();
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				let post_info = SygmaBridge::execute_proposal(
+					Origin::signed(ALICE),
+					proposals.clone(),
+					signature.clone(),
+				)
+				.unwrap();
+				assert_eq!(post_info.pays_fee, Pays::No);
+				assert!(FailedProposals::<Runtime>::contains_key(DEST_DOMAIN_ID, 2));
+
+				// failed proposal is re-executed through execute_failed_proposal rather than
+				// replaying the batch for free
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_eq!(
+					SygmaBridge::validate_unsigned(
+						TransactionSource::External,
+						&bridge::Call::<Runtime>::execute_proposal {
+							proposals: proposals.clone(),
+							signature: signature.clone(),
+						}
+					),
+					InvalidTransaction::Stale.into()
+				);
+				let post_info =
+					SygmaBridge::execute_proposal(Origin::signed(ALICE), proposals, signature)
+						.unwrap();
+				assert_eq!(post_info.pays_fee, Pays::Yes);
+				assert!(SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
 			})
 		}
 
 		#[test]
-		fn deposit_native_asset_with_percentage_fee() {
+		fn deposit_with_fee_asset_should_work() {
 			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
-
-				// test cases
-				let fee_rate_1 = 500u32; // 5%
-				let fee_rate_2 = 10000u32; // 100%
-				let fee_rate_3 = 9999u32; // 99.99%
-				let fee_rate_4 = 0u32; // 0%
-				let fee_rate_5 = 15000u32; // 150%
+				DepositRecordRetention::set(2);
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				let fee = 1_000_000_000_000u128; // 1 native token with 12 decimals
+				let amount = 200u128;
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[1]),
+					),
+				};
 
 				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
 				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::PercentageFeeHandler,
+					Box::new(UsdtLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
 				));
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
 
-				// test 5%
-				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+				// Register foreign asset (USDT) with asset id 0 and mint some to ALICE
+				assert_ok!(<pallet_assets::pallet::Pallet<Runtime> as FungibleCerate<
+					<Runtime as frame_system::Config>::AccountId,
+				>>::create(UsdtAssetId::get(), ASSET_OWNER, true, 1,));
+				assert_ok!(Assets::mint(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(0),
+					ALICE,
+					ENDOWED_BALANCE,
+				));
+
+				// fee of USDT transfer can not be paid in native asset yet
+				assert_noop!(
+					SygmaBridge::deposit_with_fee_asset(
+						Origin::signed(ALICE),
+						Box::new((Concrete(UsdtLocation::get()), Fungible(amount)).into()),
+						Box::new(dest),
+						Box::new(NativeLocation::get().into()),
+					),
+					bridge::Error::<Runtime>::MissingFeeConfig
+				);
+
+				assert_ok!(SygmaBasicFeeHandler::set_fee_in_asset(
 					Origin::root(),
 					DEST_DOMAIN_ID,
+					Box::new(UsdtLocation::get().into()),
 					Box::new(NativeLocation::get().into()),
-					fee_rate_1,
-					0u128,
-					1_000_000_000_000_000u128
+					fee
 				));
-				assert_ok!(SygmaBridge::deposit(
+				assert_ok!(SygmaBridge::deposit_with_fee_asset(
 					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+					Box::new((Concrete(UsdtLocation::get()), Fungible(amount)).into()),
+					Box::new(dest),
+					Box::new(NativeLocation::get().into()),
 				));
-				// Check balances of Alice after deposit 200 native token
-				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
-				// Check reserved native token
+
+				// full amount of USDT is bridged and the fee is paid in native asset
+				let usdt_reserved_account = AccountId::new(
+					SygmaBridge::get_token_reserved_account(&UsdtLocation::get().into()).unwrap(),
+				);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), ENDOWED_BALANCE - amount);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &usdt_reserved_account), amount);
+				assert_eq!(Assets::balance(UsdtAssetId::get(), TreasuryAccount::get()), 0);
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - fee);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
 				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					190_000_000_000_000u128
+					SygmaBridge::deposit_fee_assets(DEST_DOMAIN_ID, 0),
+					Some(NativeLocation::get().into())
 				);
-				// Check fee collected
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 10_000_000_000_000u128);
-				// Check event
-				let final_amount_in_deposit_event_1 = 190_000_000_000_000_000_000; // 200 cut 5% then adjust to 18 decimals
+				assert_eq!(SygmaBridge::deposit_records(DEST_DOMAIN_ID, 0).unwrap().fee, fee);
+
 				assert_events(vec![
 					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
 						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: NativeResourceId::get(),
+						resource_id: UsdtResourceId::get(),
 						deposit_nonce: 0,
 						sender: ALICE,
 						transfer_type: TransferType::FungibleTransfer,
 						deposit_data: SygmaBridge::create_deposit_data(
-							final_amount_in_deposit_event_1,
+							amount,
 							b"ethereum recipient".to_vec(),
 						),
 						handler_response: vec![],
@@ -3067,455 +8212,697 @@ pub mod pallet {
 					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
 						fee_payer: ALICE,
 						dest_domain_id: DEST_DOMAIN_ID,
-						resource_id: NativeResourceId::get(),
-						fee_amount: 10_000_000_000_000u128,
+						resource_id: UsdtResourceId::get(),
+						fee_amount: fee,
 						fee_asset_id: NativeLocation::get().into(),
 					}),
 				]);
 
-				// test 100%
-				// should not work because 100% is out of fee rate
-				assert_noop!(
-					SygmaPercentageFeeHandler::set_fee_rate(
+				// fee is refunded in the asset it was paid in
+				let final_message =
+					SygmaBridge::construct_ecdsa_signing_refund_data(DEST_DOMAIN_ID, 0, true);
+				assert_ok!(SygmaBridge::refund_deposit(
+					Origin::signed(BOB),
+					DEST_DOMAIN_ID,
+					0,
+					true,
+					pair.sign_prehashed(&final_message).encode()
+				));
+				assert_eq!(Assets::balance(UsdtAssetId::get(), &ALICE), ENDOWED_BALANCE);
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 0);
+				assert!(!DepositFeeAssets::<Runtime>::contains_key(DEST_DOMAIN_ID, 0));
+			})
+		}
+
+		#[test]
+		fn deposit_batch_should_work() {
+			new_test_ext().execute_with(|| {
+				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
+				let another_dest_domain_id: DomainID = 2;
+				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+				let dest = |domain_id: DomainID| MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[domain_id]),
+					),
+				};
+				let native_asset: MultiAsset = (Concrete(NativeLocation::get()), amount).into();
+				let unbounded_asset: MultiAsset =
+					(Concrete(MultiLocation::new(1, X1(GeneralIndex(123)))), amount).into();
+
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				for domain_id in [DEST_DOMAIN_ID, another_dest_domain_id] {
+					assert_ok!(SygmaBridge::register_domain(
 						Origin::root(),
-						DEST_DOMAIN_ID,
+						domain_id,
+						U256::from(domain_id)
+					));
+					assert_ok!(SygmaBasicFeeHandler::set_fee(
+						Origin::root(),
+						domain_id,
 						Box::new(NativeLocation::get().into()),
-						fee_rate_2,
-						0u128,
-						1_000_000_000_000_000u128
+						fee
+					));
+					assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+						Origin::root(),
+						domain_id,
+						Box::new(NativeLocation::get().into()),
+						FeeHandlerType::BasicFeeHandler,
+					));
+				}
+
+				// empty batch should fail
+				assert_noop!(
+					SygmaBridge::deposit_batch(
+						Origin::signed(ALICE),
+						vec![],
+						DepositBatchMode::AllOrNothing
+					),
+					bridge::Error::<Runtime>::InvalidDepositBatch
+				);
+				// too large batch should fail
+				assert_noop!(
+					SygmaBridge::deposit_batch(
+						Origin::signed(ALICE),
+						vec![
+							(native_asset.clone(), dest(DEST_DOMAIN_ID));
+							bridge::MAX_DEPOSIT_BATCH_LENGTH as usize + 1
+						],
+						DepositBatchMode::AllOrNothing
+					),
+					bridge::Error::<Runtime>::InvalidDepositBatch
+				);
+				// the whole batch is reverted if one transfer fails in all-or-nothing mode
+				assert_noop!(
+					SygmaBridge::deposit_batch(
+						Origin::signed(ALICE),
+						vec![
+							(native_asset.clone(), dest(DEST_DOMAIN_ID)),
+							(unbounded_asset.clone(), dest(DEST_DOMAIN_ID)),
+						],
+						DepositBatchMode::AllOrNothing
+					),
+					bridge::Error::<Runtime>::AssetNotBound
+				);
+
+				assert_ok!(SygmaBridge::deposit_batch(
+					Origin::signed(ALICE),
+					vec![
+						(native_asset.clone(), dest(DEST_DOMAIN_ID)),
+						(native_asset.clone(), dest(another_dest_domain_id)),
+						(native_asset.clone(), dest(DEST_DOMAIN_ID)),
+					],
+					DepositBatchMode::AllOrNothing
+				));
+				assert_eq!(DepositCounts::<Runtime>::get(DEST_DOMAIN_ID), 2);
+				assert_eq!(DepositCounts::<Runtime>::get(another_dest_domain_id), 1);
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount * 3);
+				assert_eq!(Balances::free_balance(BridgeAccountNative::get()), (amount - fee) * 3);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee * 3);
+
+				// the failed transfer is skipped in best-effort mode
+				assert_ok!(SygmaBridge::deposit_batch(
+					Origin::signed(ALICE),
+					vec![
+						(unbounded_asset, dest(DEST_DOMAIN_ID)),
+						(native_asset, dest(another_dest_domain_id)),
+					],
+					DepositBatchMode::BestEffort
+				));
+				assert_eq!(DepositCounts::<Runtime>::get(DEST_DOMAIN_ID), 2);
+				assert_eq!(DepositCounts::<Runtime>::get(another_dest_domain_id), 2);
+				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount * 4);
+				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee * 4);
+
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::BatchDepositFailed {
+						sender: ALICE,
+						index: 0,
+						error: b"AssetNotBound".to_vec(),
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::Deposit {
+						dest_domain_id: another_dest_domain_id,
+						resource_id: NativeResourceId::get(),
+						deposit_nonce: 1,
+						sender: ALICE,
+						transfer_type: TransferType::FungibleTransfer,
+						deposit_data: SygmaBridge::create_deposit_data(
+							(amount - fee) * 1_000_000, // adjust to 18 decimals
+							b"ethereum recipient".to_vec(),
+						),
+						handler_response: vec![],
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FeeCollected {
+						fee_payer: ALICE,
+						dest_domain_id: another_dest_domain_id,
+						resource_id: NativeResourceId::get(),
+						fee_amount: fee,
+						fee_asset_id: NativeLocation::get().into(),
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn circuit_breaker_should_work() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
+				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+				let converted_amount = 199_000_000_000_000_000_000u128; // 200 - 1 in 18 decimals
+				let threshold = 300_000_000_000_000_000_000u128; // 300 in 18 decimals
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[DEST_DOMAIN_ID]),
 					),
-					sygma_percentage_feehandler::Error::<Runtime>::FeeRateOutOfRange
-				);
+				};
+				let deposit = || {
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(dest),
+					)
+				};
 
-				// test 99.99%
-				// override 5% to 99.99%
-				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					Box::new(NativeLocation::get().into()),
-					fee_rate_3,
-					0u128,
-					1_000_000_000_000_000u128
-				));
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+					fee
 				));
-				// Check reserved native token, should increase by 0.02 to 190.020000000000
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					190_020_000_000_000u128
-				);
-				// Check fee collected, should increase by 199.98 to 209.980000000000
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 209_980_000_000_000u128);
-
-				// test 0%
-				// override 99.99% to 0%
-				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					Box::new(NativeLocation::get().into()),
-					fee_rate_4,
-					0u128,
-					1_000_000_000_000_000u128
-				));
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+					FeeHandlerType::BasicFeeHandler,
 				));
-				// Check reserved native token, should increase by 200 to 390.020000000000
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					390_020_000_000_000u128
-				);
-				// Check fee collected, should increase by 0 to 209.980000000000
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 209_980_000_000_000u128);
 
-				// test 150%
-				// should not work because 150% is out of fee rate
+				// domain must be registered and window can not be zero
 				assert_noop!(
-					SygmaPercentageFeeHandler::set_fee_rate(
+					SygmaBridge::set_circuit_breaker(
 						Origin::root(),
 						DEST_DOMAIN_ID,
-						Box::new(NativeLocation::get().into()),
-						fee_rate_5,
-						0u128,
-						1_000_000_000_000_000u128
+						10,
+						threshold,
+						threshold
 					),
-					sygma_percentage_feehandler::Error::<Runtime>::FeeRateOutOfRange
+					bridge::Error::<Runtime>::DestDomainNotSupported
 				);
-
-				// Check reserved native token, should remain as 390.020000000000
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					390_020_000_000_000u128
+				assert_ok!(SygmaBridge::register_domain(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					U256::from(1)
+				));
+				assert_noop!(
+					SygmaBridge::set_circuit_breaker(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						0,
+						threshold,
+						threshold
+					),
+					bridge::Error::<Runtime>::InvalidCircuitBreaker
 				);
-				// Check fee collected, should remain as 209.980000000000
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 209_980_000_000_000u128);
-
-				// test fee bound: fee rate 5%
-				let fee_lower_bound = 100_000_000_000_000u128; // 100
-				let fee_upper_bound = 1_000_000_000_000_000u128;
-				// 1000
-				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
+				// only admin can set circuit breaker
+				assert_noop!(
+					SygmaBridge::set_circuit_breaker(
+						Origin::signed(ALICE),
+						DEST_DOMAIN_ID,
+						10,
+						threshold,
+						threshold
+					),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_ok!(SygmaBridge::set_circuit_breaker(
 					Origin::root(),
 					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					fee_rate_1,
-					fee_lower_bound,
-					fee_upper_bound
+					10,
+					threshold,
+					threshold
 				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::CircuitBreakerSet {
+						sender: [0u8; 32].into(),
+						domain_id: DEST_DOMAIN_ID,
+						window: 10,
+						max_inbound: threshold,
+						max_outbound: threshold,
+					},
+				)]);
 
-				// with higher fee lower bound
-				// 5% fee of 200 token should be 10 but fee lower bound is 100, so fee is 100 now
-				assert_ok!(SygmaBridge::deposit(
+				// the deposit exceeding the outbound threshold is not made and pauses the domain
+				assert_ok!(deposit());
+				assert_eq!(
+					SygmaBridge::circuit_breaker_volumes(DEST_DOMAIN_ID),
+					Some(CircuitBreakerVolume {
+						window_start: 1,
+						inbound: 0,
+						outbound: converted_amount
+					})
+				);
+				let alice_balance = Balances::free_balance(ALICE);
+				assert_ok!(deposit());
+				assert!(SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
+				assert_eq!(DepositCounts::<Runtime>::get(DEST_DOMAIN_ID), 1);
+				assert_eq!(Balances::free_balance(ALICE), alice_balance);
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::CircuitBreakerTripped {
+						domain_id: DEST_DOMAIN_ID,
+						reason: CircuitBreakerTripReason::OutboundVolumeExceeded,
+					},
+				)]);
+				assert_noop!(deposit(), bridge::Error::<Runtime>::BridgePaused);
+
+				// unpausing starts a new window, and so does the end of the window
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_eq!(SygmaBridge::circuit_breaker_volumes(DEST_DOMAIN_ID), None);
+				for block_number in [11, 21, 31] {
+					System::set_block_number(block_number);
+					assert_ok!(deposit());
+					assert_eq!(
+						SygmaBridge::circuit_breaker_volumes(DEST_DOMAIN_ID),
+						Some(CircuitBreakerVolume {
+							window_start: block_number,
+							inbound: 0,
+							outbound: converted_amount
+						})
+					);
+				}
+				assert_eq!(Balances::free_balance(BridgeAccountNative::get()), (amount - fee) * 4);
+
+				// the batch with a deposit tripping the circuit breaker is reverted, the trip is
+				// kept
+				System::set_block_number(41);
+				assert_ok!(SygmaBridge::deposit_batch(
 					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
+					vec![
+						((Concrete(NativeLocation::get()), Fungible(amount)).into(), dest),
+						((Concrete(NativeLocation::get()), Fungible(amount)).into(), dest),
+					],
+					DepositBatchMode::AllOrNothing
 				));
-				// Check reserved native token, should increase by 100 to 490.020000000000
+				assert!(SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
+				assert_eq!(DepositCounts::<Runtime>::get(DEST_DOMAIN_ID), 4);
 				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					490_020_000_000_000u128
+					SygmaBridge::circuit_breaker_volumes(DEST_DOMAIN_ID),
+					Some(CircuitBreakerVolume {
+						window_start: 31,
+						inbound: 0,
+						outbound: converted_amount
+					})
 				);
-				// Check fee collected, should increase by 100 to 309.980000000000
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), 309_980_000_000_000u128);
+				assert_eq!(Balances::free_balance(BridgeAccountNative::get()), (amount - fee) * 4);
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
 
-				// with lower fee upper bound
-				// 5% fee of 200000 token should be 10000 but fee upper bound is 1000, so fee is
-				// 1000 now
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new(
-						(Concrete(NativeLocation::get()), Fungible(200_000_000_000_000_000)).into()
+				// amount is in 18 decimals, 200 native tokens
+				let proposal = |deposit_nonce: DepositNonce| Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						200_000_000_000_000_000_000u128,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
 					),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
+				};
+				let execute = |proposals: Vec<Proposal>| {
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode(),
+					)
+				};
+
+				// the proposal exceeding the inbound threshold is kept as failed and pauses the
+				// domain
+				assert_ok!(execute(vec![proposal(1), proposal(2)]));
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert!(FailedProposals::<Runtime>::contains_key(DEST_DOMAIN_ID, 2));
+				assert!(SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::CircuitBreakerTripped {
+						domain_id: DEST_DOMAIN_ID,
+						reason: CircuitBreakerTripReason::InboundVolumeExceeded,
 					}),
-				));
-				// Check reserved native token, should increase by 199000 to 199490.020000000000
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+						error: "CircuitBreakerTripped".as_bytes().to_vec(),
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 2,
+					}),
+				]);
 				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					199_490_020_000_000_000u128
+					Balances::free_balance(BridgeAccountNative::get()),
+					(amount - fee) * 4 - amount
 				);
-				// Check fee collected, should increase by 1000 to 1309.980000000000
+
+				// reserve floor can only be set by admin
+				assert_noop!(
+					SygmaBridge::set_reserve_floor(
+						Origin::signed(ALICE),
+						Box::new(NativeLocation::get().into()),
+						amount * 3
+					),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+				assert_ok!(SygmaBridge::set_reserve_floor(
+					Origin::root(),
+					Box::new(NativeLocation::get().into()),
+					amount * 3
+				));
 				assert_eq!(
-					Balances::free_balance(TreasuryAccount::get()),
-					1_309_980_000_000_000u128
+					SygmaBridge::reserve_floors(AssetId::from(NativeLocation::get())),
+					Some(amount * 3)
 				);
-			})
-		}
 
-		#[test]
-		fn percentage_fee_rate_not_set_for_domain_and_asset() {
-			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+				// the proposal draining the reserve below its floor pauses the domain, unpausing
+				// starts a new window
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_eq!(SygmaBridge::circuit_breaker_volumes(DEST_DOMAIN_ID), None);
+				assert_ok!(execute(vec![proposal(3)]));
+				assert!(!SygmaBridge::is_proposal_executed(3, DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
+				assert_events(vec![
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::CircuitBreakerTripped {
+						domain_id: DEST_DOMAIN_ID,
+						reason: CircuitBreakerTripReason::ReserveBelowFloor {
+							asset_id: NativeLocation::get().into(),
+						},
+					}),
+					RuntimeEvent::SygmaBridge(SygmaBridgeEvent::FailedHandlerExecution {
+						error: "CircuitBreakerTripped".as_bytes().to_vec(),
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 3,
+					}),
+				]);
 
-				assert_ok!(SygmaBridge::register_domain(
-					Origin::root(),
+				// re-executing the failed proposal trips the circuit breaker again, the trip is
+				// kept and so is the failed proposal
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(SygmaBridge::execute_failed_proposal(
+					Origin::signed(ALICE),
 					DEST_DOMAIN_ID,
-					U256::from(1)
+					2,
+					Weight::zero()
 				));
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				assert!(!SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert!(FailedProposals::<Runtime>::contains_key(DEST_DOMAIN_ID, 2));
+				assert!(SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::CircuitBreakerTripped {
+						domain_id: DEST_DOMAIN_ID,
+						reason: CircuitBreakerTripReason::ReserveBelowFloor {
+							asset_id: NativeLocation::get().into(),
+						},
+					},
+				)]);
 
-				// only set fee handler but not set fee rate for domain and asset
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
+				// proposals are executed once the circuit breaker and reserve floor are removed
+				assert_ok!(SygmaBridge::remove_circuit_breaker(Origin::root(), DEST_DOMAIN_ID));
+				assert_noop!(
+					SygmaBridge::remove_circuit_breaker(Origin::root(), DEST_DOMAIN_ID),
+					bridge::Error::<Runtime>::CircuitBreakerNotSet
+				);
+				assert_ok!(SygmaBridge::set_reserve_floor(
 					Origin::root(),
-					DEST_DOMAIN_ID,
 					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::PercentageFeeHandler,
+					0
 				));
-
-				// deposit should not go through because fee rate is not set in storage, so when
-				// get_fee, it returns None
-				assert_noop!(
-					SygmaBridge::deposit(
-						Origin::signed(ALICE),
-						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-						Box::new(MultiLocation {
-							parents: 0,
-							interior: X2(
-								slice_to_generalkey(b"ethereum recipient"),
-								slice_to_generalkey(&[1]),
-							)
-						}),
-					),
-					bridge::Error::<Runtime>::MissingFeeConfig
-				);
+				assert_eq!(SygmaBridge::reserve_floors(AssetId::from(NativeLocation::get())), None);
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert_ok!(SygmaBridge::execute_failed_proposal(
+					Origin::signed(ALICE),
+					DEST_DOMAIN_ID,
+					2,
+					Weight::zero()
+				));
+				assert_ok!(execute(vec![proposal(3)]));
+				assert!(SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert!(SygmaBridge::is_proposal_executed(3, DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
 			})
 		}
 
 		#[test]
-		fn deposit_native_asset_with_percentage_fee_override_basic_fee_handler() {
+		fn directional_and_resource_pause_should_work() {
 			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let test_mpc_addr: MpcAddress = MpcAddress(pair.public().to_eth_address().unwrap());
 				let fee = 1_000_000_000_000u128; // 1 with 12 decimals
+				let amount = 200_000_000_000_000u128; // 200 with 12 decimals
+				let dest = MultiLocation {
+					parents: 0,
+					interior: X2(
+						slice_to_generalkey(b"ethereum recipient"),
+						slice_to_generalkey(&[DEST_DOMAIN_ID]),
+					),
+				};
+				let deposit = || {
+					SygmaBridge::deposit(
+						Origin::signed(ALICE),
+						Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
+						Box::new(dest),
+					)
+				};
+				// amount is in 18 decimals, 100 native tokens
+				let proposal = |deposit_nonce: DepositNonce| Proposal {
+					origin_domain_id: DEST_DOMAIN_ID,
+					deposit_nonce,
+					resource_id: NativeResourceId::get(),
+					data: SygmaBridge::create_deposit_data(
+						100_000_000_000_000_000_000u128,
+						MultiLocation::new(0, X1(AccountId32 { network: None, id: BOB.into() }))
+							.encode(),
+					),
+				};
+				let execute = |proposals: Vec<Proposal>| {
+					let final_message =
+						SygmaBridge::construct_ecdsa_signing_proposals_data(&proposals);
+					SygmaBridge::execute_proposal(
+						Origin::signed(ALICE),
+						proposals,
+						pair.sign_prehashed(&final_message).encode(),
+					)
+				};
 
+				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
 				assert_ok!(SygmaBridge::register_domain(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					U256::from(1)
 				));
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
-
-				// set fee handler with basic fee handler and fixed fee
-				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::BasicFeeHandler,
-				));
 				assert_ok!(SygmaBasicFeeHandler::set_fee(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					Box::new(NativeLocation::get().into()),
 					fee
 				));
-
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
-				));
-				// Check balances
-				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount);
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					amount - fee
-				);
-				assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee);
-
-				// Override Basic fee handler to Percentage fee handler with 5% fee rate
 				assert_ok!(SygmaFeeHandlerRouter::set_fee_handler(
 					Origin::root(),
 					DEST_DOMAIN_ID,
 					Box::new(NativeLocation::get().into()),
-					FeeHandlerType::PercentageFeeHandler,
-				));
-				assert_ok!(SygmaPercentageFeeHandler::set_fee_rate(
-					Origin::root(),
-					DEST_DOMAIN_ID,
-					Box::new(NativeLocation::get().into()),
-					500u32,
-					0u128,
-					1_000_000_000_000_000u128
+					FeeHandlerType::BasicFeeHandler,
 				));
+				assert_ok!(deposit());
 
-				assert_ok!(SygmaBridge::deposit(
-					Origin::signed(ALICE),
-					Box::new((Concrete(NativeLocation::get()), Fungible(amount)).into()),
-					Box::new(MultiLocation {
-						parents: 0,
-						interior: X2(
-							slice_to_generalkey(b"ethereum recipient"),
-							slice_to_generalkey(&[1]),
-						)
-					}),
-				));
-				// Check balances
-				assert_eq!(Balances::free_balance(ALICE), ENDOWED_BALANCE - amount * 2);
-				// Check reserved native token, should increase by 190
-				assert_eq!(
-					Balances::free_balance(AccountId::new(
-						SygmaBridge::get_token_reserved_account(&NativeLocation::get().into())
-							.unwrap()
-					)),
-					amount - fee + 190_000_000_000_000u128
+				// permission test: unauthorized account should not be able to pause a direction
+				assert_noop!(
+					SygmaBridge::pause_bridge_direction(
+						Origin::signed(ALICE),
+						DEST_DOMAIN_ID,
+						TransferDirection::Outbound
+					),
+					bridge::Error::<Runtime>::AccessDenied
 				);
-				// Check fee collected, should increase by 10
-				assert_eq!(
-					Balances::free_balance(TreasuryAccount::get()),
-					fee + 10_000_000_000_000u128
+				assert_noop!(
+					SygmaBridge::pause_bridge_direction(
+						Origin::root(),
+						2,
+						TransferDirection::Outbound
+					),
+					bridge::Error::<Runtime>::DestDomainNotSupported
 				);
-			})
-		}
-
-		#[test]
-		fn pause_all_bridges_test() {
-			new_test_ext().execute_with(|| {
-				let domain_1: DomainID = 1;
-				let domain_2: DomainID = 2;
-				let domain_3: DomainID = 3;
-
-				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_1, U256::from(1)));
-				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_2, U256::from(2)));
-				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_3, U256::from(3)));
-
-				// all registered domains should be unpaused now
-				assert!(!IsPaused::<Runtime>::get(domain_1));
-				assert!(!IsPaused::<Runtime>::get(domain_2));
-				assert!(!IsPaused::<Runtime>::get(domain_3));
 
-				// permission test: unauthorized account should not be able to pause bridge
-				let unauthorized_account = Origin::from(Some(ALICE));
+				// only deposits are stopped while outbound direction is paused
+				assert_ok!(SygmaBridge::pause_bridge_direction(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					TransferDirection::Outbound
+				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::BridgeDirectionPaused {
+						dest_domain_id: DEST_DOMAIN_ID,
+						direction: TransferDirection::Outbound,
+					},
+				)]);
+				assert!(IsPaused::<Runtime>::get(DEST_DOMAIN_ID, TransferDirection::Outbound));
+				assert!(!IsPaused::<Runtime>::get(DEST_DOMAIN_ID, TransferDirection::Inbound));
+				assert_noop!(deposit(), bridge::Error::<Runtime>::BridgePaused);
 				assert_noop!(
-					SygmaBridge::pause_all_bridges(unauthorized_account),
-					bridge::Error::<Runtime>::AccessDenied
+					SygmaBridge::deposit_generic(Origin::signed(ALICE), DEST_DOMAIN_ID, vec![1u8]),
+					bridge::Error::<Runtime>::BridgePaused
 				);
-				// Grant ALICE the access
-				assert_ok!(AccessSegregator::grant_access(
+				assert_ok!(execute(vec![proposal(1)]));
+				assert!(SygmaBridge::is_proposal_executed(1, DEST_DOMAIN_ID));
+
+				assert_ok!(SygmaBridge::unpause_bridge_direction(
 					Origin::root(),
-					BridgePalletIndex::get(),
-					b"pause_all_bridges".to_vec(),
-					ALICE
+					DEST_DOMAIN_ID,
+					TransferDirection::Outbound
 				));
-
-				assert_ok!(SygmaBridge::pause_all_bridges(Origin::signed(ALICE)));
-
-				// all registered domains should be paused now
-				assert!(IsPaused::<Runtime>::get(domain_1));
-				assert!(IsPaused::<Runtime>::get(domain_2));
-				assert!(IsPaused::<Runtime>::get(domain_3));
-
-				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::AllBridgePaused {
-					sender: ALICE,
-				})]);
-			})
-		}
-
-		#[test]
-		fn unpause_all_bridges_test() {
-			new_test_ext().execute_with(|| {
-				let test_mpc_addr: MpcAddress = MpcAddress([1u8; 20]);
-
-				let domain_1: DomainID = 1;
-				let domain_2: DomainID = 2;
-				let domain_3: DomainID = 3;
-
-				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_1, U256::from(1)));
-				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_2, U256::from(2)));
-				assert_ok!(SygmaBridge::register_domain(Origin::root(), domain_3, U256::from(3)));
-
-				// mpc address not setup, should be error
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::BridgeDirectionUnpaused {
+						dest_domain_id: DEST_DOMAIN_ID,
+						direction: TransferDirection::Outbound,
+					},
+				)]);
 				assert_noop!(
-					SygmaBridge::unpause_all_bridges(Origin::root()),
-					bridge::Error::<Runtime>::MissingMpcAddress
+					SygmaBridge::unpause_bridge_direction(
+						Origin::root(),
+						DEST_DOMAIN_ID,
+						TransferDirection::Outbound
+					),
+					bridge::Error::<Runtime>::BridgeUnpaused
 				);
 
-				assert_ok!(SygmaBridge::set_mpc_address(Origin::root(), test_mpc_addr));
+				// only proposal execution is stopped while inbound direction is paused
+				assert_ok!(SygmaBridge::pause_bridge_direction(
+					Origin::root(),
+					DEST_DOMAIN_ID,
+					TransferDirection::Inbound
+				));
+				assert_ok!(deposit());
+				assert_ok!(execute(vec![proposal(2)]));
+				assert!(!SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
+				assert_events(vec![RuntimeEvent::SygmaBridge(
+					SygmaBridgeEvent::FailedHandlerExecution {
+						error: "BridgePaused".as_bytes().to_vec(),
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 2,
+					},
+				)]);
+				// unpause_bridge unpauses both directions
+				assert_ok!(SygmaBridge::unpause_bridge(Origin::root(), DEST_DOMAIN_ID));
+				assert!(!SygmaBridge::is_domain_paused(DEST_DOMAIN_ID));
 
-				// permission test: unauthorized account should not be able to pause bridge
-				let unauthorized_account = Origin::from(Some(ALICE));
+				// permission test: unauthorized account should not be able to pause a resource
 				assert_noop!(
-					SygmaBridge::unpause_all_bridges(unauthorized_account),
+					SygmaBridge::pause_resource(
+						Origin::signed(ALICE),
+						NativeResourceId::get(),
+						TransferDirection::Outbound
+					),
 					bridge::Error::<Runtime>::AccessDenied
 				);
 
-				// Grant ALICE the access
-				assert_ok!(AccessSegregator::grant_access(
+				// deposits of the resource are stopped while its outbound direction is paused
+				assert_ok!(SygmaBridge::pause_resource(
 					Origin::root(),
-					BridgePalletIndex::get(),
-					b"unpause_all_bridges".to_vec(),
-					ALICE
+					NativeResourceId::get(),
+					TransferDirection::Outbound
 				));
+				assert_events(vec![RuntimeEvent::SygmaBridge(SygmaBridgeEvent::ResourcePaused {
+					resource_id: NativeResourceId::get(),
+					direction: TransferDirection::Outbound,
+				})]);
+				assert_noop!(deposit(), bridge::Error::<Runtime>::ResourcePaused);
+				assert_ok!(SygmaBridge::deposit_generic(
+					Origin::signed(ALICE),
+					DEST_DOMAIN_ID,
+					vec![1u8]
+				));
+				assert_ok!(execute(vec![proposal(2)]));
+				assert!(SygmaBridge::is_proposal_executed(2, DEST_DOMAIN_ID));
 
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), domain_1));
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), domain_2));
-				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), domain_3));
-
-				// all registered domains should be paused now
-				assert!(IsPaused::<Runtime>::get(domain_1));
-				assert!(IsPaused::<Runtime>::get(domain_2));
-				assert!(IsPaused::<Runtime>::get(domain_3));
-
-				assert_ok!(SygmaBridge::unpause_all_bridges(Origin::signed(ALICE)));
-
-				// all registered domains should be unpaused now
-				assert!(!IsPaused::<Runtime>::get(domain_1));
-				assert!(!IsPaused::<Runtime>::get(domain_2));
-				assert!(!IsPaused::<Runtime>::get(domain_3));
-
+				// proposals of the resource are stopped while its inbound direction is paused
+				assert_ok!(SygmaBridge::pause_resource(
+					Origin::root(),
+					NativeResourceId::get(),
+					TransferDirection::Inbound
+				));
+				assert_ok!(execute(vec![proposal(3)]));
+				assert!(!SygmaBridge::is_proposal_executed(3, DEST_DOMAIN_ID));
 				assert_events(vec![RuntimeEvent::SygmaBridge(
-					SygmaBridgeEvent::AllBridgeUnpaused { sender: ALICE },
+					SygmaBridgeEvent::FailedHandlerExecution {
+						error: "ResourcePaused".as_bytes().to_vec(),
+						origin_domain_id: DEST_DOMAIN_ID,
+						deposit_nonce: 3,
+					},
 				)]);
+
+				for direction in [TransferDirection::Outbound, TransferDirection::Inbound] {
+					assert_ok!(SygmaBridge::unpause_resource(
+						Origin::root(),
+						NativeResourceId::get(),
+						direction
+					));
+					assert_events(vec![RuntimeEvent::SygmaBridge(
+						SygmaBridgeEvent::ResourceUnpaused {
+							resource_id: NativeResourceId::get(),
+							direction,
+						},
+					)]);
+				}
+				assert_noop!(
+					SygmaBridge::unpause_resource(
+						Origin::root(),
+						NativeResourceId::get(),
+						TransferDirection::Inbound
+					),
+					bridge::Error::<Runtime>::ResourceUnpaused
+				);
+				assert_ok!(deposit());
+				assert_ok!(execute(vec![proposal(3)]));
+				assert!(SygmaBridge::is_proposal_executed(3, DEST_DOMAIN_ID));
 			})
 		}
 
 		#[test]
-		fn deposit_nonce_fix_should_work() {
+		fn timelocked_register_domain_should_work() {
 			new_test_ext().execute_with(|| {
-				// Nonce from source chain start from 1, set first batch of nonce under [1, 63]
-				for nonce in 1..64u64 {
-					SygmaBridge::set_proposal_executed(nonce, 0);
-				}
-				// Nonce 0 should not be set
-				assert!(!SygmaBridge::is_proposal_executed(0, 0));
-				// Nonce 1 should be set
-				assert!(SygmaBridge::is_proposal_executed(1, 0));
-				// Nonce 63 should be set
-				assert!(SygmaBridge::is_proposal_executed(63, 0));
+				let dest_domain_id: DomainID = 2;
+				let dest_chain_id: ChainID = U256::from(2);
 
-				// set second batch of nonce under [64, 127]
-				for nonce in 64..128u64 {
-					SygmaBridge::set_proposal_executed(nonce, 0);
-				}
-				// Nonce 64 should be set
-				assert!(SygmaBridge::is_proposal_executed(64, 0));
-				// Nonce 127 should be set
-				assert!(SygmaBridge::is_proposal_executed(127, 0));
-				// Nonce 128 should not be set
-				assert!(!SygmaBridge::is_proposal_executed(128, 0));
+				// Delay `register_domain` for 10 blocks
+				assert_ok!(AccessSegregator::set_extrinsic_delay(
+					Origin::root(),
+					BridgePalletIndex::get(),
+					b"register_domain".to_vec(),
+					10
+				));
 
-				// set future batch of nonce under [256, 300]
-				for nonce in 256..301u64 {
-					SygmaBridge::set_proposal_executed(nonce, 0);
-				}
-				// Nonce 256 should be set
-				assert!(SygmaBridge::is_proposal_executed(256, 0));
-				// Nonce 300 should be set
-				assert!(SygmaBridge::is_proposal_executed(300, 0));
-				// Nonce 301 should not be set
-				assert!(!SygmaBridge::is_proposal_executed(301, 0));
+				// Calling `register_domain` directly is no longer allowed
+				assert_noop!(
+					SygmaBridge::register_domain(Origin::root(), dest_domain_id, dest_chain_id),
+					bridge::Error::<Runtime>::AccessDenied
+				);
+
+				let call: RuntimeCall =
+					bridge::Call::<Runtime>::register_domain { dest_domain_id, dest_chain_id }
+						.into();
+				let call_weight = call.get_dispatch_info().weight;
+				assert_ok!(AccessSegregator::schedule_action(Origin::root(), Box::new(call)));
+				assert!(!DestDomainIds::<Runtime>::get(dest_domain_id));
+
+				// Domain is registered once the action is executed after the delay
+				System::set_block_number(System::block_number() + 10);
+				assert_ok!(AccessSegregator::execute_action(Origin::signed(BOB), 0, call_weight));
+				assert!(DestDomainIds::<Runtime>::get(dest_domain_id));
+				assert_eq!(DestChainIds::<Runtime>::get(dest_domain_id), Some(dest_chain_id));
+
+				// `pause_bridge` has no delay and stays instant
+				assert_ok!(SygmaBridge::pause_bridge(Origin::root(), dest_domain_id));
+				assert!(IsPaused::<Runtime>::get(dest_domain_id, TransferDirection::Outbound));
 			})
 		}
 	}
@@ -4,13 +4,15 @@
 #[allow(unused_imports)]
 use super::*;
 
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
 #[cfg(feature = "try-runtime")]
 use frame_support::ensure;
 use frame_support::traits::{Get, OnRuntimeUpgrade, StorageVersion};
 use log;
-#[cfg(feature = "try-runtime")]
 use sp_std::vec::Vec;
-use sygma_traits::MpcAddress;
+use sygma_traits::{DomainID, MpcAddress, ResourceId};
+use xcm::latest::AssetId;
 
 const EXPECTED_STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
 #[cfg(feature = "try-runtime")]
@@ -69,3 +71,154 @@ impl<T: Config> OnRuntimeUpgrade for FixMpcAddress<T> {
 		Ok(())
 	}
 }
+
+const RESOURCE_REGISTRY_EXPECTED_STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+#[cfg(feature = "try-runtime")]
+const RESOURCE_REGISTRY_FINAL_STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+/// Seed the on-chain resource registry from the (AssetId, ResourceId) pairs that used to be
+/// configured through the runtime constant `ResourcePairs`.
+pub struct RegisterResourcePairs<T, ResourcePairs>(sp_std::marker::PhantomData<(T, ResourcePairs)>);
+
+impl<T: Config, ResourcePairs: Get<Vec<(AssetId, ResourceId)>>> OnRuntimeUpgrade
+	for RegisterResourcePairs<T, ResourcePairs>
+{
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		if StorageVersion::get::<Pallet<T>>() == RESOURCE_REGISTRY_EXPECTED_STORAGE_VERSION {
+			log::info!("Start sygma bridge resource registry migration");
+
+			let pairs = ResourcePairs::get();
+			for (asset_id, resource_id) in pairs.iter() {
+				AssetResourceIds::<T>::insert(asset_id, resource_id);
+				ResourceAssetIds::<T>::insert(resource_id, asset_id);
+			}
+
+			// Set new storage version to 2
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!("Sygma bridge resource registry migration done👏");
+
+			// two inserts per pair + put
+			T::DbWeight::get().reads_writes(1, pairs.len() as u64 * 2 + 1)
+		} else {
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == RESOURCE_REGISTRY_EXPECTED_STORAGE_VERSION,
+			"Incorrect Sygma bridge storage version in pre migrate"
+		);
+
+		log::info!("Sygma bridge resource registry pre migration check passed👏");
+
+		Ok(Vec::new())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == RESOURCE_REGISTRY_FINAL_STORAGE_VERSION,
+			"Incorrect Sygma bridge storage version in post migrate"
+		);
+
+		for (asset_id, resource_id) in ResourcePairs::get().iter() {
+			ensure!(
+				AssetResourceIds::<T>::get(asset_id) == Some(*resource_id),
+				"Unexpected resource id in post migrate"
+			);
+			ensure!(
+				ResourceAssetIds::<T>::get(resource_id) == Some(*asset_id),
+				"Unexpected asset id in post migrate"
+			);
+		}
+
+		log::info!("Sygma bridge resource registry post migration check passed👏");
+
+		Ok(())
+	}
+}
+
+const PAUSE_DIRECTION_EXPECTED_STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+#[cfg(feature = "try-runtime")]
+const PAUSE_DIRECTION_FINAL_STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
+mod v2 {
+	use super::*;
+	use frame_support::{storage_alias, Twox64Concat};
+
+	/// Pause state keyed by `DomainID` only, before it's split by transfer direction
+	#[storage_alias]
+	pub type IsPaused<T: Config> = StorageMap<Pallet<T>, Twox64Concat, DomainID, bool>;
+}
+
+/// Migrate the pause state keyed by `DomainID` to the one keyed by `DomainID` and
+/// `TransferDirection`, a paused domain stays paused in both directions.
+pub struct MigratePauseDirections<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigratePauseDirections<T> {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		if StorageVersion::get::<Pallet<T>>() == PAUSE_DIRECTION_EXPECTED_STORAGE_VERSION {
+			log::info!("Start sygma bridge pause direction migration");
+
+			// Old keys share the prefix with new ones, so drain them all before inserting
+			let states: Vec<(DomainID, bool)> = v2::IsPaused::<T>::drain().collect();
+			for (domain_id, is_paused) in states.iter() {
+				if *is_paused {
+					IsPaused::<T>::insert(domain_id, TransferDirection::Outbound, true);
+					IsPaused::<T>::insert(domain_id, TransferDirection::Inbound, true);
+				}
+			}
+
+			// Set new storage version to 3
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			log::info!("Sygma bridge pause direction migration done👏");
+
+			// drain + two inserts per domain + put
+			T::DbWeight::get().reads_writes(states.len() as u64 + 1, states.len() as u64 * 3 + 1)
+		} else {
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == PAUSE_DIRECTION_EXPECTED_STORAGE_VERSION,
+			"Incorrect Sygma bridge storage version in pre migrate"
+		);
+
+		let paused_domains: Vec<DomainID> = v2::IsPaused::<T>::iter()
+			.filter_map(|(domain_id, is_paused)| is_paused.then_some(domain_id))
+			.collect();
+
+		log::info!("Sygma bridge pause direction pre migration check passed👏");
+
+		Ok(paused_domains.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == PAUSE_DIRECTION_FINAL_STORAGE_VERSION,
+			"Incorrect Sygma bridge storage version in post migrate"
+		);
+
+		let paused_domains: Vec<DomainID> =
+			Decode::decode(&mut &state[..]).map_err(|_| "Can not decode paused domains")?;
+		for domain_id in paused_domains {
+			ensure!(
+				IsPaused::<T>::get(domain_id, TransferDirection::Outbound)
+					&& IsPaused::<T>::get(domain_id, TransferDirection::Inbound),
+				"Paused domain is unpaused in post migrate"
+			);
+		}
+
+		log::info!("Sygma bridge pause direction post migration check passed👏");
+
+		Ok(())
+	}
+}
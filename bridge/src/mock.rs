@@ -2,10 +2,13 @@
 // SPDX-License-Identifier: LGPL-3.0-only
 
 use crate as sygma_bridge;
-use fixed::{types::extra::U16, FixedU128};
 use frame_support::{
+	dispatch::DispatchResult,
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU32, ContainsPair, PalletInfoAccess},
+	traits::{
+		tokens::nonfungibles::Inspect, AsEnsureOriginWithArg, ConstU32, Contains, ContainsPair,
+		PalletInfoAccess,
+	},
 	PalletId,
 };
 use frame_system::{self as system, EnsureSigned};
@@ -14,21 +17,22 @@ use sp_core::{hash::H256, Get};
 use sp_runtime::traits::AccountIdConversion;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
-	AccountId32, BuildStorage, Perbill,
+	transaction_validity::TransactionPriority,
+	AccountId32, BuildStorage, DispatchError, Perbill,
 };
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::{marker::PhantomData, prelude::*, result};
 
 use sygma_traits::{
-	ChainID, DecimalConverter, DomainID, ExtractDestinationData, ResourceId,
-	VerifyingContractAddress,
+	ChainID, DepositNonce, DomainID, ExtractDestinationData, NonFungibleMetadataHandler,
+	ResourceId, VerifyingContractAddress,
 };
 use xcm::latest::{prelude::*, AssetId as XcmAssetId, MultiLocation};
 use xcm_builder::{
-	AccountId32Aliases, CurrencyAdapter, FungiblesAdapter, IsConcrete, NoChecking, ParentIsPreset,
-	SiblingParachainConvertsVia,
+	AccountId32Aliases, CurrencyAdapter, FungiblesAdapter, IsConcrete, NoChecking,
+	NonFungiblesAdapter, ParentIsPreset, SiblingParachainConvertsVia,
 };
-use xcm_executor::traits::{Error as ExecutionError, MatchesFungibles};
+use xcm_executor::traits::{Error as ExecutionError, MatchesFungibles, MatchesNonFungibles};
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
@@ -42,9 +46,11 @@ frame_support::construct_runtime!(
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
 		AccessSegregator: sygma_access_segregator::{Pallet, Call, Storage, Event<T>} = 4,
 		SygmaBasicFeeHandler: sygma_basic_feehandler::{Pallet, Call, Storage, Event<T>} = 5,
-		SygmaBridge: sygma_bridge::{Pallet, Call, Storage, Event<T>} = 6,
+		SygmaBridge: sygma_bridge::{Pallet, Call, Storage, Event<T>, ValidateUnsigned} = 6,
 		SygmaPercentageFeeHandler: sygma_percentage_feehandler::{Pallet, Call, Storage, Event<T>} = 7,
 		SygmaFeeHandlerRouter: sygma_fee_handler_router::{Pallet, Call, Storage, Event<T>} = 8,
+		SygmaDecimalConverter: sygma_decimal_converter::{Pallet, Call, Storage, Event<T>} = 9,
+		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>} = 10,
 	}
 );
 
@@ -142,6 +148,36 @@ impl pallet_assets::Config for Runtime {
 	type BenchmarkHelper = ();
 }
 
+parameter_types! {
+	pub const CollectionDeposit: Balance = 0;
+	pub const ItemDeposit: Balance = 0;
+	pub const AttributeDepositBase: Balance = 0;
+	pub const DepositPerByte: Balance = 0;
+}
+
+pub type CollectionId = u32;
+pub type ItemId = u32;
+impl pallet_uniques::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = CollectionId;
+	type ItemId = ItemId;
+	type Currency = Balances;
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId32>>;
+	type Locker = ();
+	type CollectionDeposit = CollectionDeposit;
+	type ItemDeposit = ItemDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type AttributeDepositBase = AttributeDepositBase;
+	type DepositPerByte = DepositPerByte;
+	type StringLimit = AssetsStringLimit;
+	type KeyLimit = ConstU32<32>;
+	type ValueLimit = ConstU32<64>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
 impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = ();
@@ -156,11 +192,28 @@ parameter_types! {
 	pub const BridgePalletIndex: u8 = 6;
 	pub const PercentageFeeHandlerPalletIndex: u8 = 7;
 	pub const FeeHandlerRouterPalletIndex: u8 = 8;
+	pub const DecimalConverterPalletIndex: u8 = 9;
 	pub RegisteredExtrinsics: Vec<(u8, Vec<u8>)> = [
 		(AccessSegregatorPalletIndex::get(), b"grant_access".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"grant_access_until".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"revoke_access".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"grant_origin_access".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"revoke_origin_access".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"create_role".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"update_role".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"assign_role".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"unassign_role".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"set_extrinsic_delay".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"cancel_action".to_vec()),
 		(BaiscFeeHandlerPalletIndex::get(), b"set_fee".to_vec()),
+		(BaiscFeeHandlerPalletIndex::get(), b"set_fee_in_asset".to_vec()),
 		(PercentageFeeHandlerPalletIndex::get(), b"set_fee_rate".to_vec()),
+		(PercentageFeeHandlerPalletIndex::get(), b"set_fee_asset_rate".to_vec()),
 		(FeeHandlerRouterPalletIndex::get(), b"set_fee_handler".to_vec()),
+		(FeeHandlerRouterPalletIndex::get(), b"set_discount_tier".to_vec()),
+		(FeeHandlerRouterPalletIndex::get(), b"set_account_discount_tier".to_vec()),
+		(FeeHandlerRouterPalletIndex::get(), b"remove_account_discount_tier".to_vec()),
+		(FeeHandlerRouterPalletIndex::get(), b"set_fee_exemption".to_vec()),
 		(BridgePalletIndex::get(), b"set_mpc_address".to_vec()),
 		(BridgePalletIndex::get(), b"pause_bridge".to_vec()),
 		(BridgePalletIndex::get(), b"unpause_bridge".to_vec()),
@@ -169,6 +222,26 @@ parameter_types! {
 		(BridgePalletIndex::get(), b"retry".to_vec()),
 		(BridgePalletIndex::get(), b"pause_all_bridges".to_vec()),
 		(BridgePalletIndex::get(), b"unpause_all_bridges".to_vec()),
+		(BridgePalletIndex::get(), b"register_resource".to_vec()),
+		(BridgePalletIndex::get(), b"unregister_resource".to_vec()),
+		(BridgePalletIndex::get(), b"schedule_mpc_rotation".to_vec()),
+		(BridgePalletIndex::get(), b"set_transfer_limit".to_vec()),
+		(BridgePalletIndex::get(), b"remove_transfer_limit".to_vec()),
+		(BridgePalletIndex::get(), b"set_eip712_domain".to_vec()),
+		(BridgePalletIndex::get(), b"set_signer_set".to_vec()),
+		(BridgePalletIndex::get(), b"clear_signer_set".to_vec()),
+		(BridgePalletIndex::get(), b"set_circuit_breaker".to_vec()),
+		(BridgePalletIndex::get(), b"remove_circuit_breaker".to_vec()),
+		(BridgePalletIndex::get(), b"set_reserve_floor".to_vec()),
+		(BridgePalletIndex::get(), b"pause_bridge_direction".to_vec()),
+		(BridgePalletIndex::get(), b"unpause_bridge_direction".to_vec()),
+		(BridgePalletIndex::get(), b"pause_resource".to_vec()),
+		(BridgePalletIndex::get(), b"unpause_resource".to_vec()),
+		(BridgePalletIndex::get(), b"set_fee_distribution".to_vec()),
+		(BridgePalletIndex::get(), b"withdraw_fees".to_vec()),
+		(BridgePalletIndex::get(), b"distribute_fees".to_vec()),
+		(DecimalConverterPalletIndex::get(), b"set_decimals".to_vec()),
+		(DecimalConverterPalletIndex::get(), b"remove_decimals".to_vec()),
 	].to_vec();
 }
 
@@ -177,6 +250,11 @@ impl sygma_access_segregator::Config for Runtime {
 	type BridgeCommitteeOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type PalletIndex = AccessSegregatorPalletIndex;
 	type Extrinsics = RegisteredExtrinsics;
+	type RuntimeCall = RuntimeCall;
+	type AccessOriginConverter = sygma_access_segregator::SignedAccessOrigin;
+	type MaxCollectiveGrants = ConstU32<8>;
+	type MaxRolesPerAccount = ConstU32<8>;
+	type PalletsOrigin = OriginCaller;
 	type WeightInfo = sygma_access_segregator::weights::SygmaWeightInfo<Runtime>;
 }
 
@@ -189,6 +267,13 @@ impl sygma_fee_handler_router::Config for Runtime {
 	type WeightInfo = sygma_fee_handler_router::weights::SygmaWeightInfo<Runtime>;
 }
 
+impl sygma_decimal_converter::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type FallbackDecimals = ();
+	type PalletIndex = DecimalConverterPalletIndex;
+	type WeightInfo = sygma_decimal_converter::weights::SygmaWeightInfo<Runtime>;
+}
+
 impl sygma_basic_feehandler::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PalletIndex = BaiscFeeHandlerPalletIndex;
@@ -206,6 +291,8 @@ fn bridge_accounts_generator() -> BTreeMap<XcmAssetId, AccountId32> {
 	account_map.insert(NativeLocation::get().into(), BridgeAccountNative::get());
 	account_map.insert(UsdtLocation::get().into(), BridgeAccountOtherTokens::get());
 	account_map.insert(AstrLocation::get().into(), BridgeAccountOtherTokens::get());
+	account_map.insert(NftLocation::get().into(), BridgeAccountOtherTokens::get());
+	account_map.insert(ForeignNftLocation::get().into(), BridgeAccountOtherTokens::get());
 	account_map
 }
 
@@ -239,12 +326,36 @@ parameter_types! {
 			slice_to_generalkey(b"astr"),
 		),
 	);
+	pub NftCollectionId: CollectionId = 0;
+	pub NftLocation: MultiLocation = MultiLocation::new(
+		1,
+		X3(
+			Parachain(2005),
+			slice_to_generalkey(b"sygma"),
+			slice_to_generalkey(b"nft"),
+		),
+	);
+	pub ForeignNftCollectionId: CollectionId = 1;
+	pub ForeignNftLocation: MultiLocation = MultiLocation::new(
+		1,
+		X3(
+			Parachain(2004),
+			slice_to_generalkey(b"sygma"),
+			slice_to_generalkey(b"nft"),
+		),
+	);
 	pub NativeResourceId: ResourceId = hex_literal::hex!("00e6dfb61a2fb903df487c401663825643bb825d41695e63df8af6162ab145a6");
 	pub UsdtResourceId: ResourceId = hex_literal::hex!("00b14e071ddad0b12be5aca6dffc5f2584ea158d9b0ce73e1437115e97a32a3e");
 	pub AstrResourceId: ResourceId = hex_literal::hex!("4e071db61a2fb903df487c401663825643ba158d9b0ce73e1437163825643bba");
-	pub ResourcePairs: Vec<(XcmAssetId, ResourceId)> = vec![(NativeLocation::get().into(), NativeResourceId::get()), (UsdtLocation::get().into(), UsdtResourceId::get()), (AstrLocation::get().into(), AstrResourceId::get())];
+	pub NftResourceId: ResourceId = hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000600");
+	pub ForeignNftResourceId: ResourceId = hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000700");
+	pub ResourcePairs: Vec<(XcmAssetId, ResourceId)> = vec![(NativeLocation::get().into(), NativeResourceId::get()), (UsdtLocation::get().into(), UsdtResourceId::get()), (AstrLocation::get().into(), AstrResourceId::get()), (NftLocation::get().into(), NftResourceId::get()), (ForeignNftLocation::get().into(), ForeignNftResourceId::get())];
 	pub AssetDecimalPairs: Vec<(XcmAssetId, u8)> = vec![(NativeLocation::get().into(), 12u8), (UsdtLocation::get().into(), 18u8), (AstrLocation::get().into(), 24u8)];
 	pub const SygmaBridgePalletId: PalletId = PalletId(*b"sygma/01");
+	pub const MpcRotationWindow: u64 = 10;
+	pub static DepositRecordRetention: DepositNonce = 0;
+	pub const UnsignedPriority: TransactionPriority = TransactionPriority::max_value();
+	pub GenericResourceId: ResourceId = hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000500");
 }
 
 /// Type for specifying how a `MultiLocation` can be converted into an `AccountId`. This is used
@@ -309,15 +420,93 @@ pub type FungiblesTransactor = FungiblesAdapter<
 	// The account to use for tracking teleports.
 	CheckingAccount,
 >;
+/// A simple non-fungible asset converter that extract the binding relationship between
+/// collection and MultiLocation, And convert asset instance to item
+pub struct SimpleNonFungibleConverter(PhantomData<()>);
+
+impl MatchesNonFungibles<CollectionId, ItemId> for SimpleNonFungibleConverter {
+	fn matches_nonfungibles(
+		a: &MultiAsset,
+	) -> result::Result<(CollectionId, ItemId), ExecutionError> {
+		match (&a.fun, &a.id) {
+			(NonFungible(Index(index)), Concrete(ref id)) => {
+				let item = ItemId::try_from(*index)
+					.map_err(|_| ExecutionError::InstanceConversionFailed)?;
+				if id == &NftLocation::get() {
+					Ok((NftCollectionId::get(), item))
+				} else if id == &ForeignNftLocation::get() {
+					Ok((ForeignNftCollectionId::get(), item))
+				} else {
+					Err(ExecutionError::AssetNotHandled)
+				}
+			},
+			_ => Err(ExecutionError::AssetNotHandled),
+		}
+	}
+}
+
+/// Means for transacting non-fungible assets on this chain.
+pub type NonFungiblesTransactor = NonFungiblesAdapter<
+	// Use this non-fungibles implementation:
+	Uniques,
+	// Use this collection when it is a non-fungible asset matching the given location:
+	SimpleNonFungibleConverter,
+	// Convert an XCM MultiLocation into a local account id:
+	LocationToAccountId,
+	// Our chain's account ID type (we can't get away without mentioning it explicitly):
+	AccountId32,
+	// Disable teleport.
+	NoChecking,
+	// No account for tracking teleports.
+	(),
+>;
+
 /// Means for transacting assets on this chain.
-pub type AssetTransactors = (CurrencyTransactor, FungiblesTransactor);
+pub type AssetTransactors = (CurrencyTransactor, FungiblesTransactor, NonFungiblesTransactor);
+
+/// Collections of `pallet-uniques` that are bridged as non-fungible assets
+pub struct NonFungibleCollections;
+impl Contains<XcmAssetId> for NonFungibleCollections {
+	fn contains(asset_id: &XcmAssetId) -> bool {
+		*asset_id == NftLocation::get().into() || *asset_id == ForeignNftLocation::get().into()
+	}
+}
+
+/// Keep metadata of bridged non-fungible assets as the item metadata of `pallet-uniques`
+pub struct UniquesMetadataHandler;
+impl NonFungibleMetadataHandler for UniquesMetadataHandler {
+	fn metadata(asset: &MultiAsset) -> Vec<u8> {
+		SimpleNonFungibleConverter::matches_nonfungibles(asset)
+			.ok()
+			// Empty attribute key maps to the item metadata
+			.and_then(|(collection, item)| {
+				<Uniques as Inspect<AccountId32>>::attribute(&collection, &item, &[])
+			})
+			.unwrap_or_default()
+	}
+
+	fn set_metadata(asset: &MultiAsset, metadata: Vec<u8>) -> DispatchResult {
+		if metadata.is_empty() {
+			return Ok(());
+		}
+		let (collection, item) = SimpleNonFungibleConverter::matches_nonfungibles(asset)
+			.map_err(|_| DispatchError::Other("non-fungible asset not handled"))?;
+		Uniques::set_metadata(
+			RuntimeOrigin::root(),
+			collection,
+			item,
+			metadata.try_into().map_err(|_| DispatchError::Other("metadata too long"))?,
+			false,
+		)
+	}
+}
 
 pub struct ConcrateSygmaAsset;
 impl ConcrateSygmaAsset {
 	pub fn id(asset: &MultiAsset) -> Option<MultiLocation> {
 		match (&asset.id, &asset.fun) {
 			// So far our native asset is concrete
-			(Concrete(id), Fungible(_)) => Some(*id),
+			(Concrete(id), Fungible(_)) | (Concrete(id), NonFungible(_)) => Some(*id),
 			_ => None,
 		}
 	}
@@ -353,87 +542,11 @@ impl ConcrateSygmaAsset {
 	}
 }
 
-pub struct SygmaDecimalConverter<DecimalPairs>(PhantomData<DecimalPairs>);
-impl<DecimalPairs: Get<Vec<(XcmAssetId, u8)>>> DecimalConverter
-	for SygmaDecimalConverter<DecimalPairs>
-{
-	fn convert_to(asset: &MultiAsset) -> Option<u128> {
-		match (&asset.fun, &asset.id) {
-			(Fungible(amount), _) => {
-				for (asset_id, decimal) in DecimalPairs::get().iter() {
-					if *asset_id == asset.id {
-						return if *decimal == 18 {
-							Some(*amount)
-						} else {
-							type U112F16 = FixedU128<U16>;
-							if *decimal > 18 {
-								let a =
-									U112F16::from_num(10u128.saturating_pow(*decimal as u32 - 18));
-								let b = U112F16::from_num(*amount).checked_div(a);
-								let r: u128 = b.unwrap_or_else(|| U112F16::from_num(0)).to_num();
-								if r == 0 {
-									return None;
-								}
-								Some(r)
-							} else {
-								// Max is 5192296858534827628530496329220095
-								// if source asset decimal is 12, the max amount sending to sygma
-								// relayer is 5192296858534827.628530496329
-								if *amount > U112F16::MAX {
-									return None;
-								}
-								let a =
-									U112F16::from_num(10u128.saturating_pow(18 - *decimal as u32));
-								let b = U112F16::from_num(*amount).saturating_mul(a);
-								Some(b.to_num())
-							}
-						};
-					}
-				}
-				None
-			},
-			_ => None,
-		}
-	}
-
-	fn convert_from(asset: &MultiAsset) -> Option<MultiAsset> {
-		match (&asset.fun, &asset.id) {
-			(Fungible(amount), _) => {
-				for (asset_id, decimal) in DecimalPairs::get().iter() {
-					if *asset_id == asset.id {
-						return if *decimal == 18 {
-							Some((asset.id, *amount).into())
-						} else {
-							type U112F16 = FixedU128<U16>;
-							if *decimal > 18 {
-								// Max is 5192296858534827628530496329220095
-								// if dest asset decimal is 24, the max amount coming from sygma
-								// relayer is 5192296858.534827628530496329
-								if *amount > U112F16::MAX {
-									return None;
-								}
-								let a =
-									U112F16::from_num(10u128.saturating_pow(*decimal as u32 - 18));
-								let b = U112F16::from_num(*amount).saturating_mul(a);
-								let r: u128 = b.to_num();
-								Some((asset.id, r).into())
-							} else {
-								let a =
-									U112F16::from_num(10u128.saturating_pow(18 - *decimal as u32));
-								let b = U112F16::from_num(*amount).checked_div(a);
-								let r: u128 = b.unwrap_or_else(|| U112F16::from_num(0)).to_num();
-								if r == 0 {
-									return None;
-								}
-								Some((asset.id, r).into())
-							}
-						};
-					}
-				}
-				None
-			},
-			_ => None,
-		}
+/// Only allow remark calls to be dispatched by generic proposals
+pub struct GenericCallFilter;
+impl Contains<RuntimeCall> for GenericCallFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(call, RuntimeCall::System(frame_system::Call::remark_with_event { .. }))
 	}
 }
 
@@ -481,12 +594,23 @@ impl sygma_bridge::Config for Runtime {
 	type DestVerifyingContractAddress = DestVerifyingContractAddress;
 	type FeeHandler = SygmaFeeHandlerRouter;
 	type AssetTransactor = AssetTransactors;
-	type ResourcePairs = ResourcePairs;
 	type IsReserve = ReserveChecker;
 	type ExtractDestData = DestinationDataParser;
 	type PalletId = SygmaBridgePalletId;
 	type PalletIndex = BridgePalletIndex;
-	type DecimalConverter = SygmaDecimalConverter<AssetDecimalPairs>;
+	type DecimalConverter = SygmaDecimalConverter;
+	type ReserveBalances = (
+		sygma_bridge::FungibleBalance<AccountId32, Balances, IsConcrete<NativeLocation>>,
+		sygma_bridge::FungiblesBalance<AccountId32, Assets, SimpleForeignAssetConverter>,
+	);
+	type MpcRotationWindow = MpcRotationWindow;
+	type DepositRecordRetention = DepositRecordRetention;
+	type UnsignedPriority = UnsignedPriority;
+	type GenericResourceId = GenericResourceId;
+	type RuntimeCall = RuntimeCall;
+	type GenericCallFilter = GenericCallFilter;
+	type NonFungibleAssets = NonFungibleCollections;
+	type NonFungibleMetadata = UniquesMetadataHandler;
 	type WeightInfo = sygma_bridge::weights::SygmaWeightInfo<Runtime>;
 }
 
@@ -508,6 +632,22 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	}
 	.assimilate_storage(&mut t)
 	.unwrap();
+
+	// bind all supported assets with their resourceID
+	sygma_bridge::GenesisConfig::<Runtime> {
+		resource_pairs: ResourcePairs::get(),
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	// set decimals of all supported assets
+	sygma_decimal_converter::GenesisConfig::<Runtime> {
+		asset_decimals: AssetDecimalPairs::get(),
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
 	let mut ext = sp_io::TestExternalities::new(t);
 	ext.execute_with(|| System::set_block_number(1));
 	ext
@@ -35,9 +35,11 @@ use sp_std::marker::PhantomData;
 /// Weight functions for `sygma_bridge`.
 pub struct SygmaWeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
 	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
-	/// Storage: SygmaBridge IsPaused (r:0 w:1)
+	/// Storage: SygmaBridge IsPaused (r:0 w:2)
 	/// Proof Skipped: SygmaBridge IsPaused (max_values: None, max_size: None, mode: Measured)
 	fn pause_bridge() -> Weight {
 		// Proof Size summary in bytes:
@@ -46,23 +48,29 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 		// Minimum execution time: 12_000_000 picoseconds.
 		Weight::from_parts(12_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 3683))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
 	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
-	/// Storage: SygmaBridge IsPaused (r:1 w:1)
+	/// Storage: SygmaBridge IsPaused (r:2 w:2)
 	/// Proof Skipped: SygmaBridge IsPaused (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge CircuitBreakerVolumes (r:0 w:1)
+	/// Proof: SygmaBridge CircuitBreakerVolumes (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
 	fn unpause_bridge() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `143`
 		//  Estimated: `7216`
-		// Minimum execution time: 14_000_000 picoseconds.
-		Weight::from_parts(14_000_000, 0)
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 7216))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge MpcAddr (r:1 w:1)
 	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: SygmaBridge IsPaused (r:1 w:0)
@@ -74,9 +82,11 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 		// Minimum execution time: 8_000_000 picoseconds.
 		Weight::from_parts(9_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 5034))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge DestDomainIds (r:0 w:1)
 	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge DestChainIds (r:0 w:1)
@@ -88,8 +98,11 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 		// Minimum execution time: 10_000_000 picoseconds.
 		Weight::from_parts(10_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge DestDomainIds (r:1 w:1)
 	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge DestChainIds (r:1 w:1)
@@ -101,7 +114,7 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 		// Minimum execution time: 16_000_000 picoseconds.
 		Weight::from_parts(17_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 7204))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
@@ -118,30 +131,52 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: SygmaBridge DepositCounts (r:1 w:1)
 	/// Proof Skipped: SygmaBridge DepositCounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositRecords (r:1 w:2)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositBlocks (r:2 w:2)
+	/// Proof: SygmaBridge DepositBlocks (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TransferLimits (r:1 w:0)
+	/// Proof: SygmaBridge TransferLimits (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TransferVolumes (r:1 w:1)
+	/// Proof: SygmaBridge TransferVolumes (max_values: None, max_size: Some(686), added: 3161, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositFeeAssets (r:0 w:1)
+	/// Proof: SygmaBridge DepositFeeAssets (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CircuitBreakers (r:1 w:0)
+	/// Proof: SygmaBridge CircuitBreakers (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CircuitBreakerVolumes (r:1 w:1)
+	/// Proof: SygmaBridge CircuitBreakerVolumes (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge IsResourcePaused (r:1 w:0)
+	/// Proof: SygmaBridge IsResourcePaused (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CollectedFees (r:1 w:1)
+	/// Proof: SygmaBridge CollectedFees (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
 	fn deposit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `312`
-		//  Estimated: `26878`
-		// Minimum execution time: 89_000_000 picoseconds.
-		Weight::from_parts(91_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 26878))
-			.saturating_add(T::DbWeight::get().reads(8))
-			.saturating_add(T::DbWeight::get().writes(3))
+		//  Measured:  `877`
+		//  Estimated: `46070`
+		// Minimum execution time: 114_000_000 picoseconds.
+		Weight::from_parts(116_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 46070))
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().writes(11))
 	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
 	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: SygmaBridge IsPaused (r:1 w:0)
 	/// Proof Skipped: SygmaBridge IsPaused (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
 	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositBlocks (r:1 w:0)
+	/// Proof: SygmaBridge DepositBlocks (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
 	fn retry() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `177`
-		//  Estimated: `8946`
-		// Minimum execution time: 16_000_000 picoseconds.
-		Weight::from_parts(17_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 8946))
-			.saturating_add(T::DbWeight::get().reads(3))
+		//  Measured:  `253`
+		//  Estimated: `11454`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 11454))
+			.saturating_add(T::DbWeight::get().reads(5))
 	}
 	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
 	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
@@ -153,23 +188,41 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 	/// Proof Skipped: SygmaBridge UsedNonces (max_values: None, max_size: None, mode: Measured)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TransferLimits (r:1 w:0)
+	/// Proof: SygmaBridge TransferLimits (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TransferVolumes (r:1 w:1)
+	/// Proof: SygmaBridge TransferVolumes (max_values: None, max_size: Some(686), added: 3161, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge FailedProposals (r:1 w:1)
+	/// Proof: SygmaBridge FailedProposals (max_values: None, max_size: Some(2177), added: 4652, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CircuitBreakers (r:1 w:0)
+	/// Proof: SygmaBridge CircuitBreakers (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CircuitBreakerVolumes (r:1 w:1)
+	/// Proof: SygmaBridge CircuitBreakerVolumes (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ReserveFloors (r:1 w:0)
+	/// Proof: SygmaBridge ReserveFloors (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 1000]`.
+	/// Storage: SygmaBridge IsResourcePaused (r:1 w:0)
+	/// Proof: SygmaBridge IsResourcePaused (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
 	fn execute_proposal(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `280`
-		//  Estimated: `16593`
-		// Minimum execution time: 123_000_000 picoseconds.
-		Weight::from_parts(151_050_908, 0)
-			.saturating_add(Weight::from_parts(0, 16593))
-			// Standard Error: 18_882
-			.saturating_add(Weight::from_parts(10_748_102, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(2))
+		//  Measured:  `546`
+		//  Estimated: `31072`
+		// Minimum execution time: 138_000_000 picoseconds.
+		Weight::from_parts(165_208_417, 0)
+			.saturating_add(Weight::from_parts(0, 31072))
+			// Standard Error: 20_117
+			.saturating_add(Weight::from_parts(16_804_166, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
 
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge DestDomainIds (r:3 w:0)
 	/// Proof: SygmaBridge DestDomainIds (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
-	/// Storage: SygmaBridge IsPaused (r:3 w:2)
+	/// Storage: SygmaBridge IsPaused (r:3 w:4)
 	/// Proof: SygmaBridge IsPaused (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
 	fn pause_all_bridges() -> Weight {
 		// Proof Size summary in bytes:
@@ -178,15 +231,17 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 		// Minimum execution time: 82_000_000 picoseconds.
 		Weight::from_parts(84_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 8445))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
 	/// Proof: SygmaBridge MpcAddr (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
 	/// Storage: SygmaBridge DestDomainIds (r:3 w:0)
 	/// Proof: SygmaBridge DestDomainIds (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
-	/// Storage: SygmaBridge IsPaused (r:3 w:2)
+	/// Storage: SygmaBridge IsPaused (r:3 w:4)
 	/// Proof: SygmaBridge IsPaused (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
 	fn unpause_all_bridges() -> Weight {
 		// Proof Size summary in bytes:
@@ -195,7 +250,493 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 		// Minimum execution time: 81_000_000 picoseconds.
 		Weight::from_parts(99_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 8445))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge ResourceAssetIds (r:1 w:1)
+	/// Proof: SygmaBridge ResourceAssetIds (max_values: None, max_size: Some(634), added: 3109, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge AssetResourceIds (r:1 w:1)
+	/// Proof: SygmaBridge AssetResourceIds (max_values: None, max_size: Some(634), added: 3109, mode: MaxEncodedLen)
+	fn register_resource() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `8208`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8208))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge ResourceAssetIds (r:1 w:1)
+	/// Proof: SygmaBridge ResourceAssetIds (max_values: None, max_size: Some(634), added: 3109, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge AssetResourceIds (r:0 w:1)
+	/// Proof: SygmaBridge AssetResourceIds (max_values: None, max_size: Some(634), added: 3109, mode: MaxEncodedLen)
+	fn unregister_resource() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `192`
+		//  Estimated: `4099`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4099))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof: SygmaBridge MpcAddr (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PendingMpcAddr (r:1 w:1)
+	/// Proof: SygmaBridge PendingMpcAddr (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MpcRotationNonce (r:1 w:1)
+	/// Proof: SygmaBridge MpcRotationNonce (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn schedule_mpc_rotation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `138`
+		//  Estimated: `1509`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1509))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof: SygmaBridge MpcAddr (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge MpcRotationNonce (r:1 w:1)
+	/// Proof: SygmaBridge MpcRotationNonce (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge PendingMpcAddr (r:1 w:1)
+	/// Proof: SygmaBridge PendingMpcAddr (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn schedule_mpc_rotation_signed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `138`
+		//  Estimated: `1509`
+		// Minimum execution time: 71_000_000 picoseconds.
+		Weight::from_parts(72_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1509))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof: SygmaBridge MpcAddr (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge IsPaused (r:1 w:0)
+	/// Proof: SygmaBridge IsPaused (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof: SygmaBridge DestDomainIds (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositCounts (r:1 w:1)
+	/// Proof: SygmaBridge DepositCounts (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecords (r:1 w:2)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositBlocks (r:2 w:2)
+	/// Proof: SygmaBridge DepositBlocks (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositFeeAssets (r:0 w:1)
+	/// Proof: SygmaBridge DepositFeeAssets (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge IsResourcePaused (r:1 w:0)
+	/// Proof: SygmaBridge IsResourcePaused (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	fn deposit_generic() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `516`
+		//  Estimated: `3728`
+		// Minimum execution time: 34_000_000 picoseconds.
+		Weight::from_parts(36_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3728))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof: SygmaBridge DestDomainIds (max_values: None, max_size: Some(10), added: 2485, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TransferLimits (r:0 w:1)
+	/// Proof: SygmaBridge TransferLimits (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	fn set_transfer_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141`
+		//  Estimated: `3475`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3475))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge TransferLimits (r:1 w:1)
+	/// Proof: SygmaBridge TransferLimits (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TransferVolumes (r:0 w:2)
+	/// Proof: SygmaBridge TransferVolumes (max_values: None, max_size: Some(686), added: 3161, mode: MaxEncodedLen)
+	fn remove_transfer_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `206`
+		//  Estimated: `4116`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4116))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: SygmaBridge FailedProposals (r:1 w:1)
+	/// Proof: SygmaBridge FailedProposals (max_values: None, max_size: Some(2177), added: 4652, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge IsPaused (r:1 w:0)
+	/// Proof Skipped: SygmaBridge IsPaused (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge UsedNonces (r:1 w:1)
+	/// Proof Skipped: SygmaBridge UsedNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TransferLimits (r:1 w:0)
+	/// Proof: SygmaBridge TransferLimits (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TransferVolumes (r:1 w:1)
+	/// Proof: SygmaBridge TransferVolumes (max_values: None, max_size: Some(686), added: 3161, mode: MaxEncodedLen)
+	fn execute_failed_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `21630`
+		// Minimum execution time: 68_000_000 picoseconds.
+		Weight::from_parts(71_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 21630))
 			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge PendingMpcAddr (r:1 w:0)
+	/// Proof: SygmaBridge PendingMpcAddr (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositRecords (r:1 w:1)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositBlocks (r:1 w:1)
+	/// Proof: SygmaBridge DepositBlocks (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge ResourceAssetIds (r:1 w:0)
+	/// Proof: SygmaBridge ResourceAssetIds (max_values: None, max_size: Some(634), added: 3109, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositFeeAssets (r:1 w:1)
+	/// Proof: SygmaBridge DepositFeeAssets (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CollectedFees (r:1 w:1)
+	/// Proof: SygmaBridge CollectedFees (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	fn refund_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `731`
+		//  Estimated: `20889`
+		// Minimum execution time: 106_000_000 picoseconds.
+		Weight::from_parts(109_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 20889))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge SigningDomain (r:0 w:1)
+	/// Proof: SygmaBridge SigningDomain (max_values: Some(1), max_size: Some(183), added: 678, mode: MaxEncodedLen)
+	fn set_eip712_domain() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge ThresholdSigners (r:0 w:1)
+	/// Proof: SygmaBridge ThresholdSigners (max_values: Some(1), max_size: Some(325), added: 820, mode: MaxEncodedLen)
+	fn set_signer_set() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge ThresholdSigners (r:1 w:1)
+	/// Proof: SygmaBridge ThresholdSigners (max_values: Some(1), max_size: Some(325), added: 820, mode: MaxEncodedLen)
+	fn clear_signer_set() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `363`
+		//  Estimated: `1810`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1810))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaBridge ThresholdSigners (r:1 w:0)
+	/// Proof: SygmaBridge ThresholdSigners (max_values: Some(1), max_size: Some(325), added: 820, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 16]`.
+	fn verify_signatures(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `363`
+		//  Estimated: `1810`
+		// Minimum execution time: 58_000_000 picoseconds.
+		Weight::from_parts(12_514_285, 0)
+			.saturating_add(Weight::from_parts(0, 1810))
+			// Standard Error: 21_377
+			.saturating_add(Weight::from_parts(46_285_714, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge IsPaused (r:1 w:0)
+	/// Proof Skipped: SygmaBridge IsPaused (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaFeeHandlerRouter HandlerType (r:1 w:0)
+	/// Proof Skipped: SygmaFeeHandlerRouter HandlerType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBasicFeeHandler FeeAssetFees (r:1 w:0)
+	/// Proof Skipped: SygmaBasicFeeHandler FeeAssetFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositCounts (r:1 w:1)
+	/// Proof Skipped: SygmaBridge DepositCounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositRecords (r:1 w:2)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositBlocks (r:2 w:2)
+	/// Proof: SygmaBridge DepositBlocks (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TransferLimits (r:1 w:0)
+	/// Proof: SygmaBridge TransferLimits (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TransferVolumes (r:1 w:1)
+	/// Proof: SygmaBridge TransferVolumes (max_values: None, max_size: Some(686), added: 3161, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositFeeAssets (r:0 w:2)
+	/// Proof: SygmaBridge DepositFeeAssets (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CircuitBreakers (r:1 w:0)
+	/// Proof: SygmaBridge CircuitBreakers (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CircuitBreakerVolumes (r:1 w:1)
+	/// Proof: SygmaBridge CircuitBreakerVolumes (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge IsResourcePaused (r:1 w:0)
+	/// Proof: SygmaBridge IsResourcePaused (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CollectedFees (r:1 w:1)
+	/// Proof: SygmaBridge CollectedFees (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	fn deposit_with_fee_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `877`
+		//  Estimated: `48673`
+		// Minimum execution time: 126_000_000 picoseconds.
+		Weight::from_parts(129_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 48673))
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().writes(13))
+	}
+	/// Storage: SygmaBridge MpcAddr (r:1 w:0)
+	/// Proof Skipped: SygmaBridge MpcAddr (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaBridge IsPaused (r:1 w:0)
+	/// Proof Skipped: SygmaBridge IsPaused (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaFeeHandlerRouter HandlerType (r:1 w:0)
+	/// Proof Skipped: SygmaFeeHandlerRouter HandlerType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBasicFeeHandler AssetFees (r:1 w:0)
+	/// Proof Skipped: SygmaBasicFeeHandler AssetFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositCounts (r:1 w:1)
+	/// Proof Skipped: SygmaBridge DepositCounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DepositRecords (r:32 w:33)
+	/// Proof: SygmaBridge DepositRecords (max_values: None, max_size: Some(263), added: 2738, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositBlocks (r:2 w:2)
+	/// Proof: SygmaBridge DepositBlocks (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TransferLimits (r:1 w:0)
+	/// Proof: SygmaBridge TransferLimits (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge TransferVolumes (r:1 w:1)
+	/// Proof: SygmaBridge TransferVolumes (max_values: None, max_size: Some(686), added: 3161, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge DepositFeeAssets (r:0 w:32)
+	/// Proof: SygmaBridge DepositFeeAssets (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CircuitBreakers (r:1 w:0)
+	/// Proof: SygmaBridge CircuitBreakers (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CircuitBreakerVolumes (r:1 w:1)
+	/// Proof: SygmaBridge CircuitBreakerVolumes (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 32]`.
+	/// Storage: SygmaBridge IsResourcePaused (r:1 w:0)
+	/// Proof: SygmaBridge IsResourcePaused (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CollectedFees (r:1 w:1)
+	/// Proof: SygmaBridge CollectedFees (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	fn deposit_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `877 + n * (42 ±0)`
+		//  Estimated: `46070 + n * (2738 ±0)`
+		// Minimum execution time: 117_000_000 picoseconds.
+		Weight::from_parts(50_127_381, 0)
+			.saturating_add(Weight::from_parts(0, 46070))
+			// Standard Error: 22_032
+			.saturating_add(Weight::from_parts(70_418_506, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2738).saturating_mul(n.into()))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge CircuitBreakers (r:0 w:1)
+	/// Proof: SygmaBridge CircuitBreakers (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CircuitBreakerVolumes (r:0 w:1)
+	/// Proof: SygmaBridge CircuitBreakerVolumes (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	fn set_circuit_breaker() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3574`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3574))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge CircuitBreakers (r:1 w:1)
+	/// Proof: SygmaBridge CircuitBreakers (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CircuitBreakerVolumes (r:0 w:1)
+	/// Proof: SygmaBridge CircuitBreakerVolumes (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	fn remove_circuit_breaker() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3523`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3523))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge ReserveFloors (r:0 w:1)
+	/// Proof: SygmaBridge ReserveFloors (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	fn set_reserve_floor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge IsPaused (r:0 w:1)
+	/// Proof Skipped: SygmaBridge IsPaused (max_values: None, max_size: None, mode: Measured)
+	fn pause_bridge_direction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3683`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3683))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge DestDomainIds (r:1 w:0)
+	/// Proof Skipped: SygmaBridge DestDomainIds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge IsPaused (r:1 w:1)
+	/// Proof Skipped: SygmaBridge IsPaused (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge CircuitBreakerVolumes (r:0 w:1)
+	/// Proof: SygmaBridge CircuitBreakerVolumes (max_values: None, max_size: Some(58), added: 2533, mode: MaxEncodedLen)
+	fn unpause_bridge_direction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `152`
+		//  Estimated: `7234`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 7234))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge IsResourcePaused (r:0 w:1)
+	/// Proof: SygmaBridge IsResourcePaused (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	fn pause_resource() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge IsResourcePaused (r:1 w:1)
+	/// Proof: SygmaBridge IsResourcePaused (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	fn unpause_resource() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141`
+		//  Estimated: `3515`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3515))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge FeeDistributionRatios (r:0 w:1)
+	/// Proof: SygmaBridge FeeDistributionRatios (max_values: Some(1), max_size: Some(578), added: 1073, mode: MaxEncodedLen)
+	fn set_fee_distribution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge CollectedFees (r:1 w:1)
+	/// Proof: SygmaBridge CollectedFees (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_fees() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `357`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(54_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaBridge FeeDistributionRatios (r:1 w:0)
+	/// Proof: SygmaBridge FeeDistributionRatios (max_values: Some(1), max_size: Some(578), added: 1073, mode: MaxEncodedLen)
+	/// Storage: SygmaBridge CollectedFees (r:1 w:1)
+	/// Proof: SygmaBridge CollectedFees (max_values: None, max_size: Some(651), added: 3126, mode: MaxEncodedLen)
+	/// Storage: System Account (r:17 w:17)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn distribute_fees(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `452 + n * (32 ±0)`
+		//  Estimated: `4116 + n * (2603 ±0)`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(38_212_519, 0)
+			.saturating_add(Weight::from_parts(0, 4116))
+			// Standard Error: 11_238
+			.saturating_add(Weight::from_parts(27_384_106, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
 	}
 }
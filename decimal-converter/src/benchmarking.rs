@@ -0,0 +1,38 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Sygma decimal-converter pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin as SystemOrigin;
+
+use sp_std::{boxed::Box, vec};
+use xcm::latest::prelude::*;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_decimals() {
+		let native_location: MultiLocation = MultiLocation::here();
+
+		#[extrinsic_call]
+		set_decimals(SystemOrigin::Root, Box::new(native_location.into()), 12u8);
+
+		assert_eq!(AssetDecimals::<T>::get::<AssetId>(native_location.into()), Some(12u8));
+	}
+
+	#[benchmark]
+	fn remove_decimals() {
+		let native_location: MultiLocation = MultiLocation::here();
+		AssetDecimals::<T>::insert::<AssetId, u8>(native_location.into(), 12u8);
+
+		#[extrinsic_call]
+		remove_decimals(SystemOrigin::Root, Box::new(native_location.into()));
+
+		assert_eq!(AssetDecimals::<T>::get::<AssetId>(native_location.into()), None);
+	}
+}
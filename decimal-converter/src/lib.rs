@@ -0,0 +1,476 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use self::pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migration;
+pub mod weights;
+pub use weights::*;
+
+#[cfg(test)]
+mod mock;
+
+use frame_support::traits::tokens::fungibles;
+use sp_std::marker::PhantomData;
+use sygma_traits::DecimalsProvider;
+use xcm::latest::{AssetId, Fungibility::Fungible, MultiAsset};
+use xcm_executor::traits::MatchesFungibles;
+
+/// Read the asset decimals from the metadata of a `fungibles` implementation, e.g.
+/// `pallet_assets`. `Matcher` is used to convert the XCM `AssetId` into the local asset id.
+pub struct FungiblesMetadataDecimals<AccountId, Assets, Matcher>(
+	PhantomData<(AccountId, Assets, Matcher)>,
+);
+impl<AccountId, Assets, Matcher> DecimalsProvider
+	for FungiblesMetadataDecimals<AccountId, Assets, Matcher>
+where
+	Assets: fungibles::metadata::Inspect<AccountId>,
+	Matcher: MatchesFungibles<Assets::AssetId, Assets::Balance>,
+{
+	fn decimals(asset_id: &AssetId) -> Option<u8> {
+		let asset: MultiAsset = (*asset_id, Fungible(0)).into();
+		let (local_asset_id, _) = Matcher::matches_fungibles(&asset).ok()?;
+		// Assets without metadata report an empty symbol and zero decimals
+		if Assets::symbol(local_asset_id.clone()).is_empty() {
+			return None;
+		}
+		Some(Assets::decimals(local_asset_id))
+	}
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::large_enum_variant)]
+#[frame_support::pallet]
+pub mod pallet {
+	use fixed::{types::extra::U16, FixedU128};
+	use frame_support::{
+		dispatch::DispatchResult,
+		pallet_prelude::*,
+		traits::{BuildGenesisConfig, StorageVersion},
+		DefaultNoBound,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_std::{boxed::Box, vec::Vec};
+	use sygma_traits::{DecimalConverter, DecimalsProvider};
+	use xcm::latest::{AssetId, Fungibility::Fungible, MultiAsset};
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Max decimals of an asset, the factor 10^(decimals - 18) its amounts are converted with
+	/// must fit in `U112F16`
+	pub const MAX_DECIMALS: u8 = 51;
+
+	/// Mapping asset id to its decimals
+	#[pallet::storage]
+	#[pallet::getter(fn asset_decimals)]
+	pub type AssetDecimals<T: Config> = StorageMap<_, Twox64Concat, AssetId, u8>;
+
+	#[pallet::genesis_config]
+	#[derive(DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Assets and their decimals the decimal registry is seeded with
+		pub asset_decimals: Vec<(AssetId, u8)>,
+		#[serde(skip)]
+		pub _config: PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (asset_id, decimals) in self.asset_decimals.iter() {
+				assert!(*decimals <= MAX_DECIMALS, "Asset decimals exceed MAX_DECIMALS");
+				AssetDecimals::<T>::insert(asset_id, decimals);
+			}
+		}
+	}
+
+	pub trait WeightInfo {
+		fn set_decimals() -> Weight;
+		fn remove_decimals() -> Weight;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + sygma_access_segregator::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Decimals source of assets that are not configured in `AssetDecimals`
+		type FallbackDecimals: DecimalsProvider;
+
+		/// Current pallet index defined in runtime
+		type PalletIndex: Get<u8>;
+
+		/// Type representing the weight of this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub (super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Decimals set for a specific asset
+		/// args: [asset, decimals]
+		DecimalsSet { asset: AssetId, decimals: u8 },
+		/// Decimals removed for a specific asset
+		/// args: [asset]
+		DecimalsRemoved { asset: AssetId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Function unimplemented
+		Unimplemented,
+		/// Account has not gained access permission
+		AccessDenied,
+		/// Decimals not set for the asset
+		DecimalsNotSet,
+		/// Decimals exceed the max decimals amounts can be converted with
+		InvalidDecimals,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set decimals for a specific asset, this takes precedence over `FallbackDecimals`
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_decimals())]
+		pub fn set_decimals(
+			origin: OriginFor<T>,
+			asset: Box<AssetId>,
+			decimals: u8,
+		) -> DispatchResult {
+			let asset: AssetId = *asset;
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_decimals".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(decimals <= MAX_DECIMALS, Error::<T>::InvalidDecimals);
+
+			// Update asset decimals
+			AssetDecimals::<T>::insert(asset, decimals);
+
+			// Emit DecimalsSet event
+			Self::deposit_event(Event::DecimalsSet { asset, decimals });
+			Ok(())
+		}
+
+		/// Remove decimals of a specific asset, `FallbackDecimals` will be used afterwards
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_decimals())]
+		pub fn remove_decimals(origin: OriginFor<T>, asset: Box<AssetId>) -> DispatchResult {
+			let asset: AssetId = *asset;
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"remove_decimals".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(AssetDecimals::<T>::contains_key(asset), Error::<T>::DecimalsNotSet);
+
+			// Remove asset decimals
+			AssetDecimals::<T>::remove(asset);
+
+			// Emit DecimalsRemoved event
+			Self::deposit_event(Event::DecimalsRemoved { asset });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Return the decimals of the asset, looking up `AssetDecimals` first then
+		/// `FallbackDecimals`, fallback decimals above `MAX_DECIMALS` are ignored
+		pub fn decimals(asset_id: &AssetId) -> Option<u8> {
+			AssetDecimals::<T>::get(asset_id).or_else(|| {
+				T::FallbackDecimals::decimals(asset_id).filter(|decimals| *decimals <= MAX_DECIMALS)
+			})
+		}
+	}
+
+	impl<T: Config> DecimalConverter for Pallet<T> {
+		fn convert_to(asset: &MultiAsset) -> Option<u128> {
+			match (&asset.fun, &asset.id) {
+				(Fungible(amount), _) => {
+					let decimal = Self::decimals(&asset.id)?;
+					if decimal == 18 {
+						Some(*amount)
+					} else {
+						type U112F16 = FixedU128<U16>;
+						if decimal > 18 {
+							let a = U112F16::from_num(10u128.saturating_pow(decimal as u32 - 18));
+							let b = U112F16::from_num(*amount).checked_div(a);
+							let r: u128 = b.unwrap_or_else(|| U112F16::from_num(0)).to_num();
+							if r == 0 {
+								return None;
+							}
+							Some(r)
+						} else {
+							// Max is 5192296858534827628530496329220095
+							// if source asset decimal is 12, the max amount sending to sygma
+							// relayer is 5192296858534827.628530496329
+							if *amount > U112F16::MAX {
+								return None;
+							}
+							let a = U112F16::from_num(10u128.saturating_pow(18 - decimal as u32));
+							let b = U112F16::from_num(*amount).saturating_mul(a);
+							Some(b.to_num())
+						}
+					}
+				},
+				_ => None,
+			}
+		}
+
+		fn convert_from(asset: &MultiAsset) -> Option<MultiAsset> {
+			match (&asset.fun, &asset.id) {
+				(Fungible(amount), _) => {
+					let decimal = Self::decimals(&asset.id)?;
+					if decimal == 18 {
+						Some((asset.id, *amount).into())
+					} else {
+						type U112F16 = FixedU128<U16>;
+						if decimal > 18 {
+							// Max is 5192296858534827628530496329220095
+							// if dest asset decimal is 24, the max amount coming from sygma
+							// relayer is 5192296858.534827628530496329
+							if *amount > U112F16::MAX {
+								return None;
+							}
+							let a = U112F16::from_num(10u128.saturating_pow(decimal as u32 - 18));
+							let b = U112F16::from_num(*amount).saturating_mul(a);
+							let r: u128 = b.to_num();
+							Some((asset.id, r).into())
+						} else {
+							let a = U112F16::from_num(10u128.saturating_pow(18 - decimal as u32));
+							let b = U112F16::from_num(*amount).checked_div(a);
+							let r: u128 = b.unwrap_or_else(|| U112F16::from_num(0)).to_num();
+							if r == 0 {
+								return None;
+							}
+							Some((asset.id, r).into())
+						}
+					}
+				},
+				_ => None,
+			}
+		}
+	}
+
+	#[cfg(test)]
+	mod test {
+		use crate as decimal_converter;
+		use crate::{AssetDecimals, Event as DecimalConverterEvent};
+		use decimal_converter::mock::{
+			assert_events, new_test_ext, AccessSegregator, Assets, DecimalConverter,
+			DecimalConverterPalletIndex, RuntimeEvent as Event, RuntimeOrigin as Origin, Test,
+			UsdtAssetId, UsdtLocation, ALICE, ASSET_OWNER,
+		};
+		use frame_support::{assert_noop, assert_ok};
+		use sp_std::boxed::Box;
+		use sygma_traits::DecimalConverter as DecimalConverterT;
+		use xcm::latest::{prelude::*, MultiLocation};
+
+		#[test]
+		fn set_remove_decimals() {
+			new_test_ext().execute_with(|| {
+				let asset_id = Concrete(MultiLocation::new(0, Here));
+
+				assert_ok!(DecimalConverter::set_decimals(Origin::root(), Box::new(asset_id), 12));
+				assert_eq!(AssetDecimals::<Test>::get(asset_id), Some(12));
+				assert_eq!(DecimalConverter::decimals(&asset_id), Some(12));
+
+				// override the decimals
+				assert_ok!(DecimalConverter::set_decimals(Origin::root(), Box::new(asset_id), 6));
+				assert_eq!(AssetDecimals::<Test>::get(asset_id), Some(6));
+
+				assert_ok!(DecimalConverter::remove_decimals(Origin::root(), Box::new(asset_id)));
+				assert_eq!(AssetDecimals::<Test>::get(asset_id), None);
+				assert_eq!(DecimalConverter::decimals(&asset_id), None);
+
+				// remove again should fail
+				assert_noop!(
+					DecimalConverter::remove_decimals(Origin::root(), Box::new(asset_id)),
+					decimal_converter::Error::<Test>::DecimalsNotSet
+				);
+
+				// decimals amounts can not be converted with should be rejected
+				assert_noop!(
+					DecimalConverter::set_decimals(
+						Origin::root(),
+						Box::new(asset_id),
+						decimal_converter::MAX_DECIMALS + 1
+					),
+					decimal_converter::Error::<Test>::InvalidDecimals
+				);
+
+				assert_events(vec![
+					Event::DecimalConverter(DecimalConverterEvent::DecimalsSet {
+						asset: asset_id,
+						decimals: 12,
+					}),
+					Event::DecimalConverter(DecimalConverterEvent::DecimalsSet {
+						asset: asset_id,
+						decimals: 6,
+					}),
+					Event::DecimalConverter(DecimalConverterEvent::DecimalsRemoved {
+						asset: asset_id,
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn access_control() {
+			new_test_ext().execute_with(|| {
+				let asset_id = Concrete(MultiLocation::new(0, Here));
+
+				assert_noop!(
+					DecimalConverter::set_decimals(Some(ALICE).into(), Box::new(asset_id), 12),
+					decimal_converter::Error::<Test>::AccessDenied
+				);
+				assert_noop!(
+					DecimalConverter::remove_decimals(Some(ALICE).into(), Box::new(asset_id)),
+					decimal_converter::Error::<Test>::AccessDenied
+				);
+
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					DecimalConverterPalletIndex::get(),
+					b"set_decimals".to_vec(),
+					ALICE
+				));
+				assert_ok!(DecimalConverter::set_decimals(
+					Some(ALICE).into(),
+					Box::new(asset_id),
+					12
+				));
+				assert_eq!(AssetDecimals::<Test>::get(asset_id), Some(12));
+
+				// ALICE still can not remove decimals
+				assert_noop!(
+					DecimalConverter::remove_decimals(Some(ALICE).into(), Box::new(asset_id)),
+					decimal_converter::Error::<Test>::AccessDenied
+				);
+			})
+		}
+
+		#[test]
+		fn fallback_to_assets_metadata() {
+			new_test_ext().execute_with(|| {
+				let usdt_asset_id: AssetId = UsdtLocation::get().into();
+
+				// asset not created yet
+				assert_eq!(DecimalConverter::decimals(&usdt_asset_id), None);
+
+				assert_ok!(Assets::create(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(UsdtAssetId::get()),
+					ASSET_OWNER,
+					1
+				));
+				// asset created without metadata
+				assert_eq!(DecimalConverter::decimals(&usdt_asset_id), None);
+
+				assert_ok!(Assets::set_metadata(
+					Origin::signed(ASSET_OWNER),
+					codec::Compact(UsdtAssetId::get()),
+					b"USDT".to_vec(),
+					b"USDT".to_vec(),
+					6
+				));
+				assert_eq!(DecimalConverter::decimals(&usdt_asset_id), Some(6));
+
+				// decimals in storage take precedence over metadata
+				assert_ok!(DecimalConverter::set_decimals(
+					Origin::root(),
+					Box::new(usdt_asset_id),
+					12
+				));
+				assert_eq!(DecimalConverter::decimals(&usdt_asset_id), Some(12));
+			})
+		}
+
+		#[test]
+		fn convert_to_and_from() {
+			new_test_ext().execute_with(|| {
+				let asset_12: AssetId = Concrete(MultiLocation::new(0, Here));
+				let asset_18: AssetId = Concrete(MultiLocation::new(1, Here));
+				let asset_24: AssetId = Concrete(MultiLocation::new(2, Here));
+				let unknown_asset: AssetId = Concrete(MultiLocation::new(3, Here));
+
+				assert_ok!(DecimalConverter::set_decimals(Origin::root(), Box::new(asset_12), 12));
+				assert_ok!(DecimalConverter::set_decimals(Origin::root(), Box::new(asset_18), 18));
+				assert_ok!(DecimalConverter::set_decimals(Origin::root(), Box::new(asset_24), 24));
+
+				// 1 token of 12 decimals => 1 token of 18 decimals
+				assert_eq!(
+					DecimalConverter::convert_to(&(asset_12, 1_000_000_000_000u128).into()),
+					Some(1_000_000_000_000_000_000u128)
+				);
+				assert_eq!(
+					DecimalConverter::convert_from(
+						&(asset_12, 1_000_000_000_000_000_000u128).into()
+					),
+					Some((asset_12, 1_000_000_000_000u128).into())
+				);
+				// too small to be represented in 12 decimals
+				assert_eq!(DecimalConverter::convert_from(&(asset_12, 1u128).into()), None);
+
+				assert_eq!(
+					DecimalConverter::convert_to(&(asset_18, 100u128).into()),
+					Some(100u128)
+				);
+				assert_eq!(
+					DecimalConverter::convert_from(&(asset_18, 100u128).into()),
+					Some((asset_18, 100u128).into())
+				);
+
+				// 1 token of 24 decimals => 1 token of 18 decimals
+				assert_eq!(
+					DecimalConverter::convert_to(
+						&(asset_24, 1_000_000_000_000_000_000_000_000u128).into()
+					),
+					Some(1_000_000_000_000_000_000u128)
+				);
+				assert_eq!(
+					DecimalConverter::convert_from(
+						&(asset_24, 1_000_000_000_000_000_000u128).into()
+					),
+					Some((asset_24, 1_000_000_000_000_000_000_000_000u128).into())
+				);
+				// too small to be represented in 18 decimals
+				assert_eq!(DecimalConverter::convert_to(&(asset_24, 1u128).into()), None);
+
+				// amounts of an asset with the max decimals are converted without overflow
+				let asset_max: AssetId = Concrete(MultiLocation::new(4, Here));
+				assert_ok!(DecimalConverter::set_decimals(
+					Origin::root(),
+					Box::new(asset_max),
+					decimal_converter::MAX_DECIMALS
+				));
+				assert_eq!(
+					DecimalConverter::convert_from(&(asset_max, 1u128).into()),
+					Some((asset_max, 10u128.pow(33)).into())
+				);
+				assert_eq!(
+					DecimalConverter::convert_to(&(asset_max, 10u128.pow(33)).into()),
+					Some(1u128)
+				);
+
+				// unknown asset should not be converted
+				assert_eq!(DecimalConverter::convert_to(&(unknown_asset, 1u128).into()), None);
+				assert_eq!(DecimalConverter::convert_from(&(unknown_asset, 1u128).into()), None);
+			})
+		}
+	}
+}
@@ -0,0 +1,76 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+#[allow(unused_imports)]
+use super::*;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::ensure;
+use frame_support::traits::{Get, OnRuntimeUpgrade, StorageVersion};
+use log;
+use sp_std::vec::Vec;
+use xcm::latest::AssetId;
+
+const EXPECTED_STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+#[cfg(feature = "try-runtime")]
+const FINAL_STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+/// Seed the on-chain decimal registry from the (AssetId, decimals) pairs that used to be
+/// configured through the runtime constant `AssetDecimalPairs`.
+pub struct SeedAssetDecimals<T, DecimalPairs>(sp_std::marker::PhantomData<(T, DecimalPairs)>);
+
+impl<T: Config, DecimalPairs: Get<Vec<(AssetId, u8)>>> OnRuntimeUpgrade
+	for SeedAssetDecimals<T, DecimalPairs>
+{
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		if StorageVersion::get::<Pallet<T>>() == EXPECTED_STORAGE_VERSION {
+			log::info!("Start sygma decimal converter migration");
+
+			let pairs = DecimalPairs::get();
+			for (asset_id, decimals) in pairs.iter() {
+				AssetDecimals::<T>::insert(asset_id, decimals);
+			}
+
+			// Set new storage version to 1
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!("Sygma decimal converter migration done👏");
+
+			// one insert per pair + put
+			T::DbWeight::get().reads_writes(1, pairs.len() as u64 + 1)
+		} else {
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == EXPECTED_STORAGE_VERSION,
+			"Incorrect Sygma decimal converter storage version in pre migrate"
+		);
+
+		log::info!("Sygma decimal converter pre migration check passed👏");
+
+		Ok(Vec::new())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(
+			StorageVersion::get::<Pallet<T>>() == FINAL_STORAGE_VERSION,
+			"Incorrect Sygma decimal converter storage version in post migrate"
+		);
+
+		for (asset_id, decimals) in DecimalPairs::get().iter() {
+			ensure!(
+				AssetDecimals::<T>::get(asset_id) == Some(*decimals),
+				"Unexpected asset decimals in post migrate"
+			);
+		}
+
+		log::info!("Sygma decimal converter post migration check passed👏");
+
+		Ok(())
+	}
+}
@@ -0,0 +1,214 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use frame_support::{
+	pallet_prelude::ConstU32,
+	parameter_types,
+	sp_runtime::{
+		testing::H256,
+		traits::{BlakeTwo256, IdentityLookup},
+		AccountId32, BuildStorage, Perbill,
+	},
+	traits::{AsEnsureOriginWithArg, ConstU128},
+};
+use frame_system::{self as system, EnsureRoot, EnsureSigned};
+use sp_std::result;
+use xcm::latest::{prelude::*, MultiLocation};
+use xcm_executor::traits::{Error as ExecutionError, MatchesFungibles};
+
+use crate as decimal_converter;
+use crate::FungiblesMetadataDecimals;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) type Balance = u128;
+
+pub const ALICE: AccountId32 = AccountId32::new([0u8; 32]);
+pub const ASSET_OWNER: AccountId32 = AccountId32::new([1u8; 32]);
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		AccessSegregator: sygma_access_segregator::{Pallet, Call, Storage, Event<T>} = 3,
+		DecimalConverter: decimal_converter::{Pallet, Call, Storage, Event<T>} = 4,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+	pub const MaxLocks: u32 = 100;
+	pub const MinimumPeriod: u64 = 1;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type Block = Block;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId32;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<2>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ();
+}
+
+parameter_types! {
+	pub const AssetDeposit: Balance = 1; // 1 Unit deposit to create asset
+	pub const ApprovalDeposit: Balance = 1;
+	pub const AssetsStringLimit: u32 = 50;
+	pub const MetadataDepositBase: Balance = 1;
+	pub const MetadataDepositPerByte: Balance = 1;
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = u32;
+	type AssetIdParameter = codec::Compact<u32>;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId32>>;
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = ConstU128<10>;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = AssetsStringLimit;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Test>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	// Make sure put same value with `construct_runtime`
+	pub const AccessSegregatorPalletIndex: u8 = 3;
+	pub const DecimalConverterPalletIndex: u8 = 4;
+	pub RegisteredExtrinsics: Vec<(u8, Vec<u8>)> = [
+		(AccessSegregatorPalletIndex::get(), b"grant_access".to_vec()),
+		(DecimalConverterPalletIndex::get(), b"set_decimals".to_vec()),
+		(DecimalConverterPalletIndex::get(), b"remove_decimals".to_vec()),
+	].to_vec();
+}
+
+impl sygma_access_segregator::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type BridgeCommitteeOrigin = EnsureRoot<Self::AccountId>;
+	type PalletIndex = AccessSegregatorPalletIndex;
+	type Extrinsics = RegisteredExtrinsics;
+	type RuntimeCall = RuntimeCall;
+	type AccessOriginConverter = sygma_access_segregator::SignedAccessOrigin;
+	type MaxCollectiveGrants = ConstU32<8>;
+	type MaxRolesPerAccount = ConstU32<8>;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = sygma_access_segregator::weights::SygmaWeightInfo<Test>;
+}
+
+parameter_types! {
+	pub const UsdtAssetId: u32 = 0;
+	pub UsdtLocation: MultiLocation = MultiLocation::new(
+		1,
+		X2(
+			Parachain(2005),
+			slice_to_generalkey(b"usdt"),
+		),
+	);
+}
+
+/// Only recognize USDT as a local asset of `pallet_assets`
+pub struct UsdtMatcher;
+impl MatchesFungibles<u32, Balance> for UsdtMatcher {
+	fn matches_fungibles(a: &MultiAsset) -> result::Result<(u32, Balance), ExecutionError> {
+		match (&a.fun, &a.id) {
+			(Fungible(amount), Concrete(id)) if id == &UsdtLocation::get() => {
+				Ok((UsdtAssetId::get(), *amount))
+			},
+			_ => Err(ExecutionError::AssetNotHandled),
+		}
+	}
+}
+
+impl decimal_converter::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type FallbackDecimals = FungiblesMetadataDecimals<AccountId32, Assets, UsdtMatcher>;
+	type PalletIndex = DecimalConverterPalletIndex;
+	type WeightInfo = decimal_converter::weights::SygmaWeightInfo<Test>;
+}
+
+pub fn slice_to_generalkey(key: &[u8]) -> Junction {
+	let len = key.len();
+	assert!(len <= 32);
+	GeneralKey {
+		length: len as u8,
+		data: {
+			let mut data = [0u8; 32];
+			data[..len].copy_from_slice(key);
+			data
+		},
+	}
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(ASSET_OWNER, 1_000_000)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+// Checks events against the latest. A contiguous set of events must be provided. They must
+// include the most recent event, but do not have to include every past event.
+pub fn assert_events(mut expected: Vec<RuntimeEvent>) {
+	let mut actual: Vec<RuntimeEvent> =
+		system::Pallet::<Test>::events().iter().map(|e| e.event.clone()).collect();
+
+	expected.reverse();
+
+	for evt in expected {
+		let next = actual.pop().expect("event expected");
+		assert_eq!(next, evt, "Events don't match");
+	}
+}
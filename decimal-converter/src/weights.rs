@@ -0,0 +1,67 @@
+//! Autogenerated weights for `sygma_decimal_converter`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-07-26, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `Freddys-MacBook-Pro-15.local`, CPU: `<UNKNOWN>`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/standalone-node-template
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// sygma_decimal_converter
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// decimal_converter_weight.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `sygma_decimal_converter`.
+pub struct SygmaWeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaDecimalConverter AssetDecimals (r:0 w:1)
+	/// Proof: SygmaDecimalConverter AssetDecimals (max_values: None, max_size: Some(613), added: 3088, mode: MaxEncodedLen)
+	fn set_decimals() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaDecimalConverter AssetDecimals (r:1 w:1)
+	/// Proof: SygmaDecimalConverter AssetDecimals (max_values: None, max_size: Some(613), added: 3088, mode: MaxEncodedLen)
+	fn remove_decimals() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `145`
+		//  Estimated: `4078`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(34_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4078))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
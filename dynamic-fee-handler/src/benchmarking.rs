@@ -0,0 +1,91 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+//! Sygma dynamic-fee-handler pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+use codec::Encode;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	crypto::ecdsa::ECDSAExt,
+	sp_runtime::{FixedPointNumber, FixedU128},
+};
+use frame_system::RawOrigin as SystemOrigin;
+use sp_core::H160;
+
+use sp_std::vec;
+use sygma_traits::DomainID;
+use xcm::latest::prelude::*;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_fee_oracle() {
+		let oracle = H160::from([1u8; 20]);
+
+		#[extrinsic_call]
+		set_fee_oracle(SystemOrigin::Root, oracle);
+
+		assert_eq!(FeeOracle::<T>::get(), Some(oracle));
+	}
+
+	#[benchmark]
+	fn set_destination_type() {
+		let dest_domain_id: DomainID = 1;
+
+		#[extrinsic_call]
+		set_destination_type(
+			SystemOrigin::Root,
+			dest_domain_id,
+			DestinationType::Evm { gas_used: 100_000 },
+		);
+
+		assert_eq!(
+			DestinationTypes::<T>::get(dest_domain_id),
+			Some(DestinationType::Evm { gas_used: 100_000 })
+		);
+	}
+
+	#[benchmark]
+	fn submit_fee_quote() {
+		let caller: T::AccountId = whitelisted_caller();
+		let dest_domain_id: DomainID = 1;
+		let asset_id: AssetId = Concrete(MultiLocation::here());
+		let key_type = sp_core::crypto::KeyTypeId(*b"code");
+		let pub_key = sp_io::crypto::ecdsa_generate(key_type, None);
+		Pallet::<T>::set_fee_oracle(
+			SystemOrigin::Root.into(),
+			H160::from(pub_key.to_eth_address().unwrap()),
+		)
+		.unwrap();
+
+		// Replace an existing quote, which is the worst case
+		let mut quote = FeeOracleMsg {
+			dest_domain_id,
+			asset_id,
+			dst_gas_price: 20_000_000_000,
+			inclusion_fee: 1_000_000_000,
+			msg_gas_limit: 200_000,
+			ter: FixedU128::saturating_from_rational(3u128, 2u128),
+			ber: FixedU128::saturating_from_rational(1u128, 2u128),
+			expires_at: frame_system::Pallet::<T>::block_number() + 10u32.into(),
+		};
+		FeeQuotes::<T>::insert((dest_domain_id, asset_id), quote.clone());
+		quote.expires_at = quote.expires_at + 10u32.into();
+
+		let signature = sp_io::crypto::ecdsa_sign_prehashed(
+			key_type,
+			&pub_key,
+			&Pallet::<T>::fee_quote_signing_message(&quote),
+		)
+		.expect("Generates signature");
+
+		#[extrinsic_call]
+		submit_fee_quote(SystemOrigin::Signed(caller), quote.clone(), signature.encode());
+
+		assert_eq!(FeeQuotes::<T>::get((dest_domain_id, asset_id)), Some(quote));
+	}
+}
@@ -0,0 +1,706 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use self::pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+#[cfg(test)]
+mod mock;
+
+#[allow(unused_variables)]
+#[allow(clippy::large_enum_variant)]
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		dispatch::DispatchResult,
+		pallet_prelude::*,
+		sp_runtime::{traits::Zero, FixedPointNumber, FixedU128},
+		traits::StorageVersion,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_core::H160;
+	use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
+	use sp_std::vec::Vec;
+	use sygma_traits::{DomainID, FeeContext, FeeHandler, FeeQuote, TransferType};
+	use xcm::latest::{AssetId, MultiAsset};
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	/// Prefix of the message signed by fee oracle, so a fee quote signature can not be reused
+	/// as the signature of any other message signed by the same key
+	const FEE_ORACLE_MSG_PREFIX: &[u8] = b"sygma-fee-oracle-msg";
+
+	/// Type of destination domain, which decides how the fee is calculated from a fee quote
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub enum DestinationType {
+		/// EVM domain, executing the transfer costs `gas_used` gas on it
+		Evm { gas_used: u128 },
+		/// Substrate domain, executing the transfer costs an inclusion fee on it
+		Substrate,
+	}
+
+	/// Fee quote of transferring an asset to a domain, signed by fee oracle
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+	pub struct FeeOracleMsg<BlockNumber> {
+		/// Dest domain of the transfer
+		pub dest_domain_id: DomainID,
+		/// Transferred asset, fee is paid in it
+		pub asset_id: AssetId,
+		/// Gas price of EVM dest domain
+		pub dst_gas_price: u128,
+		/// Inclusion fee of Substrate dest domain
+		pub inclusion_fee: u128,
+		/// Gas limit of executing a generic message on EVM dest domain
+		pub msg_gas_limit: u128,
+		/// Exchange rate of dest domain native currency to the transferred asset
+		pub ter: FixedU128,
+		/// Exchange rate of dest domain native currency to the base currency of this chain
+		pub ber: FixedU128,
+		/// Quote can not be used since this block
+		pub expires_at: BlockNumber,
+	}
+
+	/// Address of fee oracle that signs fee quotes
+	#[pallet::storage]
+	#[pallet::getter(fn fee_oracle)]
+	pub type FeeOracle<T: Config> = StorageValue<_, H160>;
+
+	/// Mapping domain id to its destination type
+	#[pallet::storage]
+	#[pallet::getter(fn destination_types)]
+	pub type DestinationTypes<T: Config> = StorageMap<_, Twox64Concat, DomainID, DestinationType>;
+
+	/// Mapping (domain id, asset id) to the latest fee quote signed by fee oracle
+	#[pallet::storage]
+	#[pallet::getter(fn fee_quotes)]
+	pub type FeeQuotes<T: Config> =
+		StorageMap<_, Twox64Concat, (DomainID, AssetId), FeeOracleMsg<BlockNumberFor<T>>>;
+
+	pub trait WeightInfo {
+		fn set_fee_oracle() -> Weight;
+		fn set_destination_type() -> Weight;
+		fn submit_fee_quote() -> Weight;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + sygma_access_segregator::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Current pallet index defined in runtime
+		type PalletIndex: Get<u8>;
+
+		/// Asset of the base currency of this chain, fee can be paid in it at the `ber` of a
+		/// fee quote
+		type NativeFeeAsset: Get<AssetId>;
+
+		/// Type representing the weight of this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub (super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Fee oracle address set
+		/// args: [oracle]
+		FeeOracleSet { oracle: H160 },
+		/// Destination type set for a specific domain
+		/// args: [domain, dest_type]
+		DestinationTypeSet { domain: DomainID, dest_type: DestinationType },
+		/// Fee quote signed by fee oracle submitted for a specific asset
+		/// args: [domain, asset, expires_at]
+		FeeQuoteSubmitted { domain: DomainID, asset: AssetId, expires_at: BlockNumberFor<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Account has not gained access permission
+		AccessDenied,
+		/// Fee oracle address not set
+		MissingFeeOracle,
+		/// Fee quote is not signed by fee oracle
+		BadFeeOracleSignature,
+		/// Fee quote is expired
+		FeeQuoteExpired,
+		/// Fee quote expires no later than the current one
+		StaleFeeQuote,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the address of fee oracle that signs fee quotes
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_oracle())]
+		pub fn set_fee_oracle(origin: OriginFor<T>, oracle: H160) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_fee_oracle".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+
+			// Update fee oracle
+			FeeOracle::<T>::put(oracle);
+
+			// Emit FeeOracleSet event
+			Self::deposit_event(Event::FeeOracleSet { oracle });
+			Ok(())
+		}
+
+		/// Set the destination type of a domain
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_destination_type())]
+		pub fn set_destination_type(
+			origin: OriginFor<T>,
+			domain: DomainID,
+			dest_type: DestinationType,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_destination_type".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+
+			// Update destination type
+			DestinationTypes::<T>::insert(domain, dest_type.clone());
+
+			// Emit DestinationTypeSet event
+			Self::deposit_event(Event::DestinationTypeSet { domain, dest_type });
+			Ok(())
+		}
+
+		/// Submit a fee quote signed by fee oracle, it's used to calculate the fee of
+		/// transferring the asset to the domain until it expires or a newer quote is submitted.
+		/// The signature is over `keccak256(FEE_ORACLE_MSG_PREFIX ++ genesis_hash ++ SCALE(quote))`,
+		/// so a quote signed for another chain is rejected.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::submit_fee_quote())]
+		pub fn submit_fee_quote(
+			origin: OriginFor<T>,
+			quote: FeeOracleMsg<BlockNumberFor<T>>,
+			signature: Vec<u8>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let oracle = FeeOracle::<T>::get().ok_or(Error::<T>::MissingFeeOracle)?;
+			ensure!(
+				Self::recover_signer(&quote, signature) == Some(oracle),
+				Error::<T>::BadFeeOracleSignature
+			);
+			ensure!(
+				quote.expires_at > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::FeeQuoteExpired
+			);
+
+			let domain = quote.dest_domain_id;
+			let asset = quote.asset_id;
+			// A quote replayed after a newer one is submitted is rejected
+			if let Some(current) = FeeQuotes::<T>::get((domain, &asset)) {
+				ensure!(quote.expires_at > current.expires_at, Error::<T>::StaleFeeQuote);
+			}
+
+			let expires_at = quote.expires_at;
+			FeeQuotes::<T>::insert((domain, &asset), quote);
+
+			// Emit FeeQuoteSubmitted event
+			Self::deposit_event(Event::FeeQuoteSubmitted { domain, asset, expires_at });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Return the message fee oracle signs for the fee quote on this chain
+		pub fn fee_quote_signing_message(quote: &FeeOracleMsg<BlockNumberFor<T>>) -> [u8; 32] {
+			let genesis_hash = <frame_system::Pallet<T>>::block_hash(BlockNumberFor::<T>::zero());
+			keccak_256(&[FEE_ORACLE_MSG_PREFIX, genesis_hash.as_ref(), &quote.encode()].concat())
+		}
+
+		/// Recover the address which signed the fee quote
+		fn recover_signer(
+			quote: &FeeOracleMsg<BlockNumberFor<T>>,
+			signature: Vec<u8>,
+		) -> Option<H160> {
+			let sig: [u8; 65] = signature.try_into().ok()?;
+			let pubkey =
+				secp256k1_ecdsa_recover(&sig, &Self::fee_quote_signing_message(quote)).ok()?;
+			Some(H160::from_slice(&keccak_256(&pubkey)[12..]))
+		}
+
+		/// Return the fee quote of transferring the asset to the domain, None if it is expired
+		fn current_fee_quote(
+			domain: DomainID,
+			asset: &AssetId,
+		) -> Option<FeeOracleMsg<BlockNumberFor<T>>> {
+			FeeQuotes::<T>::get((domain, asset))
+				.filter(|quote| quote.expires_at > <frame_system::Pallet<T>>::block_number())
+		}
+
+		/// Return the cost of executing the transfer on dest domain in its native currency,
+		/// a generic message costs `msg_gas_limit` gas instead of `gas_used` on EVM dest domain
+		fn dest_cost(
+			domain: DomainID,
+			quote: &FeeOracleMsg<BlockNumberFor<T>>,
+			transfer_type: &TransferType,
+		) -> Option<u128> {
+			Some(match DestinationTypes::<T>::get(domain)? {
+				DestinationType::Evm { .. } if *transfer_type == TransferType::GenericTransfer =>
+					quote.dst_gas_price.saturating_mul(quote.msg_gas_limit),
+				DestinationType::Evm { gas_used } => quote.dst_gas_price.saturating_mul(gas_used),
+				DestinationType::Substrate => quote.inclusion_fee,
+			})
+		}
+
+		/// Return the fee of the transfer paid in `fee_asset`, which is either the transferred
+		/// asset at `ter` or the native fee asset at `ber`
+		fn fee_in_asset(
+			domain: DomainID,
+			asset: &AssetId,
+			fee_asset: &AssetId,
+			transfer_type: &TransferType,
+		) -> Option<u128> {
+			let quote = Self::current_fee_quote(domain, asset)?;
+			let dest_cost = Self::dest_cost(domain, &quote, transfer_type)?;
+			if fee_asset == asset {
+				Some(quote.ter.saturating_mul_int(dest_cost))
+			} else if *fee_asset == T::NativeFeeAsset::get() {
+				Some(quote.ber.saturating_mul_int(dest_cost))
+			} else {
+				None
+			}
+		}
+	}
+
+	impl<T: Config> FeeHandler for Pallet<T> {
+		fn get_fee(domain: DomainID, asset: MultiAsset) -> Option<u128> {
+			Self::fee_in_asset(domain, &asset.id, &asset.id, &TransferType::FungibleTransfer)
+		}
+
+		fn get_fee_in_asset(
+			domain: DomainID,
+			asset: MultiAsset,
+			fee_asset: AssetId,
+		) -> Option<u128> {
+			Self::fee_in_asset(domain, &asset.id, &fee_asset, &TransferType::FungibleTransfer)
+		}
+
+		fn quote_fee(
+			context: &FeeContext,
+			asset: MultiAsset,
+			fee_asset: AssetId,
+		) -> Option<FeeQuote> {
+			// Generic messages carry no asset to pay the fee with, it is paid in base currency
+			if context.transfer_type == TransferType::GenericTransfer &&
+				fee_asset != T::NativeFeeAsset::get()
+			{
+				return None;
+			}
+			Self::fee_in_asset(
+				context.dest_domain_id,
+				&asset.id,
+				&fee_asset,
+				&context.transfer_type,
+			)
+			.map(|amount| FeeQuote { fee_asset, amount })
+		}
+	}
+
+	#[cfg(test)]
+	mod test {
+		use crate as dynamic_fee_handler;
+		use crate::{DestinationType, Event as DynamicFeeHandlerEvent, FeeOracleMsg, FeeQuotes};
+		use dynamic_fee_handler::mock::{
+			assert_events, new_test_ext, AccessSegregator, DynamicFeeHandler,
+			FeeHandlerPalletIndex, NativeFeeAsset, RuntimeEvent as Event, RuntimeOrigin as Origin,
+			System, Test, ALICE,
+		};
+		use frame_support::{
+			assert_noop, assert_ok,
+			crypto::ecdsa::ECDSAExt,
+			sp_runtime::{FixedPointNumber, FixedU128},
+		};
+		use sp_core::{ecdsa, Encode, Pair, H160, H256};
+		use sygma_traits::{DomainID, FeeContext, FeeHandler, FeeQuote, TransferType};
+		use xcm::latest::{prelude::*, MultiLocation};
+
+		fn oracle_address(pair: &ecdsa::Pair) -> H160 {
+			H160::from(pair.public().to_eth_address().unwrap())
+		}
+
+		fn sign_quote(pair: &ecdsa::Pair, quote: &FeeOracleMsg<u64>) -> Vec<u8> {
+			pair.sign_prehashed(&DynamicFeeHandler::fee_quote_signing_message(quote))
+				.encode()
+		}
+
+		#[test]
+		fn set_fee_oracle_and_destination_type() {
+			new_test_ext().execute_with(|| {
+				let dest_domain_id: DomainID = 1;
+				let oracle = H160::from([1u8; 20]);
+				let dest_type = DestinationType::Evm { gas_used: 100_000 };
+
+				assert_ok!(DynamicFeeHandler::set_fee_oracle(Origin::root(), oracle));
+				assert_eq!(DynamicFeeHandler::fee_oracle(), Some(oracle));
+				assert_events(vec![Event::DynamicFeeHandler(
+					DynamicFeeHandlerEvent::FeeOracleSet { oracle },
+				)]);
+
+				assert_ok!(DynamicFeeHandler::set_destination_type(
+					Origin::root(),
+					dest_domain_id,
+					dest_type.clone()
+				));
+				assert_eq!(DynamicFeeHandler::destination_types(dest_domain_id), Some(dest_type));
+				assert_events(vec![Event::DynamicFeeHandler(
+					DynamicFeeHandlerEvent::DestinationTypeSet {
+						domain: dest_domain_id,
+						dest_type: DestinationType::Evm { gas_used: 100_000 },
+					},
+				)]);
+
+				// permission test: unauthorized account should not be able to set them
+				assert_noop!(
+					DynamicFeeHandler::set_fee_oracle(Origin::signed(ALICE), oracle),
+					dynamic_fee_handler::Error::<Test>::AccessDenied
+				);
+				assert_noop!(
+					DynamicFeeHandler::set_destination_type(
+						Origin::signed(ALICE),
+						dest_domain_id,
+						DestinationType::Substrate
+					),
+					dynamic_fee_handler::Error::<Test>::AccessDenied
+				);
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					FeeHandlerPalletIndex::get(),
+					b"set_fee_oracle".to_vec(),
+					ALICE
+				));
+				assert_ok!(DynamicFeeHandler::set_fee_oracle(Origin::signed(ALICE), oracle));
+			})
+		}
+
+		#[test]
+		fn submit_fee_quote_and_get_fee() {
+			new_test_ext().execute_with(|| {
+				let evm_domain_id: DomainID = 1;
+				let substrate_domain_id: DomainID = 2;
+				let asset_id = Concrete(MultiLocation::new(0, Here));
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let (evil_pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let quote = |dest_domain_id: DomainID, expires_at: u64| FeeOracleMsg {
+					dest_domain_id,
+					asset_id,
+					dst_gas_price: 20_000_000_000,
+					inclusion_fee: 1_000_000_000,
+					msg_gas_limit: 200_000,
+					ter: FixedU128::saturating_from_rational(3u128, 2u128),
+					ber: FixedU128::saturating_from_rational(1u128, 2u128),
+					expires_at,
+				};
+
+				// fee oracle must be set
+				assert_noop!(
+					DynamicFeeHandler::submit_fee_quote(
+						Origin::signed(ALICE),
+						quote(evm_domain_id, 10),
+						sign_quote(&pair, &quote(evm_domain_id, 10))
+					),
+					dynamic_fee_handler::Error::<Test>::MissingFeeOracle
+				);
+				assert_ok!(DynamicFeeHandler::set_fee_oracle(
+					Origin::root(),
+					oracle_address(&pair)
+				));
+				assert_ok!(DynamicFeeHandler::set_destination_type(
+					Origin::root(),
+					evm_domain_id,
+					DestinationType::Evm { gas_used: 100_000 }
+				));
+				assert_ok!(DynamicFeeHandler::set_destination_type(
+					Origin::root(),
+					substrate_domain_id,
+					DestinationType::Substrate
+				));
+
+				// quote signed by another key or tampered quote should fail
+				assert_noop!(
+					DynamicFeeHandler::submit_fee_quote(
+						Origin::signed(ALICE),
+						quote(evm_domain_id, 10),
+						sign_quote(&evil_pair, &quote(evm_domain_id, 10))
+					),
+					dynamic_fee_handler::Error::<Test>::BadFeeOracleSignature
+				);
+				assert_noop!(
+					DynamicFeeHandler::submit_fee_quote(
+						Origin::signed(ALICE),
+						quote(evm_domain_id, 11),
+						sign_quote(&pair, &quote(evm_domain_id, 10))
+					),
+					dynamic_fee_handler::Error::<Test>::BadFeeOracleSignature
+				);
+				// expired quote should fail
+				assert_noop!(
+					DynamicFeeHandler::submit_fee_quote(
+						Origin::signed(ALICE),
+						quote(evm_domain_id, 1),
+						sign_quote(&pair, &quote(evm_domain_id, 1))
+					),
+					dynamic_fee_handler::Error::<Test>::FeeQuoteExpired
+				);
+
+				// no fee without a quote
+				assert_eq!(
+					DynamicFeeHandler::get_fee(evm_domain_id, (asset_id, 100u128).into()),
+					None
+				);
+
+				assert_ok!(DynamicFeeHandler::submit_fee_quote(
+					Origin::signed(ALICE),
+					quote(evm_domain_id, 10),
+					sign_quote(&pair, &quote(evm_domain_id, 10))
+				));
+				assert_eq!(
+					FeeQuotes::<Test>::get((evm_domain_id, asset_id)),
+					Some(quote(evm_domain_id, 10))
+				);
+				assert_events(vec![Event::DynamicFeeHandler(
+					DynamicFeeHandlerEvent::FeeQuoteSubmitted {
+						domain: evm_domain_id,
+						asset: asset_id,
+						expires_at: 10,
+					},
+				)]);
+				// dstGasPrice * gasUsed * ter
+				assert_eq!(
+					DynamicFeeHandler::get_fee(evm_domain_id, (asset_id, 100u128).into()),
+					Some(3_000_000_000_000_000)
+				);
+
+				// replaying a quote that expires no later than the current one should fail
+				assert_noop!(
+					DynamicFeeHandler::submit_fee_quote(
+						Origin::signed(ALICE),
+						quote(evm_domain_id, 10),
+						sign_quote(&pair, &quote(evm_domain_id, 10))
+					),
+					dynamic_fee_handler::Error::<Test>::StaleFeeQuote
+				);
+
+				assert_ok!(DynamicFeeHandler::submit_fee_quote(
+					Origin::signed(ALICE),
+					quote(substrate_domain_id, 10),
+					sign_quote(&pair, &quote(substrate_domain_id, 10))
+				));
+				// inclusionFee * ter
+				assert_eq!(
+					DynamicFeeHandler::get_fee(substrate_domain_id, (asset_id, 100u128).into()),
+					Some(1_500_000_000)
+				);
+
+				// no fee once the quote expires
+				System::set_block_number(10);
+				assert_eq!(
+					DynamicFeeHandler::get_fee(evm_domain_id, (asset_id, 100u128).into()),
+					None
+				);
+				assert_eq!(
+					DynamicFeeHandler::get_fee(substrate_domain_id, (asset_id, 100u128).into()),
+					None
+				);
+			})
+		}
+
+		#[test]
+		fn fee_quote_signed_for_another_chain_should_fail() {
+			new_test_ext().execute_with(|| {
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let quote = FeeOracleMsg {
+					dest_domain_id: 1,
+					asset_id: Concrete(MultiLocation::new(0, Here)),
+					dst_gas_price: 20_000_000_000,
+					inclusion_fee: 1_000_000_000,
+					msg_gas_limit: 200_000,
+					ter: FixedU128::saturating_from_rational(3u128, 2u128),
+					ber: FixedU128::saturating_from_rational(1u128, 2u128),
+					expires_at: 10,
+				};
+				assert_ok!(DynamicFeeHandler::set_fee_oracle(
+					Origin::root(),
+					oracle_address(&pair)
+				));
+				let signature = sign_quote(&pair, &quote);
+
+				// the same oracle key signing quotes for a chain of another genesis hash
+				frame_system::BlockHash::<Test>::insert(0, H256::repeat_byte(1));
+				assert_noop!(
+					DynamicFeeHandler::submit_fee_quote(
+						Origin::signed(ALICE),
+						quote.clone(),
+						signature
+					),
+					dynamic_fee_handler::Error::<Test>::BadFeeOracleSignature
+				);
+				assert_ok!(DynamicFeeHandler::submit_fee_quote(
+					Origin::signed(ALICE),
+					quote.clone(),
+					sign_quote(&pair, &quote)
+				));
+			})
+		}
+
+		#[test]
+		fn get_fee_in_native_asset() {
+			new_test_ext().execute_with(|| {
+				let evm_domain_id: DomainID = 1;
+				let substrate_domain_id: DomainID = 2;
+				let asset_id = Concrete(MultiLocation::new(1, Here));
+				let other_asset_id = Concrete(MultiLocation::new(2, Here));
+				let (pair, _): (ecdsa::Pair, _) = Pair::generate();
+				let quote = |dest_domain_id: DomainID| FeeOracleMsg {
+					dest_domain_id,
+					asset_id,
+					dst_gas_price: 20_000_000_000,
+					inclusion_fee: 1_000_000_000,
+					msg_gas_limit: 200_000,
+					ter: FixedU128::saturating_from_rational(3u128, 2u128),
+					ber: FixedU128::saturating_from_rational(1u128, 2u128),
+					expires_at: 10,
+				};
+				let context = |dest_domain_id: DomainID, transfer_type: TransferType| FeeContext {
+					sender: [0u8; 32],
+					dest_domain_id,
+					recipient: vec![1u8; 32],
+					transfer_type,
+				};
+
+				assert_ok!(DynamicFeeHandler::set_fee_oracle(
+					Origin::root(),
+					oracle_address(&pair)
+				));
+				assert_ok!(DynamicFeeHandler::set_destination_type(
+					Origin::root(),
+					evm_domain_id,
+					DestinationType::Evm { gas_used: 100_000 }
+				));
+				assert_ok!(DynamicFeeHandler::set_destination_type(
+					Origin::root(),
+					substrate_domain_id,
+					DestinationType::Substrate
+				));
+				for domain in [evm_domain_id, substrate_domain_id] {
+					assert_ok!(DynamicFeeHandler::submit_fee_quote(
+						Origin::signed(ALICE),
+						quote(domain),
+						sign_quote(&pair, &quote(domain))
+					));
+				}
+
+				// dstGasPrice * gasUsed * ter
+				assert_eq!(
+					DynamicFeeHandler::get_fee_in_asset(
+						evm_domain_id,
+						(asset_id, 100u128).into(),
+						asset_id
+					),
+					Some(3_000_000_000_000_000)
+				);
+				// dstGasPrice * gasUsed * ber
+				assert_eq!(
+					DynamicFeeHandler::get_fee_in_asset(
+						evm_domain_id,
+						(asset_id, 100u128).into(),
+						NativeFeeAsset::get()
+					),
+					Some(1_000_000_000_000_000)
+				);
+				// inclusionFee * ber
+				assert_eq!(
+					DynamicFeeHandler::get_fee_in_asset(
+						substrate_domain_id,
+						(asset_id, 100u128).into(),
+						NativeFeeAsset::get()
+					),
+					Some(500_000_000)
+				);
+				// fee can not be paid in an asset other than the transferred or native one
+				assert_eq!(
+					DynamicFeeHandler::get_fee_in_asset(
+						evm_domain_id,
+						(asset_id, 100u128).into(),
+						other_asset_id
+					),
+					None
+				);
+
+				// dstGasPrice * msgGasLimit * ber
+				assert_eq!(
+					DynamicFeeHandler::quote_fee(
+						&context(evm_domain_id, TransferType::GenericTransfer),
+						(asset_id, 0u128).into(),
+						NativeFeeAsset::get()
+					),
+					Some(FeeQuote {
+						fee_asset: NativeFeeAsset::get(),
+						amount: 2_000_000_000_000_000
+					})
+				);
+				// inclusionFee * ber
+				assert_eq!(
+					DynamicFeeHandler::quote_fee(
+						&context(substrate_domain_id, TransferType::GenericTransfer),
+						(asset_id, 0u128).into(),
+						NativeFeeAsset::get()
+					),
+					Some(FeeQuote { fee_asset: NativeFeeAsset::get(), amount: 500_000_000 })
+				);
+				// fee of generic message is only paid in native asset
+				assert_eq!(
+					DynamicFeeHandler::quote_fee(
+						&context(evm_domain_id, TransferType::GenericTransfer),
+						(asset_id, 0u128).into(),
+						asset_id
+					),
+					None
+				);
+				assert_eq!(
+					DynamicFeeHandler::quote_fee(
+						&context(evm_domain_id, TransferType::FungibleTransfer),
+						(asset_id, 100u128).into(),
+						NativeFeeAsset::get()
+					),
+					Some(FeeQuote {
+						fee_asset: NativeFeeAsset::get(),
+						amount: 1_000_000_000_000_000
+					})
+				);
+
+				// no fee in native asset once the quote expires
+				System::set_block_number(10);
+				assert_eq!(
+					DynamicFeeHandler::get_fee_in_asset(
+						evm_domain_id,
+						(asset_id, 100u128).into(),
+						NativeFeeAsset::get()
+					),
+					None
+				);
+			})
+		}
+	}
+}
@@ -0,0 +1,138 @@
+// The Licensed Work is (c) 2022 Sygma
+// SPDX-License-Identifier: LGPL-3.0-only
+
+use frame_support::{
+	pallet_prelude::ConstU32,
+	parameter_types,
+	sp_runtime::{
+		testing::H256,
+		traits::{BlakeTwo256, IdentityLookup},
+		AccountId32, BuildStorage, Perbill,
+	},
+};
+use frame_system::{self as system, EnsureRoot};
+use xcm::latest::{prelude::*, MultiLocation};
+
+use crate as dynamic_fee_handler;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) type Balance = u128;
+
+pub const ALICE: AccountId32 = AccountId32::new([0u8; 32]);
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		AccessSegregator: sygma_access_segregator::{Pallet, Call, Storage, Event<T>} = 3,
+		DynamicFeeHandler: dynamic_fee_handler::{Pallet, Call, Storage, Event<T>} = 4,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+	pub const MaxLocks: u32 = 100;
+	pub const MinimumPeriod: u64 = 1;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type Block = Block;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId32;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<2>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ();
+}
+
+parameter_types! {
+	// Make sure put same value with `construct_runtime`
+	pub const AccessSegregatorPalletIndex: u8 = 3;
+	pub const FeeHandlerPalletIndex: u8 = 4;
+	pub RegisteredExtrinsics: Vec<(u8, Vec<u8>)> = [
+		(AccessSegregatorPalletIndex::get(), b"grant_access".to_vec()),
+		(FeeHandlerPalletIndex::get(), b"set_fee_oracle".to_vec()),
+		(FeeHandlerPalletIndex::get(), b"set_destination_type".to_vec()),
+	].to_vec();
+	pub NativeFeeAsset: AssetId = Concrete(MultiLocation::here());
+}
+
+impl sygma_access_segregator::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type BridgeCommitteeOrigin = EnsureRoot<Self::AccountId>;
+	type PalletIndex = AccessSegregatorPalletIndex;
+	type Extrinsics = RegisteredExtrinsics;
+	type RuntimeCall = RuntimeCall;
+	type AccessOriginConverter = sygma_access_segregator::SignedAccessOrigin;
+	type MaxCollectiveGrants = ConstU32<8>;
+	type MaxRolesPerAccount = ConstU32<8>;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = sygma_access_segregator::weights::SygmaWeightInfo<Test>;
+}
+
+impl dynamic_fee_handler::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type PalletIndex = FeeHandlerPalletIndex;
+	type NativeFeeAsset = NativeFeeAsset;
+	type WeightInfo = dynamic_fee_handler::weights::SygmaWeightInfo<Test>;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+// Checks events against the latest. A contiguous set of events must be provided. They must
+// include the most recent event, but do not have to include every past event.
+pub fn assert_events(mut expected: Vec<RuntimeEvent>) {
+	let mut actual: Vec<RuntimeEvent> =
+		system::Pallet::<Test>::events().iter().map(|e| e.event.clone()).collect();
+
+	expected.reverse();
+
+	for evt in expected {
+		let next = actual.pop().expect("event expected");
+		assert_eq!(next, evt, "Events don't match");
+	}
+}
@@ -0,0 +1,80 @@
+
+//! Autogenerated weights for `sygma_dynamic_feehandler`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-04-26, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/standalone-node-template
+// benchmark
+// pallet
+// --chain
+// dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet
+// sygma_dynamic_feehandler
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// dynamic_feehandler_weight.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `sygma_dynamic_feehandler`.
+pub struct SygmaWeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaDynamicFeeHandler FeeOracle (r:0 w:1)
+	/// Proof Skipped: SygmaDynamicFeeHandler FeeOracle (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_fee_oracle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaDynamicFeeHandler DestinationTypes (r:0 w:1)
+	/// Proof Skipped: SygmaDynamicFeeHandler DestinationTypes (max_values: None, max_size: None, mode: Measured)
+	fn set_destination_type() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaDynamicFeeHandler FeeOracle (r:1 w:0)
+	/// Proof Skipped: SygmaDynamicFeeHandler FeeOracle (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: SygmaDynamicFeeHandler FeeQuotes (r:1 w:1)
+	/// Proof Skipped: SygmaDynamicFeeHandler FeeQuotes (max_values: None, max_size: None, mode: Measured)
+	fn submit_fee_quote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `182`
+		//  Estimated: `3647`
+		// Minimum execution time: 68_000_000 picoseconds.
+		Weight::from_parts(70_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3647))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
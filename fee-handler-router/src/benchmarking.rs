@@ -34,4 +34,45 @@ mod benchmarks {
 			Some(FeeHandlerType::BasicFeeHandler),
 		);
 	}
+
+	#[benchmark]
+	fn set_discount_tier() {
+		#[extrinsic_call]
+		set_discount_tier(SystemOrigin::Root, 1, 2500);
+
+		assert_eq!(DiscountTiers::<T>::get(1), Some(2500));
+	}
+
+	#[benchmark]
+	fn set_account_discount_tier() {
+		let who: T::AccountId = whitelisted_caller();
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		DiscountTiers::<T>::insert(1, 2500);
+
+		#[extrinsic_call]
+		set_account_discount_tier(SystemOrigin::Root, who.clone(), 1, expires_at);
+
+		assert_eq!(AccountDiscountTiers::<T>::get(who), Some((1, expires_at)));
+	}
+
+	#[benchmark]
+	fn remove_account_discount_tier() {
+		let who: T::AccountId = whitelisted_caller();
+		AccountDiscountTiers::<T>::insert(&who, (1, frame_system::Pallet::<T>::block_number()));
+
+		#[extrinsic_call]
+		remove_account_discount_tier(SystemOrigin::Root, who.clone());
+
+		assert_eq!(AccountDiscountTiers::<T>::get(who), None);
+	}
+
+	#[benchmark]
+	fn set_fee_exemption() {
+		let who: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		set_fee_exemption(SystemOrigin::Root, who.clone(), true);
+
+		assert!(FeeExemptions::<T>::get(who));
+	}
 }
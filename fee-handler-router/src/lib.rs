@@ -20,7 +20,7 @@ pub mod pallet {
 	use frame_support::{pallet_prelude::*, traits::StorageVersion};
 	use frame_system::pallet_prelude::*;
 	use sp_std::boxed::Box;
-	use sygma_traits::{DomainID, FeeHandler};
+	use sygma_traits::{DomainID, FeeContext, FeeHandler, FeeQuote};
 	use xcm::latest::{AssetId, MultiAsset};
 
 	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, RuntimeDebug, MaxEncodedLen)]
@@ -34,6 +34,10 @@ pub mod pallet {
 
 	pub trait WeightInfo {
 		fn set_fee_handler() -> Weight;
+		fn set_discount_tier() -> Weight;
+		fn set_account_discount_tier() -> Weight;
+		fn remove_account_discount_tier() -> Weight;
+		fn set_fee_exemption() -> Weight;
 	}
 
 	#[pallet::pallet]
@@ -64,6 +68,18 @@ pub mod pallet {
 		/// When fee handler was set for a specific (domain, asset) pair
 		/// args: [dest_domain_id, asset_id, handler_type]
 		FeeHandlerSet { domain: DomainID, asset: AssetId, handler_type: FeeHandlerType },
+		/// When discount of a tier was set, discount 0 removes the tier
+		/// args: [tier, discount_basis_point]
+		DiscountTierSet { tier: u8, discount_basis_point: u32 },
+		/// When an account was put on a discount tier
+		/// args: [who, tier, expires_at]
+		AccountDiscountTierSet { who: T::AccountId, tier: u8, expires_at: BlockNumberFor<T> },
+		/// When an account was removed from its discount tier
+		/// args: [who]
+		AccountDiscountTierRemoved { who: T::AccountId },
+		/// When an account was exempted from fee or the exemption was lifted
+		/// args: [who, exempt]
+		FeeExemptionSet { who: T::AccountId, exempt: bool },
 	}
 
 	#[pallet::error]
@@ -72,6 +88,14 @@ pub mod pallet {
 		AccessDenied,
 		/// Function unimplemented
 		Unimplemented,
+		/// Discount is more than 10000 basis point
+		DiscountOutOfRange,
+		/// Discount tier not set
+		DiscountTierNotSet,
+		/// Account is not on any discount tier
+		AccountDiscountTierNotSet,
+		/// Discount expires no later than the current block
+		InvalidDiscountExpiry,
 	}
 
 	/// Return the Fee handler type based on domainID and assetID
@@ -80,6 +104,23 @@ pub mod pallet {
 	pub type HandlerType<T: Config> =
 		StorageMap<_, Twox64Concat, (DomainID, AssetId), FeeHandlerType>;
 
+	/// Mapping discount tier to its discount in basis point
+	#[pallet::storage]
+	#[pallet::getter(fn discount_tiers)]
+	pub type DiscountTiers<T: Config> = StorageMap<_, Twox64Concat, u8, u32>;
+
+	/// Mapping account to its discount tier and the block the discount expires at
+	#[pallet::storage]
+	#[pallet::getter(fn account_discount_tiers)]
+	pub type AccountDiscountTiers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (u8, BlockNumberFor<T>)>;
+
+	/// Accounts exempt from bridge fee
+	#[pallet::storage]
+	#[pallet::getter(fn fee_exemptions)]
+	pub type FeeExemptions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Set fee handler specific (domain, asset) pair
@@ -108,9 +149,147 @@ pub mod pallet {
 			Self::deposit_event(Event::FeeHandlerSet { domain, asset, handler_type });
 			Ok(())
 		}
+
+		/// Set discount of a tier in basis point, discount 0 removes the tier
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_discount_tier())]
+		pub fn set_discount_tier(
+			origin: OriginFor<T>,
+			tier: u8,
+			discount_basis_point: u32,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_discount_tier".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(discount_basis_point <= 10_000u32, Error::<T>::DiscountOutOfRange);
+
+			// Update discount tier
+			if discount_basis_point == 0 {
+				DiscountTiers::<T>::remove(tier);
+			} else {
+				DiscountTiers::<T>::insert(tier, discount_basis_point);
+			}
+
+			// Emit DiscountTierSet event
+			Self::deposit_event(Event::DiscountTierSet { tier, discount_basis_point });
+			Ok(())
+		}
+
+		/// Put an account on a discount tier until `expires_at`
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_account_discount_tier())]
+		pub fn set_account_discount_tier(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			tier: u8,
+			expires_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_account_discount_tier".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(DiscountTiers::<T>::contains_key(tier), Error::<T>::DiscountTierNotSet);
+			ensure!(
+				expires_at > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidDiscountExpiry
+			);
+
+			// Update account discount tier
+			AccountDiscountTiers::<T>::insert(&who, (tier, expires_at));
+
+			// Emit AccountDiscountTierSet event
+			Self::deposit_event(Event::AccountDiscountTierSet { who, tier, expires_at });
+			Ok(())
+		}
+
+		/// Remove an account from its discount tier
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_account_discount_tier())]
+		pub fn remove_account_discount_tier(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"remove_account_discount_tier".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+			ensure!(
+				AccountDiscountTiers::<T>::contains_key(&who),
+				Error::<T>::AccountDiscountTierNotSet
+			);
+
+			// Remove account discount tier
+			AccountDiscountTiers::<T>::remove(&who);
+
+			// Emit AccountDiscountTierRemoved event
+			Self::deposit_event(Event::AccountDiscountTierRemoved { who });
+			Ok(())
+		}
+
+		/// Exempt an account from fee, or lift its exemption
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_exemption())]
+		pub fn set_fee_exemption(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			exempt: bool,
+		) -> DispatchResult {
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_fee_exemption".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+
+			// Update fee exemption
+			if exempt {
+				FeeExemptions::<T>::insert(&who, true);
+			} else {
+				FeeExemptions::<T>::remove(&who);
+			}
+
+			// Emit FeeExemptionSet event
+			Self::deposit_event(Event::FeeExemptionSet { who, exempt });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Apply the fee exemption or the unexpired tier discount of the account to the quote
+		fn apply_discount(who: &T::AccountId, quote: FeeQuote) -> FeeQuote {
+			if FeeExemptions::<T>::get(who) {
+				return FeeQuote { amount: 0, ..quote };
+			}
+
+			let discount_basis_point = AccountDiscountTiers::<T>::get(who)
+				.filter(|(_, expires_at)| *expires_at > <frame_system::Pallet<T>>::block_number())
+				.and_then(|(tier, _)| DiscountTiers::<T>::get(tier))
+				.unwrap_or_default();
+			let discount =
+				quote.amount.saturating_mul(discount_basis_point as u128).saturating_div(10000);
+			FeeQuote { amount: quote.amount.saturating_sub(discount), ..quote }
+		}
 	}
 
-	impl<T: Config> FeeHandler for Pallet<T> {
+	impl<T: Config> FeeHandler for Pallet<T>
+	where
+		<T as frame_system::Config>::AccountId: From<[u8; 32]>,
+	{
 		fn get_fee(domain: DomainID, asset: MultiAsset) -> Option<u128> {
 			if let Some(handler_type) = HandlerType::<T>::get((&domain, asset.id)) {
 				match handler_type {
@@ -121,14 +300,67 @@ pub mod pallet {
 						sygma_percentage_feehandler::Pallet::<T>::get_fee(domain, asset)
 					},
 					FeeHandlerType::DynamicFeeHandler => {
-						// TODO: Support dynamic fee handler
-						None
+						T::DynamicFeeHandler::get_fee(domain, asset)
 					},
 				}
 			} else {
 				None
 			}
 		}
+
+		fn get_fee_in_asset(
+			domain: DomainID,
+			asset: MultiAsset,
+			fee_asset: AssetId,
+		) -> Option<u128> {
+			if let Some(handler_type) = HandlerType::<T>::get((&domain, asset.id)) {
+				match handler_type {
+					FeeHandlerType::BasicFeeHandler => {
+						sygma_basic_feehandler::Pallet::<T>::get_fee_in_asset(
+							domain, asset, fee_asset,
+						)
+					},
+					FeeHandlerType::PercentageFeeHandler => {
+						sygma_percentage_feehandler::Pallet::<T>::get_fee_in_asset(
+							domain, asset, fee_asset,
+						)
+					},
+					FeeHandlerType::DynamicFeeHandler => {
+						T::DynamicFeeHandler::get_fee_in_asset(domain, asset, fee_asset)
+					},
+				}
+			} else {
+				None
+			}
+		}
+
+		fn quote_fee(
+			context: &FeeContext,
+			asset: MultiAsset,
+			fee_asset: AssetId,
+		) -> Option<FeeQuote> {
+			// Discount is applied after the selected fee handler returns
+			let quote = if let Some(handler_type) =
+				HandlerType::<T>::get((&context.dest_domain_id, asset.id))
+			{
+				match handler_type {
+					FeeHandlerType::BasicFeeHandler => {
+						sygma_basic_feehandler::Pallet::<T>::quote_fee(context, asset, fee_asset)
+					},
+					FeeHandlerType::PercentageFeeHandler => {
+						sygma_percentage_feehandler::Pallet::<T>::quote_fee(
+							context, asset, fee_asset,
+						)
+					},
+					FeeHandlerType::DynamicFeeHandler => {
+						T::DynamicFeeHandler::quote_fee(context, asset, fee_asset)
+					},
+				}
+			} else {
+				None
+			}?;
+			Some(Self::apply_discount(&context.sender.into(), quote))
+		}
 	}
 
 	#[cfg(test)]
@@ -138,11 +370,12 @@ pub mod pallet {
 		use fee_router::mock::{
 			assert_events, new_test_ext, AccessSegregator, EthereumDomainID, FeeHandlerRouter,
 			FeeHandlerRouterPalletIndex, MoonbeamDomainID, PhaLocation, RuntimeEvent,
-			RuntimeOrigin as Origin, SygmaBasicFeeHandler, Test, ALICE,
+			RuntimeOrigin as Origin, SygmaBasicFeeHandler, System, Test, ALICE, BOB,
 		};
 		use frame_support::{assert_noop, assert_ok};
+		use sp_runtime::AccountId32;
 		use sp_std::boxed::Box;
-		use sygma_traits::FeeHandler;
+		use sygma_traits::{FeeContext, FeeHandler, FeeQuote, TransferType};
 		use xcm::latest::prelude::*;
 
 		#[test]
@@ -230,7 +463,7 @@ pub mod pallet {
 					.unwrap(),
 					10000
 				);
-				// We don't support dynamic fee handler, return None
+				// No dynamic fee handler configured in mock, return None
 				assert_eq!(
 					FeeHandlerRouter::get_fee(
 						MoonbeamDomainID::get(),
@@ -257,5 +490,248 @@ pub mod pallet {
 				]);
 			})
 		}
+
+		#[test]
+		fn fee_router_in_fee_asset_should_work() {
+			new_test_ext().execute_with(|| {
+				let usdt_asset_id: AssetId = Concrete(MultiLocation::new(1, X1(Parachain(1000))));
+
+				// config dest of (ethereum, USDT) use basic fee handler
+				assert_ok!(FeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					EthereumDomainID::get(),
+					Box::new(usdt_asset_id),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				// set fee 10000 in USDT and 200 in PHA for transferring USDT to Ethereum
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					EthereumDomainID::get(),
+					Box::new(usdt_asset_id),
+					10000
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee_in_asset(
+					Origin::root(),
+					EthereumDomainID::get(),
+					Box::new(usdt_asset_id),
+					Box::new(PhaLocation::get().into()),
+					200
+				));
+
+				assert_eq!(
+					FeeHandlerRouter::get_fee_in_asset(
+						EthereumDomainID::get(),
+						(usdt_asset_id, 10000u128).into(),
+						usdt_asset_id
+					),
+					Some(10000)
+				);
+				assert_eq!(
+					FeeHandlerRouter::get_fee_in_asset(
+						EthereumDomainID::get(),
+						(usdt_asset_id, 10000u128).into(),
+						PhaLocation::get().into()
+					),
+					Some(200)
+				);
+				// no fee handler set for Moonbeam, return None
+				assert_eq!(
+					FeeHandlerRouter::get_fee_in_asset(
+						MoonbeamDomainID::get(),
+						(usdt_asset_id, 10000u128).into(),
+						PhaLocation::get().into()
+					),
+					None
+				);
+			})
+		}
+
+		#[test]
+		fn fee_router_quote_fee_should_work() {
+			new_test_ext().execute_with(|| {
+				let context = FeeContext {
+					sender: ALICE.into(),
+					dest_domain_id: EthereumDomainID::get(),
+					recipient: [1u8; 20].to_vec(),
+					transfer_type: TransferType::FungibleTransfer,
+				};
+
+				// no fee handler set, return None
+				assert_eq!(
+					FeeHandlerRouter::quote_fee(
+						&context,
+						(PhaLocation::get(), 10000u128).into(),
+						PhaLocation::get().into()
+					),
+					None
+				);
+
+				assert_ok!(FeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					EthereumDomainID::get(),
+					Box::new(PhaLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					EthereumDomainID::get(),
+					Box::new(PhaLocation::get().into()),
+					10000
+				));
+
+				// basic fee handler quotes by the dest domain of the context
+				assert_eq!(
+					FeeHandlerRouter::quote_fee(
+						&context,
+						(PhaLocation::get(), 10000u128).into(),
+						PhaLocation::get().into()
+					),
+					Some(FeeQuote { fee_asset: PhaLocation::get().into(), amount: 10000 })
+				);
+				assert_eq!(
+					FeeHandlerRouter::quote_fee(
+						&FeeContext { dest_domain_id: MoonbeamDomainID::get(), ..context },
+						(PhaLocation::get(), 10000u128).into(),
+						PhaLocation::get().into()
+					),
+					None
+				);
+			})
+		}
+
+		#[test]
+		fn fee_discount_should_work() {
+			new_test_ext().execute_with(|| {
+				let quote_fee = |sender: AccountId32| {
+					FeeHandlerRouter::quote_fee(
+						&FeeContext {
+							sender: sender.into(),
+							dest_domain_id: EthereumDomainID::get(),
+							recipient: [1u8; 20].to_vec(),
+							transfer_type: TransferType::FungibleTransfer,
+						},
+						(PhaLocation::get(), 10000u128).into(),
+						PhaLocation::get().into(),
+					)
+					.map(|quote| quote.amount)
+				};
+				assert_ok!(FeeHandlerRouter::set_fee_handler(
+					Origin::root(),
+					EthereumDomainID::get(),
+					Box::new(PhaLocation::get().into()),
+					FeeHandlerType::BasicFeeHandler,
+				));
+				assert_ok!(SygmaBasicFeeHandler::set_fee(
+					Origin::root(),
+					EthereumDomainID::get(),
+					Box::new(PhaLocation::get().into()),
+					10000
+				));
+
+				// discount out of range or of unknown tier should fail
+				assert_noop!(
+					FeeHandlerRouter::set_discount_tier(Origin::root(), 1, 10001),
+					fee_router::Error::<Test>::DiscountOutOfRange
+				);
+				assert_noop!(
+					FeeHandlerRouter::set_account_discount_tier(Origin::root(), ALICE, 1, 10),
+					fee_router::Error::<Test>::DiscountTierNotSet
+				);
+				assert_noop!(
+					FeeHandlerRouter::remove_account_discount_tier(Origin::root(), ALICE),
+					fee_router::Error::<Test>::AccountDiscountTierNotSet
+				);
+
+				// put ALICE on 25% discount tier until block 10
+				assert_ok!(FeeHandlerRouter::set_discount_tier(Origin::root(), 1, 2500));
+				assert_noop!(
+					FeeHandlerRouter::set_account_discount_tier(Origin::root(), ALICE, 1, 1),
+					fee_router::Error::<Test>::InvalidDiscountExpiry
+				);
+				assert_ok!(FeeHandlerRouter::set_account_discount_tier(
+					Origin::root(),
+					ALICE,
+					1,
+					10
+				));
+				assert_eq!(quote_fee(ALICE), Some(7500));
+				assert_eq!(quote_fee(BOB), Some(10000));
+				// discount doesn't apply to quotes without sender
+				assert_eq!(
+					FeeHandlerRouter::get_fee(
+						EthereumDomainID::get(),
+						(PhaLocation::get(), 10000u128).into()
+					),
+					Some(10000)
+				);
+
+				// exempt BOB from fee
+				assert_ok!(FeeHandlerRouter::set_fee_exemption(Origin::root(), BOB, true));
+				assert_eq!(quote_fee(BOB), Some(0));
+				assert_ok!(FeeHandlerRouter::set_fee_exemption(Origin::root(), BOB, false));
+				assert_eq!(quote_fee(BOB), Some(10000));
+
+				// discount is gone once it expires, the tier is removed or the account is removed
+				System::set_block_number(10);
+				assert_eq!(quote_fee(ALICE), Some(10000));
+				assert_ok!(FeeHandlerRouter::set_account_discount_tier(
+					Origin::root(),
+					ALICE,
+					1,
+					20
+				));
+				assert_eq!(quote_fee(ALICE), Some(7500));
+				assert_ok!(FeeHandlerRouter::set_discount_tier(Origin::root(), 1, 0));
+				assert_eq!(quote_fee(ALICE), Some(10000));
+				assert_ok!(FeeHandlerRouter::set_discount_tier(Origin::root(), 1, 10000));
+				assert_eq!(quote_fee(ALICE), Some(0));
+				assert_ok!(FeeHandlerRouter::remove_account_discount_tier(Origin::root(), ALICE));
+				assert_eq!(quote_fee(ALICE), Some(10000));
+
+				assert_events(vec![
+					RuntimeEvent::FeeHandlerRouter(fee_router::Event::DiscountTierSet {
+						tier: 1,
+						discount_basis_point: 10000,
+					}),
+					RuntimeEvent::FeeHandlerRouter(fee_router::Event::AccountDiscountTierRemoved {
+						who: ALICE,
+					}),
+				]);
+			})
+		}
+
+		#[test]
+		fn fee_discount_access_control() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					FeeHandlerRouter::set_discount_tier(Origin::signed(ALICE), 1, 2500),
+					fee_router::Error::<Test>::AccessDenied
+				);
+				assert_noop!(
+					FeeHandlerRouter::set_account_discount_tier(Origin::signed(ALICE), BOB, 1, 10),
+					fee_router::Error::<Test>::AccessDenied
+				);
+				assert_noop!(
+					FeeHandlerRouter::remove_account_discount_tier(Origin::signed(ALICE), BOB),
+					fee_router::Error::<Test>::AccessDenied
+				);
+				assert_noop!(
+					FeeHandlerRouter::set_fee_exemption(Origin::signed(ALICE), ALICE, true),
+					fee_router::Error::<Test>::AccessDenied
+				);
+
+				assert_ok!(AccessSegregator::grant_access(
+					Origin::root(),
+					FeeHandlerRouterPalletIndex::get(),
+					b"set_fee_exemption".to_vec(),
+					ALICE
+				));
+				assert_ok!(FeeHandlerRouter::set_fee_exemption(Origin::signed(ALICE), BOB, true));
+				assert!(FeeHandlerRouter::fee_exemptions(BOB));
+				assert_events(vec![RuntimeEvent::FeeHandlerRouter(
+					fee_router::Event::FeeExemptionSet { who: BOB, exempt: true },
+				)]);
+			})
+		}
 	}
 }
@@ -22,6 +22,7 @@ type Block = frame_system::mocking::MockBlock<Test>;
 pub(crate) type Balance = u128;
 
 pub const ALICE: AccountId32 = AccountId32::new([0u8; 32]);
+pub const BOB: AccountId32 = AccountId32::new([1u8; 32]);
 
 frame_support::construct_runtime!(
 	pub enum Test {
@@ -131,6 +132,10 @@ parameter_types! {
 	pub RegisteredExtrinsics: Vec<(u8, Vec<u8>)> = [
 		(AccessSegregatorPalletIndex::get(), b"grant_access".to_vec()),
 		(FeeHandlerRouterPalletIndex::get(), b"set_fee_handler".to_vec()),
+		(FeeHandlerRouterPalletIndex::get(), b"set_discount_tier".to_vec()),
+		(FeeHandlerRouterPalletIndex::get(), b"set_account_discount_tier".to_vec()),
+		(FeeHandlerRouterPalletIndex::get(), b"remove_account_discount_tier".to_vec()),
+		(FeeHandlerRouterPalletIndex::get(), b"set_fee_exemption".to_vec()),
 	].to_vec();
 	pub PhaLocation: MultiLocation = MultiLocation::here();
 }
@@ -146,6 +151,11 @@ impl sygma_access_segregator::Config for Test {
 	type BridgeCommitteeOrigin = EnsureRoot<Self::AccountId>;
 	type PalletIndex = AccessSegregatorPalletIndex;
 	type Extrinsics = RegisteredExtrinsics;
+	type RuntimeCall = RuntimeCall;
+	type AccessOriginConverter = sygma_access_segregator::SignedAccessOrigin;
+	type MaxCollectiveGrants = ConstU32<8>;
+	type MaxRolesPerAccount = ConstU32<8>;
+	type PalletsOrigin = OriginCaller;
 	type WeightInfo = sygma_access_segregator::weights::SygmaWeightInfo<Test>;
 }
 
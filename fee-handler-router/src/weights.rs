@@ -35,6 +35,8 @@ use sp_std::marker::PhantomData;
 /// Weight functions for `sygma_fee_handler_router`.
 pub struct SygmaWeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaFeeHandlerRouter HandlerType (r:0 w:1)
 	/// Proof Skipped: SygmaFeeHandlerRouter HandlerType (max_values: None, max_size: None, mode: Measured)
 	fn set_fee_handler() -> Weight {
@@ -44,6 +46,65 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 		// Minimum execution time: 9_000_000 picoseconds.
 		Weight::from_parts(9_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaFeeHandlerRouter DiscountTiers (r:0 w:1)
+	/// Proof Skipped: SygmaFeeHandlerRouter DiscountTiers (max_values: None, max_size: None, mode: Measured)
+	fn set_discount_tier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaFeeHandlerRouter DiscountTiers (r:1 w:0)
+	/// Proof Skipped: SygmaFeeHandlerRouter DiscountTiers (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaFeeHandlerRouter AccountDiscountTiers (r:0 w:1)
+	/// Proof Skipped: SygmaFeeHandlerRouter AccountDiscountTiers (max_values: None, max_size: None, mode: Measured)
+	fn set_account_discount_tier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3507`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaFeeHandlerRouter AccountDiscountTiers (r:1 w:1)
+	/// Proof Skipped: SygmaFeeHandlerRouter AccountDiscountTiers (max_values: None, max_size: None, mode: Measured)
+	fn remove_account_discount_tier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `95`
+		//  Estimated: `3560`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3560))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaFeeHandlerRouter FeeExemptions (r:0 w:1)
+	/// Proof Skipped: SygmaFeeHandlerRouter FeeExemptions (max_values: None, max_size: None, mode: Measured)
+	fn set_fee_exemption() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
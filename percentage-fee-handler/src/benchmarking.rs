@@ -6,6 +6,7 @@
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 use frame_benchmarking::v2::*;
+use frame_support::sp_runtime::{FixedPointNumber, FixedU128};
 use frame_system::RawOrigin as SystemOrigin;
 
 use sp_std::vec;
@@ -37,4 +38,24 @@ mod benchmarks {
 			Some((fee_rate, 0u128, 100_000_000_000_000u128)),
 		);
 	}
+
+	#[benchmark]
+	fn set_fee_asset_rate() {
+		let asset_location: MultiLocation = MultiLocation::new(1, X1(Parachain(2004)));
+		let native_location: MultiLocation = MultiLocation::here();
+		let rate = FixedU128::saturating_from_rational(3u128, 2u128);
+
+		#[extrinsic_call]
+		set_fee_asset_rate(
+			SystemOrigin::Root,
+			Box::new(asset_location.into()),
+			Box::new(native_location.into()),
+			rate,
+		);
+
+		assert_eq!(
+			FeeAssetRate::<T>::get(&(asset_location.into(), native_location.into())),
+			Some(rate),
+		);
+	}
 }
@@ -17,7 +17,12 @@ mod mock;
 #[allow(clippy::large_enum_variant)]
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::{dispatch::DispatchResult, pallet_prelude::*, traits::StorageVersion};
+	use frame_support::{
+		dispatch::DispatchResult,
+		pallet_prelude::*,
+		sp_runtime::{traits::Zero, FixedPointNumber, FixedU128},
+		traits::StorageVersion,
+	};
 	use frame_system::pallet_prelude::*;
 	use sp_std::boxed::Box;
 	use sygma_traits::{DomainID, FeeHandler};
@@ -30,8 +35,14 @@ pub mod pallet {
 	pub type AssetFeeRate<T: Config> =
 		StorageMap<_, Twox64Concat, (DomainID, AssetId), (u32, u128, u128)>;
 
+	/// Mapping fungible asset id and the asset its fee can be paid in to the amount of fee asset
+	/// equivalent to one unit of the fungible asset
+	#[pallet::storage]
+	pub type FeeAssetRate<T: Config> = StorageMap<_, Twox64Concat, (AssetId, AssetId), FixedU128>;
+
 	pub trait WeightInfo {
 		fn set_fee_rate() -> Weight;
+		fn set_fee_asset_rate() -> Weight;
 	}
 
 	#[pallet::pallet]
@@ -61,6 +72,9 @@ pub mod pallet {
 			fee_lower_bound: u128,
 			fee_upper_bound: u128,
 		},
+		/// Exchange rate set between a specific asset and the asset its fee is paid in
+		/// args: [asset, fee_asset, rate]
+		FeeAssetRateSet { asset: AssetId, fee_asset: AssetId, rate: FixedU128 },
 	}
 
 	#[pallet::error]
@@ -74,6 +88,8 @@ pub mod pallet {
 
 		/// Percentage fee bound is invalid
 		InvalidFeeBound,
+		/// Fee asset rate is zero or set between an asset and itself
+		InvalidFeeAssetRate,
 	}
 
 	#[pallet::call]
@@ -122,6 +138,38 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Set exchange rate between a specific asset and the asset its fee is paid in. The
+		/// percentage fee is calculated in `asset` and then converted to `fee_asset` with the rate
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_asset_rate())]
+		pub fn set_fee_asset_rate(
+			origin: OriginFor<T>,
+			asset: Box<AssetId>,
+			fee_asset: Box<AssetId>,
+			rate: FixedU128,
+		) -> DispatchResult {
+			let asset: AssetId = *asset;
+			let fee_asset: AssetId = *fee_asset;
+			ensure!(
+				<sygma_access_segregator::pallet::Pallet<T>>::has_access(
+					<T as Config>::PalletIndex::get(),
+					b"set_fee_asset_rate".to_vec(),
+					origin
+				),
+				Error::<T>::AccessDenied
+			);
+
+			// Make sure fee asset rate is valid
+			ensure!(asset != fee_asset && !rate.is_zero(), Error::<T>::InvalidFeeAssetRate);
+
+			// Update fee asset rate
+			FeeAssetRate::<T>::insert((&asset, &fee_asset), rate);
+
+			// Emit FeeAssetRateSet event
+			Self::deposit_event(Event::FeeAssetRateSet { asset, fee_asset, rate });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> FeeHandler for Pallet<T> {
@@ -143,20 +191,37 @@ pub mod pallet {
 				_ => None,
 			}
 		}
+
+		fn get_fee_in_asset(
+			domain: DomainID,
+			asset: MultiAsset,
+			fee_asset: AssetId,
+		) -> Option<u128> {
+			if asset.id == fee_asset {
+				return Self::get_fee(domain, asset);
+			}
+
+			let rate = FeeAssetRate::<T>::get((&asset.id, &fee_asset))?;
+			let fee_amount = Self::get_fee(domain, asset)?;
+			Some(rate.saturating_mul_int(fee_amount))
+		}
 	}
 
 	#[cfg(test)]
 	mod test {
 		use crate as percentage_fee_handler;
-		use crate::{AssetFeeRate, Event as PercentageFeeHandlerEvent};
-		use frame_support::{assert_noop, assert_ok};
+		use crate::{AssetFeeRate, Event as PercentageFeeHandlerEvent, FeeAssetRate};
+		use frame_support::{
+			assert_noop, assert_ok,
+			sp_runtime::{traits::Zero, FixedPointNumber, FixedU128},
+		};
 		use percentage_fee_handler::mock::{
 			assert_events, new_test_ext, AccessSegregator, PercentageFeeHandler,
 			PercentageFeeHandlerPalletIndex, RuntimeEvent as Event, RuntimeOrigin as Origin, Test,
 			ALICE,
 		};
 		use sp_std::boxed::Box;
-		use sygma_traits::DomainID;
+		use sygma_traits::{DomainID, FeeHandler};
 		use xcm::latest::{prelude::*, MultiLocation};
 
 		#[test]
@@ -312,5 +377,97 @@ pub mod pallet {
 				);
 			})
 		}
+
+		#[test]
+		fn set_get_fee_in_asset() {
+			new_test_ext().execute_with(|| {
+				let dest_domain_id: DomainID = 0;
+				let asset_id = Concrete(MultiLocation::new(1, X1(Parachain(2004))));
+				let fee_asset_id = Concrete(MultiLocation::new(0, Here));
+				let asset: MultiAsset = (asset_id, 10_000u128).into();
+				// one unit of asset is worth 2.5 units of fee asset
+				let rate = FixedU128::saturating_from_rational(5u128, 2u128);
+
+				assert_ok!(PercentageFeeHandler::set_fee_rate(
+					Origin::root(),
+					dest_domain_id,
+					Box::new(asset_id),
+					100u32,
+					0u128,
+					1_000u128
+				));
+
+				// no rate set, fee can not be paid in fee asset
+				assert_eq!(
+					PercentageFeeHandler::get_fee_in_asset(
+						dest_domain_id,
+						asset.clone(),
+						fee_asset_id
+					),
+					None
+				);
+
+				// rate between an asset and itself is not allowed
+				assert_noop!(
+					PercentageFeeHandler::set_fee_asset_rate(
+						Origin::root(),
+						Box::new(asset_id),
+						Box::new(asset_id),
+						rate
+					),
+					percentage_fee_handler::Error::<Test>::InvalidFeeAssetRate
+				);
+				// zero rate is not allowed
+				assert_noop!(
+					PercentageFeeHandler::set_fee_asset_rate(
+						Origin::root(),
+						Box::new(asset_id),
+						Box::new(fee_asset_id),
+						FixedU128::zero()
+					),
+					percentage_fee_handler::Error::<Test>::InvalidFeeAssetRate
+				);
+				// unauthorized account should not be able to set rate
+				assert_noop!(
+					PercentageFeeHandler::set_fee_asset_rate(
+						Some(ALICE).into(),
+						Box::new(asset_id),
+						Box::new(fee_asset_id),
+						rate
+					),
+					percentage_fee_handler::Error::<Test>::AccessDenied
+				);
+
+				assert_ok!(PercentageFeeHandler::set_fee_asset_rate(
+					Origin::root(),
+					Box::new(asset_id),
+					Box::new(fee_asset_id),
+					rate
+				));
+				assert_eq!(FeeAssetRate::<Test>::get((asset_id, fee_asset_id)).unwrap(), rate);
+
+				// 1% of 10000 is 100 asset, which is 250 fee asset
+				assert_eq!(PercentageFeeHandler::get_fee(dest_domain_id, asset.clone()), Some(100));
+				assert_eq!(
+					PercentageFeeHandler::get_fee_in_asset(dest_domain_id, asset, fee_asset_id),
+					Some(250)
+				);
+
+				assert_events(vec![
+					Event::PercentageFeeHandler(PercentageFeeHandlerEvent::FeeRateSet {
+						domain: dest_domain_id,
+						asset: asset_id,
+						rate_basis_point: 100u32,
+						fee_lower_bound: 0u128,
+						fee_upper_bound: 1_000u128,
+					}),
+					Event::PercentageFeeHandler(PercentageFeeHandlerEvent::FeeAssetRateSet {
+						asset: asset_id,
+						fee_asset: fee_asset_id,
+						rate,
+					}),
+				]);
+			})
+		}
 	}
 }
@@ -37,6 +37,8 @@ use core::marker::PhantomData;
 /// Weight functions for `sygma_percentage_feehandler`.
 pub struct SygmaWeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
 	/// Storage: SygmaPercentageFeeHandler AssetFeeRate (r:0 w:1)
 	/// Proof: SygmaPercentageFeeHandler AssetFeeRate (max_values: None, max_size: Some(648), added: 3123, mode: MaxEncodedLen)
 	fn set_fee_rate() -> Weight {
@@ -46,6 +48,21 @@ impl<T: frame_system::Config> super::WeightInfo for SygmaWeightInfo<T> {
 		// Minimum execution time: 31_000_000 picoseconds.
 		Weight::from_parts(32_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SygmaAccessSegregator ExtrinsicDelays (r:1 w:0)
+	/// Proof Skipped: SygmaAccessSegregator ExtrinsicDelays (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SygmaPercentageFeeHandler FeeAssetRate (r:0 w:1)
+	/// Proof: SygmaPercentageFeeHandler FeeAssetRate (max_values: None, max_size: Some(1262), added: 3737, mode: MaxEncodedLen)
+	fn set_fee_asset_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
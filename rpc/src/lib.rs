@@ -2,13 +2,15 @@
 // SPDX-License-Identifier: LGPL-3.0-only
 use std::{marker::PhantomData, sync::Arc};
 
+use codec::Codec;
 use jsonrpsee::{
 	core::{async_trait, Error as JsonRpseeError, RpcResult},
 	proc_macros::rpc,
 };
+use serde::{de::DeserializeOwned, Serialize};
 use sp_api::{BlockT, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sygma_runtime_api::SygmaBridgeApi;
+use sygma_runtime_api::{DepositRecord, EIP712DomainParams, SygmaBridgeApi};
 use sygma_traits::{DepositNonce, DomainID};
 
 pub struct SygmaBridgeStorage<Block: BlockT, C> {
@@ -24,7 +26,7 @@ impl<Block: BlockT, C> SygmaBridgeStorage<Block, C> {
 }
 
 #[rpc(server, namespace = "sygma")]
-pub trait SygmaBridgeRpc<BlockHash> {
+pub trait SygmaBridgeRpc<BlockHash, AccountId, BlockNumber> {
 	#[method(name = "isProposalExecuted")]
 	fn is_proposal_executed(
 		&self,
@@ -32,16 +34,31 @@ pub trait SygmaBridgeRpc<BlockHash> {
 		domain_id: DomainID,
 		at: Option<BlockHash>,
 	) -> RpcResult<bool>;
+
+	#[method(name = "depositRecord")]
+	fn deposit_record(
+		&self,
+		dest_domain_id: DomainID,
+		deposit_nonce: DepositNonce,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<DepositRecord<AccountId, BlockNumber>>>;
+
+	#[method(name = "eip712Domain")]
+	fn eip712_domain(&self, at: Option<BlockHash>) -> RpcResult<EIP712DomainParams>;
 }
 
 #[async_trait]
-impl<Block, C> SygmaBridgeRpcServer<<Block as BlockT>::Hash> for SygmaBridgeStorage<Block, C>
+impl<Block, C, AccountId, BlockNumber>
+	SygmaBridgeRpcServer<<Block as BlockT>::Hash, AccountId, BlockNumber>
+	for SygmaBridgeStorage<Block, C>
 where
 	Block: BlockT,
 	C: Send + Sync + 'static,
 	C: ProvideRuntimeApi<Block>,
 	C: HeaderBackend<Block>,
-	C::Api: SygmaBridgeApi<Block>,
+	C::Api: SygmaBridgeApi<Block, AccountId, BlockNumber>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
 {
 	fn is_proposal_executed(
 		&self,
@@ -55,4 +72,25 @@ where
 		let runtime_api_result = api.is_proposal_executed(at, nonce, domain_id);
 		runtime_api_result.map_err(|e| JsonRpseeError::Custom(format!("runtime error: {e:?}")))
 	}
+
+	fn deposit_record(
+		&self,
+		dest_domain_id: DomainID,
+		deposit_nonce: DepositNonce,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<DepositRecord<AccountId, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let runtime_api_result = api.deposit_record(at, dest_domain_id, deposit_nonce);
+		runtime_api_result.map_err(|e| JsonRpseeError::Custom(format!("runtime error: {e:?}")))
+	}
+
+	fn eip712_domain(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<EIP712DomainParams> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let runtime_api_result = api.eip712_domain(at);
+		runtime_api_result.map_err(|e| JsonRpseeError::Custom(format!("runtime error: {e:?}")))
+	}
 }
@@ -3,10 +3,22 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::Codec;
+pub use sygma_bridge::{DepositRecord, EIP712DomainParams};
 use sygma_traits::{DepositNonce, DomainID};
 
 sp_api::decl_runtime_apis! {
-	pub trait SygmaBridgeApi {
+	#[api_version(3)]
+	pub trait SygmaBridgeApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
 		fn is_proposal_executed(nonce: DepositNonce, domain_id: DomainID) -> bool;
+		fn deposit_record(
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		) -> Option<DepositRecord<AccountId, BlockNumber>>;
+		fn eip712_domain() -> EIP712DomainParams;
 	}
 }
@@ -10,12 +10,16 @@ const {
     setFeeHandler,
     setMpcAddress,
     registerDomain,
+    registerResource,
+    setDecimals,
     setFee,
     setFeeRate,
     getNativeAssetId,
     getERC20TSTAssetId,
     getERC20TSTD20AssetId,
+    getNftAssetId,
     createAsset,
+    createNftCollection,
     setAssetMetadata,
     mintAsset,
     getUSDCAssetId,
@@ -70,6 +74,16 @@ async function main() {
         await registerDomain(api, domain.domainID, domain.chainID, true, sudo);
     }
 
+    // bind supported assets with their resourceID, those resourceIDs are defined in runtime.rs
+    await registerResource(api, "0x0000000000000000000000000000000000000000000000000000000000000001", getNativeAssetId(api), true, sudo);
+    await registerResource(api, "0x0000000000000000000000000000000000000000000000000000000000000300", getUSDCAssetId(api), true, sudo);
+    await registerResource(api, "0x0000000000000000000000000000000000000000000000000000000000000000", getERC20TSTAssetId(api), true, sudo);
+    await registerResource(api, "0x0000000000000000000000000000000000000000000000000000000000000900", getERC20TSTD20AssetId(api), true, sudo);
+    await registerResource(api, "0x0000000000000000000000000000000000000000000000000000000000000600", getNftAssetId(api), true, sudo);
+
+    // set decimals of native asset, decimals of other assets come from their metadata
+    await setDecimals(api, getNativeAssetId(api), 12, true, sudo);
+
     // set fee rate for native asset for domains
     for (const domain of supportedDestDomains) {
         await setFeeHandler(api, domain.domainID, getNativeAssetId(api), feeHandlerType.PercentageFeeHandler, true, sudo)
@@ -118,6 +132,11 @@ async function main() {
     await mintAsset(api, erc20tstd20AssetID, erc20tstd20Admin, bn1e20.mul(new BN(100)), true, sudo); // mint 100 ERC20TSTD20 to Alice
     await mintAsset(api, erc20tstd20AssetID, TransferReserveOtherAccount, bn1e12.mul(new BN(100)), true, sudo); // mint 100 ERC20TSTD20 to OtherTokenReserved account
 
+    // create NFT test collection (foreign non-fungible asset), its fee is paid in native asset
+    // NftCollectionId: CollectionId defined in runtime.rs
+    const nftCollectionID = 0;
+    await createNftCollection(api, nftCollectionID, sudo.address, true, sudo);
+
     // set fee for tokens with domains
     for (const domain of supportedDestDomains) {
         await setFeeHandler(api, domain.domainID, getUSDCAssetId(api), feeHandlerType.PercentageFeeHandler, true, sudo)
@@ -218,6 +218,42 @@ async function createAsset(api, id, admin, minBalance, finalization, sudo) {
     });
 }
 
+async function createNftCollection(api, id, owner, finalization, sudo) {
+    return new Promise(async (resolve, reject) => {
+        const nonce = Number((await api.query.system.account(sudo.address)).nonce);
+
+        console.log(
+            `--- Submitting extrinsic to create nft collection: (nonce: ${nonce}) ---`
+        );
+
+        const unsub = await api.tx.sudo
+            .sudo(api.tx.uniques.forceCreate(id, owner, true))
+            .signAndSend(sudo, {nonce: nonce, era: 0}, (result) => {
+                console.log(`Current status is ${result.status}`);
+                if (result.status.isInBlock) {
+                    console.log(
+                        `Transaction included at blockHash ${result.status.asInBlock}`
+                    );
+                    if (finalization) {
+                        console.log('Waiting for finalization...');
+                    } else {
+                        unsub();
+                        resolve();
+                    }
+                } else if (result.status.isFinalized) {
+                    console.log(
+                        `Transaction finalized at blockHash ${result.status.asFinalized}`
+                    );
+                    unsub();
+                    resolve();
+                } else if (result.isError) {
+                    console.log(`Transaction Error`);
+                    reject(`Transaction Error`);
+                }
+            });
+    });
+}
+
 async function setAssetMetadata(api, id, name, symbol, decimals, finalization, sudo) {
     return new Promise(async (resolve, reject) => {
         const nonce = Number((await api.query.system.account(sudo.address)).nonce);
@@ -321,6 +357,76 @@ async function registerDomain(api, domainID, chainID, finalization, sudo) {
     });
 }
 
+async function registerResource(api, resourceID, asset, finalization, sudo) {
+    return new Promise(async (resolve, reject) => {
+        const nonce = Number((await api.query.system.account(sudo.address)).nonce);
+
+        console.log(
+            `--- Submitting extrinsic to register resourceID ${resourceID}. (nonce: ${nonce}) ---`
+        );
+        const unsub = await api.tx.sudo
+            .sudo(api.tx.sygmaBridge.registerResource(resourceID, asset))
+            .signAndSend(sudo, {nonce: nonce, era: 0}, (result) => {
+                console.log(`Current status is ${result.status}`);
+                if (result.status.isInBlock) {
+                    console.log(
+                        `Transaction included at blockHash ${result.status.asInBlock}`
+                    );
+                    if (finalization) {
+                        console.log('Waiting for finalization...');
+                    } else {
+                        unsub();
+                        resolve();
+                    }
+                } else if (result.status.isFinalized) {
+                    console.log(
+                        `Transaction finalized at blockHash ${result.status.asFinalized}`
+                    );
+                    unsub();
+                    resolve();
+                } else if (result.isError) {
+                    console.log(`Transaction Error`);
+                    reject(`Transaction Error`);
+                }
+            });
+    });
+}
+
+async function setDecimals(api, asset, decimals, finalization, sudo) {
+    return new Promise(async (resolve, reject) => {
+        const nonce = Number((await api.query.system.account(sudo.address)).nonce);
+
+        console.log(
+            `--- Submitting extrinsic to set decimals ${decimals}. (nonce: ${nonce}) ---`
+        );
+        const unsub = await api.tx.sudo
+            .sudo(api.tx.sygmaDecimalConverter.setDecimals(asset, decimals))
+            .signAndSend(sudo, {nonce: nonce, era: 0}, (result) => {
+                console.log(`Current status is ${result.status}`);
+                if (result.status.isInBlock) {
+                    console.log(
+                        `Transaction included at blockHash ${result.status.asInBlock}`
+                    );
+                    if (finalization) {
+                        console.log('Waiting for finalization...');
+                    } else {
+                        unsub();
+                        resolve();
+                    }
+                } else if (result.status.isFinalized) {
+                    console.log(
+                        `Transaction finalized at blockHash ${result.status.asFinalized}`
+                    );
+                    unsub();
+                    resolve();
+                } else if (result.isError) {
+                    console.log(`Transaction Error`);
+                    reject(`Transaction Error`);
+                }
+            });
+    });
+}
+
 function getUSDCAssetId(api) {
     return api.createType('StagingXcmV3MultiassetAssetId', {
         Concrete: api.createType('StagingXcmV3MultiLocation', {
@@ -406,6 +512,37 @@ function getERC20TSTD20AssetId(api) {
     })
 }
 
+function getNftAssetId(api) {
+    return api.createType('StagingXcmV3MultiassetAssetId', {
+        Concrete: api.createType('StagingXcmV3MultiLocation', {
+            parents: 1,
+            interior: api.createType('StagingXcmV3Junctions', {
+                X3: [
+                    api.createType('StagingXcmV3Junction', {
+                        Parachain: api.createType('Compact<U32>', 2005)
+                    }),
+                    api.createType('StagingXcmV3Junction', {
+                        // 0x7379676d61 is general key of "sygma" defined in sygma substrate pallet runtime for testing
+                        // see NftLocation definition in runtime.rs
+                        GeneralKey: {
+                            length: 5,
+                            data: '0x7379676d61000000000000000000000000000000000000000000000000000000'
+                        }
+                    }),
+                    api.createType('StagingXcmV3Junction', {
+                        // 0x6e6674 is general key of "nft" defined in sygma substrate pallet runtime for testing
+                        // see NftLocation definition in runtime.rs
+                        GeneralKey: {
+                            length: 3,
+                            data: '0x6e66740000000000000000000000000000000000000000000000000000000000'
+                        }
+                    }),
+                ]
+            })
+        })
+    })
+}
+
 function getNativeAssetId(api) {
     return api.createType('StagingXcmV3MultiassetAssetId', {
         Concrete: api.createType('StagingXcmV3MultiLocation', {
@@ -469,10 +606,14 @@ module.exports = {
     getUSDCAssetId,
     getERC20TSTAssetId,
     getERC20TSTD20AssetId,
+    getNftAssetId,
     registerDomain,
+    registerResource,
+    setDecimals,
     mintAsset,
     setAssetMetadata,
     createAsset,
+    createNftCollection,
     queryBridgePauseStatus,
     setMpcAddress,
     setFee,
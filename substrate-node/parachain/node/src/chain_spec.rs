@@ -234,5 +234,13 @@ fn testnet_genesis(
 		transaction_payment: Default::default(),
 		assets: Default::default(),
 		sudo: parachain_template_runtime::SudoConfig { key: Some(root) },
+		sygma_bridge: parachain_template_runtime::SygmaBridgeConfig {
+			resource_pairs: parachain_template_runtime::ResourcePairs::get(),
+			..Default::default()
+		},
+		sygma_decimal_converter: parachain_template_runtime::SygmaDecimalConverterConfig {
+			asset_decimals: parachain_template_runtime::AssetDecimalPairs::get(),
+			..Default::default()
+		},
 	}
 }
@@ -24,7 +24,6 @@ pub mod xcm_config;
 
 use cumulus_pallet_parachain_system::RelayNumberStrictlyIncreases;
 use cumulus_primitives_core::ParaId;
-use fixed::{types::extra::U16, FixedU128};
 pub use frame_support::{
 	construct_runtime,
 	traits::{
@@ -38,14 +37,19 @@ use frame_support::{
 	dispatch::DispatchClass,
 	genesis_builder_helper::{build_config, create_default_config},
 	parameter_types,
-	traits::{ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse, Everything},
+	traits::{
+		ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse, Everything, Nothing, OriginTrait,
+	},
 	weights::{
 		constants::WEIGHT_REF_TIME_PER_SECOND, ConstantMultiplier, Weight, WeightToFeeCoefficient,
 		WeightToFeeCoefficients, WeightToFeePolynomial,
 	},
 	PalletId,
 };
-use frame_support::{pallet_prelude::*, traits::ContainsPair};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Contains, ContainsPair},
+};
 use frame_system::{
 	limits::{BlockLength, BlockWeights},
 	EnsureRoot,
@@ -63,7 +67,7 @@ use sp_runtime::traits::AccountIdConversion;
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, Verify},
-	transaction_validity::{TransactionSource, TransactionValidity},
+	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	AccountId32, ApplyExtrinsicResult, MultiSignature,
 };
 pub use sp_runtime::{MultiAddress, Perbill, Permill};
@@ -72,10 +76,12 @@ use sp_std::{marker::PhantomData, prelude::*, result, vec::Vec};
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
+use sygma_access_segregator::{AccessOrigin, ToAccessOrigin};
 use sygma_bridge_forwarder::xcm_asset_transactor::XCMAssetTransactor;
+use sygma_decimal_converter::FungiblesMetadataDecimals;
 use sygma_traits::{
-	AssetTypeIdentifier, ChainID, DecimalConverter, DepositNonce, DomainID, ExtractDestinationData,
-	ResourceId, VerifyingContractAddress,
+	AssetTypeIdentifier, ChainID, DepositNonce, DomainID, ExtractDestinationData, ResourceId,
+	VerifyingContractAddress,
 };
 use xcm::latest::{prelude::*, AssetId as XcmAssetId, MultiLocation};
 use xcm_builder::{CurrencyAdapter, FungiblesAdapter, IsConcrete, NoChecking};
@@ -151,6 +157,13 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	(
+		sygma_bridge::migration::RegisterResourcePairs<Runtime, ResourcePairs>,
+		sygma_bridge::migration::MigratePauseDirections<Runtime>,
+		sygma_access_segregator::migration::MigrateMultiHolderAccess<Runtime>,
+		sygma_access_segregator::migration::MigrateAccessOrigins<Runtime>,
+		sygma_decimal_converter::migration::SeedAssetDecimals<Runtime, AssetDecimalPairs>,
+	),
 >;
 
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
@@ -561,11 +574,24 @@ parameter_types! {
 	pub const BridgePalletIndex: u8 = 11;
 	pub const FeeHandlerRouterPalletIndex: u8 = 12;
 	pub const PercentageFeeHandlerRouterPalletIndex: u8 = 13;
+	pub const DecimalConverterPalletIndex: u8 = 14;
+	pub const DynamicFeeHandlerPalletIndex: u8 = 15;
 	// RegisteredExtrinsics here registers all valid (pallet index, extrinsic_name) paris
 	// make sure to update this when adding new access control extrinsic
 	pub RegisteredExtrinsics: Vec<(u8, Vec<u8>)> = [
 		(AccessSegregatorPalletIndex::get(), b"grant_access".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"grant_access_until".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"revoke_access".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"grant_origin_access".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"revoke_origin_access".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"create_role".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"update_role".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"assign_role".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"unassign_role".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"set_extrinsic_delay".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"cancel_action".to_vec()),
 		(BasicFeeHandlerPalletIndex::get(), b"set_fee".to_vec()),
+		(BasicFeeHandlerPalletIndex::get(), b"set_fee_in_asset".to_vec()),
 		(BridgePalletIndex::get(), b"set_mpc_address".to_vec()),
 		(BridgePalletIndex::get(), b"pause_bridge".to_vec()),
 		(BridgePalletIndex::get(), b"unpause_bridge".to_vec()),
@@ -574,16 +600,68 @@ parameter_types! {
 		(BridgePalletIndex::get(), b"retry".to_vec()),
 		(BridgePalletIndex::get(), b"pause_all_bridges".to_vec()),
 		(BridgePalletIndex::get(), b"unpause_all_bridges".to_vec()),
+		(BridgePalletIndex::get(), b"register_resource".to_vec()),
+		(BridgePalletIndex::get(), b"unregister_resource".to_vec()),
+		(BridgePalletIndex::get(), b"schedule_mpc_rotation".to_vec()),
+		(BridgePalletIndex::get(), b"set_transfer_limit".to_vec()),
+		(BridgePalletIndex::get(), b"remove_transfer_limit".to_vec()),
+		(BridgePalletIndex::get(), b"set_eip712_domain".to_vec()),
+		(BridgePalletIndex::get(), b"set_signer_set".to_vec()),
+		(BridgePalletIndex::get(), b"clear_signer_set".to_vec()),
+		(BridgePalletIndex::get(), b"set_circuit_breaker".to_vec()),
+		(BridgePalletIndex::get(), b"remove_circuit_breaker".to_vec()),
+		(BridgePalletIndex::get(), b"set_reserve_floor".to_vec()),
+		(BridgePalletIndex::get(), b"pause_bridge_direction".to_vec()),
+		(BridgePalletIndex::get(), b"unpause_bridge_direction".to_vec()),
+		(BridgePalletIndex::get(), b"pause_resource".to_vec()),
+		(BridgePalletIndex::get(), b"unpause_resource".to_vec()),
+		(BridgePalletIndex::get(), b"set_fee_distribution".to_vec()),
+		(BridgePalletIndex::get(), b"withdraw_fees".to_vec()),
+		(BridgePalletIndex::get(), b"distribute_fees".to_vec()),
 		(FeeHandlerRouterPalletIndex::get(), b"set_fee_handler".to_vec()),
+		(FeeHandlerRouterPalletIndex::get(), b"set_discount_tier".to_vec()),
+		(FeeHandlerRouterPalletIndex::get(), b"set_account_discount_tier".to_vec()),
+		(FeeHandlerRouterPalletIndex::get(), b"remove_account_discount_tier".to_vec()),
+		(FeeHandlerRouterPalletIndex::get(), b"set_fee_exemption".to_vec()),
 		(PercentageFeeHandlerRouterPalletIndex::get(), b"set_fee_rate".to_vec()),
+		(PercentageFeeHandlerRouterPalletIndex::get(), b"set_fee_asset_rate".to_vec()),
+		(DecimalConverterPalletIndex::get(), b"set_decimals".to_vec()),
+		(DecimalConverterPalletIndex::get(), b"remove_decimals".to_vec()),
+		(DynamicFeeHandlerPalletIndex::get(), b"set_fee_oracle".to_vec()),
+		(DynamicFeeHandlerPalletIndex::get(), b"set_destination_type".to_vec()),
 	].to_vec();
 }
 
+/// Converts signed and XCM origins to the origins extrinsic access can be granted to, so that
+/// the relay chain and sibling governance parachains are able to operate the bridge
+pub struct SygmaAccessOrigin;
+
+impl ToAccessOrigin<RuntimeOrigin, AccountId> for SygmaAccessOrigin {
+	fn to_access_origin(origin: RuntimeOrigin) -> Option<AccessOrigin<AccountId>> {
+		match origin.caller() {
+			OriginCaller::system(frame_system::RawOrigin::Signed(who)) =>
+				Some(AccessOrigin::Signed(who.clone())),
+			OriginCaller::PolkadotXcm(pallet_xcm::Origin::Xcm(location)) =>
+				Some(AccessOrigin::Xcm(*location)),
+			OriginCaller::CumulusXcm(cumulus_pallet_xcm::Origin::Relay) =>
+				Some(AccessOrigin::Xcm(MultiLocation::parent())),
+			OriginCaller::CumulusXcm(cumulus_pallet_xcm::Origin::SiblingParachain(para_id)) =>
+				Some(AccessOrigin::Xcm(MultiLocation::new(1, X1(Parachain((*para_id).into()))))),
+			_ => None,
+		}
+	}
+}
+
 impl sygma_access_segregator::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type BridgeCommitteeOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type PalletIndex = AccessSegregatorPalletIndex;
 	type Extrinsics = RegisteredExtrinsics;
+	type RuntimeCall = RuntimeCall;
+	type AccessOriginConverter = SygmaAccessOrigin;
+	type MaxCollectiveGrants = ConstU32<8>;
+	type MaxRolesPerAccount = ConstU32<8>;
+	type PalletsOrigin = OriginCaller;
 	type WeightInfo = sygma_access_segregator::weights::SygmaWeightInfo<Runtime>;
 }
 
@@ -599,15 +677,31 @@ impl sygma_percentage_feehandler::Config for Runtime {
 	type WeightInfo = sygma_percentage_feehandler::weights::SygmaWeightInfo<Runtime>;
 }
 
+impl sygma_dynamic_feehandler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type PalletIndex = DynamicFeeHandlerPalletIndex;
+	type NativeFeeAsset = NativeFeeAsset;
+	type WeightInfo = sygma_dynamic_feehandler::weights::SygmaWeightInfo<Runtime>;
+}
+
 impl sygma_fee_handler_router::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type BasicFeeHandler = SygmaBasicFeeHandler;
-	type DynamicFeeHandler = ();
+	type DynamicFeeHandler = SygmaDynamicFeeHandler;
 	type PercentageFeeHandler = SygmaPercentageFeeHandler;
 	type PalletIndex = FeeHandlerRouterPalletIndex;
 	type WeightInfo = sygma_fee_handler_router::weights::SygmaWeightInfo<Runtime>;
 }
 
+impl sygma_decimal_converter::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	// Assets without explicitly set decimals fall back to their pallet_assets metadata
+	type FallbackDecimals =
+		FungiblesMetadataDecimals<AccountId, Assets, SimpleForeignAssetConverter>;
+	type PalletIndex = DecimalConverterPalletIndex;
+	type WeightInfo = sygma_decimal_converter::weights::SygmaWeightInfo<Runtime>;
+}
+
 // This address is defined in the substrate E2E test of sygma-relayer
 const DEST_VERIFYING_CONTRACT_ADDRESS: &str = "6CdE2Cd82a4F8B74693Ff5e194c19CA08c2d1c68";
 
@@ -641,6 +735,8 @@ parameter_types! {
 		PalletInstance(<Assets as PalletInfoAccess>::index() as u8).into();
 	// NativeLocation is the representation of the current parachain's native asset location in substrate, it can be various on different parachains
 	pub NativeLocation: MultiLocation = MultiLocation::here();
+	// NativeFeeAsset is the asset of the native currency that dynamic fees can be paid in
+	pub NativeFeeAsset: XcmAssetId = NativeLocation::get().into();
 	// UsdtLocation is the representation of the USDT asset location in substrate
 	// USDT is a foreign asset, and in our local testing env, it's being registered on Parachain 2004 with the following location
 	pub UsdtLocation: MultiLocation = MultiLocation::new(
@@ -679,10 +775,23 @@ parameter_types! {
 	pub ERC20TSTD20ResourceId: ResourceId = hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000900");
 
 	// ResourcePairs is where all supported assets and their associated resourceID are binding
+	// it's only used to seed the on-chain resource registry at genesis or by the migration, new pairs should be added through `register_resource`
 	pub ResourcePairs: Vec<(XcmAssetId, ResourceId)> = vec![(NativeLocation::get().into(), NativeResourceId::get()), (UsdtLocation::get().into(), UsdtResourceId::get()), (ERC20TSTLocation::get().into(), ERC20TSTResourceId::get()), (ERC20TSTD20Location::get().into(), ERC20TSTD20ResourceId::get())];
 	// SygmaBridgePalletId is the palletIDl
 	// this is used as the replacement of handler address in the ProposalExecution event
 	pub const SygmaBridgePalletId: PalletId = PalletId(*b"sygma/01");
+	// MpcRotationWindow is the number of blocks both the old and new MPC addresses are accepted
+	// during MPC key rotation
+	pub const MpcRotationWindow: BlockNumber = HOURS;
+	// DepositRecordRetention is the number of latest deposits per dest domain kept in deposit records
+	pub const DepositRecordRetention: DepositNonce = 10_000;
+	// UnsignedPriority is the priority of unsigned proposal execution transactions
+	pub const UnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+	// GenericResourceId is the resourceID of generic message transfer
+	pub GenericResourceId: ResourceId = hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000500");
+	// AssetDecimalPairs is only used to seed the on-chain decimal registry at genesis or by the
+	// migration, decimals of new assets should be set through `set_decimals` or come from the
+	// pallet_assets metadata
 	pub AssetDecimalPairs: Vec<(XcmAssetId, u8)> = vec![(NativeLocation::get().into(), 12u8), (UsdtLocation::get().into(), 12u8), (ERC20TSTLocation::get().into(), 18u8), (ERC20TSTD20Location::get().into(), 20u8)];
 }
 
@@ -780,87 +889,11 @@ impl ConcrateSygmaAsset {
 	}
 }
 
-pub struct SygmaDecimalConverter<DecimalPairs>(PhantomData<DecimalPairs>);
-impl<DecimalPairs: Get<Vec<(XcmAssetId, u8)>>> DecimalConverter
-	for SygmaDecimalConverter<DecimalPairs>
-{
-	fn convert_to(asset: &MultiAsset) -> Option<u128> {
-		match (&asset.fun, &asset.id) {
-			(Fungible(amount), _) => {
-				for (asset_id, decimal) in DecimalPairs::get().iter() {
-					if *asset_id == asset.id {
-						return if *decimal == 18 {
-							Some(*amount)
-						} else {
-							type U112F16 = FixedU128<U16>;
-							if *decimal > 18 {
-								let a =
-									U112F16::from_num(10u128.saturating_pow(*decimal as u32 - 18));
-								let b = U112F16::from_num(*amount).checked_div(a);
-								let r: u128 = b.unwrap_or_else(|| U112F16::from_num(0)).to_num();
-								if r == 0 {
-									return None;
-								}
-								Some(r)
-							} else {
-								// Max is 5192296858534827628530496329220095
-								// if source asset decimal is 12, the max amount sending to sygma
-								// relayer is 5192296858534827.628530496329
-								if *amount > U112F16::MAX {
-									return None;
-								}
-								let a =
-									U112F16::from_num(10u128.saturating_pow(18 - *decimal as u32));
-								let b = U112F16::from_num(*amount).saturating_mul(a);
-								Some(b.to_num())
-							}
-						};
-					}
-				}
-				None
-			},
-			_ => None,
-		}
-	}
-
-	fn convert_from(asset: &MultiAsset) -> Option<MultiAsset> {
-		match (&asset.fun, &asset.id) {
-			(Fungible(amount), _) => {
-				for (asset_id, decimal) in DecimalPairs::get().iter() {
-					if *asset_id == asset.id {
-						return if *decimal == 18 {
-							Some((asset.id, *amount).into())
-						} else {
-							type U112F16 = FixedU128<U16>;
-							if *decimal > 18 {
-								// Max is 5192296858534827628530496329220095
-								// if dest asset decimal is 24, the max amount coming from sygma
-								// relayer is 5192296858.534827628530496329
-								if *amount > U112F16::MAX {
-									return None;
-								}
-								let a =
-									U112F16::from_num(10u128.saturating_pow(*decimal as u32 - 18));
-								let b = U112F16::from_num(*amount).saturating_mul(a);
-								let r: u128 = b.to_num();
-								Some((asset.id, r).into())
-							} else {
-								let a =
-									U112F16::from_num(10u128.saturating_pow(18 - *decimal as u32));
-								let b = U112F16::from_num(*amount).checked_div(a);
-								let r: u128 = b.unwrap_or_else(|| U112F16::from_num(0)).to_num();
-								if r == 0 {
-									return None;
-								}
-								Some((asset.id, r).into())
-							}
-						};
-					}
-				}
-				None
-			},
-			_ => None,
-		}
+/// Only allow remark calls to be dispatched by generic proposals
+pub struct GenericCallFilter;
+impl Contains<RuntimeCall> for GenericCallFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(call, RuntimeCall::System(frame_system::Call::remark_with_event { .. }))
 	}
 }
 
@@ -914,12 +947,23 @@ impl sygma_bridge::Config for Runtime {
 		NativeAssetTypeIdentifier<ParachainInfo>,
 		SygmaBridgeForwarder,
 	>;
-	type ResourcePairs = ResourcePairs;
 	type IsReserve = ReserveChecker;
 	type ExtractDestData = DestinationDataParser;
 	type PalletId = SygmaBridgePalletId;
 	type PalletIndex = BridgePalletIndex;
-	type DecimalConverter = SygmaDecimalConverter<AssetDecimalPairs>;
+	type DecimalConverter = SygmaDecimalConverter;
+	type ReserveBalances = (
+		sygma_bridge::FungibleBalance<AccountId, Balances, IsConcrete<RelayLocation>>,
+		sygma_bridge::FungiblesBalance<AccountId, Assets, SimpleForeignAssetConverter>,
+	);
+	type MpcRotationWindow = MpcRotationWindow;
+	type DepositRecordRetention = DepositRecordRetention;
+	type UnsignedPriority = UnsignedPriority;
+	type GenericResourceId = GenericResourceId;
+	type RuntimeCall = RuntimeCall;
+	type GenericCallFilter = GenericCallFilter;
+	type NonFungibleAssets = Nothing;
+	type NonFungibleMetadata = ();
 	type WeightInfo = sygma_bridge::weights::SygmaWeightInfo<Runtime>;
 }
 
@@ -987,11 +1031,13 @@ construct_runtime!(
 
 		SygmaAccessSegregator: sygma_access_segregator::{Pallet, Call, Storage, Event<T>} = 40, // 9
 		SygmaBasicFeeHandler: sygma_basic_feehandler::{Pallet, Call, Storage, Event<T>} = 41, // 10,
-		SygmaBridge: sygma_bridge::{Pallet, Call, Storage, Event<T>} = 42, // 11
+		SygmaBridge: sygma_bridge::{Pallet, Call, Storage, Event<T>, Config<T>, ValidateUnsigned} = 42, // 11
 		SygmaFeeHandlerRouter: sygma_fee_handler_router::{Pallet, Call, Storage, Event<T>} = 43, // 12
 		SygmaPercentageFeeHandler: sygma_percentage_feehandler::{Pallet, Call, Storage, Event<T>} = 44, // 13
 		SygmaXcmBridge: sygma_xcm_bridge::{Pallet, Event<T>} = 45,
 		SygmaBridgeForwarder: sygma_bridge_forwarder::{Pallet, Event<T>} = 46,
+		SygmaDecimalConverter: sygma_decimal_converter::{Pallet, Call, Storage, Event<T>, Config<T>} = 47, // 14
+		SygmaDynamicFeeHandler: sygma_dynamic_feehandler::{Pallet, Call, Storage, Event<T>} = 48, // 15
 	}
 );
 
@@ -1010,7 +1056,9 @@ mod benches {
 		[sygma_access_segregator, SygmaAccessSegregator::<Runtime>]
 		[sygma_basic_feehandler, SygmaBasicFeeHandler::<Runtime>]
 		[sygma_percentage_feehandler, SygmaPercentageFeeHandler::<Runtime>]
+		[sygma_dynamic_feehandler, SygmaDynamicFeeHandler::<Runtime>]
 		[sygma_fee_handler_router, SygmaFeeHandlerRouter::<Runtime>]
+		[sygma_decimal_converter, SygmaDecimalConverter::<Runtime>]
 	);
 }
 
@@ -1074,10 +1122,21 @@ impl_runtime_apis! {
 		}
 	}
 
-	impl sygma_runtime_api::SygmaBridgeApi<Block> for Runtime {
+	impl sygma_runtime_api::SygmaBridgeApi<Block, AccountId, BlockNumber> for Runtime {
 		fn is_proposal_executed(nonce: DepositNonce, domain_id: DomainID) -> bool {
 			SygmaBridge::is_proposal_executed(nonce, domain_id)
 		}
+
+		fn deposit_record(
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		) -> Option<sygma_bridge::DepositRecord<AccountId, BlockNumber>> {
+			SygmaBridge::deposit_records(dest_domain_id, deposit_nonce)
+		}
+
+		fn eip712_domain() -> sygma_bridge::EIP712DomainParams {
+			SygmaBridge::eip712_domain()
+		}
 	}
 
 	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
@@ -8,8 +8,9 @@ use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_core::{sr25519, Pair, Public};
 use sp_runtime::traits::{IdentifyAccount, Verify};
 use standalone_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GrandpaConfig, ParachainInfoConfig,
-	RuntimeGenesisConfig, Signature, SudoConfig, SystemConfig, WASM_BINARY,
+	AccountId, AssetDecimalPairs, AuraConfig, BalancesConfig, GrandpaConfig, ParachainInfoConfig,
+	ResourcePairs, RuntimeGenesisConfig, Signature, SudoConfig, SygmaBridgeConfig,
+	SygmaDecimalConverterConfig, SystemConfig, WASM_BINARY,
 };
 
 // The URL for the telemetry server.
@@ -160,6 +161,15 @@ fn testnet_genesis(
 			key: Some(root_key),
 		},
 		parachain_info: ParachainInfoConfig { parachain_id: id, ..Default::default() },
+		sygma_bridge: SygmaBridgeConfig {
+			resource_pairs: ResourcePairs::get(),
+			..Default::default()
+		},
+		sygma_decimal_converter: SygmaDecimalConverterConfig {
+			asset_decimals: AssetDecimalPairs::get(),
+			..Default::default()
+		},
 		transaction_payment: Default::default(),
+		bridge_council: Default::default(),
 	}
 }
@@ -15,7 +15,7 @@ use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
-use standalone_template_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use standalone_template_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Nonce};
 
 pub use sc_rpc_api::DenyUnsafe;
 
@@ -40,7 +40,7 @@ where
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BlockBuilder<Block>,
-	C::Api: sygma_runtime_api::SygmaBridgeApi<Block>,
+	C::Api: sygma_runtime_api::SygmaBridgeApi<Block, AccountId, BlockNumber>,
 	P: TransactionPool + 'static,
 {
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
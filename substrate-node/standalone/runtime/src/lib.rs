@@ -9,8 +9,11 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
-use fixed::{types::extra::U16, FixedU128};
-use frame_support::{pallet_prelude::*, traits::ContainsPair, PalletId};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{tokens::nonfungibles::Inspect, Contains, ContainsPair, OriginTrait},
+	PalletId,
+};
 use pallet_grandpa::AuthorityId as GrandpaId;
 use polkadot_parachain_primitives::primitives::Sibling;
 use primitive_types::U256;
@@ -23,7 +26,7 @@ use sp_runtime::{
 		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount,
 		NumberFor, One, Verify,
 	},
-	transaction_validity::{TransactionSource, TransactionValidity},
+	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	AccountId32, ApplyExtrinsicResult, MultiSignature, Perbill,
 };
 use sp_std::collections::btree_map::BTreeMap;
@@ -31,16 +34,18 @@ use sp_std::{marker::PhantomData, prelude::*, result, vec::Vec};
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
+use sygma_access_segregator::{AccessOrigin, ToAccessOrigin};
+use sygma_decimal_converter::FungiblesMetadataDecimals;
 use sygma_traits::{
-	ChainID, DecimalConverter, DepositNonce, DomainID, ExtractDestinationData, ResourceId,
-	VerifyingContractAddress,
+	ChainID, DepositNonce, DomainID, ExtractDestinationData, NonFungibleMetadataHandler,
+	ResourceId, VerifyingContractAddress,
 };
 use xcm::latest::{prelude::*, AssetId as XcmAssetId, MultiLocation};
 use xcm_builder::{
-	AccountId32Aliases, CurrencyAdapter, FungiblesAdapter, IsConcrete, NoChecking, ParentIsPreset,
-	SiblingParachainConvertsVia,
+	AccountId32Aliases, CurrencyAdapter, FungiblesAdapter, IsConcrete, NoChecking,
+	NonFungiblesAdapter, ParentIsPreset, SiblingParachainConvertsVia,
 };
-use xcm_executor::traits::{Error as ExecutionError, MatchesFungibles};
+use xcm_executor::traits::{Error as ExecutionError, MatchesFungibles, MatchesNonFungibles};
 
 // A few exports that help ease life for downstream crates.
 pub use frame_support::{
@@ -289,6 +294,29 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const BridgeCouncilMotionDuration: BlockNumber = 3 * DAYS;
+	pub const BridgeCouncilMaxProposals: u32 = 100;
+	pub const BridgeCouncilMaxMembers: u32 = 100;
+	pub BridgeCouncilMaxProposalWeight: Weight =
+		Perbill::from_percent(50) * BlockWeights::get().max_block;
+}
+
+type BridgeCouncilInstance = pallet_collective::Instance1;
+
+impl pallet_collective::Config<BridgeCouncilInstance> for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Proposal = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type MotionDuration = BridgeCouncilMotionDuration;
+	type MaxProposals = BridgeCouncilMaxProposals;
+	type MaxMembers = BridgeCouncilMaxMembers;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+	type SetMembersOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type MaxProposalWeight = BridgeCouncilMaxProposalWeight;
+}
+
 parameter_types! {
 	pub const ExistentialDeposit: Balance = 1;
 	pub const UNIT: Balance = 1_000_000_000_000;
@@ -338,6 +366,38 @@ impl pallet_assets::Config for Runtime {
 	type BenchmarkHelper = ();
 }
 
+parameter_types! {
+	pub const CollectionDeposit: Balance = 10 * UNIT::get(); // 10 UNITS deposit to create non-fungible collection
+	pub const ItemDeposit: Balance = CENTS::get();
+	/// Key = 32 bytes, Value = 32 bytes
+	pub const UniquesMetadataDepositBase: Balance = deposit(1, 64);
+	pub const AttributeDepositBase: Balance = deposit(1, 64);
+	pub const DepositPerByte: Balance = deposit(0, 1);
+}
+
+pub type CollectionId = u32;
+pub type ItemId = u32;
+impl pallet_uniques::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = CollectionId;
+	type ItemId = ItemId;
+	type Currency = Balances;
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type Locker = ();
+	type CollectionDeposit = CollectionDeposit;
+	type ItemDeposit = ItemDeposit;
+	type MetadataDepositBase = UniquesMetadataDepositBase;
+	type AttributeDepositBase = AttributeDepositBase;
+	type DepositPerByte = DepositPerByte;
+	type StringLimit = AssetsStringLimit;
+	type KeyLimit = ConstU32<32>;
+	type ValueLimit = ConstU32<64>;
+	type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
 parameter_types! {
 	// Make sure put same value with `construct_runtime`
 	pub const AccessSegregatorPalletIndex: u8 = 9;
@@ -345,11 +405,25 @@ parameter_types! {
 	pub const BridgePalletIndex: u8 = 11;
 	pub const FeeHandlerRouterPalletIndex: u8 = 12;
 	pub const PercentageFeeHandlerRouterPalletIndex: u8 = 13;
+	pub const DecimalConverterPalletIndex: u8 = 14;
+	pub const DynamicFeeHandlerPalletIndex: u8 = 15;
+	pub const BridgeCouncilPalletIndex: u8 = 16;
 	// RegisteredExtrinsics here registers all valid (pallet index, extrinsic_name) paris
 	// make sure to update this when adding new access control extrinsic
 	pub RegisteredExtrinsics: Vec<(u8, Vec<u8>)> = [
 		(AccessSegregatorPalletIndex::get(), b"grant_access".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"grant_access_until".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"revoke_access".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"grant_origin_access".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"revoke_origin_access".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"create_role".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"update_role".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"assign_role".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"unassign_role".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"set_extrinsic_delay".to_vec()),
+		(AccessSegregatorPalletIndex::get(), b"cancel_action".to_vec()),
 		(BasicFeeHandlerPalletIndex::get(), b"set_fee".to_vec()),
+		(BasicFeeHandlerPalletIndex::get(), b"set_fee_in_asset".to_vec()),
 		(BridgePalletIndex::get(), b"set_mpc_address".to_vec()),
 		(BridgePalletIndex::get(), b"pause_bridge".to_vec()),
 		(BridgePalletIndex::get(), b"unpause_bridge".to_vec()),
@@ -358,16 +432,68 @@ parameter_types! {
 		(BridgePalletIndex::get(), b"retry".to_vec()),
 		(BridgePalletIndex::get(), b"pause_all_bridges".to_vec()),
 		(BridgePalletIndex::get(), b"unpause_all_bridges".to_vec()),
+		(BridgePalletIndex::get(), b"register_resource".to_vec()),
+		(BridgePalletIndex::get(), b"unregister_resource".to_vec()),
+		(BridgePalletIndex::get(), b"schedule_mpc_rotation".to_vec()),
+		(BridgePalletIndex::get(), b"set_transfer_limit".to_vec()),
+		(BridgePalletIndex::get(), b"remove_transfer_limit".to_vec()),
+		(BridgePalletIndex::get(), b"set_eip712_domain".to_vec()),
+		(BridgePalletIndex::get(), b"set_signer_set".to_vec()),
+		(BridgePalletIndex::get(), b"clear_signer_set".to_vec()),
+		(BridgePalletIndex::get(), b"set_circuit_breaker".to_vec()),
+		(BridgePalletIndex::get(), b"remove_circuit_breaker".to_vec()),
+		(BridgePalletIndex::get(), b"set_reserve_floor".to_vec()),
+		(BridgePalletIndex::get(), b"pause_bridge_direction".to_vec()),
+		(BridgePalletIndex::get(), b"unpause_bridge_direction".to_vec()),
+		(BridgePalletIndex::get(), b"pause_resource".to_vec()),
+		(BridgePalletIndex::get(), b"unpause_resource".to_vec()),
+		(BridgePalletIndex::get(), b"set_fee_distribution".to_vec()),
+		(BridgePalletIndex::get(), b"withdraw_fees".to_vec()),
+		(BridgePalletIndex::get(), b"distribute_fees".to_vec()),
 		(FeeHandlerRouterPalletIndex::get(), b"set_fee_handler".to_vec()),
+		(FeeHandlerRouterPalletIndex::get(), b"set_discount_tier".to_vec()),
+		(FeeHandlerRouterPalletIndex::get(), b"set_account_discount_tier".to_vec()),
+		(FeeHandlerRouterPalletIndex::get(), b"remove_account_discount_tier".to_vec()),
+		(FeeHandlerRouterPalletIndex::get(), b"set_fee_exemption".to_vec()),
 		(PercentageFeeHandlerRouterPalletIndex::get(), b"set_fee_rate".to_vec()),
+		(PercentageFeeHandlerRouterPalletIndex::get(), b"set_fee_asset_rate".to_vec()),
+		(DecimalConverterPalletIndex::get(), b"set_decimals".to_vec()),
+		(DecimalConverterPalletIndex::get(), b"remove_decimals".to_vec()),
+		(DynamicFeeHandlerPalletIndex::get(), b"set_fee_oracle".to_vec()),
+		(DynamicFeeHandlerPalletIndex::get(), b"set_destination_type".to_vec()),
 	].to_vec();
 }
 
+/// Converts signed origins and motions of the bridge council to the origins extrinsic access can
+/// be granted to, so that the council is able to operate the bridge
+pub struct SygmaAccessOrigin;
+
+impl ToAccessOrigin<RuntimeOrigin, AccountId> for SygmaAccessOrigin {
+	fn to_access_origin(origin: RuntimeOrigin) -> Option<AccessOrigin<AccountId>> {
+		match origin.caller() {
+			OriginCaller::system(frame_system::RawOrigin::Signed(who)) =>
+				Some(AccessOrigin::Signed(who.clone())),
+			OriginCaller::BridgeCouncil(pallet_collective::RawOrigin::Members(yes, total)) =>
+				Some(AccessOrigin::Collective {
+					pallet_index: BridgeCouncilPalletIndex::get(),
+					yes: *yes,
+					total: *total,
+				}),
+			_ => None,
+		}
+	}
+}
+
 impl sygma_access_segregator::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type BridgeCommitteeOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type PalletIndex = AccessSegregatorPalletIndex;
 	type Extrinsics = RegisteredExtrinsics;
+	type RuntimeCall = RuntimeCall;
+	type AccessOriginConverter = SygmaAccessOrigin;
+	type MaxCollectiveGrants = ConstU32<8>;
+	type MaxRolesPerAccount = ConstU32<8>;
+	type PalletsOrigin = OriginCaller;
 	type WeightInfo = sygma_access_segregator::weights::SygmaWeightInfo<Runtime>;
 }
 
@@ -383,15 +509,31 @@ impl sygma_percentage_feehandler::Config for Runtime {
 	type WeightInfo = sygma_percentage_feehandler::weights::SygmaWeightInfo<Runtime>;
 }
 
+impl sygma_dynamic_feehandler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type PalletIndex = DynamicFeeHandlerPalletIndex;
+	type NativeFeeAsset = NativeFeeAsset;
+	type WeightInfo = sygma_dynamic_feehandler::weights::SygmaWeightInfo<Runtime>;
+}
+
 impl sygma_fee_handler_router::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type BasicFeeHandler = SygmaBasicFeeHandler;
-	type DynamicFeeHandler = ();
+	type DynamicFeeHandler = SygmaDynamicFeeHandler;
 	type PercentageFeeHandler = SygmaPercentageFeeHandler;
 	type PalletIndex = FeeHandlerRouterPalletIndex;
 	type WeightInfo = sygma_fee_handler_router::weights::SygmaWeightInfo<Runtime>;
 }
 
+impl sygma_decimal_converter::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	// Assets without explicitly set decimals fall back to their pallet_assets metadata
+	type FallbackDecimals =
+		FungiblesMetadataDecimals<AccountId, Assets, SimpleForeignAssetConverter>;
+	type PalletIndex = DecimalConverterPalletIndex;
+	type WeightInfo = sygma_decimal_converter::weights::SygmaWeightInfo<Runtime>;
+}
+
 // This address is defined in the substrate E2E test of sygma-relayer
 const DEST_VERIFYING_CONTRACT_ADDRESS: &str = "6CdE2Cd82a4F8B74693Ff5e194c19CA08c2d1c68";
 
@@ -401,6 +543,7 @@ fn bridge_accounts_generator() -> BTreeMap<XcmAssetId, AccountId32> {
 	account_map.insert(UsdcLocation::get().into(), BridgeAccountOtherToken::get());
 	account_map.insert(ERC20TSTLocation::get().into(), BridgeAccountOtherToken::get());
 	account_map.insert(ERC20TSTD20Location::get().into(), BridgeAccountOtherToken::get());
+	account_map.insert(NftLocation::get().into(), BridgeAccountOtherToken::get());
 	account_map
 }
 
@@ -426,6 +569,8 @@ parameter_types! {
 		PalletInstance(<Assets as PalletInfoAccess>::index() as u8).into();
 	// NativeLocation is the representation of the current parachain's native asset location in substrate, it can be various on different parachains
 	pub NativeLocation: MultiLocation = MultiLocation::here();
+	// NativeFeeAsset is the asset of the native currency that dynamic fees can be paid in
+	pub NativeFeeAsset: XcmAssetId = NativeLocation::get().into();
 	// UsdcLocation is the representation of the USDC asset location in substrate
 	// USDC is a foreign asset, and in our local testing env, it's being registered on Parachain 2004 with the following location
 	// USDC is a reserved token
@@ -455,22 +600,49 @@ parameter_types! {
 			slice_to_generalkey(b"erc20tstd20"),
 		),
 	);
+	// NftLocation is the representation of the bridged non-fungible collection location in substrate
+	// NFT is a reserved token
+	pub NftLocation: MultiLocation = MultiLocation::new(
+		1,
+		X3(
+			Parachain(2005),
+			slice_to_generalkey(b"sygma"),
+			slice_to_generalkey(b"nft"),
+		),
+	);
 	// UsdcAssetId is the substrate assetID of USDC
 	pub UsdcAssetId: AssetId = 2000;
 	pub ERC20TSTAssetId: AssetId = 2001;
 	pub ERC20TSTD20AssetId: AssetId = 2002;
+	// NftCollectionId is the pallet_uniques collection of the bridged NFT
+	pub NftCollectionId: CollectionId = 0;
 	// NativeResourceId is the resourceID that mapping with the current parachain native asset
 	pub NativeResourceId: ResourceId = hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000001");
 	// UsdcResourceId is the resourceID that mapping with the foreign asset USDC
 	pub UsdcResourceId: ResourceId = hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000300");
 	pub ERC20TSTResourceId: ResourceId = hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000000");
 	pub ERC20TSTD20ResourceId: ResourceId = hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000900");
+	// NftResourceId is the resourceID that mapping with the bridged non-fungible collection
+	pub NftResourceId: ResourceId = hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000600");
 
 	// ResourcePairs is where all supported assets and their associated resourceID are binding
-	pub ResourcePairs: Vec<(XcmAssetId, ResourceId)> = vec![(NativeLocation::get().into(), NativeResourceId::get()), (UsdcLocation::get().into(), UsdcResourceId::get()), (ERC20TSTLocation::get().into(), ERC20TSTResourceId::get()), (ERC20TSTD20Location::get().into(), ERC20TSTD20ResourceId::get())];
+	// it's only used to seed the on-chain resource registry at genesis or by the migration, new pairs should be added through `register_resource`
+	pub ResourcePairs: Vec<(XcmAssetId, ResourceId)> = vec![(NativeLocation::get().into(), NativeResourceId::get()), (UsdcLocation::get().into(), UsdcResourceId::get()), (ERC20TSTLocation::get().into(), ERC20TSTResourceId::get()), (ERC20TSTD20Location::get().into(), ERC20TSTD20ResourceId::get()), (NftLocation::get().into(), NftResourceId::get())];
 	// SygmaBridgePalletId is the palletIDl
 	// this is used as the replacement of handler address in the ProposalExecution event
 	pub const SygmaBridgePalletId: PalletId = PalletId(*b"sygma/01");
+	// MpcRotationWindow is the number of blocks both the old and new MPC addresses are accepted
+	// during MPC key rotation
+	pub const MpcRotationWindow: BlockNumber = HOURS;
+	// DepositRecordRetention is the number of latest deposits per dest domain kept in deposit records
+	pub const DepositRecordRetention: DepositNonce = 10_000;
+	// UnsignedPriority is the priority of unsigned proposal execution transactions
+	pub const UnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+	// GenericResourceId is the resourceID of generic message transfer
+	pub GenericResourceId: ResourceId = hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000500");
+	// AssetDecimalPairs is only used to seed the on-chain decimal registry at genesis or by the
+	// migration, decimals of new assets should be set through `set_decimals` or come from the
+	// pallet_assets metadata
 	pub AssetDecimalPairs: Vec<(XcmAssetId, u8)> = vec![(NativeLocation::get().into(), 12u8), (UsdcLocation::get().into(), 12u8), (ERC20TSTLocation::get().into(), 18u8), (ERC20TSTD20Location::get().into(), 20u8)];
 }
 
@@ -538,15 +710,92 @@ pub type FungiblesTransactor = FungiblesAdapter<
 	// The account to use for tracking teleports.
 	CheckingAccount,
 >;
+
+/// A simple non-fungible asset converter that extract the binding relationship between
+/// collection and MultiLocation, And convert asset instance to item
+pub struct SimpleNonFungibleConverter(PhantomData<()>);
+
+impl MatchesNonFungibles<CollectionId, ItemId> for SimpleNonFungibleConverter {
+	fn matches_nonfungibles(
+		a: &MultiAsset,
+	) -> result::Result<(CollectionId, ItemId), ExecutionError> {
+		match (&a.fun, &a.id) {
+			(NonFungible(Index(index)), Concrete(ref id)) => {
+				let item = ItemId::try_from(*index)
+					.map_err(|_| ExecutionError::InstanceConversionFailed)?;
+				if id == &NftLocation::get() {
+					Ok((NftCollectionId::get(), item))
+				} else {
+					Err(ExecutionError::AssetNotHandled)
+				}
+			},
+			_ => Err(ExecutionError::AssetNotHandled),
+		}
+	}
+}
+
+/// Means for transacting non-fungible assets on this chain.
+pub type NonFungiblesTransactor = NonFungiblesAdapter<
+	// Use this non-fungibles implementation:
+	Uniques,
+	// Use this collection when it is a non-fungible asset matching the given location:
+	SimpleNonFungibleConverter,
+	// Convert an XCM MultiLocation into a local account id:
+	LocationToAccountId,
+	// Our chain's account ID type (we can't get away without mentioning it explicitly):
+	AccountId32,
+	// Disable teleport.
+	NoChecking,
+	// No account for tracking teleports.
+	(),
+>;
+
 /// Means for transacting assets on this chain.
-pub type AssetTransactors = (CurrencyTransactor, FungiblesTransactor);
+pub type AssetTransactors = (CurrencyTransactor, FungiblesTransactor, NonFungiblesTransactor);
+
+/// Collections of `pallet-uniques` that are bridged as non-fungible assets
+pub struct NonFungibleCollections;
+impl Contains<XcmAssetId> for NonFungibleCollections {
+	fn contains(asset_id: &XcmAssetId) -> bool {
+		*asset_id == NftLocation::get().into()
+	}
+}
+
+/// Keep metadata of bridged non-fungible assets as the item metadata of `pallet-uniques`
+pub struct UniquesMetadataHandler;
+impl NonFungibleMetadataHandler for UniquesMetadataHandler {
+	fn metadata(asset: &MultiAsset) -> Vec<u8> {
+		SimpleNonFungibleConverter::matches_nonfungibles(asset)
+			.ok()
+			// Empty attribute key maps to the item metadata
+			.and_then(|(collection, item)| {
+				<Uniques as Inspect<AccountId32>>::attribute(&collection, &item, &[])
+			})
+			.unwrap_or_default()
+	}
+
+	fn set_metadata(asset: &MultiAsset, metadata: Vec<u8>) -> DispatchResult {
+		if metadata.is_empty() {
+			return Ok(());
+		}
+		let (collection, item) = SimpleNonFungibleConverter::matches_nonfungibles(asset)
+			.map_err(|_| DispatchError::Other("non-fungible asset not handled"))?;
+		Uniques::set_metadata(
+			RuntimeOrigin::root(),
+			collection,
+			item,
+			metadata.try_into().map_err(|_| DispatchError::Other("metadata too long"))?,
+			false,
+		)
+	}
+}
 
 pub struct ConcrateSygmaAsset;
 impl ConcrateSygmaAsset {
 	pub fn id(asset: &MultiAsset) -> Option<MultiLocation> {
 		match (&asset.id, &asset.fun) {
 			// So far our native asset is concrete
-			(Concrete(id), Fungible(_)) => Some(*id),
+			(Concrete(id), Fungible(_)) | (Concrete(id), NonFungible(_)) => Some(*id),
 			_ => None,
 		}
 	}
@@ -582,87 +831,11 @@ impl ConcrateSygmaAsset {
 	}
 }
 
-pub struct SygmaDecimalConverter<DecimalPairs>(PhantomData<DecimalPairs>);
-impl<DecimalPairs: Get<Vec<(XcmAssetId, u8)>>> DecimalConverter
-	for SygmaDecimalConverter<DecimalPairs>
-{
-	fn convert_to(asset: &MultiAsset) -> Option<u128> {
-		match (&asset.fun, &asset.id) {
-			(Fungible(amount), _) => {
-				for (asset_id, decimal) in DecimalPairs::get().iter() {
-					if *asset_id == asset.id {
-						return if *decimal == 18 {
-							Some(*amount)
-						} else {
-							type U112F16 = FixedU128<U16>;
-							if *decimal > 18 {
-								let a =
-									U112F16::from_num(10u128.saturating_pow(*decimal as u32 - 18));
-								let b = U112F16::from_num(*amount).checked_div(a);
-								let r: u128 = b.unwrap_or_else(|| U112F16::from_num(0)).to_num();
-								if r == 0 {
-									return None;
-								}
-								Some(r)
-							} else {
-								// Max is 5192296858534827628530496329220095
-								// if source asset decimal is 12, the max amount sending to sygma
-								// relayer is 5192296858534827.628530496329
-								if *amount > U112F16::MAX {
-									return None;
-								}
-								let a =
-									U112F16::from_num(10u128.saturating_pow(18 - *decimal as u32));
-								let b = U112F16::from_num(*amount).saturating_mul(a);
-								Some(b.to_num())
-							}
-						};
-					}
-				}
-				None
-			},
-			_ => None,
-		}
-	}
-
-	fn convert_from(asset: &MultiAsset) -> Option<MultiAsset> {
-		match (&asset.fun, &asset.id) {
-			(Fungible(amount), _) => {
-				for (asset_id, decimal) in DecimalPairs::get().iter() {
-					if *asset_id == asset.id {
-						return if *decimal == 18 {
-							Some((asset.id, *amount).into())
-						} else {
-							type U112F16 = FixedU128<U16>;
-							if *decimal > 18 {
-								// Max is 5192296858534827628530496329220095
-								// if dest asset decimal is 24, the max amount coming from sygma
-								// relayer is 5192296858.534827628530496329
-								if *amount > U112F16::MAX {
-									return None;
-								}
-								let a =
-									U112F16::from_num(10u128.saturating_pow(*decimal as u32 - 18));
-								let b = U112F16::from_num(*amount).saturating_mul(a);
-								let r: u128 = b.to_num();
-								Some((asset.id, r).into())
-							} else {
-								let a =
-									U112F16::from_num(10u128.saturating_pow(18 - *decimal as u32));
-								let b = U112F16::from_num(*amount).checked_div(a);
-								let r: u128 = b.unwrap_or_else(|| U112F16::from_num(0)).to_num();
-								if r == 0 {
-									return None;
-								}
-								Some((asset.id, r).into())
-							}
-						};
-					}
-				}
-				None
-			},
-			_ => None,
-		}
+/// Only allow remark calls to be dispatched by generic proposals
+pub struct GenericCallFilter;
+impl Contains<RuntimeCall> for GenericCallFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(call, RuntimeCall::System(frame_system::Call::remark_with_event { .. }))
 	}
 }
 
@@ -710,12 +883,23 @@ impl sygma_bridge::Config for Runtime {
 	type DestVerifyingContractAddress = DestVerifyingContractAddress;
 	type FeeHandler = SygmaFeeHandlerRouter;
 	type AssetTransactor = AssetTransactors;
-	type ResourcePairs = ResourcePairs;
 	type IsReserve = ReserveChecker;
 	type ExtractDestData = DestinationDataParser;
 	type PalletId = SygmaBridgePalletId;
 	type PalletIndex = BridgePalletIndex;
-	type DecimalConverter = SygmaDecimalConverter<AssetDecimalPairs>;
+	type DecimalConverter = SygmaDecimalConverter;
+	type ReserveBalances = (
+		sygma_bridge::FungibleBalance<AccountId32, Balances, IsConcrete<NativeLocation>>,
+		sygma_bridge::FungiblesBalance<AccountId32, Assets, SimpleForeignAssetConverter>,
+	);
+	type MpcRotationWindow = MpcRotationWindow;
+	type DepositRecordRetention = DepositRecordRetention;
+	type UnsignedPriority = UnsignedPriority;
+	type GenericResourceId = GenericResourceId;
+	type RuntimeCall = RuntimeCall;
+	type GenericCallFilter = GenericCallFilter;
+	type NonFungibleAssets = NonFungibleCollections;
+	type NonFungibleMetadata = UniquesMetadataHandler;
 	type WeightInfo = sygma_bridge::weights::SygmaWeightInfo<Runtime>;
 }
 
@@ -746,9 +930,13 @@ construct_runtime!(
 		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>} = 8,
 		SygmaAccessSegregator: sygma_access_segregator::{Pallet, Call, Storage, Event<T>} = 9,
 		SygmaBasicFeeHandler: sygma_basic_feehandler::{Pallet, Call, Storage, Event<T>} = 10,
-		SygmaBridge: sygma_bridge::{Pallet, Call, Storage, Event<T>} = 11,
+		SygmaBridge: sygma_bridge::{Pallet, Call, Storage, Event<T>, Config<T>, ValidateUnsigned} = 11,
 		SygmaFeeHandlerRouter: sygma_fee_handler_router::{Pallet, Call, Storage, Event<T>} = 12,
 		SygmaPercentageFeeHandler: sygma_percentage_feehandler::{Pallet, Call, Storage, Event<T>} = 13,
+		SygmaDecimalConverter: sygma_decimal_converter::{Pallet, Call, Storage, Event<T>, Config<T>} = 14,
+		SygmaDynamicFeeHandler: sygma_dynamic_feehandler::{Pallet, Call, Storage, Event<T>} = 15,
+		BridgeCouncil: pallet_collective::<Instance1>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>} = 16,
+		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>} = 17,
 		ParachainInfo: pallet_parachain_info = 20,
 	}
 );
@@ -786,6 +974,11 @@ pub type Executive = frame_executive::Executive<
 	(
 		// Just demonstrate how migration works
 		sygma_bridge::migration::FixMpcAddress<Runtime>,
+		sygma_bridge::migration::RegisterResourcePairs<Runtime, ResourcePairs>,
+		sygma_bridge::migration::MigratePauseDirections<Runtime>,
+		sygma_access_segregator::migration::MigrateMultiHolderAccess<Runtime>,
+		sygma_access_segregator::migration::MigrateAccessOrigins<Runtime>,
+		sygma_decimal_converter::migration::SeedAssetDecimals<Runtime, AssetDecimalPairs>,
 	),
 >;
 
@@ -804,7 +997,10 @@ mod benches {
 		[sygma_access_segregator, SygmaAccessSegregator::<Runtime>]
 		[sygma_basic_feehandler, SygmaBasicFeeHandler::<Runtime>]
 		[sygma_percentage_feehandler, SygmaPercentageFeeHandler::<Runtime>]
+		[sygma_dynamic_feehandler, SygmaDynamicFeeHandler::<Runtime>]
 		[sygma_fee_handler_router, SygmaFeeHandlerRouter::<Runtime>]
+		[sygma_decimal_converter, SygmaDecimalConverter::<Runtime>]
+		[pallet_collective, BridgeCouncil]
 	);
 }
 
@@ -858,10 +1054,21 @@ impl_runtime_apis! {
 		}
 	}
 
-	impl sygma_runtime_api::SygmaBridgeApi<Block> for Runtime {
+	impl sygma_runtime_api::SygmaBridgeApi<Block, AccountId, BlockNumber> for Runtime {
 		fn is_proposal_executed(nonce: DepositNonce, domain_id: DomainID) -> bool {
 			SygmaBridge::is_proposal_executed(nonce, domain_id)
 		}
+
+		fn deposit_record(
+			dest_domain_id: DomainID,
+			deposit_nonce: DepositNonce,
+		) -> Option<sygma_bridge::DepositRecord<AccountId, BlockNumber>> {
+			SygmaBridge::deposit_records(dest_domain_id, deposit_nonce)
+		}
+
+		fn eip712_domain() -> sygma_bridge::EIP712DomainParams {
+			SygmaBridge::eip712_domain()
+		}
 	}
 
 	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
@@ -997,6 +1204,7 @@ impl_runtime_apis! {
 			use sygma_basic_feehandler::Pallet as SygmaBasicFeeHandler;
 			use sygma_fee_handler_router::Pallet as SygmaFeeHandlerRouter;
 			use sygma_percentage_feehandler::Pallet as SygmaPercentageFeeHandler;
+			use sygma_dynamic_feehandler::Pallet as SygmaDynamicFeeHandler;
 
 			let mut list = Vec::<BenchmarkList>::new();
 			list_benchmarks!(list, extra);
@@ -1017,6 +1225,7 @@ impl_runtime_apis! {
 			use sygma_access_segregator::Pallet as SygmaAccessSegregator;
 			use sygma_basic_feehandler::Pallet as SygmaBasicFeeHandler;
 			use sygma_percentage_feehandler::Pallet as SygmaPercentageFeeHandler;
+			use sygma_dynamic_feehandler::Pallet as SygmaDynamicFeeHandler;
 			use sygma_fee_handler_router::Pallet as SygmaFeeHandlerRouter;
 
 			impl frame_system_benchmarking::Config for Runtime {}
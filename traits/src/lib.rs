@@ -43,9 +43,47 @@ pub trait ExtractDestinationData {
 	fn extract_dest(dest: &MultiLocation) -> Option<(Vec<u8>, DomainID)>;
 }
 
+/// Context of the transfer a fee is quoted for
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct FeeContext {
+	/// Account initiating the transfer
+	pub sender: [u8; 32],
+	/// Dest domain of the transfer
+	pub dest_domain_id: DomainID,
+	/// Recipient of the transfer on dest domain
+	pub recipient: Vec<u8>,
+	pub transfer_type: TransferType,
+}
+
+/// Fee quoted for a transfer
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct FeeQuote {
+	/// Asset the fee is paid in
+	pub fee_asset: AssetId,
+	/// Fee amount in `fee_asset`
+	pub amount: u128,
+}
+
 pub trait FeeHandler {
 	// Return fee represent by a specific asset
 	fn get_fee(domain: DomainID, asset: MultiAsset) -> Option<u128>;
+	/// Return fee of transferring `asset` to `domain` that is paid in `fee_asset`, None if the
+	/// fee can not be paid in `fee_asset`
+	fn get_fee_in_asset(domain: DomainID, asset: MultiAsset, fee_asset: AssetId) -> Option<u128> {
+		if asset.id == fee_asset {
+			Self::get_fee(domain, asset)
+		} else {
+			None
+		}
+	}
+	/// Return fee quote of the transfer of `asset` described by `context` that is paid in
+	/// `fee_asset`, None if the fee can not be paid in `fee_asset`. Handlers that don't price
+	/// by sender, recipient or transfer type can rely on the default, which falls back to
+	/// `get_fee_in_asset`
+	fn quote_fee(context: &FeeContext, asset: MultiAsset, fee_asset: AssetId) -> Option<FeeQuote> {
+		Self::get_fee_in_asset(context.dest_domain_id, asset, fee_asset)
+			.map(|amount| FeeQuote { fee_asset, amount })
+	}
 }
 
 impl FeeHandler for () {
@@ -63,6 +101,51 @@ pub trait DecimalConverter {
 	fn convert_from(asset: &MultiAsset) -> Option<MultiAsset>;
 }
 
+pub trait DecimalsProvider {
+	/// Return the decimals of the given asset, None if the asset is unknown
+	fn decimals(asset_id: &AssetId) -> Option<u8>;
+}
+
+impl DecimalsProvider for () {
+	fn decimals(_asset_id: &AssetId) -> Option<u8> {
+		None
+	}
+}
+
+pub trait BalanceProvider {
+	/// Return the balance of the given asset held by the account, None if the asset is unknown
+	fn balance(asset_id: &AssetId, who: &[u8; 32]) -> Option<u128>;
+}
+
+impl BalanceProvider for () {
+	fn balance(_asset_id: &AssetId, _who: &[u8; 32]) -> Option<u128> {
+		None
+	}
+}
+
+impl<A: BalanceProvider, B: BalanceProvider> BalanceProvider for (A, B) {
+	fn balance(asset_id: &AssetId, who: &[u8; 32]) -> Option<u128> {
+		A::balance(asset_id, who).or_else(|| B::balance(asset_id, who))
+	}
+}
+
+pub trait NonFungibleMetadataHandler {
+	/// Return the metadata of the given non-fungible asset, empty if it has none
+	fn metadata(asset: &MultiAsset) -> Vec<u8>;
+	/// Attach the metadata bridged from the source chain to the given non-fungible asset
+	fn set_metadata(asset: &MultiAsset, metadata: Vec<u8>) -> DispatchResult;
+}
+
+impl NonFungibleMetadataHandler for () {
+	fn metadata(_asset: &MultiAsset) -> Vec<u8> {
+		Vec::new()
+	}
+
+	fn set_metadata(_asset: &MultiAsset, _metadata: Vec<u8>) -> DispatchResult {
+		Ok(())
+	}
+}
+
 // when integrating with parachain, parachain team can implement their own version
 pub trait AssetTypeIdentifier {
 	fn is_native_asset(asset: &MultiAsset) -> bool;